@@ -0,0 +1,66 @@
+//! Converts a Bible between formats:
+//!
+//! ```text
+//! cargo run --example convert -- json:tests/fixtures/en_kjv.json md:kjv.md
+//! ```
+//!
+//! Sources are `json:PATH`, `dbl:DIR` or `txt:PATH` (verse per line or
+//! Forge); targets are `json:`, `vpl:`, `jsonl:`,
+//! `html:`, `md:`, `search:` (JSON lines) or `bulk:` (Elasticsearch bulk).
+
+use bible_io::{convert, JsonSchema, PlainTextFormat, SearchExportFormat, SourceSpec, TargetSpec};
+
+fn parse_source(spec: &str) -> Option<SourceSpec> {
+    let (kind, path) = spec.split_once(':')?;
+    let path = path.to_string();
+    match kind {
+        "json" => Some(SourceSpec::Json {
+            path,
+            schema: JsonSchema::Auto,
+        }),
+        "dbl" => Some(SourceSpec::DblBundle { path }),
+        "txt" => Some(SourceSpec::PlainText {
+            path,
+            format: PlainTextFormat::Auto,
+        }),
+        _ => None,
+    }
+}
+
+fn parse_target(spec: &str) -> Option<TargetSpec> {
+    let (kind, path) = spec.split_once(':')?;
+    let path = path.to_string();
+    match kind {
+        "json" => Some(TargetSpec::Json { path }),
+        "vpl" => Some(TargetSpec::VersePerLine { path }),
+        "jsonl" => Some(TargetSpec::JsonLines { path }),
+        "html" => Some(TargetSpec::Html { path }),
+        "md" => Some(TargetSpec::Markdown { path }),
+        "search" => Some(TargetSpec::SearchDocuments {
+            path,
+            format: SearchExportFormat::JsonLines,
+        }),
+        "bulk" => Some(TargetSpec::SearchDocuments {
+            path,
+            format: SearchExportFormat::ElasticsearchBulk,
+        }),
+        _ => None,
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (Some(source), Some(target)) = (
+        args.first().and_then(|s| parse_source(s)),
+        args.get(1).and_then(|s| parse_target(s)),
+    ) else {
+        eprintln!(
+            "usage: convert <json|dbl|txt>:SOURCE <json|vpl|jsonl|html|md|search|bulk>:TARGET"
+        );
+        std::process::exit(2);
+    };
+
+    let summary = convert(&source, &target)?;
+    println!("{summary}");
+    Ok(())
+}
@@ -0,0 +1,280 @@
+//! Highlight anchors that survive corrections to the verse text.
+//!
+//! A byte range into a verse breaks as soon as an upstream data correction
+//! changes the text before it. A [`TextAnchor`] also records the quoted text
+//! and a little of the text around it, so [`Bible::resolve_anchor`] can find
+//! the highlight again after the verse changes. Anchors keep their reference
+//! as text, written as for [`Bible::get_verse_by_reference`], and serialize
+//! to JSON:
+//!
+//! ```json
+//! { "reference": "John 3:16", "quote": "loved the world", "prefix": "For God so ", "suffix": ", that he", "offset": 11 }
+//! ```
+
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bible::{Bible, BibleError},
+    verse::Verse,
+};
+
+/// Characters of surrounding text stored on each side of the quote.
+const CONTEXT_CHARS: usize = 32;
+
+/// A highlighted span of a verse, located by its text rather than only by
+/// offset.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TextAnchor {
+    reference: String,
+    quote: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    prefix: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    suffix: String,
+    offset: usize,
+}
+
+impl TextAnchor {
+    /// Anchors the byte `range` of `verse`'s text. Returns `None` if the
+    /// range is empty, out of bounds, or not on character boundaries.
+    pub fn new(verse: &Verse, range: Range<usize>) -> Option<Self> {
+        let text = verse.text();
+        let quote = text.get(range.clone()).filter(|quote| !quote.is_empty())?;
+        Some(Self {
+            reference: verse.reference().to_string(),
+            quote: quote.to_string(),
+            prefix: last_chars(&text[..range.start], CONTEXT_CHARS).to_string(),
+            suffix: first_chars(&text[range.end..], CONTEXT_CHARS).to_string(),
+            offset: range.start,
+        })
+    }
+
+    /// Returns the reference of the verse.
+    pub fn reference(&self) -> &str {
+        &self.reference
+    }
+
+    /// Returns the highlighted text.
+    pub fn quote(&self) -> &str {
+        &self.quote
+    }
+
+    /// Returns the text just before the quote.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the text just after the quote.
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Returns the byte offset of the quote when the anchor was made.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Locates the anchor in `text`; see [`Bible::resolve_anchor`].
+    pub fn locate(&self, text: &str) -> AnchorMatch {
+        if self.quote.is_empty() {
+            return AnchorMatch::Lost;
+        }
+        let end = self.offset + self.quote.len();
+        if text.get(self.offset..end) == Some(self.quote.as_str()) {
+            return AnchorMatch::Exact(self.offset..end);
+        }
+
+        let exact = text
+            .match_indices(self.quote.as_str())
+            .map(|(start, quote)| start..start + quote.len());
+        if let Some(range) = self.best_candidate(text, exact) {
+            return AnchorMatch::Moved(range);
+        }
+
+        let folded = (0..text.len().saturating_sub(self.quote.len() - 1)).filter_map(|start| {
+            let range = start..start + self.quote.len();
+            text.get(range.clone())
+                .filter(|window| window.eq_ignore_ascii_case(&self.quote))
+                .map(|_| range)
+        });
+        if let Some(range) = self.best_candidate(text, folded) {
+            return AnchorMatch::Moved(range);
+        }
+
+        // The quote itself was edited: take whatever now lies between the
+        // surrounding context.
+        if !self.prefix.is_empty() && !self.suffix.is_empty() {
+            for (start, prefix) in text.match_indices(self.prefix.as_str()) {
+                let from = start + prefix.len();
+                if let Some(length) = text[from..].find(self.suffix.as_str()) {
+                    if length > 0 {
+                        return AnchorMatch::Moved(from..from + length);
+                    }
+                }
+            }
+        }
+        AnchorMatch::Lost
+    }
+
+    /// Picks the candidate whose surroundings best match the stored context,
+    /// preferring the one nearest the old offset on ties.
+    fn best_candidate(
+        &self,
+        text: &str,
+        candidates: impl Iterator<Item = Range<usize>>,
+    ) -> Option<Range<usize>> {
+        candidates.max_by_key(|range| {
+            let before = common_suffix_len(&text[..range.start], &self.prefix);
+            let after = common_prefix_len(&text[range.end..], &self.suffix);
+            (
+                before + after,
+                std::cmp::Reverse(range.start.abs_diff(self.offset)),
+            )
+        })
+    }
+}
+
+/// Where a [`TextAnchor`] was found in the current verse text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnchorMatch {
+    /// The quote is still at its recorded offset.
+    Exact(Range<usize>),
+    /// The text changed and the highlight now covers this byte range.
+    Moved(Range<usize>),
+    /// The highlighted text could not be found.
+    Lost,
+}
+
+impl AnchorMatch {
+    /// Returns the byte range of the highlight, unless it was lost.
+    pub fn range(&self) -> Option<Range<usize>> {
+        match self {
+            AnchorMatch::Exact(range) | AnchorMatch::Moved(range) => Some(range.clone()),
+            AnchorMatch::Lost => None,
+        }
+    }
+}
+
+impl Bible {
+    /// Finds `anchor` in the current text of its verse.
+    ///
+    /// The recorded offset is tried first, then every occurrence of the
+    /// quote (exactly, then ignoring ASCII case) ranked by how well the
+    /// surrounding text matches, and finally the text between the recorded
+    /// prefix and suffix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the anchor's reference does not resolve to a
+    /// verse.
+    pub fn resolve_anchor(&self, anchor: &TextAnchor) -> Result<AnchorMatch, BibleError> {
+        let verse = self.get_verse_by_reference(&anchor.reference)?;
+        Ok(anchor.locate(verse.text()))
+    }
+
+    /// Moves `anchor` to where it is found in this Bible and refreshes its
+    /// quote and context, so it keeps resolving after further updates.
+    /// Returns `false`, leaving the anchor unchanged, if it was lost.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the anchor's reference does not resolve to a
+    /// verse.
+    pub fn reanchor(&self, anchor: &mut TextAnchor) -> Result<bool, BibleError> {
+        let verse = self.get_verse_by_reference(&anchor.reference)?;
+        let Some(range) = anchor.locate(verse.text()).range() else {
+            return Ok(false);
+        };
+        if let Some(updated) = TextAnchor::new(verse, range) {
+            *anchor = TextAnchor {
+                reference: std::mem::take(&mut anchor.reference),
+                ..updated
+            };
+        }
+        Ok(true)
+    }
+}
+
+fn first_chars(text: &str, count: usize) -> &str {
+    let end = text
+        .char_indices()
+        .nth(count)
+        .map_or(text.len(), |(i, _)| i);
+    &text[..end]
+}
+
+fn last_chars(text: &str, count: usize) -> &str {
+    let start = text
+        .char_indices()
+        .rev()
+        .take(count)
+        .last()
+        .map_or(text.len(), |(i, _)| i);
+    &text[start..]
+}
+
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count()
+}
+
+fn common_suffix_len(a: &str, b: &str) -> usize {
+    a.chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(x, y)| x == y)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    fn verse(text: &str) -> Verse {
+        Verse::new(BibleBook::John, 3, 16, text.to_string())
+    }
+
+    #[test]
+    fn test_anchor_survives_text_changes() {
+        let old = verse("For God so loved the world, that he gave his only begotten Son");
+        let anchor = TextAnchor::new(&old, 11..26).unwrap();
+        assert_eq!(anchor.quote(), "loved the world");
+        assert_eq!(anchor.reference(), "John 3:16");
+        assert_eq!(anchor.locate(old.text()), AnchorMatch::Exact(11..26));
+
+        let moved = "For God so greatly loved the world, that he gave his only Son";
+        assert_eq!(anchor.locate(moved), AnchorMatch::Moved(19..34));
+
+        let recased = "For GOD so LOVED THE WORLD, that he gave";
+        assert_eq!(anchor.locate(recased).range(), Some(11..26));
+
+        let reworded = "For God so ✝ cherished the earth, that he gave his only begotten Son";
+        let range = anchor.locate(reworded).range().unwrap();
+        assert_eq!(&reworded[range], "✝ cherished the earth");
+
+        assert_eq!(anchor.locate("Jesus wept."), AnchorMatch::Lost);
+        assert!(TextAnchor::new(&old, 5..5).is_none());
+        assert!(TextAnchor::new(&old, 0..500).is_none());
+    }
+
+    #[test]
+    fn test_repeated_quote_uses_context() {
+        let old = verse("the first day, and the second day, and the third day");
+        let second = old.text().find("the second day").unwrap() + 11;
+        let anchor = TextAnchor::new(&old, second..second + 3).unwrap();
+        assert_eq!(anchor.quote(), "day");
+
+        let edited = "on the first day, and on the second day, and on the third day";
+        let range = anchor.locate(edited).range().unwrap();
+        assert_eq!(
+            &edited[..range.end],
+            "on the first day, and on the second day"
+        );
+
+        let json = simd_json::serde::to_string(&anchor).unwrap();
+        let parsed: TextAnchor = simd_json::serde::from_slice(&mut json.into_bytes()).unwrap();
+        assert_eq!(parsed, anchor);
+    }
+}
@@ -0,0 +1,211 @@
+//! Traditional authorship and approximate dates of each book, for the
+//! introduction screens of study apps.
+//!
+//! The authors are those named by Jewish and Christian tradition, not
+//! settled history; many are disputed by modern scholarship. Dates are
+//! approximate, following the traditional attribution for the Protestant
+//! canon and the commonly given range for the deuterocanonical books.
+//! Interfaces showing this data should show [`Attribution::CAVEAT`] with it.
+
+use std::fmt;
+
+use crate::bible_books_enum::BibleBook;
+
+/// The approximate period in which a book was written.
+///
+/// Years are historical years: negative for BC and positive for AD, with no
+/// year 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompositionPeriod {
+    earliest: i32,
+    latest: i32,
+}
+
+impl CompositionPeriod {
+    const fn new(earliest: i32, latest: i32) -> Self {
+        CompositionPeriod { earliest, latest }
+    }
+
+    /// Returns the earliest likely year of writing.
+    pub fn earliest(&self) -> i32 {
+        self.earliest
+    }
+
+    /// Returns the latest likely year of writing.
+    pub fn latest(&self) -> i32 {
+        self.latest
+    }
+}
+
+/// Writes the period as `"c. 1450–1400 BC"`, `"c. AD 50–70"`, or
+/// `"c. 100 BC – AD 30"`.
+impl fmt::Display for CompositionPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (earliest, latest) = (self.earliest, self.latest);
+        match (earliest < 0, latest < 0) {
+            _ if earliest == latest && earliest < 0 => write!(f, "c. {} BC", -earliest),
+            _ if earliest == latest => write!(f, "c. AD {earliest}"),
+            (true, true) => write!(f, "c. {}\u{2013}{} BC", -earliest, -latest),
+            (false, false) => write!(f, "c. AD {earliest}\u{2013}{latest}"),
+            _ => write!(f, "c. {} BC \u{2013} AD {latest}", -earliest),
+        }
+    }
+}
+
+/// Who tradition holds wrote a book, and roughly when.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Attribution {
+    author: Option<&'static str>,
+    period: CompositionPeriod,
+}
+
+impl Attribution {
+    /// A note to show beside attribution data.
+    pub const CAVEAT: &'static str = "Traditional attribution; authorship and dates \
+        of many books are uncertain and debated by scholars.";
+
+    const fn new(author: Option<&'static str>, earliest: i32, latest: i32) -> Self {
+        Attribution {
+            author,
+            period: CompositionPeriod::new(earliest, latest),
+        }
+    }
+
+    /// Returns the author named by tradition, or `None` for a book that
+    /// tradition leaves anonymous.
+    pub fn author(&self) -> Option<&'static str> {
+        self.author
+    }
+
+    /// Returns the approximate period of writing.
+    pub fn period(&self) -> CompositionPeriod {
+        self.period
+    }
+}
+
+impl BibleBook {
+    /// Returns the traditional author and approximate date of this book. See
+    /// the [module documentation](crate::attribution) for the caveats.
+    pub const fn attribution(&self) -> Attribution {
+        use BibleBook::*;
+        match self {
+            Genesis | Exodus | Leviticus | Numbers | Deuteronomy => {
+                Attribution::new(Some("Moses"), -1450, -1400)
+            }
+            Joshua => Attribution::new(Some("Joshua"), -1400, -1370),
+            Judges | Ruth => Attribution::new(Some("Samuel"), -1050, -1000),
+            FirstSamuel | SecondSamuel => {
+                Attribution::new(Some("Samuel, Nathan and Gad"), -1010, -930)
+            }
+            FirstKings | SecondKings => Attribution::new(Some("Jeremiah"), -560, -540),
+            FirstChronicles | SecondChronicles | Ezra => Attribution::new(Some("Ezra"), -450, -425),
+            Nehemiah => Attribution::new(Some("Nehemiah"), -430, -420),
+            Esther => Attribution::new(Some("Mordecai"), -470, -450),
+            Job => Attribution::new(Some("Moses"), -1450, -1400),
+            Psalms => Attribution::new(Some("David and others"), -1000, -450),
+            Proverbs => Attribution::new(Some("Solomon and others"), -950, -700),
+            Ecclesiastes => Attribution::new(Some("Solomon"), -935, -930),
+            SongOfSolomon => Attribution::new(Some("Solomon"), -965, -960),
+            Isaiah => Attribution::new(Some("Isaiah"), -740, -680),
+            Jeremiah => Attribution::new(Some("Jeremiah"), -627, -580),
+            Lamentations => Attribution::new(Some("Jeremiah"), -586, -586),
+            Ezekiel => Attribution::new(Some("Ezekiel"), -593, -570),
+            Daniel => Attribution::new(Some("Daniel"), -605, -530),
+            Hosea => Attribution::new(Some("Hosea"), -755, -715),
+            Joel => Attribution::new(Some("Joel"), -835, -800),
+            Amos => Attribution::new(Some("Amos"), -760, -750),
+            Obadiah => Attribution::new(Some("Obadiah"), -586, -580),
+            Jonah => Attribution::new(Some("Jonah"), -785, -760),
+            Micah => Attribution::new(Some("Micah"), -735, -700),
+            Nahum => Attribution::new(Some("Nahum"), -663, -612),
+            Habakkuk => Attribution::new(Some("Habakkuk"), -609, -598),
+            Zephaniah => Attribution::new(Some("Zephaniah"), -640, -621),
+            Haggai => Attribution::new(Some("Haggai"), -520, -520),
+            Zechariah => Attribution::new(Some("Zechariah"), -520, -480),
+            Malachi => Attribution::new(Some("Malachi"), -433, -400),
+            Matthew => Attribution::new(Some("Matthew"), 50, 70),
+            Mark => Attribution::new(Some("Mark"), 50, 65),
+            Luke => Attribution::new(Some("Luke"), 60, 62),
+            John => Attribution::new(Some("John"), 85, 95),
+            Acts => Attribution::new(Some("Luke"), 62, 64),
+            Romans => Attribution::new(Some("Paul"), 56, 57),
+            FirstCorinthians => Attribution::new(Some("Paul"), 54, 55),
+            SecondCorinthians => Attribution::new(Some("Paul"), 55, 56),
+            Galatians => Attribution::new(Some("Paul"), 48, 55),
+            Ephesians | Philippians | Colossians | Philemon => {
+                Attribution::new(Some("Paul"), 60, 62)
+            }
+            FirstThessalonians => Attribution::new(Some("Paul"), 50, 51),
+            SecondThessalonians => Attribution::new(Some("Paul"), 51, 52),
+            FirstTimothy | Titus => Attribution::new(Some("Paul"), 62, 64),
+            SecondTimothy => Attribution::new(Some("Paul"), 66, 67),
+            Hebrews => Attribution::new(None, 60, 69),
+            James => Attribution::new(Some("James"), 45, 62),
+            FirstPeter => Attribution::new(Some("Peter"), 62, 64),
+            SecondPeter => Attribution::new(Some("Peter"), 64, 68),
+            FirstJohn | SecondJohn | ThirdJohn => Attribution::new(Some("John"), 85, 95),
+            Jude => Attribution::new(Some("Jude"), 65, 80),
+            Revelation => Attribution::new(Some("John"), 90, 96),
+            Tobit => Attribution::new(None, -225, -175),
+            Judith => Attribution::new(None, -150, -100),
+            Wisdom => Attribution::new(Some("Solomon"), -100, -30),
+            Sirach => Attribution::new(Some("Jesus ben Sira"), -200, -175),
+            Baruch => Attribution::new(Some("Baruch"), -200, -100),
+            FirstMaccabees => Attribution::new(None, -110, -90),
+            SecondMaccabees => Attribution::new(Some("Jason of Cyrene"), -124, -100),
+            EstherAdditions => Attribution::new(None, -150, -75),
+            DanielSongOfThree | DanielSusanna | DanielBelAndTheDragon => {
+                Attribution::new(None, -200, -100)
+            }
+            FirstEsdras => Attribution::new(None, -200, -100),
+            SecondEsdras => Attribution::new(Some("Ezra"), 90, 120),
+            PrayerOfManasseh => Attribution::new(Some("Manasseh"), -150, -50),
+            Psalm151 => Attribution::new(Some("David"), -300, -100),
+            ThirdMaccabees => Attribution::new(None, -100, -30),
+            FourthMaccabees => Attribution::new(None, 20, 70),
+        }
+    }
+
+    /// Returns the author tradition names for this book, such as `"Moses"`
+    /// for Genesis, or `None` if tradition names none.
+    pub const fn traditional_author(&self) -> Option<&'static str> {
+        self.attribution().author
+    }
+
+    /// Returns the approximate period in which this book was written.
+    pub const fn composition_period(&self) -> CompositionPeriod {
+        self.attribution().period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attributions() {
+        assert_eq!(BibleBook::Genesis.traditional_author(), Some("Moses"));
+        assert_eq!(BibleBook::Hebrews.traditional_author(), None);
+        assert_eq!(
+            BibleBook::Genesis.composition_period().to_string(),
+            "c. 1450\u{2013}1400 BC"
+        );
+        assert_eq!(
+            BibleBook::Matthew.composition_period().to_string(),
+            "c. AD 50\u{2013}70"
+        );
+        assert_eq!(
+            BibleBook::Lamentations.composition_period().to_string(),
+            "c. 586 BC"
+        );
+        assert_eq!(
+            CompositionPeriod::new(-100, 30).to_string(),
+            "c. 100 BC \u{2013} AD 30"
+        );
+        for book in BibleBook::ALL {
+            let period = book.composition_period();
+            assert!(period.earliest() <= period.latest(), "{book:?}");
+            assert!(period.earliest() != 0 && period.latest() != 0, "{book:?}");
+        }
+    }
+}
@@ -1,23 +1,70 @@
-use std::{collections::HashMap, error::Error, fmt, fs, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt, fs,
+    str::FromStr,
+    sync::{Arc, Mutex, PoisonError},
+};
 
 use indexmap::IndexMap;
 use phf::phf_map;
 use serde::{de, Deserialize, Deserializer, Serialize};
-use simd_json::serde::from_slice as simd_from_slice;
+use simd_json::serde::to_string_pretty as simd_to_string_pretty;
 
 use crate::{
-    bible_books_enum::BibleBook, book::Book, chapter::Chapter, search_index::SearchIndex,
+    bible_books_enum::{BibleBook, Canon, CanonProfile, DeuterocanonPlacement},
+    book::{Book, OutlineEntry},
+    book_id::BookId,
+    chapter::{take_bracketed_superscription, Chapter, ParagraphMarker},
+    entities::EntityIndex,
+    instrumentation::{HookScope, Stage, StageTimer},
+    json_schema::{self, is_valid_number, numbered_to_positions, JsonSchema, NumberedChapters},
+    load_options::{LoadOptions, LoadProgress},
+    provenance::Provenance,
+    query_cache::QueryCache,
+    reference::{Reference, ReferenceRange},
+    reference_parser::ReferenceParsing,
+    render_cache::RenderCache,
+    search_index::{SearchBackend, SearchIndex, SearchIndexOptions},
+    snapshot,
     verse::Verse,
+    verse_ref::{ChapterRef, VerseRef},
 };
 
-/// Errors that can occur when accessing Bible content.
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Errors that can occur when loading or accessing Bible content.
+///
+/// New variants may be added in minor releases, so matches need a wildcard arm.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum BibleError {
+    /// A Bible file could not be read or parsed. The underlying I/O or JSON
+    /// error is available through [`Error::source`].
+    LoadError {
+        path: String,
+        source: Arc<dyn Error + Send + Sync>,
+    },
+    /// A Bible could not be serialized or written. The underlying I/O or
+    /// JSON error is available through [`Error::source`].
+    SaveError {
+        path: String,
+        source: Arc<dyn Error + Send + Sync>,
+    },
     /// The requested book is not present in the specified Bible translation.
+    /// `suggestions` lists the titles of books whose names are spelled most
+    /// like the request, closest first, and may be empty.
     BookNotFound {
         book_abbrev: String,
         book_name: String,
         translation: String,
+        suggestions: Vec<String>,
+    },
+    /// The requested book belongs to a canon that the translation does not
+    /// include at all, as opposed to a book missing from the data file.
+    CanonMismatch {
+        book_abbrev: String,
+        book_name: String,
+        canon: Canon,
+        translation: String,
     },
     /// The requested chapter number does not exist in the specified book.
     ChapterOutOfBounds {
@@ -34,22 +81,61 @@ pub enum BibleError {
         verse: usize,
         max_verse: usize,
     },
+    /// The requested verse lies within the chapter but is omitted by the
+    /// translation, as many modern translations omit Mark 15:28.
+    VerseMissing {
+        book_abbrev: String,
+        book_name: String,
+        chapter: usize,
+        verse: usize,
+    },
     /// The provided reference string could not be parsed.
     InvalidReference { input: String },
+    /// A book appears in more than one part passed to [`Bible::merge`] under
+    /// [`MergePolicy::Error`].
+    MergeConflict {
+        book_abbrev: String,
+        book_name: String,
+        translation: String,
+    },
 }
 
 impl fmt::Display for BibleError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            BibleError::LoadError { path, source } => {
+                write!(f, "Failed to load Bible from '{}': {}", path, source)
+            }
+            BibleError::SaveError { path, source } => {
+                write!(f, "Failed to save Bible to '{}': {}", path, source)
+            }
             BibleError::BookNotFound {
                 book_abbrev,
                 book_name,
                 translation,
+                suggestions,
             } => {
                 write!(
                     f,
                     "Book {} ('{}') not found in the '{}' Bible translation",
                     book_name, book_abbrev, translation
+                )?;
+                match suggestions.as_slice() {
+                    [] => Ok(()),
+                    [only] => write!(f, "; did you mean {only}?"),
+                    [rest @ .., last] => write!(f, "; did you mean {} or {last}?", rest.join(", ")),
+                }
+            }
+            BibleError::CanonMismatch {
+                book_abbrev,
+                book_name,
+                canon,
+                translation,
+            } => {
+                write!(
+                    f,
+                    "Book {} ('{}') belongs to the {}, which the '{}' Bible translation does not include",
+                    book_name, book_abbrev, canon, translation
                 )
             }
             BibleError::ChapterOutOfBounds {
@@ -77,33 +163,220 @@ impl fmt::Display for BibleError {
                     verse, book_name, book_abbrev, chapter, max_verse
                 )
             }
+            BibleError::VerseMissing {
+                book_abbrev,
+                book_name,
+                chapter,
+                verse,
+            } => {
+                write!(
+                    f,
+                    "Verse {} of book {} ('{}') chapter {} is omitted by this translation",
+                    verse, book_name, book_abbrev, chapter
+                )
+            }
             BibleError::InvalidReference { input } => {
                 write!(f, "Invalid reference: '{}'", input)
             }
+            BibleError::MergeConflict {
+                book_abbrev,
+                book_name,
+                translation,
+            } => {
+                write!(
+                    f,
+                    "Book {} ('{}') appears in more than one part of the '{}' Bible translation",
+                    book_name, book_abbrev, translation
+                )
+            }
+        }
+    }
+}
+
+impl Error for BibleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BibleError::LoadError { source, .. } | BibleError::SaveError { source, .. } => {
+                Some(source.as_ref())
+            }
+            _ => None,
         }
     }
 }
 
-impl Error for BibleError {}
+impl BibleError {
+    pub(crate) fn load_error(path: &str, source: impl Error + Send + Sync + 'static) -> Self {
+        BibleError::LoadError {
+            path: path.to_string(),
+            source: Arc::new(source),
+        }
+    }
 
-#[derive(Deserialize, Debug)]
-struct BibleFileRoot {
-    id: String,
-    name: String,
-    description: String,
-    language: String,
-    books: IndexMap<String, FileDataEntry>,
+    pub(crate) fn save_error(path: &str, source: impl Error + Send + Sync + 'static) -> Self {
+        BibleError::SaveError {
+            path: path.to_string(),
+            source: Arc::new(source),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub(crate) struct BibleFileRoot {
+    #[serde(default)]
+    pub(crate) id: String,
+    #[serde(default)]
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) description: String,
+    #[serde(default)]
+    pub(crate) language: String,
+    #[serde(flatten)]
+    pub(crate) metadata: BibleMetadata,
+    pub(crate) books: IndexMap<String, FileDataEntry>,
+}
+
+/// Optional descriptive metadata carried alongside the Bible text.
+///
+/// Redistribution tooling relies on these fields to keep license and source
+/// information attached to the text, so they are preserved on serialization.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct BibleMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copyright: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+    /// Versification scheme the text follows (e.g. `"kjv"`, `"lxx"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub versification: Option<String>,
+    /// Script direction declared by the data file, `"ltr"` or `"rtl"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direction: Option<TextDirection>,
+}
+
+/// Writing direction of a translation's script.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum TextDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl TextDirection {
+    /// Guesses the direction from a language code (`"he"`, `"ar-EG"`) or an
+    /// English language name (`"Hebrew"`). Unknown languages are left-to-right.
+    pub fn from_language(language: &str) -> Self {
+        const RTL_LANGUAGES: &[&str] = &[
+            "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ps", "sd", "syr", "ug", "ur", "yi",
+            "arabic", "aramaic", "dhivehi", "farsi", "hebrew", "pashto", "persian", "sindhi",
+            "syriac", "uyghur", "urdu", "yiddish",
+        ];
+
+        let primary = language
+            .trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if RTL_LANGUAGES.contains(&primary.as_str()) {
+            TextDirection::Rtl
+        } else {
+            TextDirection::Ltr
+        }
+    }
+
+    /// Returns the value used in HTML `dir` attributes.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            TextDirection::Ltr => "ltr",
+            TextDirection::Rtl => "rtl",
+        }
+    }
+}
+
+impl fmt::Display for TextDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Internal structure for deserializing JSON data from Bible files.
-#[derive(Serialize, Deserialize, Debug)]
-struct FileDataEntry {
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub(crate) struct FileDataEntry {
     #[serde(deserialize_with = "deserialize_chapters")]
-    chapters: Vec<Vec<String>>,
-    name: String,
+    pub(crate) chapters: Vec<Vec<String>>,
+    pub(crate) name: String,
+    /// Alternative names or abbreviations the translation uses for this book.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) aliases: Vec<String>,
+    /// Chapter superscriptions (e.g. Psalm titles) keyed by chapter number.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub(crate) superscriptions: IndexMap<String, String>,
+    /// Paragraph start markers keyed by chapter number.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub(crate) paragraphs: IndexMap<String, Vec<ParagraphMarker>>,
+    /// Verse bridges keyed by chapter number, each the first and last verse
+    /// combined. The bridge text is stored at the first verse and the other
+    /// verses are left empty; bridges that do not fit the verses this way
+    /// are ignored.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub(crate) bridges: IndexMap<String, Vec<[usize; 2]>>,
+    /// Introduction shown before the book text in study Bibles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) introduction: Option<String>,
+    /// Section titles with the verses they cover.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) outline: Vec<OutlineEntry>,
+}
+
+/// A verse bridge from a data file that cannot be applied, with the reason.
+pub(crate) type RejectedBridge = ([usize; 2], &'static str);
+
+/// Splits a chapter's verse bridges into those that can be applied, as last
+/// verses keyed by first verse, and those that cannot, each with the reason.
+///
+/// A bridge must start at a verse with text, and every other verse it
+/// combines must be an empty placeholder within the chapter that no other
+/// bridge claims.
+pub(crate) fn check_bridges(
+    verses: &[String],
+    bridges: &[[usize; 2]],
+) -> (HashMap<usize, usize>, Vec<RejectedBridge>) {
+    let has_text = |n: usize| verses.get(n - 1).is_some_and(|text| !text.is_empty());
+    let mut ends = HashMap::new();
+    let mut claimed = HashSet::new();
+    let mut rejected = Vec::new();
+    for &[first, last] in bridges {
+        let reason = if first == 0 || last <= first {
+            Some("does not combine two or more verses")
+        } else if last > verses.len() {
+            Some("runs past the end of the chapter")
+        } else if (first..=last).any(|n| claimed.contains(&n)) {
+            Some("overlaps another bridge")
+        } else if !has_text(first) {
+            Some("starts at a verse with no text")
+        } else if (first + 1..=last).any(has_text) {
+            Some("covers a verse with text")
+        } else {
+            None
+        };
+        match reason {
+            Some(reason) => rejected.push(([first, last], reason)),
+            None => {
+                claimed.extend(first..=last);
+                ends.insert(first, last);
+            }
+        }
+    }
+    (ends, rejected)
 }
 
-fn deserialize_chapters<'de, D>(deserializer: D) -> Result<Vec<Vec<String>>, D::Error>
+pub(crate) fn deserialize_chapters<'de, D>(deserializer: D) -> Result<Vec<Vec<String>>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -118,47 +391,234 @@ where
 
     match helper {
         ChaptersHelper::Array(chapters) => Ok(chapters),
-        ChaptersHelper::Map(map) => map
-            .into_iter()
-            .map(|(chapter_key, verses)| {
-                let chapter_num = chapter_key.parse::<usize>().map_err(|_| {
-                    de::Error::custom(format!(
-                        "Invalid chapter key '{}': expected positive integer",
-                        chapter_key
-                    ))
-                })?;
-
-                let mut verses_vec = verses
-                    .into_iter()
-                    .map(|(verse_key, text)| {
-                        let verse_num = verse_key.parse::<usize>().map_err(|_| {
-                            de::Error::custom(format!(
-                                "Invalid verse key '{}': expected positive integer",
-                                verse_key
-                            ))
-                        })?;
-
-                        Ok((verse_num, text))
+        ChaptersHelper::Map(map) => {
+            // Numbers are kept, so verses a translation omits stay missing
+            // rather than shifting later verses down.
+            let number = |key: &str, kind: &str| {
+                key.parse()
+                    .ok()
+                    .filter(|&n| is_valid_number(n))
+                    .ok_or_else(|| {
+                        de::Error::custom(format!(
+                            "Invalid {kind} key '{key}': expected positive integer"
+                        ))
                     })
-                    .collect::<Result<Vec<_>, D::Error>>()?;
+            };
+            let mut chapters = NumberedChapters::new();
+            for (chapter_key, verses) in map {
+                let numbered = chapters
+                    .entry(number(&chapter_key, "chapter")?)
+                    .or_default();
+                for (verse_key, text) in verses {
+                    numbered.insert(number(&verse_key, "verse")?, text);
+                }
+            }
+            Ok(numbered_to_positions(chapters))
+        }
+    }
+}
 
-                verses_vec.sort_by_key(|(verse_num, _)| *verse_num);
+/// Common alternative book abbreviations accepted in references and book lookups.
+pub(crate) static ALT_ABBREVS: phf::Map<&'static str, BibleBook> = phf_map! {
+    // --- Protestant (66) ---
+    "gen" => BibleBook::Genesis,
+    "ge" => BibleBook::Genesis,
+    "exo" => BibleBook::Exodus,
+    "exod" => BibleBook::Exodus,
+    "lev" => BibleBook::Leviticus,
+    "le" => BibleBook::Leviticus,
+    "num" => BibleBook::Numbers,
+    "nu" => BibleBook::Numbers,
+    "deut" => BibleBook::Deuteronomy,
+    "deu" => BibleBook::Deuteronomy,
+    "jos" => BibleBook::Joshua,
+    "josh" => BibleBook::Joshua,
+    "jdg" => BibleBook::Judges,
+    "judg" => BibleBook::Judges,
+    "rut" => BibleBook::Ruth,
+    "ru" => BibleBook::Ruth,
+    "1sa" => BibleBook::FirstSamuel,
+    "1sam" => BibleBook::FirstSamuel,
+    "2sa" => BibleBook::SecondSamuel,
+    "2sam" => BibleBook::SecondSamuel,
+    "1ki" => BibleBook::FirstKings,
+    "1kings" => BibleBook::FirstKings,
+    "2ki" => BibleBook::SecondKings,
+    "2kings" => BibleBook::SecondKings,
+    "1ch" => BibleBook::FirstChronicles,
+    "1chr" => BibleBook::FirstChronicles,
+    "2ch" => BibleBook::SecondChronicles,
+    "2chr" => BibleBook::SecondChronicles,
+    "ezr" => BibleBook::Ezra,
+    "ezra" => BibleBook::Ezra,
+    "neh" => BibleBook::Nehemiah,
+    "ne" => BibleBook::Nehemiah,
+    "est" => BibleBook::Esther,
+    "esth" => BibleBook::Esther,
+    "job" => BibleBook::Job,
+    "jb" => BibleBook::Job,
+    "psa" => BibleBook::Psalms,
+    "psalm" => BibleBook::Psalms,
+    "psalms" => BibleBook::Psalms,
+    "pro" => BibleBook::Proverbs,
+    "prov" => BibleBook::Proverbs,
+    "ecc" => BibleBook::Ecclesiastes,
+    "eccl" => BibleBook::Ecclesiastes,
+    "sos" => BibleBook::SongOfSolomon,
+    "song" => BibleBook::SongOfSolomon,
+    "songofsongs" => BibleBook::SongOfSolomon,
+    "isa" => BibleBook::Isaiah,
+    "jer" => BibleBook::Jeremiah,
+    "lam" => BibleBook::Lamentations,
+    "ezek" => BibleBook::Ezekiel,
+    "eze" => BibleBook::Ezekiel,
+    "dan" => BibleBook::Daniel,
+    "da" => BibleBook::Daniel,
+    "hos" => BibleBook::Hosea,
+    "joe" => BibleBook::Joel,
+    "amo" => BibleBook::Amos,
+    "oba" => BibleBook::Obadiah,
+    "obad" => BibleBook::Obadiah,
+    "jon" => BibleBook::Jonah,
+    "jnh" => BibleBook::Jonah,
+    "mic" => BibleBook::Micah,
+    "nah" => BibleBook::Nahum,
+    "hab" => BibleBook::Habakkuk,
+    "zep" => BibleBook::Zephaniah,
+    "zeph" => BibleBook::Zephaniah,
+    "hag" => BibleBook::Haggai,
+    "zec" => BibleBook::Zechariah,
+    "zech" => BibleBook::Zechariah,
+    "mal" => BibleBook::Malachi,
+    "mat" => BibleBook::Matthew,
+    "matt" => BibleBook::Matthew,
+    "mar" => BibleBook::Mark,
+    "mrk" => BibleBook::Mark,
+    "luk" => BibleBook::Luke,
+    "luke" => BibleBook::Luke,
+    "john" => BibleBook::John,
+    "jhn" => BibleBook::John,
+    "jn" => BibleBook::John,
+    "acts" => BibleBook::Acts,
+    "ac" => BibleBook::Acts,
+    "rom" => BibleBook::Romans,
+    "1co" => BibleBook::FirstCorinthians,
+    "1cor" => BibleBook::FirstCorinthians,
+    "2co" => BibleBook::SecondCorinthians,
+    "2cor" => BibleBook::SecondCorinthians,
+    "gal" => BibleBook::Galatians,
+    "eph" => BibleBook::Ephesians,
+    "phil" => BibleBook::Philippians,
+    "php" => BibleBook::Philippians,
+    "col" => BibleBook::Colossians,
+    "1th" => BibleBook::FirstThessalonians,
+    "1thes" => BibleBook::FirstThessalonians,
+    "2th" => BibleBook::SecondThessalonians,
+    "2thes" => BibleBook::SecondThessalonians,
+    "1ti" => BibleBook::FirstTimothy,
+    "1tim" => BibleBook::FirstTimothy,
+    "2ti" => BibleBook::SecondTimothy,
+    "2tim" => BibleBook::SecondTimothy,
+    "tit" => BibleBook::Titus,
+    "phm" => BibleBook::Philemon,
+    "phlm" => BibleBook::Philemon,
+    "philemon" => BibleBook::Philemon,
+    "heb" => BibleBook::Hebrews,
+    "jas" => BibleBook::James,
+    "jam" => BibleBook::James,
+    "1pe" => BibleBook::FirstPeter,
+    "1pet" => BibleBook::FirstPeter,
+    "2pe" => BibleBook::SecondPeter,
+    "2pet" => BibleBook::SecondPeter,
+    "1jn" => BibleBook::FirstJohn,
+    "1joh" => BibleBook::FirstJohn,
+    "2jn" => BibleBook::SecondJohn,
+    "2joh" => BibleBook::SecondJohn,
+    "3jn" => BibleBook::ThirdJohn,
+    "3joh" => BibleBook::ThirdJohn,
+    "jud" => BibleBook::Jude,
+    "jude" => BibleBook::Jude,
+    "rev" => BibleBook::Revelation,
+    "revelation" => BibleBook::Revelation,
+    // --- Catholic Deuterocanon ---
+    "tob" => BibleBook::Tobit,
+    "jdt" => BibleBook::Judith,
+    "wis" => BibleBook::Wisdom,
+    "sir" => BibleBook::Sirach,
+    "bar" => BibleBook::Baruch,
+    "1mac" => BibleBook::FirstMaccabees,
+    "2mac" => BibleBook::SecondMaccabees,
+    "estg" => BibleBook::EstherAdditions,
+    "addesth" => BibleBook::EstherAdditions,
+    "dan3" => BibleBook::DanielSongOfThree,
+    "sus" => BibleBook::DanielSusanna,
+    "bel" => BibleBook::DanielBelAndTheDragon,
+    // --- Eastern Orthodox Additions ---
+    "1esd" => BibleBook::FirstEsdras,
+    "2esd" => BibleBook::SecondEsdras,
+    "man" => BibleBook::PrayerOfManasseh,
+    "prman" => BibleBook::PrayerOfManasseh,
+    "ps151" => BibleBook::Psalm151,
+    "3mac" => BibleBook::ThirdMaccabees,
+    "4mac" => BibleBook::FourthMaccabees,
+};
 
-                let verses = verses_vec
-                    .into_iter()
-                    .map(|(_, text)| text)
-                    .collect::<Vec<_>>();
+/// How [`Bible::merge`] handles a book present in more than one part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MergePolicy {
+    /// Fail with [`BibleError::MergeConflict`].
+    #[default]
+    Error,
+    /// Keep the book from the earliest part.
+    PreferFirst,
+    /// Keep the book from the latest part.
+    PreferLast,
+}
 
-                Ok((chapter_num, verses))
-            })
-            .collect::<Result<Vec<_>, D::Error>>()
-            .map(|mut chapters| {
-                chapters.sort_by_key(|(chapter_num, _)| *chapter_num);
-                chapters
-                    .into_iter()
-                    .map(|(_, verses)| verses)
-                    .collect::<Vec<_>>()
-            }),
+/// Number of recent searches cached by default; see [`Bible::set_query_cache_size`].
+const DEFAULT_QUERY_CACHE_SIZE: usize = 32;
+
+/// Recent hit lists as (book position, chapter, verse), keyed by the
+/// normalized query and the searched book positions.
+type SearchCache = QueryCache<(String, Vec<usize>), Vec<(usize, usize, usize)>>;
+
+/// Search index shards and recent results.
+#[derive(Debug, Clone)]
+struct SearchCaches {
+    /// Search index shards keyed by book position, built the first time a
+    /// search touches that book.
+    shards: HashMap<usize, SearchIndex>,
+    results: SearchCache,
+}
+
+/// Search caches shared behind a lock, so searching can stay `&self`.
+#[derive(Debug)]
+struct SearchState(Mutex<SearchCaches>);
+
+impl SearchState {
+    fn new(cache_size: usize) -> Self {
+        SearchState(Mutex::new(SearchCaches {
+            shards: HashMap::new(),
+            results: QueryCache::new(cache_size),
+        }))
+    }
+
+    fn with<T>(&self, f: impl FnOnce(&mut SearchCaches) -> T) -> T {
+        f(&mut self.0.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Discards every shard and cached result.
+    fn clear(&self) {
+        self.with(|caches| {
+            caches.shards.clear();
+            caches.results.clear();
+        });
+    }
+}
+
+impl Clone for SearchState {
+    fn clone(&self) -> Self {
+        SearchState(Mutex::new(self.with(|caches| caches.clone())))
     }
 }
 
@@ -169,14 +629,19 @@ where
 pub struct Bible {
     books: Vec<Book>,
     index_by_abbrev: HashMap<String, usize>,
-
-    /// Lazily constructed search index for verse lookups.
-    search_index: Option<SearchIndex>,
+    search: SearchState,
+    search_options: SearchIndexOptions,
+    pub(crate) render_cache: RenderCache,
 
     id: String,
     name: String,
     description: String,
     language: String,
+    metadata: BibleMetadata,
+    provenance: Provenance,
+    deuterocanon_placement: DeuterocanonPlacement,
+    reference_parsing: ReferenceParsing,
+    pub(crate) entities: EntityIndex,
 }
 
 impl Bible {
@@ -196,38 +661,339 @@ impl Bible {
         &self.language
     }
 
-    /// Returns a slice of all books in this Bible.
+    /// Returns the optional metadata (copyright, license, etc.) of this Bible.
+    pub fn metadata(&self) -> &BibleMetadata {
+        &self.metadata
+    }
+
+    pub fn copyright(&self) -> Option<&str> {
+        self.metadata.copyright.as_deref()
+    }
+
+    pub fn license(&self) -> Option<&str> {
+        self.metadata.license.as_deref()
+    }
+
+    pub fn publisher(&self) -> Option<&str> {
+        self.metadata.publisher.as_deref()
+    }
+
+    pub fn source_url(&self) -> Option<&str> {
+        self.metadata.source_url.as_deref()
+    }
+
+    pub fn versification(&self) -> Option<&str> {
+        self.metadata.versification.as_deref()
+    }
+
+    /// Returns the writing direction of this translation.
+    ///
+    /// The `direction` metadata field takes precedence; otherwise the direction
+    /// is detected from the language.
+    pub fn direction(&self) -> TextDirection {
+        self.metadata
+            .direction
+            .unwrap_or_else(|| TextDirection::from_language(&self.language))
+    }
+
+    /// Returns the path or URL this Bible was loaded from and when.
+    pub fn provenance(&self) -> &Provenance {
+        &self.provenance
+    }
+
+    /// Returns a slice of all books in this Bible, in file order, or in
+    /// canonical order after [`Bible::canonicalize`].
     pub fn books(&self) -> &[Book] {
         &self.books
     }
 
-    /// Returns a book by its BibleBook enum value.
-    pub fn get_book(&self, book: BibleBook) -> Result<&Book, BibleError> {
-        self.get_book_by_abbrev(book.as_str())
+    /// Returns where deuterocanonical books are placed when ordering books.
+    pub fn deuterocanon_placement(&self) -> DeuterocanonPlacement {
+        self.deuterocanon_placement
+    }
+
+    /// Sets where deuterocanonical books are placed by
+    /// [`Bible::books_in_canonical_order`], book navigation, and
+    /// [`Bible::to_json`]. The books themselves are not moved.
+    pub fn with_deuterocanon_placement(mut self, placement: DeuterocanonPlacement) -> Self {
+        self.deuterocanon_placement = placement;
+        self
+    }
+
+    /// Returns the books in canonical order under this Bible's
+    /// [`DeuterocanonPlacement`], with custom books last.
+    pub fn books_in_canonical_order(&self) -> Vec<&Book> {
+        let mut books: Vec<&Book> = self.books.iter().collect();
+        books.sort_by(|a, b| self.canonical_cmp(a, b));
+        books
+    }
+
+    /// Returns the book after `book` in canonical order, or `None` at the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::BookNotFound`] if `book` is not in this Bible.
+    pub fn next_book(&self, book: impl Into<BookId>) -> Result<Option<&Book>, BibleError> {
+        let (order, position) = self.canonical_position(book)?;
+        Ok(order.get(position + 1).copied())
+    }
+
+    /// Returns the book before `book` in canonical order, or `None` at the start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::BookNotFound`] if `book` is not in this Bible.
+    pub fn previous_book(&self, book: impl Into<BookId>) -> Result<Option<&Book>, BibleError> {
+        let (order, position) = self.canonical_position(book)?;
+        Ok(position.checked_sub(1).map(|p| order[p]))
+    }
+
+    fn canonical_position(
+        &self,
+        book: impl Into<BookId>,
+    ) -> Result<(Vec<&Book>, usize), BibleError> {
+        let book = self.get_book(book)?;
+        let order = self.books_in_canonical_order();
+        let position = order
+            .iter()
+            .position(|b| std::ptr::eq(*b, book))
+            .unwrap_or_default();
+        Ok((order, position))
+    }
+
+    fn canonical_cmp(&self, a: &Book, b: &Book) -> std::cmp::Ordering {
+        self.deuterocanon_placement.compare(a.id(), b.id())
+    }
+
+    /// Returns a book by its [`BibleBook`] or [`BookId`].
+    pub fn get_book(&self, book: impl Into<BookId>) -> Result<&Book, BibleError> {
+        self.get_book_by_abbrev(book.into().as_str())
     }
 
     /// Returns a book by its abbreviation string.
+    ///
+    /// Besides the book's key in the JSON data, this accepts the standard
+    /// abbreviation, common alternatives ("1sa", "gen"), the full English name,
+    /// and the translation's own title and aliases, all case-insensitively.
+    ///
+    /// Lookups do not allocate for lowercase input or for ASCII input of up to
+    /// 32 bytes.
     pub fn get_book_by_abbrev(&self, abbrev: &str) -> Result<&Book, BibleError> {
-        let key = abbrev.to_ascii_lowercase();
-        self.index_by_abbrev
-            .get(key.as_str())
-            .and_then(|&i| self.books.get(i))
-            .ok_or_else(|| {
-                let book_name = BibleBook::from_str(&key)
-                    .map(|b| b.full_name().to_string())
-                    .unwrap_or_else(|_| key.clone());
-                BibleError::BookNotFound {
-                    book_abbrev: key.clone(),
-                    book_name,
-                    translation: self.name.clone(),
+        self.book_index(abbrev)
+            .and_then(|i| self.books.get(i))
+            .ok_or_else(|| self.book_not_found(abbrev))
+    }
+
+    fn book_not_found(&self, abbrev: &str) -> BibleError {
+        let key = abbrev.to_lowercase();
+        let book_name = BibleBook::from_str(&key)
+            .map(|b| b.full_name().to_string())
+            .unwrap_or_else(|_| key.clone());
+        BibleError::BookNotFound {
+            suggestions: self.book_suggestions(&key),
+            book_abbrev: key,
+            book_name,
+            translation: self.name.clone(),
+        }
+    }
+
+    /// Returns the titles of up to three books with a name, abbreviation or
+    /// alias within a few edits of `input`, closest first.
+    pub(crate) fn book_suggestions(&self, input: &str) -> Vec<String> {
+        const MAX_SUGGESTIONS: usize = 3;
+
+        let input = input.trim().to_lowercase();
+        let max_distance = (input.chars().count() / 3).max(1);
+        let mut best: HashMap<usize, usize> = HashMap::new();
+        for (name, &i) in &self.index_by_abbrev {
+            let distance = edit_distance(&input, name);
+            if distance <= max_distance {
+                let entry = best.entry(i).or_insert(distance);
+                *entry = (*entry).min(distance);
+            }
+        }
+        let mut ranked: Vec<(usize, usize)> = best.into_iter().map(|(i, d)| (d, i)).collect();
+        ranked.sort_unstable();
+        ranked
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, i)| self.books[i].title().to_string())
+            .collect()
+    }
+
+    /// Looks up a name in the lowercase book index, lowercasing short ASCII
+    /// inputs in a stack buffer instead of a new `String`.
+    fn book_index(&self, name: &str) -> Option<usize> {
+        const STACK_LEN: usize = 32;
+
+        if !name.chars().any(char::is_uppercase) {
+            return self.index_by_abbrev.get(name).copied();
+        }
+        if name.is_ascii() && name.len() <= STACK_LEN {
+            let mut buf = [0u8; STACK_LEN];
+            let lower = &mut buf[..name.len()];
+            lower.copy_from_slice(name.as_bytes());
+            lower.make_ascii_lowercase();
+            // Lowercasing ASCII keeps the bytes valid UTF-8.
+            let lower = std::str::from_utf8(lower).ok()?;
+            return self.index_by_abbrev.get(lower).copied();
+        }
+        self.index_by_abbrev.get(&name.to_lowercase()).copied()
+    }
+
+    /// Returns a book, falling back to its closest equivalent when the
+    /// translation lacks it.
+    ///
+    /// For example, [`BibleBook::Psalm151`] resolves to Psalms in a translation
+    /// without Psalm 151; check [`Book::id`] to see which book was returned.
+    /// When no equivalent exists either, the error is
+    /// [`BibleError::CanonMismatch`] if the translation has no books of the
+    /// requested book's canon, or [`BibleError::BookNotFound`] if the data
+    /// file is merely missing a book of a canon it otherwise covers.
+    pub fn get_book_or_equivalent(&self, book: BibleBook) -> Result<&Book, BibleError> {
+        if let Ok(found) = self.get_book(book) {
+            return Ok(found);
+        }
+        if let Some(found) = book
+            .closest_equivalent()
+            .and_then(|equivalent| self.get_book(equivalent).ok())
+        {
+            return Ok(found);
+        }
+
+        let canon = book.canon();
+        let covers_canon = self
+            .books
+            .iter()
+            .any(|b| b.id().known().is_some_and(|known| known.canon() == canon));
+        if covers_canon {
+            self.get_book(book)
+        } else {
+            Err(BibleError::CanonMismatch {
+                book_abbrev: book.as_str().to_string(),
+                book_name: book.full_name().to_string(),
+                canon,
+                translation: self.name.clone(),
+            })
+        }
+    }
+
+    /// Returns a copy of this Bible containing only the books of `profile`,
+    /// such as `Canon::Protestant`, in canonical order.
+    ///
+    /// Books outside the standard book list are dropped.
+    pub fn filter_canon(&self, profile: impl Into<CanonProfile>) -> Bible {
+        let profile = profile.into();
+        let mut books: Vec<Book> = self
+            .books
+            .iter()
+            .filter(|b| b.id().known().is_some_and(|known| profile.includes(known)))
+            .cloned()
+            .collect();
+        books.sort_by(|a, b| self.canonical_cmp(a, b));
+        self.with_books(books)
+    }
+
+    /// Returns a smaller Bible containing only the verses within `ranges`,
+    /// such as just the Gospels, with the same name and metadata.
+    ///
+    /// Verses keep their numbers, so `"John 3:16"` resolves the same way in
+    /// the subset. Chapters and books left without verses are dropped.
+    pub fn subset(&self, ranges: &[ReferenceRange]) -> Bible {
+        let books = self
+            .books
+            .iter()
+            .filter(|book| {
+                ranges
+                    .iter()
+                    .any(|r| r.start().book() <= book.id() && book.id() <= r.end().book())
+            })
+            .filter_map(|book| {
+                let mut book = book.clone();
+                let chapters = book.chapters_mut();
+                for chapter in chapters.iter_mut() {
+                    chapter.retain_verses(|verse| {
+                        let reference = verse.reference();
+                        ranges.iter().any(|r| r.contains(&reference))
+                    });
                 }
+                chapters.retain(|c| !c.get_verses().is_empty());
+                (!chapters.is_empty()).then_some(book)
             })
+            .collect();
+        self.with_books(books)
+    }
+
+    /// Combines partial Bibles, such as a translation distributed as one JSON
+    /// file per book or testament, into one.
+    ///
+    /// Names, metadata, and search options come from the first part. Books are
+    /// placed in canonical order; a book found in several parts is resolved
+    /// by `policy`. Merging no parts yields an empty Bible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::MergeConflict`] if a book appears in more than
+    /// one part and `policy` is [`MergePolicy::Error`].
+    pub fn merge(parts: Vec<Bible>, policy: MergePolicy) -> Result<Bible, BibleError> {
+        let mut parts = parts.into_iter();
+        let Some(mut first) = parts.next() else {
+            return Ok(Bible::from_books(
+                Vec::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                BibleMetadata::default(),
+            ));
+        };
+
+        let mut books = std::mem::take(&mut first.books);
+        for part in parts {
+            for book in part.books {
+                match books.iter().position(|b| b.id() == book.id()) {
+                    None => books.push(book),
+                    Some(slot) => match policy {
+                        MergePolicy::Error => {
+                            return Err(BibleError::MergeConflict {
+                                book_abbrev: book.abbrev().to_string(),
+                                book_name: book.title().to_string(),
+                                translation: first.name.clone(),
+                            })
+                        }
+                        MergePolicy::PreferFirst => {}
+                        MergePolicy::PreferLast => books[slot] = book,
+                    },
+                }
+            }
+        }
+        books.sort_by(|a, b| first.canonical_cmp(a, b));
+        Ok(first.with_books(books))
+    }
+
+    /// Builds a Bible from `books` with this Bible's names, metadata,
+    /// provenance, and search options.
+    fn with_books(&self, books: Vec<Book>) -> Bible {
+        let mut bible = Bible::from_books(
+            books,
+            self.id.clone(),
+            self.name.clone(),
+            self.description.clone(),
+            self.language.clone(),
+            self.metadata.clone(),
+        );
+        bible.search_options = self.search_options.clone();
+        bible.provenance = self.provenance.clone();
+        bible.deuterocanon_placement = self.deuterocanon_placement;
+        bible.reference_parsing = self.reference_parsing;
+        bible.entities = self.entities.clone();
+        bible
     }
 
     /// Returns all verses from a specific book and chapter.
     pub fn get_verses(
         &self,
-        book: BibleBook,
+        book: impl Into<BookId>,
         chapter_number: usize,
     ) -> Result<&[Verse], BibleError> {
         self.get_book(book)?.get_verses(chapter_number)
@@ -236,324 +1002,482 @@ impl Bible {
     /// Returns a specific verse by book, chapter, and verse number.
     pub fn get_verse(
         &self,
-        book: BibleBook,
+        book: impl Into<BookId>,
         chapter_number: usize,
         verse_number: usize,
     ) -> Result<&Verse, BibleError> {
         self.get_book(book)?.get_verse(chapter_number, verse_number)
     }
 
+    /// Returns the verse at `reference` together with up to `before` preceding
+    /// and `after` following verses from the same book.
+    ///
+    /// The window crosses chapter boundaries, so the context of Genesis 2:1
+    /// includes the end of Genesis 1. Verses are returned in reading order.
+    pub fn get_context(
+        &self,
+        reference: &Reference,
+        before: usize,
+        after: usize,
+    ) -> Result<Vec<&Verse>, BibleError> {
+        let book = self.get_book(reference.book())?;
+        book.get_verse(reference.chapter(), reference.verse())?;
+
+        let verses = book.chapters().iter().flat_map(|c| c.get_verses());
+        let position = verses
+            .clone()
+            .position(|v| v.chapter() == reference.chapter() && v.covers(reference.verse()))
+            .unwrap_or_default();
+        let start = position.saturating_sub(before);
+
+        Ok(verses
+            .skip(start)
+            .take(position - start + 1 + after)
+            .collect())
+    }
+
+    /// Returns a handle to a verse that also carries its book and translation.
+    pub fn verse_ref(
+        &self,
+        book: impl Into<BookId>,
+        chapter_number: usize,
+        verse_number: usize,
+    ) -> Result<VerseRef<'_>, BibleError> {
+        let book = self.get_book(book)?;
+        let verse = book.get_verse(chapter_number, verse_number)?;
+        Ok(VerseRef::new(book, verse, &self.id))
+    }
+
+    /// Returns a handle to a chapter that also carries its book and translation.
+    pub fn chapter_ref(
+        &self,
+        book: impl Into<BookId>,
+        chapter_number: usize,
+    ) -> Result<ChapterRef<'_>, BibleError> {
+        let book = self.get_book(book)?;
+        let chapter = book.get_chapter(chapter_number)?;
+        Ok(ChapterRef::new(book, chapter, &self.id))
+    }
+
     /// Returns a specific verse using a human-readable reference string.
     ///
     /// The reference should be in the form "Book Chapter:Verse", for example
     /// `"Genesis 1:1"` or `"Jn 3:16"`. Common book abbreviations are
-    /// supported.
+    /// supported, and messier input is accepted under
+    /// [`ReferenceParsing::Tolerant`]; see [`Bible::parse_reference`].
     pub fn get_verse_by_reference(&self, reference: &str) -> Result<&Verse, BibleError> {
-        let reference = reference.trim();
-
-        // Split verse part
-        let (book_and_chapter, verse_str) =
-            reference
-                .rsplit_once(':')
-                .ok_or_else(|| BibleError::InvalidReference {
-                    input: reference.to_string(),
-                })?;
-        let verse_number: usize =
-            verse_str
-                .trim()
-                .parse()
-                .map_err(|_| BibleError::InvalidReference {
-                    input: reference.to_string(),
-                })?;
-
-        // Split chapter part
-        let (book_str, chapter_str) =
-            book_and_chapter
-                .rsplit_once(' ')
-                .ok_or_else(|| BibleError::InvalidReference {
-                    input: reference.to_string(),
-                })?;
-        let chapter_number: usize =
-            chapter_str
-                .trim()
-                .parse()
-                .map_err(|_| BibleError::InvalidReference {
-                    input: reference.to_string(),
-                })?;
-
-        // Resolve the book reference
-        let book = self
-            .resolve_book(book_str.trim())
-            .ok_or_else(|| BibleError::BookNotFound {
-                book_abbrev: book_str.trim().to_ascii_lowercase(),
-                book_name: book_str.trim().to_string(),
-                translation: self.name.clone(),
-            })?;
-
-        self.get_verse(book, chapter_number, verse_number)
+        let reference = self.parse_reference(reference)?;
+        self.get_verse(reference.book(), reference.chapter(), reference.verse())
     }
 
     /// Searches the Bible for verses containing all terms in the query.
     ///
-    /// A tokenized search index is built on first use and reused on subsequent
-    /// queries, providing fast lookups while returning cloned verse data for each match.
-    pub fn search(&mut self, query: &str) -> Vec<Verse> {
-        if query.is_empty() {
-            return Vec::new();
-        }
-
-        if self.search_index.is_none() {
-            let index = self.build_search_index();
-            self.search_index = Some(index);
-        }
-
-        // Safe to unwrap: ensured Some above
-        let matches = self.search_index.as_ref().unwrap().search(query);
+    /// The search index is sharded by book; each shard is built the first
+    /// time a search touches its book and reused afterwards. Results are
+    /// ordered canonically and returned as cloned verse data.
+    ///
+    /// The shards and cached results sit behind a lock, so a Bible shared
+    /// by reference, such as one borrowed from a
+    /// [`BibleLibrary`](crate::BibleLibrary), can be searched.
+    pub fn search(&self, query: &str) -> Vec<Verse> {
+        self.search_slots((0..self.books.len()).collect(), query)
+    }
 
-        matches
+    /// Searches with an external `backend` instead of the built-in index,
+    /// returning the matching verses of this Bible in the backend's order.
+    /// Locations the backend reports that are not in this Bible are skipped.
+    pub fn search_with(&self, backend: &dyn SearchBackend, query: &str) -> Vec<&Verse> {
+        backend
+            .search_verses(query)
             .into_iter()
-            .filter_map(|(book, chapter, verse)| self.get_verse(book, chapter, verse).ok().cloned())
+            .filter_map(|(book, chapter, verse)| self.get_verse(book, chapter, verse).ok())
             .collect()
     }
 
-    /// Builds a search index for faster repeated searches.
-    pub fn build_search_index(&self) -> SearchIndex {
-        let mut map: HashMap<String, Vec<(BibleBook, usize, usize)>> = HashMap::new();
+    /// Searches only the given books, building index shards for just those
+    /// books.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::BookNotFound`] if a book is not in this Bible.
+    pub fn search_books<B: Into<BookId>>(
+        &self,
+        books: impl IntoIterator<Item = B>,
+        query: &str,
+    ) -> Result<Vec<Verse>, BibleError> {
+        let slots = self.book_slots(books)?;
+        Ok(self.search_slots(slots, query))
+    }
 
-        for book in &self.books {
-            for chapter in book.chapters() {
-                for verse in chapter.get_verses() {
-                    for term in SearchIndex::tokenize(verse.text()) {
-                        let entry = map.entry(term).or_default();
-                        let tuple = (verse.book(), verse.chapter(), verse.number());
-                        if !entry.contains(&tuple) {
-                            entry.push(tuple);
-                        }
-                    }
-                }
+    /// Builds the search index shards for the given books ahead of time, so
+    /// the first search touching them does not pay the indexing cost, such as
+    /// warming the Gospels while an app shows its home screen.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::BookNotFound`] if a book is not in this Bible.
+    /// Every book is checked first, so nothing is built in that case.
+    pub fn warm_index<B: Into<BookId>>(
+        &self,
+        books: impl IntoIterator<Item = B>,
+    ) -> Result<(), BibleError> {
+        let slots = self.book_slots(books)?;
+        self.search.with(|caches| {
+            for slot in slots {
+                self.search_shard(&mut caches.shards, slot);
             }
-        }
+        });
+        Ok(())
+    }
 
-        for values in map.values_mut() {
-            values.sort_by_key(|&(b, c, v)| (b as usize, c, v));
-        }
+    /// Returns the number of books whose search index shard has been built.
+    pub fn indexed_book_count(&self) -> usize {
+        self.search.with(|caches| caches.shards.len())
+    }
 
-        SearchIndex::new(map)
+    /// Sets the options used by [`Bible::search`] and [`Bible::build_search_index`].
+    ///
+    /// Any previously built search index shards and cached results are discarded.
+    pub fn set_search_options(&mut self, options: SearchIndexOptions) {
+        self.search_options = options;
+        self.search.clear();
     }
 
-    fn resolve_book(&self, input: &str) -> Option<BibleBook> {
-        let lower = input.to_ascii_lowercase();
+    /// Sets how many recent searches keep their results cached, evicting the
+    /// least recently used first. Zero disables the cache.
+    ///
+    /// Queries are cached by their normalized terms and searched books, so
+    /// `"God light"` and `"LIGHT god"` share an entry.
+    pub fn set_query_cache_size(&mut self, size: usize) {
+        self.search.with(|caches| caches.results.resize(size));
+    }
 
-        static ALT_ABBREVS: phf::Map<&'static str, BibleBook> = phf_map! {
-            // --- Protestant (66) ---
-            "gen" => BibleBook::Genesis,
-            "ge" => BibleBook::Genesis,
-            "exo" => BibleBook::Exodus,
-            "exod" => BibleBook::Exodus,
-            "lev" => BibleBook::Leviticus,
-            "le" => BibleBook::Leviticus,
-            "num" => BibleBook::Numbers,
-            "nu" => BibleBook::Numbers,
-            "deut" => BibleBook::Deuteronomy,
-            "deu" => BibleBook::Deuteronomy,
-            "jos" => BibleBook::Joshua,
-            "josh" => BibleBook::Joshua,
-            "jdg" => BibleBook::Judges,
-            "judg" => BibleBook::Judges,
-            "rut" => BibleBook::Ruth,
-            "ru" => BibleBook::Ruth,
-            "1sa" => BibleBook::FirstSamuel,
-            "1sam" => BibleBook::FirstSamuel,
-            "2sa" => BibleBook::SecondSamuel,
-            "2sam" => BibleBook::SecondSamuel,
-            "1ki" => BibleBook::FirstKings,
-            "1kings" => BibleBook::FirstKings,
-            "2ki" => BibleBook::SecondKings,
-            "2kings" => BibleBook::SecondKings,
-            "1ch" => BibleBook::FirstChronicles,
-            "1chr" => BibleBook::FirstChronicles,
-            "2ch" => BibleBook::SecondChronicles,
-            "2chr" => BibleBook::SecondChronicles,
-            "ezr" => BibleBook::Ezra,
-            "ezra" => BibleBook::Ezra,
-            "neh" => BibleBook::Nehemiah,
-            "ne" => BibleBook::Nehemiah,
-            "est" => BibleBook::Esther,
-            "esth" => BibleBook::Esther,
-            "job" => BibleBook::Job,
-            "jb" => BibleBook::Job,
-            "psa" => BibleBook::Psalms,
-            "psalm" => BibleBook::Psalms,
-            "psalms" => BibleBook::Psalms,
-            "pro" => BibleBook::Proverbs,
-            "prov" => BibleBook::Proverbs,
-            "ecc" => BibleBook::Ecclesiastes,
-            "eccl" => BibleBook::Ecclesiastes,
-            "sos" => BibleBook::SongOfSolomon,
-            "song" => BibleBook::SongOfSolomon,
-            "songofsongs" => BibleBook::SongOfSolomon,
-            "isa" => BibleBook::Isaiah,
-            "jer" => BibleBook::Jeremiah,
-            "lam" => BibleBook::Lamentations,
-            "ezek" => BibleBook::Ezekiel,
-            "eze" => BibleBook::Ezekiel,
-            "dan" => BibleBook::Daniel,
-            "da" => BibleBook::Daniel,
-            "hos" => BibleBook::Hosea,
-            "joe" => BibleBook::Joel,
-            "amo" => BibleBook::Amos,
-            "oba" => BibleBook::Obadiah,
-            "obad" => BibleBook::Obadiah,
-            "jon" => BibleBook::Jonah,
-            "jnh" => BibleBook::Jonah,
-            "mic" => BibleBook::Micah,
-            "nah" => BibleBook::Nahum,
-            "hab" => BibleBook::Habakkuk,
-            "zep" => BibleBook::Zephaniah,
-            "zeph" => BibleBook::Zephaniah,
-            "hag" => BibleBook::Haggai,
-            "zec" => BibleBook::Zechariah,
-            "zech" => BibleBook::Zechariah,
-            "mal" => BibleBook::Malachi,
-            "mat" => BibleBook::Matthew,
-            "matt" => BibleBook::Matthew,
-            "mar" => BibleBook::Mark,
-            "mrk" => BibleBook::Mark,
-            "luk" => BibleBook::Luke,
-            "luke" => BibleBook::Luke,
-            "john" => BibleBook::John,
-            "jhn" => BibleBook::John,
-            "jn" => BibleBook::John,
-            "acts" => BibleBook::Acts,
-            "ac" => BibleBook::Acts,
-            "rom" => BibleBook::Romans,
-            "1co" => BibleBook::FirstCorinthians,
-            "1cor" => BibleBook::FirstCorinthians,
-            "2co" => BibleBook::SecondCorinthians,
-            "2cor" => BibleBook::SecondCorinthians,
-            "gal" => BibleBook::Galatians,
-            "eph" => BibleBook::Ephesians,
-            "phil" => BibleBook::Philippians,
-            "php" => BibleBook::Philippians,
-            "col" => BibleBook::Colossians,
-            "1th" => BibleBook::FirstThessalonians,
-            "1thes" => BibleBook::FirstThessalonians,
-            "2th" => BibleBook::SecondThessalonians,
-            "2thes" => BibleBook::SecondThessalonians,
-            "1ti" => BibleBook::FirstTimothy,
-            "1tim" => BibleBook::FirstTimothy,
-            "2ti" => BibleBook::SecondTimothy,
-            "2tim" => BibleBook::SecondTimothy,
-            "tit" => BibleBook::Titus,
-            "phm" => BibleBook::Philemon,
-            "phlm" => BibleBook::Philemon,
-            "philemon" => BibleBook::Philemon,
-            "heb" => BibleBook::Hebrews,
-            "jas" => BibleBook::James,
-            "jam" => BibleBook::James,
-            "1pe" => BibleBook::FirstPeter,
-            "1pet" => BibleBook::FirstPeter,
-            "2pe" => BibleBook::SecondPeter,
-            "2pet" => BibleBook::SecondPeter,
-            "1jn" => BibleBook::FirstJohn,
-            "1joh" => BibleBook::FirstJohn,
-            "2jn" => BibleBook::SecondJohn,
-            "2joh" => BibleBook::SecondJohn,
-            "3jn" => BibleBook::ThirdJohn,
-            "3joh" => BibleBook::ThirdJohn,
-            "jud" => BibleBook::Jude,
-            "jude" => BibleBook::Jude,
-            "rev" => BibleBook::Revelation,
-            "revelation" => BibleBook::Revelation,
-            // --- Catholic Deuterocanon ---
-            "tob" => BibleBook::Tobit,
-            "jdt" => BibleBook::Judith,
-            "wis" => BibleBook::Wisdom,
-            "sir" => BibleBook::Sirach,
-            "bar" => BibleBook::Baruch,
-            "1mac" => BibleBook::FirstMaccabees,
-            "2mac" => BibleBook::SecondMaccabees,
-            "estg" => BibleBook::EstherAdditions,
-            "addesth" => BibleBook::EstherAdditions,
-            "dan3" => BibleBook::DanielSongOfThree,
-            "sus" => BibleBook::DanielSusanna,
-            "bel" => BibleBook::DanielBelAndTheDragon,
-            // --- Eastern Orthodox Additions ---
-            "1esd" => BibleBook::FirstEsdras,
-            "2esd" => BibleBook::SecondEsdras,
-            "man" => BibleBook::PrayerOfManasseh,
-            "prman" => BibleBook::PrayerOfManasseh,
-            "ps151" => BibleBook::Psalm151,
-            "3mac" => BibleBook::ThirdMaccabees,
-            "4mac" => BibleBook::FourthMaccabees,
-        };
+    /// Returns the number of searches whose results may be cached.
+    pub fn query_cache_size(&self) -> usize {
+        self.search.with(|caches| caches.results.capacity())
+    }
 
-        ALT_ABBREVS
-            .get(lower.as_str())
-            .copied()
+    /// Sets how strictly reference strings are parsed by
+    /// [`Bible::get_verse_by_reference`] and [`Bible::parse_reference`].
+    pub fn set_reference_parsing(&mut self, parsing: ReferenceParsing) {
+        self.reference_parsing = parsing;
+    }
+
+    /// Returns how strictly reference strings are parsed.
+    pub fn reference_parsing(&self) -> ReferenceParsing {
+        self.reference_parsing
+    }
+
+    fn book_slot(&self, book: impl Into<BookId>) -> Result<usize, BibleError> {
+        let book = book.into();
+        self.book_index(book.as_str())
+            .ok_or_else(|| self.book_not_found(book.as_str()))
+    }
+
+    fn book_slots<B: Into<BookId>>(
+        &self,
+        books: impl IntoIterator<Item = B>,
+    ) -> Result<Vec<usize>, BibleError> {
+        books.into_iter().map(|book| self.book_slot(book)).collect()
+    }
+
+    fn search_shard<'a>(
+        &self,
+        shards: &'a mut HashMap<usize, SearchIndex>,
+        slot: usize,
+    ) -> &'a SearchIndex {
+        shards.entry(slot).or_insert_with(|| {
+            let timer = StageTimer::start(Stage::Index, &self.id);
+            let mut map = HashMap::new();
+            collect_postings(&self.books[slot], &mut map);
+            timer.finish(map.len());
+            SearchIndex::with_options(map, self.search_options.clone())
+        })
+    }
+
+    fn search_slots(&self, mut slots: Vec<usize>, query: &str) -> Vec<Verse> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let timer = StageTimer::start(Stage::Search, &self.id);
+        slots.sort_by(|&a, &b| self.books[a].id().cmp(self.books[b].id()));
+        slots.dedup();
+
+        let key = (self.search_options.normalize_query(query), slots);
+        let hits = self.search.with(|caches| {
+            if let Some(hits) = caches.results.get(&key) {
+                return hits.clone();
+            }
+            let mut hits = Vec::new();
+            for &slot in &key.1 {
+                let matches = self.search_shard(&mut caches.shards, slot).search(query);
+                hits.extend(
+                    matches
+                        .into_iter()
+                        .map(|(_, chapter, verse)| (slot, chapter, verse)),
+                );
+            }
+            caches.results.insert(key, hits.clone());
+            hits
+        });
+
+        let verses: Vec<Verse> = hits
+            .into_iter()
+            .filter_map(|(slot, chapter, verse)| {
+                self.books[slot].get_verse(chapter, verse).ok().cloned()
+            })
+            .collect();
+        timer.finish(verses.len());
+        verses
+    }
+
+    /// Builds a search index for faster repeated searches.
+    pub fn build_search_index(&self) -> SearchIndex {
+        self.build_search_index_with(self.search_options.clone())
+    }
+
+    /// Builds a search index with the given options.
+    pub fn build_search_index_with(&self, options: SearchIndexOptions) -> SearchIndex {
+        let timer = StageTimer::start(Stage::Index, &self.id);
+        let mut map = HashMap::new();
+        for book in &self.books {
+            collect_postings(book, &mut map);
+        }
+        timer.finish(map.len());
+        SearchIndex::with_options(map, options)
+    }
+
+    /// Replaces the text of a verse, discarding the search index shard of
+    /// its book and any cached search results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the book, chapter, or verse does not exist.
+    pub fn set_verse_text(
+        &mut self,
+        book: impl Into<BookId>,
+        chapter_number: usize,
+        verse_number: usize,
+        verse_text: &str,
+    ) -> Result<(), BibleError> {
+        let slot = self.book_slot(book)?;
+        self.books[slot].set_verse_text(chapter_number, verse_number, verse_text)?;
+        self.search.with(|caches| {
+            caches.shards.remove(&slot);
+            caches.results.clear();
+        });
+        self.render_cache.clear();
+        Ok(())
+    }
+
+    /// Normalizes the order of loaded data: books are sorted canonically
+    /// under this Bible's [`DeuterocanonPlacement`], chapters by number and
+    /// verses by number. Files that list books or verses out of order then
+    /// give the same iteration, search and export order as well-ordered
+    /// ones. Returns `true` if anything moved.
+    pub fn canonicalize(&mut self) -> bool {
+        let mut books = std::mem::take(&mut self.books);
+        let mut changed = !books.is_sorted_by(|a, b| self.canonical_cmp(a, b).is_le());
+        books.sort_by(|a, b| self.canonical_cmp(a, b));
+        for book in &mut books {
+            let chapters = book.chapters_mut();
+            changed |= !chapters.is_sorted_by_key(Chapter::number);
+            chapters.sort_by_key(Chapter::number);
+            for chapter in chapters {
+                changed |= chapter.sort_verses();
+            }
+        }
+        self.books = books;
+        if changed {
+            self.books_changed();
+        }
+        changed
+    }
+
+    /// Returns a chapter for changes that do not affect search, such as
+    /// attaching headings.
+    pub(crate) fn chapter_mut(
+        &mut self,
+        book: impl Into<BookId>,
+        chapter_number: usize,
+    ) -> Option<&mut Chapter> {
+        let slot = self.book_slot(book).ok()?;
+        self.render_cache.clear();
+        self.books[slot].get_chapter_mut(chapter_number)
+    }
+
+    /// Adds a book, returning the book it replaces if one with the same
+    /// [`BookId`] was present.
+    ///
+    /// A new book is placed before the first book that follows it in
+    /// canonical order. Book lookups and the search index are updated.
+    pub fn insert_book(&mut self, book: Book) -> Option<Book> {
+        let replaced = match self.books.iter().position(|b| b.id() == book.id()) {
+            Some(slot) => Some(std::mem::replace(&mut self.books[slot], book)),
+            None => {
+                let slot = self
+                    .books
+                    .iter()
+                    .position(|b| self.canonical_cmp(b, &book).is_gt())
+                    .unwrap_or(self.books.len());
+                self.books.insert(slot, book);
+                None
+            }
+        };
+        self.books_changed();
+        replaced
+    }
+
+    /// Removes a book and returns it. Book lookups and the search index are
+    /// updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::BookNotFound`] if the book is not in this Bible.
+    pub fn remove_book(&mut self, book: impl Into<BookId>) -> Result<Book, BibleError> {
+        let slot = self.book_slot(book)?;
+        let removed = self.books.remove(slot);
+        self.books_changed();
+        Ok(removed)
+    }
+
+    /// Rebuilds lookups that depend on book positions after books are added,
+    /// replaced, or removed.
+    fn books_changed(&mut self) {
+        self.index_by_abbrev = build_book_index(&self.books);
+        self.search.clear();
+        self.render_cache.clear();
+    }
+
+    pub(crate) fn resolve_book(&self, input: &str) -> Option<BookId> {
+        let lower = input.to_ascii_lowercase();
+
+        ALT_ABBREVS
+            .get(lower.as_str())
+            .map(|&book| BookId::Known(book))
             .or_else(|| {
                 // Try official abbreviations
-                BibleBook::from_str(&lower).ok()
+                BibleBook::from_str(&lower).ok().map(BookId::Known)
             })
             .or_else(|| {
-                // Try full book titles from loaded data
-                self.books
-                    .iter()
-                    .find(|b| b.title().eq_ignore_ascii_case(input))
-                    .and_then(|b| BibleBook::from_str(&b.abbrev().to_ascii_lowercase()).ok())
+                // Try names from loaded data: keys, titles, and aliases, including custom books
+                self.book_index(input).map(|i| self.books[i].id().clone())
+            })
+            .or_else(|| {
+                // "II Samuel", "First John", "1st Corinthians"
+                let (number, name) = split_book_number(input)?;
+                self.resolve_book(&format!("{number} {name}"))
+                    .or_else(|| self.resolve_book(&format!("{number}{name}")))
             })
     }
 
-    fn new_from_map_with_meta(
+    pub(crate) fn new_from_map_with_meta(
         map: IndexMap<String, FileDataEntry>,
         id: String,
         name: String,
         description: String,
         language: String,
+        metadata: BibleMetadata,
     ) -> Self {
         // Iterate in map order (IndexMap preserves insertion order)
-        let mut books = Vec::with_capacity(map.len());
-
-        for (abbrev, entry) in map.into_iter() {
-            let book_enum = BibleBook::from_str(&abbrev).unwrap_or_else(|_| {
-                panic!(
-                    "Unknown book abbreviation '{}' encountered while building Bible data",
-                    abbrev
-                )
-            });
-            let chapters = entry
-                .chapters
-                .into_iter()
-                .enumerate()
-                .map(|(chapter_idx, verses)| {
-                    let verses = verses
-                        .into_iter()
-                        .enumerate()
-                        .map(|(verse_idx, verse_text)| {
-                            Verse::new(book_enum, chapter_idx + 1, verse_idx + 1, verse_text)
-                        })
-                        .collect::<Vec<_>>();
-                    Chapter::new(verses, chapter_idx + 1)
-                })
-                .collect::<Vec<_>>();
+        let books = map
+            .into_iter()
+            .map(|(abbrev, entry)| Self::book_from_entry(abbrev, entry, false))
+            .collect();
+        Bible::from_books(books, id, name, description, language, metadata)
+    }
 
-            books.push(Book::new(abbrev, entry.name, chapters));
-        }
+    /// Builds a book from its file entry. With `bracketed_superscriptions`,
+    /// a Psalm without a listed superscription takes a leading `[...]` of
+    /// verse 1 as its title.
+    fn book_from_entry(
+        abbrev: String,
+        entry: FileDataEntry,
+        bracketed_superscriptions: bool,
+    ) -> Book {
+        let book_id = BookId::from_abbrev(&abbrev);
+        let mut paragraphs = entry.paragraphs;
+        let mut superscriptions = entry.superscriptions;
+        let mut bridges = entry.bridges;
+        let chapters = entry
+            .chapters
+            .into_iter()
+            .enumerate()
+            .map(|(chapter_idx, mut verses)| {
+                // Psalm titles are often prefixed to verse 1 as "[A Psalm of David.]"
+                let superscription = superscriptions
+                    .swap_remove(&(chapter_idx + 1).to_string())
+                    .or_else(|| {
+                        if bracketed_superscriptions && book_id == BibleBook::Psalms {
+                            verses.first_mut().and_then(take_bracketed_superscription)
+                        } else {
+                            None
+                        }
+                    });
+                let (bridge_ends, _) = check_bridges(
+                    &verses,
+                    &bridges
+                        .swap_remove(&(chapter_idx + 1).to_string())
+                        .unwrap_or_default(),
+                );
+                // Empty strings hold the place of omitted and bridged verses.
+                let verses = verses
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(_, verse_text)| !verse_text.is_empty())
+                    .map(|(verse_idx, verse_text)| {
+                        let verse =
+                            Verse::new(book_id.clone(), chapter_idx + 1, verse_idx + 1, verse_text);
+                        match bridge_ends.get(&(verse_idx + 1)) {
+                            Some(&last) => verse.with_bridge_end(last),
+                            None => verse,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let markers = paragraphs
+                    .swap_remove(&(chapter_idx + 1).to_string())
+                    .unwrap_or_default();
+                Chapter::new(verses, chapter_idx + 1)
+                    .with_paragraph_markers(markers)
+                    .with_superscription(superscription)
+            })
+            .collect::<Vec<_>>();
 
-        // Build abbrev index
-        let mut index_by_abbrev = HashMap::with_capacity(books.len());
-        for (i, b) in books.iter().enumerate() {
-            index_by_abbrev.insert(b.abbrev().to_ascii_lowercase(), i);
-        }
+        let outline = entry
+            .outline
+            .into_iter()
+            .map(|section| section.into_section(&book_id))
+            .collect();
+        Book::new(abbrev, entry.name, chapters)
+            .with_aliases(entry.aliases)
+            .with_introduction(entry.introduction)
+            .with_outline(outline)
+    }
 
+    /// Assembles a Bible from already-built books, indexing them by abbreviation.
+    pub(crate) fn from_books(
+        books: Vec<Book>,
+        id: String,
+        name: String,
+        description: String,
+        language: String,
+        metadata: BibleMetadata,
+    ) -> Self {
         Bible {
+            index_by_abbrev: build_book_index(&books),
             books,
-            index_by_abbrev,
-            search_index: None,
+            search: SearchState::new(DEFAULT_QUERY_CACHE_SIZE),
+            search_options: SearchIndexOptions::default(),
+            render_cache: RenderCache::default(),
             id,
             name,
             description,
             language,
+            metadata,
+            provenance: Provenance::default(),
+            deuterocanon_placement: DeuterocanonPlacement::default(),
+            reference_parsing: ReferenceParsing::default(),
+            entities: EntityIndex::default(),
         }
     }
 
@@ -565,20 +1489,331 @@ impl Bible {
     ///
     /// # Errors
     ///
-    /// Returns an error if the file cannot be read or if the JSON cannot be
-    /// parsed. The JSON should have the structure where each book is a key
-    /// with an object containing "name" and "chapters" fields.
-    pub fn new_from_json(json_path: &str) -> Result<Self, Box<dyn Error>> {
-        let mut file_content = fs::read(json_path)?;
-        let root: BibleFileRoot = simd_from_slice(&mut file_content)?;
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or if the
+    /// JSON cannot be parsed. The JSON should have the structure where each
+    /// book is a key with an object containing "name" and "chapters" fields.
+    pub fn new_from_json(json_path: &str) -> Result<Self, BibleError> {
+        Self::new_from_json_with_schema(json_path, JsonSchema::Auto)
+    }
 
-        Ok(Bible::new_from_map_with_meta(
-            root.books,
+    /// Creates a new Bible instance from a JSON file in the given layout.
+    ///
+    /// Besides this crate's own layout, common community layouts such as
+    /// arrays of books or of verses are accepted; see [`JsonSchema`]. Files
+    /// without translation details load with an empty id and name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or does
+    /// not match the layout.
+    pub fn new_from_json_with_schema(
+        json_path: &str,
+        schema: JsonSchema,
+    ) -> Result<Self, BibleError> {
+        Self::new_from_json_with_options(json_path, &LoadOptions::new().with_schema(schema))
+    }
+
+    /// Creates a new Bible instance from a JSON file, running each verse
+    /// through the [`TextProcessor`](crate::TextProcessor)s in `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or does
+    /// not match the layout.
+    pub fn new_from_json_with_options(
+        json_path: &str,
+        options: &LoadOptions,
+    ) -> Result<Self, BibleError> {
+        let _scope = HookScope::enter(options.instrumentation_hook());
+        let timer = StageTimer::start(Stage::Read, json_path);
+        let mut file_content =
+            fs::read(json_path).map_err(|e| BibleError::load_error(json_path, e))?;
+        timer.finish(file_content.len());
+        let mut bible = Self::from_json_buffer(&mut file_content, options, json_path)?;
+        bible.provenance = Provenance::loaded_from(json_path);
+        Ok(bible)
+    }
+
+    /// Creates a Bible from JSON data already in memory, in the given layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`], with the path `"<memory>"`, if the
+    /// JSON cannot be parsed.
+    pub fn from_json_bytes(json: &[u8], schema: JsonSchema) -> Result<Self, BibleError> {
+        let options = LoadOptions::new().with_schema(schema);
+        Self::from_json_buffer(&mut json.to_vec(), &options, "<memory>")
+    }
+
+    /// Parses `buffer`, which simd-json rewrites in place; `path` names the
+    /// source in errors.
+    fn from_json_buffer(
+        buffer: &mut [u8],
+        options: &LoadOptions,
+        path: &str,
+    ) -> Result<Self, BibleError> {
+        let timer = StageTimer::start(Stage::Parse, path);
+        // Parsing rewrites the buffer in place, so the snapshot key is taken first.
+        let snapshot = options
+            .cache_dir()
+            .map(|dir| snapshot::snapshot_path(dir, buffer, options.schema()));
+        let mut root = match snapshot.as_deref().and_then(snapshot::read) {
+            Some(root) => root,
+            None => {
+                let mut root = json_schema::parse(buffer, options.schema())
+                    .map_err(|e| BibleError::load_error(path, e))?;
+                if let Some(snapshot) = &snapshot {
+                    snapshot::write(snapshot, &mut root);
+                }
+                root
+            }
+        };
+        timer.finish(root.books.len());
+        options.process(&mut root);
+
+        let timer = StageTimer::start(Stage::Build, path);
+        let total = root.books.len();
+        let books = root
+            .books
+            .into_iter()
+            .enumerate()
+            .map(|(parsed, (abbrev, entry))| {
+                let book =
+                    Self::book_from_entry(abbrev, entry, options.bracketed_superscriptions());
+                options.report(LoadProgress::BooksParsed {
+                    done: parsed + 1,
+                    total,
+                });
+                book
+            })
+            .collect();
+        let bible = Bible::from_books(
+            books,
             root.id,
             root.name,
             root.description,
             root.language,
-        ))
+            root.metadata,
+        );
+        timer.finish(bible.books.len());
+
+        if options.search_index() {
+            let verses = |book: &Book| -> usize {
+                book.chapters().iter().map(|c| c.get_verses().len()).sum()
+            };
+            let total = bible.books.iter().map(verses).sum();
+            let mut done = 0;
+            bible.search.with(|caches| {
+                for slot in 0..bible.books.len() {
+                    bible.search_shard(&mut caches.shards, slot);
+                    done += verses(&bible.books[slot]);
+                    options.report(LoadProgress::VersesIndexed { done, total });
+                }
+            });
+        }
+        Ok(bible)
+    }
+
+    /// Records where this Bible's data came from, such as the URL it was
+    /// downloaded from, and the current time as its load time.
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.provenance = Provenance::loaded_from(source);
+        self
+    }
+
+    /// Serializes this Bible back into the JSON format read by [`Bible::new_from_json`].
+    ///
+    /// Chapters are written as arrays of verse strings and translator-supplied
+    /// words are wrapped in `{}` again, so the output loads into an equivalent Bible.
+    /// Books are written in [`Bible::books_in_canonical_order`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::SaveError`], with the path `"<memory>"`, if
+    /// serialization fails.
+    pub fn to_json(&self) -> Result<String, BibleError> {
+        self.to_json_for("<memory>")
+    }
+
+    /// Serializes this Bible as [`Bible::to_json`] does; `path` names the
+    /// destination in errors.
+    fn to_json_for(&self, path: &str) -> Result<String, BibleError> {
+        let books = self
+            .books_in_canonical_order()
+            .into_iter()
+            .map(|book| {
+                // Chapters and verses are stored by position, so gaps left by
+                // `subset` are padded to keep the numbering.
+                let mut chapters: Vec<Vec<String>> = Vec::with_capacity(book.chapters().len());
+                for chapter in book.chapters() {
+                    let padded = chapters.len().max(chapter.number().saturating_sub(1));
+                    chapters.resize_with(padded, Vec::new);
+                    let mut verses = Vec::with_capacity(chapter.get_verses().len());
+                    for verse in chapter.get_verses() {
+                        let padded = verses.len().max(verse.number().saturating_sub(1));
+                        verses.resize_with(padded, String::new);
+                        verses.push(verse.source_text());
+                        // The rest of a bridge is held by empty placeholders.
+                        verses.resize_with(verses.len().max(verse.last_number()), String::new);
+                    }
+                    chapters.push(verses);
+                }
+                let paragraphs = book
+                    .chapters()
+                    .iter()
+                    .filter(|c| !c.paragraphs().is_empty())
+                    .map(|c| (c.number().to_string(), c.paragraph_markers()))
+                    .collect();
+                let superscriptions = book
+                    .chapters()
+                    .iter()
+                    .filter_map(|c| Some((c.number().to_string(), c.superscription()?.to_string())))
+                    .collect();
+                let bridges = book
+                    .chapters()
+                    .iter()
+                    .filter_map(|c| {
+                        let bridged: Vec<[usize; 2]> = c
+                            .get_verses()
+                            .iter()
+                            .filter(|v| v.is_bridge())
+                            .map(|v| [v.number(), v.last_number()])
+                            .collect();
+                        (!bridged.is_empty()).then(|| (c.number().to_string(), bridged))
+                    })
+                    .collect();
+                let entry = FileDataEntry {
+                    chapters,
+                    name: book.title().to_string(),
+                    aliases: book.aliases().to_vec(),
+                    superscriptions,
+                    paragraphs,
+                    bridges,
+                    introduction: book.introduction().map(str::to_string),
+                    outline: book
+                        .outline()
+                        .iter()
+                        .map(OutlineEntry::from_section)
+                        .collect(),
+                };
+                (book.abbrev().to_string(), entry)
+            })
+            .collect();
+
+        let root = BibleFileRoot {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            language: self.language.clone(),
+            metadata: self.metadata.clone(),
+            books,
+        };
+
+        simd_to_string_pretty(&root).map_err(|e| BibleError::save_error(path, e))
+    }
+
+    /// Writes this Bible as JSON to the given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::SaveError`] if serialization fails or the file
+    /// cannot be written.
+    pub fn save_json(&self, json_path: &str) -> Result<(), BibleError> {
+        let json = self.to_json_for(json_path)?;
+        fs::write(json_path, json).map_err(|e| BibleError::save_error(json_path, e))
+    }
+}
+
+/// Splits a leading roman numeral or ordinal, as in "II Samuel", "First
+/// John", or "1st Corinthians", into its number and the rest of the name.
+fn split_book_number(name: &str) -> Option<(u8, &str)> {
+    let (prefix, rest) = name.trim().split_once(char::is_whitespace)?;
+    let number = match prefix.to_ascii_lowercase().as_str() {
+        "i" | "first" | "1st" => 1,
+        "ii" | "second" | "2nd" => 2,
+        "iii" | "third" | "3rd" => 3,
+        "iv" | "fourth" | "4th" => 4,
+        _ => return None,
+    };
+    Some((number, rest.trim_start()))
+}
+
+/// Returns the optimal string alignment distance between `a` and `b`: the
+/// number of single-character insertions, deletions, substitutions and
+/// adjacent transpositions needed to turn one into the other.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // Three rows of the dynamic programming table: two back, previous, current.
+    let mut before: Vec<usize> = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut before, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Maps lowercase abbreviations and names to book positions. Data keys win
+/// over derived names, which never displace an earlier entry.
+fn build_book_index(books: &[Book]) -> HashMap<String, usize> {
+    let mut index_by_abbrev = HashMap::with_capacity(books.len() * 8);
+    for (i, b) in books.iter().enumerate() {
+        index_by_abbrev.insert(b.abbrev().to_lowercase(), i);
+    }
+    let position: HashMap<BibleBook, usize> = books
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| Some((b.id().known()?, i)))
+        .collect();
+    for (i, b) in books.iter().enumerate() {
+        let names = std::iter::once(b.title()).chain(b.aliases().iter().map(String::as_str));
+        for name in names {
+            index_by_abbrev.entry(name.to_lowercase()).or_insert(i);
+        }
+        if let Some(known) = b.id().known() {
+            index_by_abbrev
+                .entry(known.as_str().to_string())
+                .or_insert(i);
+            index_by_abbrev
+                .entry(known.full_name().to_lowercase())
+                .or_insert(i);
+        }
+    }
+    for (alt, book) in ALT_ABBREVS.entries() {
+        if let Some(&i) = position.get(book) {
+            index_by_abbrev.entry(alt.to_string()).or_insert(i);
+        }
+    }
+    index_by_abbrev
+}
+
+/// Adds a posting for each distinct term of each verse in `book` to `map`.
+fn collect_postings(book: &Book, map: &mut HashMap<String, Vec<(BookId, usize, usize)>>) {
+    for chapter in book.chapters() {
+        for verse in chapter.get_verses() {
+            for term in SearchIndex::tokenize(verse.text()) {
+                let entry = map.entry(term).or_default();
+                // Verses are visited in order, so a repeated term within
+                // a verse can only duplicate the last posting.
+                if entry.last().is_none_or(|(b, c, v)| {
+                    (b, *c, *v) != (verse.book(), verse.chapter(), verse.number())
+                }) {
+                    entry.push((verse.book().clone(), verse.chapter(), verse.number()));
+                }
+            }
+        }
     }
 }
 
@@ -586,6 +1821,8 @@ impl Bible {
 mod tests {
     use super::*;
     use crate::bible_books_enum::BibleBook;
+    use crate::test_support::{one_verse_book, temp_path, TestBible};
+    use simd_json::serde::from_slice as simd_from_slice;
     use std::collections::HashMap;
 
     fn create_test_bible() -> Bible {
@@ -598,14 +1835,33 @@ mod tests {
         Bible {
             books: vec![book],
             index_by_abbrev,
-            search_index: None,
+            search: SearchState::new(DEFAULT_QUERY_CACHE_SIZE),
+            search_options: SearchIndexOptions::default(),
+            render_cache: RenderCache::default(),
             id: "id".to_string(),
             name: "name".to_string(),
             description: "desc".to_string(),
             language: "lang".to_string(),
+            metadata: BibleMetadata::default(),
+            provenance: Provenance::default(),
+            deuterocanon_placement: DeuterocanonPlacement::default(),
+            reference_parsing: ReferenceParsing::default(),
+            entities: EntityIndex::default(),
         }
     }
 
+    /// Recomputes the lookup index after a test edits `books` directly.
+    fn rebuild_index(bible: Bible) -> Bible {
+        Bible::from_books(
+            bible.books,
+            bible.id,
+            bible.name,
+            bible.description,
+            bible.language,
+            bible.metadata,
+        )
+    }
+
     #[test]
     fn test_get_book_and_verse() {
         let bible = create_test_bible();
@@ -632,13 +1888,675 @@ mod tests {
         assert_ne!(original.name().as_ptr(), cloned.name().as_ptr());
     }
 
+    #[test]
+    fn test_json_round_trip_keeps_metadata() {
+        let mut bible = create_test_bible();
+        bible.metadata.license = Some("Public Domain".to_string());
+        bible.metadata.versification = Some("kjv".to_string());
+
+        let json = bible.to_json().unwrap();
+        let mut bytes = json.into_bytes();
+        let root: BibleFileRoot = simd_from_slice(&mut bytes).unwrap();
+
+        assert_eq!(root.metadata, bible.metadata);
+        assert_eq!(root.metadata.copyright, None);
+        assert_eq!(root.books["gn"].chapters, vec![vec!["In the beginning"]]);
+    }
+
+    #[test]
+    fn test_loading_records_provenance() {
+        let bible = create_test_bible();
+        assert_eq!(bible.provenance().source(), None);
+
+        let file = temp_path("loading_records_provenance.json");
+        let path = file.to_str().unwrap();
+        bible.save_json(path).unwrap();
+        let loaded = Bible::new_from_json(path);
+        fs::remove_file(&file).ok();
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded.provenance().source(), Some(path));
+        assert!(loaded.provenance().loaded_at().is_some());
+        assert_eq!(loaded.content_hash(), bible.content_hash());
+
+        let downloaded = loaded.with_source("https://example.com/kjv.json");
+        assert_eq!(
+            downloaded.provenance().source(),
+            Some("https://example.com/kjv.json")
+        );
+    }
+
+    #[test]
+    fn test_bracketed_superscriptions_are_opt_in() {
+        let json = br#"{"books": {"ps": {"name": "Psalms", "chapters": [
+            ["[A Psalm of David.] The LORD is my shepherd"],
+            ["[A Psalm of David.]", "Preserve me, O God"]
+        ]}}}"#;
+        let plain = Bible::from_json_bytes(json, JsonSchema::Native).unwrap();
+        let psalms = plain.get_book(BibleBook::Psalms).unwrap();
+        assert_eq!(psalms.get_chapter(1).unwrap().superscription(), None);
+        assert!(plain
+            .get_verse(BibleBook::Psalms, 1, 1)
+            .unwrap()
+            .text()
+            .starts_with("[A Psalm"));
+
+        let path = std::env::temp_dir().join(format!(
+            "bible_io_{}_bracketed_superscriptions.json",
+            std::process::id()
+        ));
+        fs::write(&path, json).unwrap();
+        let options = LoadOptions::new().with_bracketed_superscriptions(true);
+        let titled = Bible::new_from_json_with_options(path.to_str().unwrap(), &options);
+        fs::remove_file(&path).ok();
+        let titled = titled.unwrap();
+        let psalms = titled.get_book(BibleBook::Psalms).unwrap();
+        assert_eq!(
+            psalms.get_chapter(1).unwrap().superscription(),
+            Some("A Psalm of David.")
+        );
+        assert_eq!(
+            titled.get_verse(BibleBook::Psalms, 1, 1).unwrap().text(),
+            "The LORD is my shepherd"
+        );
+        // A title-only verse 1 is kept rather than emptied and dropped.
+        assert_eq!(psalms.get_chapter(2).unwrap().superscription(), None);
+        assert_eq!(
+            titled.get_verse(BibleBook::Psalms, 2, 1).unwrap().text(),
+            "[A Psalm of David.]"
+        );
+    }
+
+    #[test]
+    fn test_direction_from_metadata_or_language() {
+        let mut bible = create_test_bible();
+        assert_eq!(bible.direction(), TextDirection::Ltr);
+
+        bible.language = "he-IL".to_string();
+        assert_eq!(bible.direction(), TextDirection::Rtl);
+
+        bible.metadata.direction = Some(TextDirection::Ltr);
+        assert_eq!(bible.direction(), TextDirection::Ltr);
+
+        assert_eq!(TextDirection::from_language("Arabic"), TextDirection::Rtl);
+        assert_eq!(TextDirection::from_language("English"), TextDirection::Ltr);
+    }
+
+    #[test]
+    fn test_resolve_book_translation_aliases() {
+        let mut bible = create_test_bible();
+        bible.books[0] = bible.books[0]
+            .clone()
+            .with_aliases(vec!["Génesis".to_string(), "Gén".to_string()]);
+        let bible = rebuild_index(bible);
+
+        assert_eq!(
+            bible.resolve_book("génesis"),
+            Some(BibleBook::Genesis.into())
+        );
+        assert_eq!(bible.resolve_book("GÉN"), Some(BibleBook::Genesis.into()));
+        assert_eq!(bible.resolve_book("Éxodo"), None);
+    }
+
+    #[test]
+    fn test_custom_books_are_addressable_and_searchable() {
+        let mut map = IndexMap::new();
+        map.insert(
+            "gn".to_string(),
+            FileDataEntry {
+                chapters: vec![vec!["In the beginning".to_string()]],
+                name: "Genesis".to_string(),
+                ..Default::default()
+            },
+        );
+        map.insert(
+            "En".to_string(),
+            FileDataEntry {
+                chapters: vec![vec!["Enoch walked with God".to_string()]],
+                name: "Enoch".to_string(),
+                ..Default::default()
+            },
+        );
+        let bible = Bible::new_from_map_with_meta(
+            map,
+            "id".to_string(),
+            "name".to_string(),
+            "desc".to_string(),
+            "lang".to_string(),
+            BibleMetadata::default(),
+        );
+
+        let enoch = BookId::Custom("en".into());
+        assert_eq!(bible.get_book(&enoch).unwrap().title(), "Enoch");
+        assert_eq!(
+            bible.get_verse_by_reference("Enoch 1:1").unwrap().book(),
+            &enoch
+        );
+        assert_eq!(bible.get_verse_by_reference("en 1:1").unwrap().number(), 1);
+
+        let results = bible.search("walked");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].book(), &enoch);
+    }
+
+    #[test]
+    fn test_split_book_number() {
+        assert_eq!(split_book_number("II Samuel"), Some((2, "Samuel")));
+        assert_eq!(split_book_number("First  John"), Some((1, "John")));
+        assert_eq!(split_book_number("4th Maccabees"), Some((4, "Maccabees")));
+        assert_eq!(split_book_number("Song of Solomon"), None);
+        assert_eq!(split_book_number("John"), None);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("philemon", "philemon"), 0);
+        assert_eq!(edit_distance("phillemon", "philemon"), 1);
+        assert_eq!(edit_distance("jhon", "john"), 1);
+        assert_eq!(edit_distance("", "jude"), 4);
+        assert_eq!(edit_distance("gensis", "exodus"), 5);
+    }
+
+    #[test]
+    fn test_load_errors_keep_their_source() {
+        let missing = temp_path("load_errors_missing_file.json");
+        let err = Bible::new_from_json(missing.to_str().unwrap()).unwrap_err();
+        assert!(matches!(err, BibleError::LoadError { .. }));
+        assert!(err
+            .source()
+            .unwrap()
+            .downcast_ref::<std::io::Error>()
+            .is_some());
+
+        let invalid = temp_path("load_errors_invalid_file.json");
+        fs::write(&invalid, "{ not json").unwrap();
+        let err = Bible::new_from_json(invalid.to_str().unwrap()).unwrap_err();
+        fs::remove_file(&invalid).ok();
+        assert!(err.to_string().starts_with("Failed to load Bible from"));
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn test_save_errors_keep_their_source() {
+        let dir = temp_path("save_errors_missing_dir");
+        let path = dir.join("bible.json");
+        let err = create_test_bible()
+            .save_json(path.to_str().unwrap())
+            .unwrap_err();
+        assert!(matches!(&err, BibleError::SaveError { path: p, .. } if p.ends_with("bible.json")));
+        assert!(err.to_string().starts_with("Failed to save Bible to"));
+        assert!(err
+            .source()
+            .unwrap()
+            .downcast_ref::<std::io::Error>()
+            .is_some());
+    }
+
+    #[test]
+    fn test_get_book_or_equivalent() {
+        let book = one_verse_book;
+        let bible = TestBible::new(vec![book(BibleBook::Psalms), book(BibleBook::Tobit)]).build();
+
+        let psalms = bible.get_book_or_equivalent(BibleBook::Psalm151).unwrap();
+        assert_eq!(*psalms.id(), BibleBook::Psalms);
+        assert!(matches!(
+            bible.get_book_or_equivalent(BibleBook::ThirdMaccabees),
+            Err(BibleError::CanonMismatch {
+                canon: Canon::Orthodox,
+                ..
+            })
+        ));
+        // Judith is deuterocanonical like Tobit, so the data file is incomplete.
+        assert!(matches!(
+            bible.get_book_or_equivalent(BibleBook::Judith),
+            Err(BibleError::BookNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_search_builds_book_shards_on_demand() {
+        let book = |id: BibleBook, text: &str| {
+            let verse = Verse::new(id, 1, 1, text.to_string());
+            Book::new(
+                id.as_str().to_string(),
+                id.full_name().to_string(),
+                vec![Chapter::new(vec![verse], 1)],
+            )
+        };
+        let mut bible = TestBible::new(vec![
+            book(BibleBook::John, "the Word was God"),
+            book(BibleBook::Genesis, "God created"),
+            book(BibleBook::Exodus, "the children of Israel"),
+        ])
+        .build();
+
+        let hits = bible.search_books([BibleBook::John], "god").unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(bible.indexed_book_count(), 1);
+
+        bible.warm_index([BibleBook::Exodus]).unwrap();
+        assert_eq!(bible.indexed_book_count(), 2);
+        assert!(matches!(
+            bible.warm_index([BibleBook::Ruth]),
+            Err(BibleError::BookNotFound { .. })
+        ));
+        assert!(bible
+            .warm_index([BibleBook::Genesis, BibleBook::Ruth])
+            .is_err());
+        assert_eq!(bible.indexed_book_count(), 2);
+
+        let hits = bible.search("God");
+        assert_eq!(bible.indexed_book_count(), 3);
+        assert_eq!(*hits[0].book(), BibleBook::Genesis);
+        assert_eq!(*hits[1].book(), BibleBook::John);
+
+        // Repeats with the same normalized terms and books hit the cache.
+        assert_eq!(bible.search.with(|caches| caches.results.len()), 2);
+        assert_eq!(bible.search("god GOD"), hits);
+        assert_eq!(bible.search.with(|caches| caches.results.len()), 2);
+        bible.set_query_cache_size(0);
+        assert_eq!(bible.search("god"), hits);
+        assert_eq!(bible.search.with(|caches| caches.results.len()), 0);
+    }
+
+    #[test]
+    fn test_editing_updates_lookups_and_search() {
+        let book = |id: BibleBook, text: &str| {
+            let verse = Verse::new(id, 1, 1, text.to_string());
+            Book::new(
+                id.as_str().to_string(),
+                id.full_name().to_string(),
+                vec![Chapter::new(vec![verse], 1)],
+            )
+        };
+        let mut bible = TestBible::new(vec![
+            book(BibleBook::Genesis, "God created"),
+            book(BibleBook::John, "the Word"),
+        ])
+        .build();
+        assert_eq!(bible.search("god").len(), 1);
+
+        bible
+            .set_verse_text(BibleBook::John, 1, 1, "the Word was God")
+            .unwrap();
+        assert_eq!(bible.search("god").len(), 2);
+
+        assert!(bible
+            .insert_book(book(BibleBook::Exodus, "God spake"))
+            .is_none());
+        let titles: Vec<&str> = bible.books().iter().map(Book::title).collect();
+        assert_eq!(titles, ["Genesis", "Exodus", "John"]);
+        assert_eq!(bible.get_book_by_abbrev("ex").unwrap().title(), "Exodus");
+        assert_eq!(bible.search("god").len(), 3);
+
+        let replaced = bible.insert_book(book(BibleBook::Exodus, "Moses"));
+        assert_eq!(replaced.unwrap().title(), "Exodus");
+        assert_eq!(bible.books().len(), 3);
+
+        let removed = bible.remove_book(BibleBook::Genesis).unwrap();
+        assert_eq!(removed.title(), "Genesis");
+        assert!(bible.get_book(BibleBook::Genesis).is_err());
+        assert_eq!(bible.get_book_by_abbrev("john").unwrap().title(), "John");
+        assert_eq!(bible.search("god").len(), 1);
+    }
+
+    #[test]
+    fn test_filter_canon() {
+        let book = one_verse_book;
+        let bible = TestBible::new(vec![
+            book(BibleBook::Tobit),
+            book(BibleBook::John),
+            book(BibleBook::Psalm151),
+            book(BibleBook::Genesis),
+        ])
+        .build();
+
+        let ids = |bible: &Bible| -> Vec<BibleBook> {
+            bible
+                .books()
+                .iter()
+                .filter_map(|b| b.id().known())
+                .collect()
+        };
+        let protestant = bible.filter_canon(Canon::Protestant);
+        assert_eq!(ids(&protestant), [BibleBook::Genesis, BibleBook::John]);
+        assert!(protestant.get_book(BibleBook::Tobit).is_err());
+        assert_eq!(protestant.get_book_by_abbrev("jo").unwrap().title(), "John");
+
+        let catholic = bible.filter_canon(Canon::Deuterocanon);
+        assert_eq!(catholic.books().len(), 3);
+        let custom = bible.filter_canon(
+            CanonProfile::new(Canon::Protestant)
+                .with_book(BibleBook::Psalm151)
+                .without_book(BibleBook::John),
+        );
+        assert_eq!(ids(&custom), [BibleBook::Genesis, BibleBook::Psalm151]);
+    }
+
+    #[test]
+    fn test_deuterocanon_placement_orders_and_navigates() {
+        let book = one_verse_book;
+        let bible = TestBible::new(vec![
+            book(BibleBook::Matthew),
+            book(BibleBook::Tobit),
+            Book::new("enoch".into(), "Enoch".into(), vec![]),
+            book(BibleBook::Malachi),
+            book(BibleBook::Nehemiah),
+        ])
+        .build();
+        let order = |bible: &Bible| -> Vec<String> {
+            bible
+                .books_in_canonical_order()
+                .iter()
+                .map(|b| b.abbrev().to_string())
+                .collect()
+        };
+
+        assert_eq!(order(&bible), ["ne", "ml", "mt", "tb", "enoch"]);
+        let between = bible
+            .clone()
+            .with_deuterocanon_placement(DeuterocanonPlacement::BetweenTestaments);
+        assert_eq!(order(&between), ["ne", "ml", "tb", "mt", "enoch"]);
+        let interleaved = bible.with_deuterocanon_placement(DeuterocanonPlacement::Interleaved);
+        assert_eq!(order(&interleaved), ["ne", "tb", "ml", "mt", "enoch"]);
+
+        let next = interleaved.next_book(BibleBook::Nehemiah).unwrap();
+        assert_eq!(next.map(|b| b.abbrev()), Some("tb"));
+        let previous = interleaved.previous_book(BibleBook::Nehemiah).unwrap();
+        assert!(previous.is_none());
+        assert!(interleaved.next_book(BibleBook::Genesis).is_err());
+
+        let json = interleaved.to_json().unwrap();
+        assert!(json.find("\"tb\"").unwrap() < json.find("\"ml\"").unwrap());
+    }
+
+    #[test]
+    fn test_subset_keeps_verse_numbers() {
+        let chapter = |n: usize| {
+            let verses = (1..=3)
+                .map(|v| Verse::new(BibleBook::John, n, v, format!("John {}:{}", n, v)))
+                .collect();
+            Chapter::new(verses, n).with_superscription(Some("Title".to_string()))
+        };
+        let john = Book::new("jo".into(), "John".into(), (1..=3).map(chapter).collect());
+        let mut bible = rebuild_index(create_test_bible());
+        bible.insert_book(john);
+
+        let subset = bible.subset(&[
+            ReferenceRange::new(
+                Reference::new(BibleBook::John, 2, 2),
+                Reference::new(BibleBook::John, 3, 1),
+            ),
+            ReferenceRange::single(Reference::new(BibleBook::Genesis, 1, 1)),
+        ]);
+        assert_eq!(subset.books().len(), 2);
+        assert_eq!(subset.name(), bible.name());
+
+        let john = subset.get_book(BibleBook::John).unwrap();
+        let numbers: Vec<usize> = john.chapters().iter().map(Chapter::number).collect();
+        assert_eq!(numbers, [2, 3]);
+        assert_eq!(john.get_verse(2, 3).unwrap().text(), "John 2:3");
+        assert!(john.get_verse(2, 1).is_err());
+        assert_eq!(john.get_chapter(2).unwrap().superscription(), None);
+        assert_eq!(john.get_chapter(3).unwrap().superscription(), Some("Title"));
+        assert_eq!(john.get_chapter(3).unwrap().get_verses().len(), 1);
+    }
+
+    #[test]
+    fn test_merge_parts() {
+        let part = |name: &str, ids: &[BibleBook]| {
+            let books = ids
+                .iter()
+                .map(|&id| {
+                    let verse = Verse::new(id, 1, 1, name.to_string());
+                    Book::new(
+                        id.as_str().to_string(),
+                        id.full_name().to_string(),
+                        vec![Chapter::new(vec![verse], 1)],
+                    )
+                })
+                .collect();
+            TestBible::new(books).with_name(name).build()
+        };
+        let parts = || {
+            vec![
+                part("nt", &[BibleBook::John]),
+                part("ot", &[BibleBook::Genesis, BibleBook::John]),
+            ]
+        };
+
+        assert!(matches!(
+            Bible::merge(parts(), MergePolicy::Error),
+            Err(BibleError::MergeConflict { .. })
+        ));
+        let first = Bible::merge(parts(), MergePolicy::PreferFirst).unwrap();
+        assert_eq!(first.name(), "nt");
+        assert_eq!(first.books()[0].title(), "Genesis");
+        assert_eq!(first.get_verse(BibleBook::John, 1, 1).unwrap().text(), "nt");
+        let last = Bible::merge(parts(), MergePolicy::PreferLast).unwrap();
+        assert_eq!(last.get_verse(BibleBook::John, 1, 1).unwrap().text(), "ot");
+        assert_eq!(last.get_book_by_abbrev("gn").unwrap().title(), "Genesis");
+
+        let whole = Bible::merge(
+            vec![
+                part("ot", &[BibleBook::Genesis]),
+                part("nt", &[BibleBook::John]),
+            ],
+            MergePolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(whole.books().len(), 2);
+        assert!(Bible::merge(Vec::new(), MergePolicy::Error)
+            .unwrap()
+            .books()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_get_context_crosses_chapters() {
+        let chapters = (1..=2)
+            .map(|c| {
+                let verses = (1..=3)
+                    .map(|v| Verse::new(BibleBook::Genesis, c, v, format!("{}:{}", c, v)))
+                    .collect();
+                Chapter::new(verses, c)
+            })
+            .collect();
+        let mut bible = create_test_bible();
+        bible.books[0] = Book::new("gn".to_string(), "Genesis".to_string(), chapters);
+
+        let texts = |r: Reference, before, after| -> Vec<String> {
+            bible
+                .get_context(&r, before, after)
+                .unwrap()
+                .iter()
+                .map(|v| v.text().to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            texts(Reference::new(BibleBook::Genesis, 2, 1), 2, 1),
+            ["1:2", "1:3", "2:1", "2:2"]
+        );
+        assert_eq!(
+            texts(Reference::new(BibleBook::Genesis, 1, 1), 5, 0),
+            ["1:1"]
+        );
+        assert_eq!(
+            texts(Reference::new(BibleBook::Genesis, 2, 3), 0, 9),
+            ["2:3"]
+        );
+        assert!(bible
+            .get_context(&Reference::new(BibleBook::Genesis, 3, 1), 1, 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_omitted_verses_are_missing() {
+        for json in [
+            r#"{"books": {"mk": {"name": "Mark", "chapters": [[], ["a", "", "c"]]}}}"#,
+            r#"{"books": {"mk": {"name": "Mark", "chapters": {"2": {"1": "a", "3": "c"}}}}}"#,
+        ] {
+            let bible = Bible::from_json_bytes(json.as_bytes(), JsonSchema::Native).unwrap();
+            assert_eq!(bible.get_verse(BibleBook::Mark, 2, 3).unwrap().text(), "c");
+            assert!(matches!(
+                bible.get_verse(BibleBook::Mark, 2, 2),
+                Err(BibleError::VerseMissing { verse: 2, .. })
+            ));
+            assert!(matches!(
+                bible.get_verse(BibleBook::Mark, 2, 4),
+                Err(BibleError::VerseOutOfBounds { max_verse: 3, .. })
+            ));
+            let reference = Reference::new(BibleBook::Mark, 2, 3);
+            assert_eq!(bible.get_context(&reference, 1, 0).unwrap().len(), 2);
+
+            let json = bible.to_json().unwrap();
+            let reloaded = Bible::from_json_bytes(json.as_bytes(), JsonSchema::Native).unwrap();
+            assert!(reloaded.get_verse(BibleBook::Mark, 2, 2).is_err());
+            assert_eq!(
+                reloaded.get_verse(BibleBook::Mark, 2, 3).unwrap().text(),
+                "c"
+            );
+        }
+    }
+
+    #[test]
+    fn test_verse_bridges() {
+        let json = r#"{"books": {"rt": {"name": "Ruth", "chapters": [["One", "Two and three", "", "Four"]],
+            "bridges": {"1": [[2, 3]]}}}}"#;
+        let bible = Bible::from_json_bytes(json.as_bytes(), JsonSchema::Native).unwrap();
+        let bridge = bible.get_verse(BibleBook::Ruth, 1, 3).unwrap();
+        assert_eq!((bridge.number(), bridge.last_number()), (2, 3));
+        assert_eq!(bible.get_verse(BibleBook::Ruth, 1, 2).unwrap(), bridge);
+        assert_eq!(
+            bible.get_verse(BibleBook::Ruth, 1, 4).unwrap().text(),
+            "Four"
+        );
+
+        let range = ReferenceRange::new(
+            Reference::new(BibleBook::Ruth, 1, 3),
+            Reference::new(BibleBook::Ruth, 1, 4),
+        );
+        assert_eq!(bible.passage(&range).unwrap().verses().len(), 2);
+
+        let json = bible.to_json().unwrap();
+        let reloaded = Bible::from_json_bytes(json.as_bytes(), JsonSchema::Native).unwrap();
+        assert!(reloaded
+            .get_verse(BibleBook::Ruth, 1, 3)
+            .unwrap()
+            .is_bridge());
+
+        // A bridge that ends the chapter keeps its placeholders on export.
+        let json = r#"{"books": {"rt": {"name": "Ruth", "chapters": [["One", "Two", ""]],
+            "bridges": {"1": [[2, 3]]}}}}"#;
+        let bible = Bible::from_json_bytes(json.as_bytes(), JsonSchema::Native).unwrap();
+        let json = bible.to_json().unwrap();
+        let reloaded = Bible::from_json_bytes(json.as_bytes(), JsonSchema::Native).unwrap();
+        assert_eq!(
+            reloaded
+                .get_verse(BibleBook::Ruth, 1, 3)
+                .unwrap()
+                .number_label(),
+            "2-3"
+        );
+    }
+
+    #[test]
+    fn test_invalid_bridges_ignored() {
+        let json = r#"{"books": {"rt": {"name": "Ruth", "chapters": [["One", "Two", "", "Four"]],
+            "bridges": {"1": [[2, 100000], [1, 2], [2, 3], [3, 4]]}}}}"#;
+        let bible = Bible::from_json_bytes(json.as_bytes(), JsonSchema::Native).unwrap();
+        let chapter = bible
+            .get_book(BibleBook::Ruth)
+            .unwrap()
+            .get_chapter(1)
+            .unwrap();
+        assert_eq!(chapter.max_verse(), 4);
+        let labels: Vec<String> = chapter
+            .get_verses()
+            .iter()
+            .map(Verse::number_label)
+            .collect();
+        assert_eq!(labels, ["1", "2-3", "4"]);
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let verses = |book, numbers: &[usize]| {
+            numbers
+                .iter()
+                .map(|&n| Verse::new(book, 1, n, format!("verse {n}")))
+                .collect()
+        };
+        let mut bible = TestBible::new(vec![
+            Book::new(
+                "ex".to_string(),
+                "Exodus".to_string(),
+                vec![Chapter::new(verses(BibleBook::Exodus, &[1]), 1)],
+            ),
+            Book::new(
+                "gn".to_string(),
+                "Genesis".to_string(),
+                vec![Chapter::new(verses(BibleBook::Genesis, &[2, 1]), 1)],
+            ),
+        ])
+        .build();
+
+        assert!(bible.canonicalize());
+        assert_eq!(*bible.books()[0].id(), BibleBook::Genesis);
+        let numbers: Vec<usize> = bible.books()[0].chapters()[0]
+            .get_verses()
+            .iter()
+            .map(Verse::number)
+            .collect();
+        assert_eq!(numbers, [1, 2]);
+        assert_eq!(
+            bible.get_verse(BibleBook::Genesis, 1, 2).unwrap().text(),
+            "verse 2"
+        );
+        assert!(!bible.canonicalize());
+    }
+
+    #[test]
+    fn test_get_book_by_abbrev_uses_names_and_aliases() {
+        let mut bible = create_test_bible();
+        bible.books.push(
+            Book::new("1sm".to_string(), "1 Samuel".to_string(), vec![])
+                .with_aliases(vec!["Primero Samuel".to_string()]),
+        );
+        let bible = rebuild_index(bible);
+
+        let title = |abbrev: &str| bible.get_book_by_abbrev(abbrev).map(|b| b.title());
+        assert_eq!(title("genesis").unwrap(), "Genesis");
+        assert_eq!(title("GEN").unwrap(), "Genesis");
+        assert_eq!(title("1sa").unwrap(), "1 Samuel");
+        assert_eq!(title("primero samuel").unwrap(), "1 Samuel");
+        assert!(title("exodus").is_err());
+    }
+
+    #[test]
+    fn test_book_index_is_case_insensitive() {
+        let mut bible = create_test_bible();
+        bible.books[0] = bible.books[0]
+            .clone()
+            .with_aliases(vec!["Génesis".to_string()]);
+        let bible = rebuild_index(bible);
+
+        assert_eq!(bible.book_index("gn"), Some(0));
+        assert_eq!(bible.book_index("GeNeSiS"), Some(0));
+        assert_eq!(bible.book_index("GÉNESIS"), Some(0));
+        assert_eq!(bible.book_index(&"G".repeat(40)), None);
+    }
+
     #[test]
     fn test_resolve_book_abbreviations() {
         let bible = create_test_bible();
 
-        assert_eq!(bible.resolve_book("Gen"), Some(BibleBook::Genesis));
-        assert_eq!(bible.resolve_book("Ge"), Some(BibleBook::Genesis));
-        assert_eq!(bible.resolve_book("Jn"), Some(BibleBook::John));
-        assert_eq!(bible.resolve_book("Rev"), Some(BibleBook::Revelation));
+        assert_eq!(bible.resolve_book("Gen"), Some(BibleBook::Genesis.into()));
+        assert_eq!(bible.resolve_book("Ge"), Some(BibleBook::Genesis.into()));
+        assert_eq!(bible.resolve_book("Jn"), Some(BibleBook::John.into()));
+        assert_eq!(
+            bible.resolve_book("Rev"),
+            Some(BibleBook::Revelation.into())
+        );
     }
 }
@@ -1,6 +1,9 @@
+use std::cmp::Ordering;
 use std::fmt;
 use std::str::FromStr;
 
+use crate::book_id::BookId;
+
 /// Represents Bible books across Protestant (66), Catholic (Deuterocanon), and
 /// Eastern Orthodox canons, using compact lowercase abbreviations suited for JSON.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -96,6 +99,130 @@ pub enum BibleBook {
 }
 
 impl BibleBook {
+    /// The number of books known to this crate.
+    pub const COUNT: usize = 83;
+
+    /// Every book in canonical order: the 66 Protestant books, then the
+    /// deuterocanon, then Orthodox additions.
+    pub const ALL: [BibleBook; BibleBook::COUNT] = [
+        BibleBook::Genesis,
+        BibleBook::Exodus,
+        BibleBook::Leviticus,
+        BibleBook::Numbers,
+        BibleBook::Deuteronomy,
+        BibleBook::Joshua,
+        BibleBook::Judges,
+        BibleBook::Ruth,
+        BibleBook::FirstSamuel,
+        BibleBook::SecondSamuel,
+        BibleBook::FirstKings,
+        BibleBook::SecondKings,
+        BibleBook::FirstChronicles,
+        BibleBook::SecondChronicles,
+        BibleBook::Ezra,
+        BibleBook::Nehemiah,
+        BibleBook::Esther,
+        BibleBook::Job,
+        BibleBook::Psalms,
+        BibleBook::Proverbs,
+        BibleBook::Ecclesiastes,
+        BibleBook::SongOfSolomon,
+        BibleBook::Isaiah,
+        BibleBook::Jeremiah,
+        BibleBook::Lamentations,
+        BibleBook::Ezekiel,
+        BibleBook::Daniel,
+        BibleBook::Hosea,
+        BibleBook::Joel,
+        BibleBook::Amos,
+        BibleBook::Obadiah,
+        BibleBook::Jonah,
+        BibleBook::Micah,
+        BibleBook::Nahum,
+        BibleBook::Habakkuk,
+        BibleBook::Zephaniah,
+        BibleBook::Haggai,
+        BibleBook::Zechariah,
+        BibleBook::Malachi,
+        BibleBook::Matthew,
+        BibleBook::Mark,
+        BibleBook::Luke,
+        BibleBook::John,
+        BibleBook::Acts,
+        BibleBook::Romans,
+        BibleBook::FirstCorinthians,
+        BibleBook::SecondCorinthians,
+        BibleBook::Galatians,
+        BibleBook::Ephesians,
+        BibleBook::Philippians,
+        BibleBook::Colossians,
+        BibleBook::FirstThessalonians,
+        BibleBook::SecondThessalonians,
+        BibleBook::FirstTimothy,
+        BibleBook::SecondTimothy,
+        BibleBook::Titus,
+        BibleBook::Philemon,
+        BibleBook::Hebrews,
+        BibleBook::James,
+        BibleBook::FirstPeter,
+        BibleBook::SecondPeter,
+        BibleBook::FirstJohn,
+        BibleBook::SecondJohn,
+        BibleBook::ThirdJohn,
+        BibleBook::Jude,
+        BibleBook::Revelation,
+        BibleBook::Tobit,
+        BibleBook::Judith,
+        BibleBook::Wisdom,
+        BibleBook::Sirach,
+        BibleBook::Baruch,
+        BibleBook::FirstMaccabees,
+        BibleBook::SecondMaccabees,
+        BibleBook::EstherAdditions,
+        BibleBook::DanielSongOfThree,
+        BibleBook::DanielSusanna,
+        BibleBook::DanielBelAndTheDragon,
+        BibleBook::FirstEsdras,
+        BibleBook::SecondEsdras,
+        BibleBook::PrayerOfManasseh,
+        BibleBook::Psalm151,
+        BibleBook::ThirdMaccabees,
+        BibleBook::FourthMaccabees,
+    ];
+
+    /// Returns the book at a 1-based position in [`BibleBook::ALL`], so 1 is
+    /// Genesis and 66 is Revelation, as in most verse-per-row datasets.
+    pub fn from_number(number: usize) -> Option<BibleBook> {
+        Self::ALL.get(number.checked_sub(1)?).copied()
+    }
+
+    /// Returns a stable numeric id for compact storage in databases,
+    /// bitsets, and binary formats.
+    ///
+    /// Ids run from 1 (Genesis) to [`BibleBook::COUNT`] in [`BibleBook::ALL`]
+    /// order, leaving 0 free to mean "no book". New books are only ever
+    /// appended, so existing ids never change.
+    pub const fn id(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    /// Returns the book with the given [`id`](BibleBook::id).
+    pub const fn from_id(id: u8) -> Option<BibleBook> {
+        if id == 0 || id as usize > Self::COUNT {
+            return None;
+        }
+        Some(Self::ALL[id as usize - 1])
+    }
+
+    /// Finds a book by its English [full name](BibleBook::full_name),
+    /// ignoring case and surrounding whitespace.
+    pub fn from_name(name: &str) -> Option<BibleBook> {
+        let name = name.trim();
+        Self::ALL
+            .into_iter()
+            .find(|book| book.full_name().eq_ignore_ascii_case(name))
+    }
+
     /// Returns the compact abbreviation for this Bible book (e.g., "gn", "jdt", "ps151").
     pub const fn as_str(&self) -> &'static str {
         match self {
@@ -285,6 +412,816 @@ impl BibleBook {
     }
 }
 
+/// The canon tradition that first includes a book.
+///
+/// Traditions nest in declaration order: each canon includes the books of
+/// the ones before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Canon {
+    /// The 66 books shared by all traditions.
+    Protestant,
+    /// The Catholic deuterocanonical books and additions.
+    Deuterocanon,
+    /// Books found only in Eastern Orthodox (and related) canons.
+    Orthodox,
+}
+
+impl fmt::Display for Canon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Canon::Protestant => "Protestant canon",
+            Canon::Deuterocanon => "Catholic deuterocanon",
+            Canon::Orthodox => "Eastern Orthodox canon",
+        })
+    }
+}
+
+impl Canon {
+    /// Returns `true` if this canon includes `book`.
+    pub const fn includes(&self, book: BibleBook) -> bool {
+        book.canon() as u8 <= *self as u8
+    }
+}
+
+/// A set of books targeted by an app, built from a [`Canon`] with
+/// individual books added or removed, for use with
+/// [`Bible::filter_canon`](crate::Bible::filter_canon).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanonProfile {
+    canon: Canon,
+    added: Vec<BibleBook>,
+    removed: Vec<BibleBook>,
+}
+
+impl CanonProfile {
+    /// Creates a profile with exactly the books of `canon`.
+    pub fn new(canon: Canon) -> Self {
+        CanonProfile {
+            canon,
+            added: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+
+    /// Includes `book` even if the base canon does not.
+    pub fn with_book(mut self, book: BibleBook) -> Self {
+        self.removed.retain(|&b| b != book);
+        self.added.push(book);
+        self
+    }
+
+    /// Excludes `book` even if the base canon includes it.
+    pub fn without_book(mut self, book: BibleBook) -> Self {
+        self.added.retain(|&b| b != book);
+        self.removed.push(book);
+        self
+    }
+
+    /// Returns the base canon.
+    pub fn canon(&self) -> Canon {
+        self.canon
+    }
+
+    /// Returns `true` if the profile includes `book`.
+    pub fn includes(&self, book: BibleBook) -> bool {
+        if self.removed.contains(&book) {
+            return false;
+        }
+        self.canon.includes(book) || self.added.contains(&book)
+    }
+}
+
+/// Where deuterocanonical and Orthodox books sit among the others when a
+/// Bible is ordered, navigated, or exported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DeuterocanonPlacement {
+    /// After Revelation, in [`BibleBook::ALL`] order.
+    #[default]
+    Appendix,
+    /// As a block between Malachi and Matthew, as in Luther's Bible and the
+    /// 1611 King James Version.
+    BetweenTestaments,
+    /// Within the Old Testament beside related books, as in Catholic and
+    /// Orthodox Bibles: Tobit and Judith after Nehemiah, the Maccabees after
+    /// Esther, Wisdom and Sirach after the Song of Songs, and so on.
+    Interleaved,
+}
+
+impl DeuterocanonPlacement {
+    /// Every book in the order this placement gives them.
+    pub const fn order(&self) -> &'static [BibleBook; BibleBook::COUNT] {
+        match self {
+            DeuterocanonPlacement::Appendix => &BibleBook::ALL,
+            DeuterocanonPlacement::BetweenTestaments => &BETWEEN_TESTAMENTS_ORDER,
+            DeuterocanonPlacement::Interleaved => &INTERLEAVED_ORDER,
+        }
+    }
+
+    /// Returns the 0-based position of `book` in [`order`](Self::order).
+    pub fn position(&self, book: BibleBook) -> usize {
+        self.order()
+            .iter()
+            .position(|&b| b == book)
+            .unwrap_or(BibleBook::ALL.len())
+    }
+
+    /// Orders two books by this placement. Custom books follow all known
+    /// books, ordered by abbreviation.
+    pub fn compare(&self, a: &BookId, b: &BookId) -> Ordering {
+        match (a.known(), b.known()) {
+            (Some(a), Some(b)) => self.position(a).cmp(&self.position(b)),
+            _ => a.cmp(b),
+        }
+    }
+}
+
+/// The 39 Old Testament books, the deuterocanon and Orthodox additions, then
+/// the New Testament.
+const BETWEEN_TESTAMENTS_ORDER: [BibleBook; BibleBook::COUNT] = {
+    const OLD_TESTAMENT: usize = 39;
+    const ADDITIONS: usize = 17;
+    let mut order = BibleBook::ALL;
+    let mut i = OLD_TESTAMENT;
+    while i < order.len() {
+        order[i] = if i < OLD_TESTAMENT + ADDITIONS {
+            BibleBook::ALL[i + 27]
+        } else {
+            BibleBook::ALL[i - ADDITIONS]
+        };
+        i += 1;
+    }
+    order
+};
+
+const INTERLEAVED_ORDER: [BibleBook; BibleBook::COUNT] = [
+    BibleBook::Genesis,
+    BibleBook::Exodus,
+    BibleBook::Leviticus,
+    BibleBook::Numbers,
+    BibleBook::Deuteronomy,
+    BibleBook::Joshua,
+    BibleBook::Judges,
+    BibleBook::Ruth,
+    BibleBook::FirstSamuel,
+    BibleBook::SecondSamuel,
+    BibleBook::FirstKings,
+    BibleBook::SecondKings,
+    BibleBook::FirstChronicles,
+    BibleBook::SecondChronicles,
+    BibleBook::PrayerOfManasseh,
+    BibleBook::FirstEsdras,
+    BibleBook::Ezra,
+    BibleBook::Nehemiah,
+    BibleBook::SecondEsdras,
+    BibleBook::Tobit,
+    BibleBook::Judith,
+    BibleBook::Esther,
+    BibleBook::EstherAdditions,
+    BibleBook::FirstMaccabees,
+    BibleBook::SecondMaccabees,
+    BibleBook::ThirdMaccabees,
+    BibleBook::FourthMaccabees,
+    BibleBook::Job,
+    BibleBook::Psalms,
+    BibleBook::Psalm151,
+    BibleBook::Proverbs,
+    BibleBook::Ecclesiastes,
+    BibleBook::SongOfSolomon,
+    BibleBook::Wisdom,
+    BibleBook::Sirach,
+    BibleBook::Isaiah,
+    BibleBook::Jeremiah,
+    BibleBook::Lamentations,
+    BibleBook::Baruch,
+    BibleBook::Ezekiel,
+    BibleBook::Daniel,
+    BibleBook::DanielSongOfThree,
+    BibleBook::DanielSusanna,
+    BibleBook::DanielBelAndTheDragon,
+    BibleBook::Hosea,
+    BibleBook::Joel,
+    BibleBook::Amos,
+    BibleBook::Obadiah,
+    BibleBook::Jonah,
+    BibleBook::Micah,
+    BibleBook::Nahum,
+    BibleBook::Habakkuk,
+    BibleBook::Zephaniah,
+    BibleBook::Haggai,
+    BibleBook::Zechariah,
+    BibleBook::Malachi,
+    BibleBook::Matthew,
+    BibleBook::Mark,
+    BibleBook::Luke,
+    BibleBook::John,
+    BibleBook::Acts,
+    BibleBook::Romans,
+    BibleBook::FirstCorinthians,
+    BibleBook::SecondCorinthians,
+    BibleBook::Galatians,
+    BibleBook::Ephesians,
+    BibleBook::Philippians,
+    BibleBook::Colossians,
+    BibleBook::FirstThessalonians,
+    BibleBook::SecondThessalonians,
+    BibleBook::FirstTimothy,
+    BibleBook::SecondTimothy,
+    BibleBook::Titus,
+    BibleBook::Philemon,
+    BibleBook::Hebrews,
+    BibleBook::James,
+    BibleBook::FirstPeter,
+    BibleBook::SecondPeter,
+    BibleBook::FirstJohn,
+    BibleBook::SecondJohn,
+    BibleBook::ThirdJohn,
+    BibleBook::Jude,
+    BibleBook::Revelation,
+];
+
+impl From<Canon> for CanonProfile {
+    fn from(canon: Canon) -> Self {
+        CanonProfile::new(canon)
+    }
+}
+
+/// The traditional sections books are grouped into, as in the headings of
+/// a grouped book menu.
+///
+/// Acts is counted with the historical books, and Hebrews with the general
+/// epistles. Deuterocanonical books join the section of their genre: Tobit,
+/// Judith, the Maccabees and 1 Esdras the history, Wisdom, Sirach, the
+/// Prayer of Manasseh and Psalm 151 the wisdom books, Baruch and the
+/// additions to Daniel the major prophets, and 2 Esdras the apocalypses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Division {
+    /// Genesis to Deuteronomy, the Pentateuch.
+    Law,
+    /// Joshua to Esther, and Acts.
+    History,
+    /// Job to the Song of Solomon.
+    Wisdom,
+    /// Isaiah to Daniel.
+    MajorProphets,
+    /// Hosea to Malachi, the Twelve.
+    MinorProphets,
+    /// Matthew to John.
+    Gospels,
+    /// Romans to Philemon.
+    PaulineEpistles,
+    /// Hebrews to Jude.
+    GeneralEpistles,
+    /// Revelation.
+    Apocalypse,
+}
+
+impl Division {
+    /// Every division in canonical order.
+    pub const ALL: [Division; 9] = [
+        Division::Law,
+        Division::History,
+        Division::Wisdom,
+        Division::MajorProphets,
+        Division::MinorProphets,
+        Division::Gospels,
+        Division::PaulineEpistles,
+        Division::GeneralEpistles,
+        Division::Apocalypse,
+    ];
+}
+
+impl fmt::Display for Division {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Division::Law => "Law",
+            Division::History => "History",
+            Division::Wisdom => "Wisdom",
+            Division::MajorProphets => "Major Prophets",
+            Division::MinorProphets => "Minor Prophets",
+            Division::Gospels => "Gospels",
+            Division::PaulineEpistles => "Pauline Epistles",
+            Division::GeneralEpistles => "General Epistles",
+            Division::Apocalypse => "Apocalypse",
+        })
+    }
+}
+
+impl BibleBook {
+    /// Returns the narrowest canon that includes this book.
+    pub const fn canon(&self) -> Canon {
+        match self {
+            BibleBook::Tobit
+            | BibleBook::Judith
+            | BibleBook::Wisdom
+            | BibleBook::Sirach
+            | BibleBook::Baruch
+            | BibleBook::FirstMaccabees
+            | BibleBook::SecondMaccabees
+            | BibleBook::EstherAdditions
+            | BibleBook::DanielSongOfThree
+            | BibleBook::DanielSusanna
+            | BibleBook::DanielBelAndTheDragon => Canon::Deuterocanon,
+            BibleBook::FirstEsdras
+            | BibleBook::SecondEsdras
+            | BibleBook::PrayerOfManasseh
+            | BibleBook::Psalm151
+            | BibleBook::ThirdMaccabees
+            | BibleBook::FourthMaccabees => Canon::Orthodox,
+            _ => Canon::Protestant,
+        }
+    }
+
+    /// Returns the [`Division`] this book belongs to.
+    pub const fn division(&self) -> Division {
+        use BibleBook::*;
+        match self {
+            Genesis | Exodus | Leviticus | Numbers | Deuteronomy => Division::Law,
+            Joshua | Judges | Ruth | FirstSamuel | SecondSamuel | FirstKings | SecondKings
+            | FirstChronicles | SecondChronicles | Ezra | Nehemiah | Esther | Acts | Tobit
+            | Judith | FirstMaccabees | SecondMaccabees | EstherAdditions | FirstEsdras
+            | ThirdMaccabees | FourthMaccabees => Division::History,
+            Job | Psalms | Proverbs | Ecclesiastes | SongOfSolomon | Wisdom | Sirach
+            | PrayerOfManasseh | Psalm151 => Division::Wisdom,
+            Isaiah
+            | Jeremiah
+            | Lamentations
+            | Ezekiel
+            | Daniel
+            | Baruch
+            | DanielSongOfThree
+            | DanielSusanna
+            | DanielBelAndTheDragon => Division::MajorProphets,
+            Hosea | Joel | Amos | Obadiah | Jonah | Micah | Nahum | Habakkuk | Zephaniah
+            | Haggai | Zechariah | Malachi => Division::MinorProphets,
+            Matthew | Mark | Luke | John => Division::Gospels,
+            Romans | FirstCorinthians | SecondCorinthians | Galatians | Ephesians | Philippians
+            | Colossians | FirstThessalonians | SecondThessalonians | FirstTimothy
+            | SecondTimothy | Titus | Philemon => Division::PaulineEpistles,
+            Hebrews | James | FirstPeter | SecondPeter | FirstJohn | SecondJohn | ThirdJohn
+            | Jude => Division::GeneralEpistles,
+            Revelation | SecondEsdras => Division::Apocalypse,
+        }
+    }
+
+    /// Iterates over the books of `division` in [`BibleBook::ALL`] order.
+    /// Filter by [`Canon::includes`] to leave out the deuterocanon.
+    pub fn books_in(division: Division) -> impl Iterator<Item = BibleBook> {
+        Self::ALL
+            .into_iter()
+            .filter(move |book| book.division() == division)
+    }
+
+    /// Returns the Protestant book that a deuterocanonical addition extends,
+    /// e.g. [`BibleBook::Psalms`] for [`BibleBook::Psalm151`].
+    ///
+    /// Independent books such as Tobit have no equivalent and return `None`.
+    pub const fn closest_equivalent(&self) -> Option<BibleBook> {
+        match self {
+            BibleBook::Psalm151 => Some(BibleBook::Psalms),
+            BibleBook::EstherAdditions => Some(BibleBook::Esther),
+            BibleBook::DanielSongOfThree
+            | BibleBook::DanielSusanna
+            | BibleBook::DanielBelAndTheDragon => Some(BibleBook::Daniel),
+            BibleBook::FirstEsdras => Some(BibleBook::Ezra),
+            BibleBook::PrayerOfManasseh => Some(BibleBook::SecondChronicles),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` for books with a single chapter, such as Jude, which
+    /// are cited by verse number alone (`"Jude 3"`).
+    pub const fn is_single_chapter(&self) -> bool {
+        matches!(
+            self,
+            BibleBook::Obadiah
+                | BibleBook::Philemon
+                | BibleBook::SecondJohn
+                | BibleBook::ThirdJohn
+                | BibleBook::Jude
+                | BibleBook::DanielSongOfThree
+                | BibleBook::DanielSusanna
+                | BibleBook::DanielBelAndTheDragon
+                | BibleBook::PrayerOfManasseh
+                | BibleBook::Psalm151
+        )
+    }
+
+    /// Returns the OSIS book identifier, such as `"Gen"`, `"1Sam"`, or `"Tob"`,
+    /// as used in OSIS XML and SWORD modules.
+    pub const fn osis_id(&self) -> &'static str {
+        match self {
+            BibleBook::Genesis => "Gen",
+            BibleBook::Exodus => "Exod",
+            BibleBook::Leviticus => "Lev",
+            BibleBook::Numbers => "Num",
+            BibleBook::Deuteronomy => "Deut",
+            BibleBook::Joshua => "Josh",
+            BibleBook::Judges => "Judg",
+            BibleBook::Ruth => "Ruth",
+            BibleBook::FirstSamuel => "1Sam",
+            BibleBook::SecondSamuel => "2Sam",
+            BibleBook::FirstKings => "1Kgs",
+            BibleBook::SecondKings => "2Kgs",
+            BibleBook::FirstChronicles => "1Chr",
+            BibleBook::SecondChronicles => "2Chr",
+            BibleBook::Ezra => "Ezra",
+            BibleBook::Nehemiah => "Neh",
+            BibleBook::Esther => "Esth",
+            BibleBook::Job => "Job",
+            BibleBook::Psalms => "Ps",
+            BibleBook::Proverbs => "Prov",
+            BibleBook::Ecclesiastes => "Eccl",
+            BibleBook::SongOfSolomon => "Song",
+            BibleBook::Isaiah => "Isa",
+            BibleBook::Jeremiah => "Jer",
+            BibleBook::Lamentations => "Lam",
+            BibleBook::Ezekiel => "Ezek",
+            BibleBook::Daniel => "Dan",
+            BibleBook::Hosea => "Hos",
+            BibleBook::Joel => "Joel",
+            BibleBook::Amos => "Amos",
+            BibleBook::Obadiah => "Obad",
+            BibleBook::Jonah => "Jonah",
+            BibleBook::Micah => "Mic",
+            BibleBook::Nahum => "Nah",
+            BibleBook::Habakkuk => "Hab",
+            BibleBook::Zephaniah => "Zeph",
+            BibleBook::Haggai => "Hag",
+            BibleBook::Zechariah => "Zech",
+            BibleBook::Malachi => "Mal",
+            BibleBook::Matthew => "Matt",
+            BibleBook::Mark => "Mark",
+            BibleBook::Luke => "Luke",
+            BibleBook::John => "John",
+            BibleBook::Acts => "Acts",
+            BibleBook::Romans => "Rom",
+            BibleBook::FirstCorinthians => "1Cor",
+            BibleBook::SecondCorinthians => "2Cor",
+            BibleBook::Galatians => "Gal",
+            BibleBook::Ephesians => "Eph",
+            BibleBook::Philippians => "Phil",
+            BibleBook::Colossians => "Col",
+            BibleBook::FirstThessalonians => "1Thess",
+            BibleBook::SecondThessalonians => "2Thess",
+            BibleBook::FirstTimothy => "1Tim",
+            BibleBook::SecondTimothy => "2Tim",
+            BibleBook::Titus => "Titus",
+            BibleBook::Philemon => "Phlm",
+            BibleBook::Hebrews => "Heb",
+            BibleBook::James => "Jas",
+            BibleBook::FirstPeter => "1Pet",
+            BibleBook::SecondPeter => "2Pet",
+            BibleBook::FirstJohn => "1John",
+            BibleBook::SecondJohn => "2John",
+            BibleBook::ThirdJohn => "3John",
+            BibleBook::Jude => "Jude",
+            BibleBook::Revelation => "Rev",
+            BibleBook::Tobit => "Tob",
+            BibleBook::Judith => "Jdt",
+            BibleBook::Wisdom => "Wis",
+            BibleBook::Sirach => "Sir",
+            BibleBook::Baruch => "Bar",
+            BibleBook::FirstMaccabees => "1Macc",
+            BibleBook::SecondMaccabees => "2Macc",
+            BibleBook::EstherAdditions => "AddEsth",
+            BibleBook::DanielSongOfThree => "PrAzar",
+            BibleBook::DanielSusanna => "Sus",
+            BibleBook::DanielBelAndTheDragon => "Bel",
+            BibleBook::FirstEsdras => "1Esd",
+            BibleBook::SecondEsdras => "2Esd",
+            BibleBook::PrayerOfManasseh => "PrMan",
+            BibleBook::Psalm151 => "AddPs",
+            BibleBook::ThirdMaccabees => "3Macc",
+            BibleBook::FourthMaccabees => "4Macc",
+        }
+    }
+
+    /// Returns the three-character USFM book code, such as `"GEN"`, `"1SA"`, or `"TOB"`.
+    pub const fn usfm_id(&self) -> &'static str {
+        match self {
+            BibleBook::Genesis => "GEN",
+            BibleBook::Exodus => "EXO",
+            BibleBook::Leviticus => "LEV",
+            BibleBook::Numbers => "NUM",
+            BibleBook::Deuteronomy => "DEU",
+            BibleBook::Joshua => "JOS",
+            BibleBook::Judges => "JDG",
+            BibleBook::Ruth => "RUT",
+            BibleBook::FirstSamuel => "1SA",
+            BibleBook::SecondSamuel => "2SA",
+            BibleBook::FirstKings => "1KI",
+            BibleBook::SecondKings => "2KI",
+            BibleBook::FirstChronicles => "1CH",
+            BibleBook::SecondChronicles => "2CH",
+            BibleBook::Ezra => "EZR",
+            BibleBook::Nehemiah => "NEH",
+            BibleBook::Esther => "EST",
+            BibleBook::Job => "JOB",
+            BibleBook::Psalms => "PSA",
+            BibleBook::Proverbs => "PRO",
+            BibleBook::Ecclesiastes => "ECC",
+            BibleBook::SongOfSolomon => "SNG",
+            BibleBook::Isaiah => "ISA",
+            BibleBook::Jeremiah => "JER",
+            BibleBook::Lamentations => "LAM",
+            BibleBook::Ezekiel => "EZK",
+            BibleBook::Daniel => "DAN",
+            BibleBook::Hosea => "HOS",
+            BibleBook::Joel => "JOL",
+            BibleBook::Amos => "AMO",
+            BibleBook::Obadiah => "OBA",
+            BibleBook::Jonah => "JON",
+            BibleBook::Micah => "MIC",
+            BibleBook::Nahum => "NAM",
+            BibleBook::Habakkuk => "HAB",
+            BibleBook::Zephaniah => "ZEP",
+            BibleBook::Haggai => "HAG",
+            BibleBook::Zechariah => "ZEC",
+            BibleBook::Malachi => "MAL",
+            BibleBook::Matthew => "MAT",
+            BibleBook::Mark => "MRK",
+            BibleBook::Luke => "LUK",
+            BibleBook::John => "JHN",
+            BibleBook::Acts => "ACT",
+            BibleBook::Romans => "ROM",
+            BibleBook::FirstCorinthians => "1CO",
+            BibleBook::SecondCorinthians => "2CO",
+            BibleBook::Galatians => "GAL",
+            BibleBook::Ephesians => "EPH",
+            BibleBook::Philippians => "PHP",
+            BibleBook::Colossians => "COL",
+            BibleBook::FirstThessalonians => "1TH",
+            BibleBook::SecondThessalonians => "2TH",
+            BibleBook::FirstTimothy => "1TI",
+            BibleBook::SecondTimothy => "2TI",
+            BibleBook::Titus => "TIT",
+            BibleBook::Philemon => "PHM",
+            BibleBook::Hebrews => "HEB",
+            BibleBook::James => "JAS",
+            BibleBook::FirstPeter => "1PE",
+            BibleBook::SecondPeter => "2PE",
+            BibleBook::FirstJohn => "1JN",
+            BibleBook::SecondJohn => "2JN",
+            BibleBook::ThirdJohn => "3JN",
+            BibleBook::Jude => "JUD",
+            BibleBook::Revelation => "REV",
+            BibleBook::Tobit => "TOB",
+            BibleBook::Judith => "JDT",
+            BibleBook::Wisdom => "WIS",
+            BibleBook::Sirach => "SIR",
+            BibleBook::Baruch => "BAR",
+            BibleBook::FirstMaccabees => "1MA",
+            BibleBook::SecondMaccabees => "2MA",
+            BibleBook::EstherAdditions => "ESG",
+            BibleBook::DanielSongOfThree => "S3Y",
+            BibleBook::DanielSusanna => "SUS",
+            BibleBook::DanielBelAndTheDragon => "BEL",
+            BibleBook::FirstEsdras => "1ES",
+            BibleBook::SecondEsdras => "2ES",
+            BibleBook::PrayerOfManasseh => "MAN",
+            BibleBook::Psalm151 => "PS2",
+            BibleBook::ThirdMaccabees => "3MA",
+            BibleBook::FourthMaccabees => "4MA",
+        }
+    }
+
+    /// Returns the SBL Handbook of Style abbreviation, such as `"Gen"`,
+    /// `"1 Sam"`, or `"Add Esth"`.
+    pub const fn sbl_abbrev(&self) -> &'static str {
+        match self {
+            BibleBook::Genesis => "Gen",
+            BibleBook::Exodus => "Exod",
+            BibleBook::Leviticus => "Lev",
+            BibleBook::Numbers => "Num",
+            BibleBook::Deuteronomy => "Deut",
+            BibleBook::Joshua => "Josh",
+            BibleBook::Judges => "Judg",
+            BibleBook::Ruth => "Ruth",
+            BibleBook::FirstSamuel => "1 Sam",
+            BibleBook::SecondSamuel => "2 Sam",
+            BibleBook::FirstKings => "1 Kgs",
+            BibleBook::SecondKings => "2 Kgs",
+            BibleBook::FirstChronicles => "1 Chr",
+            BibleBook::SecondChronicles => "2 Chr",
+            BibleBook::Ezra => "Ezra",
+            BibleBook::Nehemiah => "Neh",
+            BibleBook::Esther => "Esth",
+            BibleBook::Job => "Job",
+            BibleBook::Psalms => "Ps",
+            BibleBook::Proverbs => "Prov",
+            BibleBook::Ecclesiastes => "Eccl",
+            BibleBook::SongOfSolomon => "Song",
+            BibleBook::Isaiah => "Isa",
+            BibleBook::Jeremiah => "Jer",
+            BibleBook::Lamentations => "Lam",
+            BibleBook::Ezekiel => "Ezek",
+            BibleBook::Daniel => "Dan",
+            BibleBook::Hosea => "Hos",
+            BibleBook::Joel => "Joel",
+            BibleBook::Amos => "Amos",
+            BibleBook::Obadiah => "Obad",
+            BibleBook::Jonah => "Jonah",
+            BibleBook::Micah => "Mic",
+            BibleBook::Nahum => "Nah",
+            BibleBook::Habakkuk => "Hab",
+            BibleBook::Zephaniah => "Zeph",
+            BibleBook::Haggai => "Hag",
+            BibleBook::Zechariah => "Zech",
+            BibleBook::Malachi => "Mal",
+            BibleBook::Matthew => "Matt",
+            BibleBook::Mark => "Mark",
+            BibleBook::Luke => "Luke",
+            BibleBook::John => "John",
+            BibleBook::Acts => "Acts",
+            BibleBook::Romans => "Rom",
+            BibleBook::FirstCorinthians => "1 Cor",
+            BibleBook::SecondCorinthians => "2 Cor",
+            BibleBook::Galatians => "Gal",
+            BibleBook::Ephesians => "Eph",
+            BibleBook::Philippians => "Phil",
+            BibleBook::Colossians => "Col",
+            BibleBook::FirstThessalonians => "1 Thess",
+            BibleBook::SecondThessalonians => "2 Thess",
+            BibleBook::FirstTimothy => "1 Tim",
+            BibleBook::SecondTimothy => "2 Tim",
+            BibleBook::Titus => "Titus",
+            BibleBook::Philemon => "Phlm",
+            BibleBook::Hebrews => "Heb",
+            BibleBook::James => "Jas",
+            BibleBook::FirstPeter => "1 Pet",
+            BibleBook::SecondPeter => "2 Pet",
+            BibleBook::FirstJohn => "1 John",
+            BibleBook::SecondJohn => "2 John",
+            BibleBook::ThirdJohn => "3 John",
+            BibleBook::Jude => "Jude",
+            BibleBook::Revelation => "Rev",
+            BibleBook::Tobit => "Tob",
+            BibleBook::Judith => "Jdt",
+            BibleBook::Wisdom => "Wis",
+            BibleBook::Sirach => "Sir",
+            BibleBook::Baruch => "Bar",
+            BibleBook::FirstMaccabees => "1 Macc",
+            BibleBook::SecondMaccabees => "2 Macc",
+            BibleBook::EstherAdditions => "Add Esth",
+            BibleBook::DanielSongOfThree => "Sg Three",
+            BibleBook::DanielSusanna => "Sus",
+            BibleBook::DanielBelAndTheDragon => "Bel",
+            BibleBook::FirstEsdras => "1 Esd",
+            BibleBook::SecondEsdras => "2 Esd",
+            BibleBook::PrayerOfManasseh => "Pr Man",
+            BibleBook::Psalm151 => "Ps 151",
+            BibleBook::ThirdMaccabees => "3 Macc",
+            BibleBook::FourthMaccabees => "4 Macc",
+        }
+    }
+
+    /// Returns the abbreviation used by Logos Bible Software, such as `"Ge"`,
+    /// `"1 Sa"`, or `"Jn"`.
+    pub const fn logos_abbrev(&self) -> &'static str {
+        match self {
+            BibleBook::Genesis => "Ge",
+            BibleBook::Exodus => "Ex",
+            BibleBook::Leviticus => "Le",
+            BibleBook::Numbers => "Nu",
+            BibleBook::Deuteronomy => "Dt",
+            BibleBook::Joshua => "Jos",
+            BibleBook::Judges => "Jdg",
+            BibleBook::Ruth => "Ru",
+            BibleBook::FirstSamuel => "1 Sa",
+            BibleBook::SecondSamuel => "2 Sa",
+            BibleBook::FirstKings => "1 Ki",
+            BibleBook::SecondKings => "2 Ki",
+            BibleBook::FirstChronicles => "1 Ch",
+            BibleBook::SecondChronicles => "2 Ch",
+            BibleBook::Ezra => "Ezr",
+            BibleBook::Nehemiah => "Ne",
+            BibleBook::Esther => "Es",
+            BibleBook::Job => "Job",
+            BibleBook::Psalms => "Ps",
+            BibleBook::Proverbs => "Pr",
+            BibleBook::Ecclesiastes => "Ec",
+            BibleBook::SongOfSolomon => "So",
+            BibleBook::Isaiah => "Is",
+            BibleBook::Jeremiah => "Je",
+            BibleBook::Lamentations => "La",
+            BibleBook::Ezekiel => "Eze",
+            BibleBook::Daniel => "Da",
+            BibleBook::Hosea => "Ho",
+            BibleBook::Joel => "Joe",
+            BibleBook::Amos => "Am",
+            BibleBook::Obadiah => "Ob",
+            BibleBook::Jonah => "Jon",
+            BibleBook::Micah => "Mic",
+            BibleBook::Nahum => "Na",
+            BibleBook::Habakkuk => "Hab",
+            BibleBook::Zephaniah => "Zep",
+            BibleBook::Haggai => "Hag",
+            BibleBook::Zechariah => "Zec",
+            BibleBook::Malachi => "Mal",
+            BibleBook::Matthew => "Mt",
+            BibleBook::Mark => "Mk",
+            BibleBook::Luke => "Lk",
+            BibleBook::John => "Jn",
+            BibleBook::Acts => "Ac",
+            BibleBook::Romans => "Ro",
+            BibleBook::FirstCorinthians => "1 Co",
+            BibleBook::SecondCorinthians => "2 Co",
+            BibleBook::Galatians => "Ga",
+            BibleBook::Ephesians => "Eph",
+            BibleBook::Philippians => "Php",
+            BibleBook::Colossians => "Col",
+            BibleBook::FirstThessalonians => "1 Th",
+            BibleBook::SecondThessalonians => "2 Th",
+            BibleBook::FirstTimothy => "1 Ti",
+            BibleBook::SecondTimothy => "2 Ti",
+            BibleBook::Titus => "Tt",
+            BibleBook::Philemon => "Phm",
+            BibleBook::Hebrews => "Heb",
+            BibleBook::James => "Jas",
+            BibleBook::FirstPeter => "1 Pe",
+            BibleBook::SecondPeter => "2 Pe",
+            BibleBook::FirstJohn => "1 Jn",
+            BibleBook::SecondJohn => "2 Jn",
+            BibleBook::ThirdJohn => "3 Jn",
+            BibleBook::Jude => "Jud",
+            BibleBook::Revelation => "Re",
+            BibleBook::Tobit => "Tob",
+            BibleBook::Judith => "Jdt",
+            BibleBook::Wisdom => "Wis",
+            BibleBook::Sirach => "Sir",
+            BibleBook::Baruch => "Bar",
+            BibleBook::FirstMaccabees => "1 Mac",
+            BibleBook::SecondMaccabees => "2 Mac",
+            BibleBook::EstherAdditions => "Add Es",
+            BibleBook::DanielSongOfThree => "Sg Three",
+            BibleBook::DanielSusanna => "Sus",
+            BibleBook::DanielBelAndTheDragon => "Bel",
+            BibleBook::FirstEsdras => "1 Es",
+            BibleBook::SecondEsdras => "2 Es",
+            BibleBook::PrayerOfManasseh => "Pr Man",
+            BibleBook::Psalm151 => "Ps 151",
+            BibleBook::ThirdMaccabees => "3 Mac",
+            BibleBook::FourthMaccabees => "4 Mac",
+        }
+    }
+
+    /// Returns the book's abbreviation in `style`.
+    pub const fn abbrev_in(&self, style: AbbreviationStyle) -> &'static str {
+        match style {
+            AbbreviationStyle::Compact => self.as_str(),
+            AbbreviationStyle::Osis => self.osis_id(),
+            AbbreviationStyle::Usfm => self.usfm_id(),
+            AbbreviationStyle::Sbl => self.sbl_abbrev(),
+            AbbreviationStyle::Logos => self.logos_abbrev(),
+        }
+    }
+
+    /// Finds a book by its [OSIS identifier](BibleBook::osis_id), ignoring case.
+    pub fn from_osis_id(id: &str) -> Option<BibleBook> {
+        Self::ALL
+            .into_iter()
+            .find(|book| book.osis_id().eq_ignore_ascii_case(id))
+    }
+
+    /// Finds a book by its [USFM code](BibleBook::usfm_id), ignoring case.
+    pub fn from_usfm_id(id: &str) -> Option<BibleBook> {
+        Self::ALL
+            .into_iter()
+            .find(|book| book.usfm_id().eq_ignore_ascii_case(id))
+    }
+
+    /// Returns the Paratext book code, such as `"GEN"`, `"EXO"`, or `"MAT"`.
+    ///
+    /// Paratext, USFM files and Digital Bible Library bundles share one set of
+    /// codes, so this is the same as [`BibleBook::usfm_id`].
+    pub const fn to_paratext_code(&self) -> &'static str {
+        self.usfm_id()
+    }
+
+    /// Finds a book by its [Paratext code](BibleBook::to_paratext_code),
+    /// ignoring case and surrounding whitespace.
+    pub fn from_paratext_code(code: &str) -> Option<BibleBook> {
+        Self::from_usfm_id(code.trim())
+    }
+}
+
+/// A standard set of book abbreviations, for [`BibleBook::abbrev_in`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbbreviationStyle {
+    /// The crate's own lowercase codes, as used in the JSON data: `"gn"`.
+    #[default]
+    Compact,
+    /// OSIS identifiers: `"Gen"`, `"1Sam"`.
+    Osis,
+    /// USFM and Paratext three-character codes: `"GEN"`, `"1SA"`.
+    Usfm,
+    /// The SBL Handbook of Style: `"Gen"`, `"1 Sam"`.
+    Sbl,
+    /// Logos Bible Software: `"Ge"`, `"1 Sa"`.
+    Logos,
+}
+
 impl fmt::Display for BibleBook {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self.as_str())
@@ -429,6 +1366,157 @@ mod tests {
         }
     }
 
+    #[test]
+    fn canon_and_equivalents() {
+        assert_eq!(BibleBook::Genesis.canon(), Canon::Protestant);
+        assert_eq!(BibleBook::Tobit.canon(), Canon::Deuterocanon);
+        assert_eq!(BibleBook::Psalm151.canon(), Canon::Orthodox);
+        assert_eq!(
+            BibleBook::Psalm151.closest_equivalent(),
+            Some(BibleBook::Psalms)
+        );
+        assert_eq!(BibleBook::Tobit.closest_equivalent(), None);
+    }
+
+    #[test]
+    fn divisions() {
+        assert_eq!(BibleBook::Deuteronomy.division(), Division::Law);
+        assert_eq!(BibleBook::Acts.division(), Division::History);
+        assert_eq!(BibleBook::Hebrews.division(), Division::GeneralEpistles);
+        assert_eq!(
+            BibleBook::books_in(Division::Gospels).collect::<Vec<_>>(),
+            [
+                BibleBook::Matthew,
+                BibleBook::Mark,
+                BibleBook::Luke,
+                BibleBook::John
+            ]
+        );
+        assert_eq!(BibleBook::books_in(Division::MinorProphets).count(), 12);
+        let protestant: usize = Division::ALL
+            .iter()
+            .map(|&d| {
+                BibleBook::books_in(d)
+                    .filter(|&b| Canon::Protestant.includes(b))
+                    .count()
+            })
+            .sum();
+        assert_eq!(protestant, 66);
+        assert_eq!(Division::PaulineEpistles.to_string(), "Pauline Epistles");
+    }
+
+    #[test]
+    fn numbers_and_names() {
+        assert_eq!(BibleBook::from_number(1), Some(BibleBook::Genesis));
+        assert_eq!(BibleBook::from_number(66), Some(BibleBook::Revelation));
+        assert_eq!(BibleBook::from_number(0), None);
+        assert_eq!(
+            BibleBook::from_name("1 samuel"),
+            Some(BibleBook::FirstSamuel)
+        );
+        assert_eq!(BibleBook::from_name("Genesis "), Some(BibleBook::Genesis));
+        assert_eq!(BibleBook::from_name("Hezekiah"), None);
+        assert!(BibleBook::ALL
+            .iter()
+            .enumerate()
+            .all(|(i, &book)| book as usize == i));
+    }
+
+    #[test]
+    fn canon_profiles() {
+        assert!(Canon::Deuterocanon.includes(BibleBook::Genesis));
+        assert!(Canon::Deuterocanon.includes(BibleBook::Tobit));
+        assert!(!Canon::Deuterocanon.includes(BibleBook::Psalm151));
+        assert!(!Canon::Protestant.includes(BibleBook::Tobit));
+
+        let profile = CanonProfile::new(Canon::Protestant)
+            .with_book(BibleBook::Psalm151)
+            .without_book(BibleBook::Esther);
+        assert!(profile.includes(BibleBook::Psalm151));
+        assert!(!profile.includes(BibleBook::Esther));
+        assert!(profile.includes(BibleBook::Genesis));
+        assert!(!profile.includes(BibleBook::Tobit));
+    }
+
+    #[test]
+    fn deuterocanon_placements() {
+        for placement in [
+            DeuterocanonPlacement::Appendix,
+            DeuterocanonPlacement::BetweenTestaments,
+            DeuterocanonPlacement::Interleaved,
+        ] {
+            let mut books = placement.order().to_vec();
+            books.sort_by_key(|&b| b as usize);
+            assert_eq!(
+                books,
+                BibleBook::ALL,
+                "{:?} is not a permutation",
+                placement
+            );
+        }
+
+        let between = DeuterocanonPlacement::BetweenTestaments;
+        assert_eq!(between.order()[38], BibleBook::Malachi);
+        assert_eq!(between.order()[39], BibleBook::Tobit);
+        assert_eq!(between.order()[56], BibleBook::Matthew);
+
+        let interleaved = DeuterocanonPlacement::Interleaved;
+        assert!(interleaved.position(BibleBook::Tobit) < interleaved.position(BibleBook::Esther));
+        assert_eq!(
+            interleaved.compare(&BookId::from_abbrev("enoch"), &BibleBook::Revelation.into()),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn osis_and_usfm_ids_are_unique() {
+        for book in BibleBook::ALL {
+            assert_eq!(BibleBook::from_osis_id(book.osis_id()), Some(book));
+            assert_eq!(BibleBook::from_usfm_id(book.usfm_id()), Some(book));
+            assert_eq!(book.usfm_id().len(), 3);
+        }
+        assert_eq!(BibleBook::from_osis_id("matt"), Some(BibleBook::Matthew));
+        assert_eq!(BibleBook::from_usfm_id("JHN"), Some(BibleBook::John));
+        assert_eq!(BibleBook::Exodus.to_paratext_code(), "EXO");
+        assert_eq!(BibleBook::Psalm151.to_paratext_code(), "PS2");
+        assert_eq!(
+            BibleBook::from_paratext_code(" mat "),
+            Some(BibleBook::Matthew)
+        );
+        assert_eq!(BibleBook::from_paratext_code("XXA"), None);
+    }
+
+    #[test]
+    fn abbreviation_styles() {
+        let samuel = BibleBook::FirstSamuel;
+        assert_eq!(samuel.abbrev_in(AbbreviationStyle::Compact), "1sm");
+        assert_eq!(samuel.abbrev_in(AbbreviationStyle::Osis), "1Sam");
+        assert_eq!(samuel.abbrev_in(AbbreviationStyle::Usfm), "1SA");
+        assert_eq!(samuel.abbrev_in(AbbreviationStyle::Sbl), "1 Sam");
+        assert_eq!(samuel.abbrev_in(AbbreviationStyle::Logos), "1 Sa");
+        assert_eq!(BibleBook::Philemon.sbl_abbrev(), "Phlm");
+        assert_eq!(BibleBook::Philippians.logos_abbrev(), "Php");
+        for style in [AbbreviationStyle::Sbl, AbbreviationStyle::Logos] {
+            let mut seen: Vec<&str> = BibleBook::ALL.iter().map(|b| b.abbrev_in(style)).collect();
+            seen.sort_unstable();
+            seen.dedup();
+            assert_eq!(seen.len(), BibleBook::COUNT, "{style:?} has duplicates");
+        }
+    }
+
+    #[test]
+    fn numeric_ids() {
+        assert_eq!(BibleBook::Genesis.id(), 1);
+        assert_eq!(BibleBook::Revelation.id(), 66);
+        assert_eq!(BibleBook::FourthMaccabees.id() as usize, BibleBook::COUNT);
+        for book in BibleBook::ALL {
+            assert_eq!(BibleBook::from_id(book.id()), Some(book));
+            assert_eq!(BibleBook::from_number(book.id().into()), Some(book));
+        }
+        assert_eq!(BibleBook::from_id(0), None);
+        assert_eq!(BibleBook::from_id(84), None);
+    }
+
     #[test]
     fn reject_unknown() {
         assert!(BibleBook::from_str("xyz").is_err());
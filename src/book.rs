@@ -1,6 +1,15 @@
-use std::fmt;
+use std::{fmt, io};
 
-use crate::{bible::BibleError, chapter::Chapter, verse::Verse};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    bible::BibleError,
+    book_id::BookId,
+    chapter::Chapter,
+    reference::{Reference, ReferenceRange},
+    search_index::SearchIndex,
+    verse::Verse,
+};
 
 /// Represents a book of the Bible.
 ///
@@ -8,7 +17,11 @@ use crate::{bible::BibleError, chapter::Chapter, verse::Verse};
 #[derive(Debug, Clone)]
 pub struct Book {
     abbrev: String, // keep the JSON key, no assumptions about canon
+    id: BookId,
     title: String,
+    aliases: Vec<String>,
+    introduction: Option<String>,
+    outline: Vec<OutlineSection>,
     chapters: Vec<Chapter>,
 }
 
@@ -22,27 +35,130 @@ impl Book {
     /// * `chapters` - A vector of chapters in this book
     pub fn new(abbrev: String, title: String, chapters: Vec<Chapter>) -> Self {
         Book {
+            id: BookId::from_abbrev(&abbrev),
             abbrev: abbrev.to_ascii_lowercase(),
             title,
+            aliases: Vec::new(),
+            introduction: None,
+            outline: Vec::new(),
             chapters,
         }
     }
 
+    /// Sets the alternative names or abbreviations this translation uses for the book.
+    ///
+    /// Aliases are matched case-insensitively when resolving references, so
+    /// local-language names such as "Génesis" work without code changes.
+    pub fn with_aliases(mut self, aliases: Vec<String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Sets the introduction printed before the text in study Bibles.
+    pub fn with_introduction(mut self, introduction: Option<String>) -> Self {
+        self.introduction = introduction;
+        self
+    }
+
+    /// Sets the book's outline, in reading order.
+    pub fn with_outline(mut self, outline: Vec<OutlineSection>) -> Self {
+        self.outline = outline;
+        self
+    }
+
     /// Returns the book's abbreviation.
     pub fn abbrev(&self) -> &str {
         &self.abbrev
     }
 
+    /// Returns the identifier of this book, which may be a custom (non-canonical) book.
+    pub fn id(&self) -> &BookId {
+        &self.id
+    }
+
     /// Returns the book's full title.
     pub fn title(&self) -> &str {
         &self.title
     }
 
+    /// Returns the translation-specific aliases for this book.
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    /// Returns the introduction to the book, if the data includes one.
+    pub fn introduction(&self) -> Option<&str> {
+        self.introduction.as_deref()
+    }
+
+    /// Returns the book's outline, in reading order. Sections of different
+    /// levels may nest.
+    pub fn outline(&self) -> &[OutlineSection] {
+        &self.outline
+    }
+
+    /// Returns the outline sections containing `chapter:verse`, outermost first.
+    pub fn outline_at(&self, chapter: usize, verse: usize) -> Vec<&OutlineSection> {
+        let reference = Reference::new(self.id.clone(), chapter, verse);
+        let mut sections: Vec<&OutlineSection> = self
+            .outline
+            .iter()
+            .filter(|section| section.range.contains(&reference))
+            .collect();
+        sections.sort_by_key(|section| section.level);
+        sections
+    }
+
     /// Returns a slice of all chapters in this book.
     pub fn chapters(&self) -> &[Chapter] {
         &self.chapters
     }
 
+    /// Returns the text of the whole book without verse or chapter numbers.
+    ///
+    /// Verses are separated by a space and chapters by a newline, which suits
+    /// text-to-speech, NLP pipelines, and copy-paste better than [`Display`](fmt::Display).
+    pub fn full_text(&self) -> String {
+        self.chapters
+            .iter()
+            .map(|c| c.text_joined(" "))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Streams the same text as [`Book::full_text`] to `writer` without
+    /// building it in memory first.
+    pub fn write_full_text<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        for (c, chapter) in self.chapters.iter().enumerate() {
+            if c > 0 {
+                writer.write_all(b"\n")?;
+            }
+            for (v, verse) in chapter.get_verses().iter().enumerate() {
+                if v > 0 {
+                    writer.write_all(b" ")?;
+                }
+                writer.write_all(verse.text().trim().as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the verses of this book that contain every term of `query`, in order.
+    ///
+    /// Matching follows the same rules as [`Bible::search`](crate::Bible::search),
+    /// but scans the book directly instead of building an index.
+    pub fn search(&self, query: &str) -> Vec<&Verse> {
+        SearchIndex::scan(self.chapters.iter().flat_map(|c| c.get_verses()), query)
+    }
+
+    /// Iterates over the chapters in order, each tagged with this book's id.
+    pub fn iter_chapters(&self) -> impl Iterator<Item = BookChapter<'_>> {
+        self.chapters.iter().map(|chapter| BookChapter {
+            book: &self.id,
+            chapter,
+        })
+    }
+
     /// Returns a specific chapter by its chapter number.
     ///
     /// # Arguments
@@ -53,16 +169,8 @@ impl Book {
     ///
     /// The requested chapter or a descriptive error if the chapter number is invalid.
     pub fn get_chapter(&self, chapter_number: usize) -> Result<&Chapter, BibleError> {
-        if chapter_number == 0 {
-            return Err(BibleError::ChapterOutOfBounds {
-                book_abbrev: self.abbrev.clone(),
-                book_name: self.title.clone(),
-                chapter: chapter_number,
-                max_chapter: self.chapters.len(),
-            });
-        }
-        self.chapters
-            .get(chapter_number - 1)
+        self.chapter_position(chapter_number)
+            .map(|position| &self.chapters[position])
             .ok_or_else(|| BibleError::ChapterOutOfBounds {
                 book_abbrev: self.abbrev.clone(),
                 book_name: self.title.clone(),
@@ -71,6 +179,28 @@ impl Book {
             })
     }
 
+    /// Finds the position of a chapter, looking it up by number when a
+    /// [subset](crate::Bible::subset) leaves gaps.
+    fn chapter_position(&self, chapter_number: usize) -> Option<usize> {
+        let position = chapter_number.checked_sub(1)?;
+        match self.chapters.get(position) {
+            Some(chapter) if chapter.number() == chapter_number => Some(position),
+            _ => self
+                .chapters
+                .iter()
+                .position(|c| c.number() == chapter_number),
+        }
+    }
+
+    pub(crate) fn get_chapter_mut(&mut self, chapter_number: usize) -> Option<&mut Chapter> {
+        let position = self.chapter_position(chapter_number)?;
+        self.chapters.get_mut(position)
+    }
+
+    pub(crate) fn chapters_mut(&mut self) -> &mut Vec<Chapter> {
+        &mut self.chapters
+    }
+
     /// Returns all verses from a specific chapter.
     ///
     /// # Arguments
@@ -100,18 +230,191 @@ impl Book {
         verse_number: usize,
     ) -> Result<&Verse, BibleError> {
         let chapter = self.get_chapter(chapter_number)?;
-        chapter
-            .get_verse(verse_number)
-            .ok_or_else(|| BibleError::VerseOutOfBounds {
-                book_abbrev: self.abbrev.clone(),
-                book_name: self.title.clone(),
-                chapter: chapter_number,
-                verse: verse_number,
-                max_verse: chapter.get_verses().len(),
+        chapter.get_verse(verse_number).ok_or_else(|| {
+            let max_verse = chapter.max_verse();
+            if (1..max_verse).contains(&verse_number) {
+                BibleError::VerseMissing {
+                    book_abbrev: self.abbrev.clone(),
+                    book_name: self.title.clone(),
+                    chapter: chapter_number,
+                    verse: verse_number,
+                }
+            } else {
+                BibleError::VerseOutOfBounds {
+                    book_abbrev: self.abbrev.clone(),
+                    book_name: self.title.clone(),
+                    chapter: chapter_number,
+                    verse: verse_number,
+                    max_verse,
+                }
+            }
+        })
+    }
+
+    /// Replaces the text of a verse.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::ChapterOutOfBounds`] or
+    /// [`BibleError::VerseOutOfBounds`] if the verse does not exist.
+    pub fn set_verse_text(
+        &mut self,
+        chapter_number: usize,
+        verse_number: usize,
+        verse_text: &str,
+    ) -> Result<(), BibleError> {
+        // Validate through the shared accessor so errors match `get_verse`.
+        self.get_verse(chapter_number, verse_number)?;
+        if let Some(verse) = self
+            .get_chapter_mut(chapter_number)
+            .and_then(|chapter| chapter.get_verse_mut(verse_number))
+        {
+            verse.set_text(verse_text);
+        }
+        Ok(())
+    }
+}
+
+/// A titled section of a book's outline, such as "The Creation (1:1-2:3)".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineSection {
+    title: String,
+    range: ReferenceRange,
+    level: u8,
+}
+
+impl OutlineSection {
+    /// Creates a top-level section covering `range`.
+    pub fn new(title: impl Into<String>, range: ReferenceRange) -> Self {
+        OutlineSection {
+            title: title.into(),
+            range,
+            level: 0,
+        }
+    }
+
+    /// Sets the nesting depth, where `0` is a top-level section.
+    pub fn with_level(mut self, level: u8) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Returns the section title.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the verses the section covers.
+    pub fn range(&self) -> &ReferenceRange {
+        &self.range
+    }
+
+    /// Returns the nesting depth, where `0` is a top-level section.
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+}
+
+/// An outline section as stored in data files, with positions written as
+/// `"chapter:verse"` within the book.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OutlineEntry {
+    title: String,
+    start: ChapterVerse,
+    /// Defaults to `start` for single-verse sections.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end: Option<ChapterVerse>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    level: u8,
+}
+
+fn is_zero(value: &u8) -> bool {
+    *value == 0
+}
+
+impl OutlineEntry {
+    pub(crate) fn into_section(self, book: &BookId) -> OutlineSection {
+        let start = Reference::new(book.clone(), self.start.0, self.start.1);
+        let end = self.end.map_or_else(
+            || start.clone(),
+            |end| Reference::new(book.clone(), end.0, end.1),
+        );
+        OutlineSection::new(self.title, ReferenceRange::new(start, end)).with_level(self.level)
+    }
+
+    pub(crate) fn from_section(section: &OutlineSection) -> Self {
+        let (start, end) = (section.range.start(), section.range.end());
+        OutlineEntry {
+            title: section.title.clone(),
+            start: ChapterVerse(start.chapter(), start.verse()),
+            end: (start != end).then(|| ChapterVerse(end.chapter(), end.verse())),
+            level: section.level,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ChapterVerse(usize, usize);
+
+impl Serialize for ChapterVerse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{}:{}", self.0, self.1))
+    }
+}
+
+impl<'de> Deserialize<'de> for ChapterVerse {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.split_once(':')
+            .and_then(|(chapter, verse)| {
+                Some(ChapterVerse(
+                    chapter.trim().parse().ok()?,
+                    verse.trim().parse().ok()?,
+                ))
+            })
+            .ok_or_else(|| {
+                de::Error::custom(format!(
+                    "Invalid outline position '{}': expected \"chapter:verse\"",
+                    text
+                ))
             })
     }
 }
 
+/// A chapter together with the book it belongs to, yielded by [`Book::iter_chapters`].
+#[derive(Debug, Clone, Copy)]
+pub struct BookChapter<'a> {
+    book: &'a BookId,
+    chapter: &'a Chapter,
+}
+
+impl<'a> BookChapter<'a> {
+    /// Returns the book containing the chapter.
+    pub fn book(&self) -> &'a BookId {
+        self.book
+    }
+
+    /// Returns the chapter number within the book.
+    pub fn number(&self) -> usize {
+        self.chapter.number()
+    }
+
+    /// Returns the chapter itself.
+    pub fn chapter(&self) -> &'a Chapter {
+        self.chapter
+    }
+
+    /// Returns a reference to the given verse of this chapter.
+    pub fn reference(&self, verse: usize) -> Reference {
+        Reference::new(self.book, self.chapter.number(), verse)
+    }
+
+    /// Iterates over the chapter's verses, each paired with its full reference.
+    pub fn iter_verses(&self) -> impl Iterator<Item = (Reference, &'a Verse)> + 'a {
+        self.chapter.iter_verses()
+    }
+}
+
 impl fmt::Display for Book {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Book: {} ({})", self.title, self.abbrev)
@@ -137,6 +440,123 @@ mod tests {
         assert!(book.get_chapter(0).is_err());
     }
 
+    #[test]
+    fn test_set_verse_text() {
+        let mut book = Book::new("GN".into(), "Genesis".into(), vec![create_test_chapter()]);
+        book.set_verse_text(1, 1, "In {the} beginning").unwrap();
+        let verse = book.get_verse(1, 1).unwrap();
+        assert_eq!(verse.text(), "In the beginning");
+        assert_eq!(verse.spans().len(), 3);
+
+        assert!(matches!(
+            book.set_verse_text(1, 2, "missing"),
+            Err(BibleError::VerseOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            book.set_verse_text(2, 1, "missing"),
+            Err(BibleError::ChapterOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn test_outline_sections() {
+        let mut json = br#"[
+            {"title": "Primeval history", "start": "1:1", "end": "11:32"},
+            {"title": "Creation", "start": "1:1", "end": "2:3", "level": 1},
+            {"title": "Blessing", "start": "1:28"}
+        ]"#
+        .to_vec();
+        let entries: Vec<OutlineEntry> = simd_json::serde::from_slice(&mut json).unwrap();
+        let book_id = BookId::from(BibleBook::Genesis);
+        let outline: Vec<OutlineSection> = entries
+            .iter()
+            .cloned()
+            .map(|entry| entry.into_section(&book_id))
+            .collect();
+        let book = Book::new("gn".into(), "Genesis".into(), vec![create_test_chapter()])
+            .with_introduction(Some("The book of beginnings.".into()))
+            .with_outline(outline);
+
+        assert_eq!(book.introduction(), Some("The book of beginnings."));
+        assert_eq!(book.outline()[1].range().to_string(), "Genesis 1:1-2:3");
+        assert_eq!(book.outline()[1].level(), 1);
+        let titles: Vec<&str> = book.outline_at(2, 1).iter().map(|s| s.title()).collect();
+        assert_eq!(titles, ["Primeval history", "Creation"]);
+        assert!(book.outline_at(12, 1).is_empty());
+
+        let written: Vec<OutlineEntry> = book
+            .outline()
+            .iter()
+            .map(OutlineEntry::from_section)
+            .collect();
+        assert_eq!(written, entries);
+
+        let mut invalid = br#"[{"title": "x", "start": "1"}]"#.to_vec();
+        assert!(simd_json::serde::from_slice::<Vec<OutlineEntry>>(&mut invalid).is_err());
+    }
+
+    #[test]
+    fn test_iteration_carries_positions() {
+        let book = Book::new("GN".into(), "Genesis".into(), vec![create_test_chapter()]);
+        let chapters: Vec<BookChapter> = book.iter_chapters().collect();
+
+        assert_eq!(chapters.len(), 1);
+        assert_eq!(*chapters[0].book(), BibleBook::Genesis);
+        assert_eq!(chapters[0].number(), 1);
+        assert_eq!(
+            chapters[0].reference(3),
+            Reference::new(BibleBook::Genesis, 1, 3)
+        );
+
+        let (reference, verse) = chapters[0].iter_verses().next().unwrap();
+        assert_eq!(reference.to_string(), "Genesis 1:1");
+        assert_eq!(verse.text(), "Test");
+    }
+
+    #[test]
+    fn test_full_text() {
+        let second = Chapter::new(
+            vec![
+                Verse::new(BibleBook::Genesis, 2, 1, "One ".into()),
+                Verse::new(BibleBook::Genesis, 2, 2, "Two".into()),
+            ],
+            2,
+        );
+        let book = Book::new(
+            "gn".into(),
+            "Genesis".into(),
+            vec![create_test_chapter(), second],
+        );
+
+        assert_eq!(book.full_text(), "Test\nOne Two");
+        let mut streamed = Vec::new();
+        book.write_full_text(&mut streamed).unwrap();
+        assert_eq!(streamed, book.full_text().as_bytes());
+    }
+
+    #[test]
+    fn test_scoped_search() {
+        let second = Chapter::new(
+            vec![
+                Verse::new(BibleBook::Genesis, 2, 1, "the heavens and the earth".into()),
+                Verse::new(BibleBook::Genesis, 2, 2, "the seventh day".into()),
+            ],
+            2,
+        );
+        let book = Book::new(
+            "gn".into(),
+            "Genesis".into(),
+            vec![create_test_chapter(), second],
+        );
+
+        let hits = book.search("Earth THE");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].reference().to_string(), "Genesis 2:1");
+        assert_eq!(book.get_chapter(2).unwrap().search("the").len(), 2);
+        assert!(book.get_chapter(1).unwrap().search("the").is_empty());
+        assert!(book.search("").is_empty());
+    }
+
     #[test]
     fn test_clone_independence() {
         let book = Book::new("GN".into(), "Genesis".into(), vec![create_test_chapter()]);
@@ -0,0 +1,113 @@
+use std::{cmp::Ordering, fmt, str::FromStr, sync::Arc};
+
+use crate::bible_books_enum::BibleBook;
+
+/// Identifies a book of a loaded Bible.
+///
+/// Books whose abbreviation matches a [`BibleBook`] are `Known`; any other key
+/// found in a data file (extra or differently-keyed books) is kept as `Custom`
+/// so those books remain fully searchable and addressable.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BookId {
+    Known(BibleBook),
+    /// The lowercase abbreviation used as the book key in the data file.
+    Custom(Arc<str>),
+}
+
+impl BookId {
+    /// Builds a book id from a data-file abbreviation, falling back to
+    /// [`BookId::Custom`] when the abbreviation is not a known [`BibleBook`].
+    pub fn from_abbrev(abbrev: &str) -> Self {
+        let lower = abbrev.to_ascii_lowercase();
+        match BibleBook::from_str(&lower) {
+            Ok(book) => BookId::Known(book),
+            Err(_) => BookId::Custom(lower.into()),
+        }
+    }
+
+    /// Returns the lowercase abbreviation for this book.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BookId::Known(book) => book.as_str(),
+            BookId::Custom(abbrev) => abbrev,
+        }
+    }
+
+    /// Returns the [`BibleBook`] if this is a known book.
+    pub fn known(&self) -> Option<BibleBook> {
+        match self {
+            BookId::Known(book) => Some(*book),
+            BookId::Custom(_) => None,
+        }
+    }
+}
+
+impl From<BibleBook> for BookId {
+    fn from(book: BibleBook) -> Self {
+        BookId::Known(book)
+    }
+}
+
+impl From<&BookId> for BookId {
+    fn from(id: &BookId) -> Self {
+        id.clone()
+    }
+}
+
+impl PartialEq<BibleBook> for BookId {
+    fn eq(&self, other: &BibleBook) -> bool {
+        matches!(self, BookId::Known(book) if book == other)
+    }
+}
+
+/// Known books sort in canonical enum order, followed by custom books
+/// ordered by abbreviation.
+impl Ord for BookId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (BookId::Known(a), BookId::Known(b)) => (*a as usize).cmp(&(*b as usize)),
+            (BookId::Known(_), BookId::Custom(_)) => Ordering::Less,
+            (BookId::Custom(_), BookId::Known(_)) => Ordering::Greater,
+            (BookId::Custom(a), BookId::Custom(b)) => a.cmp(b),
+        }
+    }
+}
+
+impl PartialOrd for BookId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BookId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_abbrev() {
+        assert_eq!(BookId::from_abbrev("GN"), BibleBook::Genesis);
+        let custom = BookId::from_abbrev("Enoch");
+        assert_eq!(custom, BookId::Custom("enoch".into()));
+        assert_eq!(custom.as_str(), "enoch");
+        assert_eq!(custom.known(), None);
+    }
+
+    #[test]
+    fn test_ordering_puts_custom_books_last() {
+        let mut ids = [
+            BookId::Custom("enoch".into()),
+            BookId::Known(BibleBook::Revelation),
+            BookId::Known(BibleBook::Genesis),
+        ];
+        ids.sort();
+        assert_eq!(ids[0], BibleBook::Genesis);
+        assert_eq!(ids[1], BibleBook::Revelation);
+        assert_eq!(ids[2].as_str(), "enoch");
+    }
+}
@@ -1,6 +1,121 @@
-use std::fmt;
+use std::{fmt, ops::RangeInclusive};
 
-use crate::verse::Verse;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bible_books_enum::BibleBook, format::ChapterFormat, reference::Reference,
+    search_index::SearchIndex, verse::Verse,
+};
+
+/// The typographic kind of a paragraph.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ParagraphKind {
+    /// Running prose text.
+    #[default]
+    Prose,
+    /// Poetry, typically set as indented lines.
+    Poetry,
+}
+
+/// A run of consecutive verses forming one paragraph or poetic stanza.
+///
+/// Paragraphs cover whole verses; line breaks inside a verse are not modeled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paragraph {
+    kind: ParagraphKind,
+    first_verse: usize,
+    last_verse: usize,
+    indent: u8,
+}
+
+impl Paragraph {
+    /// Returns whether this paragraph is prose or poetry.
+    pub fn kind(&self) -> ParagraphKind {
+        self.kind
+    }
+
+    /// Returns the verse numbers covered by this paragraph.
+    pub fn verse_numbers(&self) -> RangeInclusive<usize> {
+        self.first_verse..=self.last_verse
+    }
+
+    /// Returns the indentation level of poetry lines (as in USFM `\q1`, `\q2`), or `0`.
+    pub fn indent(&self) -> u8 {
+        self.indent
+    }
+}
+
+/// The start of a paragraph as stored in data files.
+///
+/// A paragraph runs until the verse before the next marker or the end of the chapter.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ParagraphMarker {
+    pub start: usize,
+    #[serde(default)]
+    pub kind: ParagraphKind,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub indent: u8,
+}
+
+fn is_zero(value: &u8) -> bool {
+    *value == 0
+}
+
+/// Verse numbering conventions that differ in how superscriptions are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VerseNumbering {
+    /// English Bibles leave superscriptions unnumbered.
+    English,
+    /// Hebrew Bibles count a superscription as verse 1.
+    Hebrew,
+}
+
+/// Psalms whose superscription Hebrew Bibles number as two verses.
+const TWO_VERSE_SUPERSCRIPTIONS: [usize; 4] = [51, 52, 54, 60];
+
+/// Removes a leading `[...]` superscription from a verse, returning its text.
+/// A verse holding nothing but the bracketed text is left alone, so the
+/// verse itself is never lost.
+pub(crate) fn take_bracketed_superscription(verse_text: &mut String) -> Option<String> {
+    let trimmed = verse_text.trim_start();
+    let rest = trimmed.strip_prefix('[')?;
+    let end = rest.find(']')?;
+    let remaining = rest[end + 1..].trim_start();
+    if remaining.is_empty() {
+        return None;
+    }
+    let superscription = rest[..end].trim().to_string();
+    *verse_text = remaining.to_string();
+    Some(superscription)
+}
+
+/// A section heading shown before a verse, attached with
+/// [`Bible::attach_headings`](crate::Bible::attach_headings).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    verse: usize,
+    text: String,
+}
+
+impl Heading {
+    /// Returns the number of the verse the heading precedes.
+    pub fn verse(&self) -> usize {
+        self.verse
+    }
+
+    /// Returns the heading text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// A heading or verse, yielded in reading order by [`Chapter::iter_with_headings`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChapterItem<'a> {
+    Heading(&'a Heading),
+    Verse(&'a Verse),
+}
 
 /// Represents a chapter from a Bible book.
 ///
@@ -9,6 +124,10 @@ use crate::verse::Verse;
 pub struct Chapter {
     verses: Vec<Verse>,
     chapter_number: usize,
+    paragraphs: Vec<Paragraph>,
+    superscription: Option<String>,
+    headings: Vec<Heading>,
+    summary: Option<String>,
 }
 
 impl Chapter {
@@ -22,6 +141,179 @@ impl Chapter {
         Chapter {
             verses,
             chapter_number,
+            paragraphs: Vec::new(),
+            superscription: None,
+            headings: Vec::new(),
+            summary: None,
+        }
+    }
+
+    /// Sets the chapter's superscription, such as a Psalm title.
+    pub fn with_superscription(mut self, superscription: Option<String>) -> Self {
+        self.superscription = superscription;
+        self
+    }
+
+    /// Returns the superscription ("A Psalm of David") preceding the verses, if any.
+    pub fn superscription(&self) -> Option<&str> {
+        self.superscription.as_deref()
+    }
+
+    /// Returns the one-line synopsis of this chapter, attached with
+    /// [`Bible::attach_chapter_summaries`](crate::Bible::attach_chapter_summaries).
+    pub fn summary(&self) -> Option<&str> {
+        self.summary.as_deref()
+    }
+
+    pub(crate) fn set_summary(&mut self, summary: String) {
+        self.summary = Some(summary);
+    }
+
+    /// Converts a verse number between numbering conventions.
+    ///
+    /// In chapters with a superscription, Hebrew numbering is one ahead of
+    /// English numbering, or two ahead in Psalms 51, 52, 54 and 60, whose
+    /// superscriptions span two Hebrew verses. Returns `None` when the verse
+    /// has no counterpart, i.e. a Hebrew verse of the superscription itself
+    /// or a number outside the chapter.
+    pub fn convert_verse_number(
+        &self,
+        verse_number: usize,
+        from: VerseNumbering,
+        to: VerseNumbering,
+    ) -> Option<usize> {
+        let offset = self.superscription_verses();
+        let english = match from {
+            VerseNumbering::English => verse_number,
+            VerseNumbering::Hebrew => verse_number.checked_sub(offset)?,
+        };
+        if english == 0 || english > self.max_verse() {
+            return None;
+        }
+        Some(match to {
+            VerseNumbering::English => english,
+            VerseNumbering::Hebrew => english + offset,
+        })
+    }
+
+    /// Returns how many Hebrew verse numbers the superscription takes.
+    fn superscription_verses(&self) -> usize {
+        if self.superscription.is_none() {
+            return 0;
+        }
+        let psalm = self
+            .verses
+            .first()
+            .is_some_and(|verse| *verse.book() == BibleBook::Psalms);
+        if psalm && TWO_VERSE_SUPERSCRIPTIONS.contains(&self.chapter_number) {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Sets the paragraph structure from paragraph start markers.
+    ///
+    /// Markers outside the chapter are ignored, and verses before the first
+    /// marker form a prose paragraph of their own.
+    pub(crate) fn with_paragraph_markers(mut self, mut markers: Vec<ParagraphMarker>) -> Self {
+        let max_verse = self.max_verse();
+        markers.retain(|m| m.start >= 1 && m.start <= max_verse);
+        markers.sort_by_key(|m| m.start);
+        markers.dedup_by_key(|m| m.start);
+
+        if markers.is_empty() {
+            self.paragraphs = Vec::new();
+            return self;
+        }
+        if markers[0].start > 1 {
+            markers.insert(
+                0,
+                ParagraphMarker {
+                    start: 1,
+                    kind: ParagraphKind::Prose,
+                    indent: 0,
+                },
+            );
+        }
+
+        self.paragraphs = markers
+            .iter()
+            .enumerate()
+            .map(|(i, m)| Paragraph {
+                kind: m.kind,
+                first_verse: m.start,
+                last_verse: markers.get(i + 1).map_or(max_verse, |next| next.start - 1),
+                indent: m.indent,
+            })
+            .collect();
+        self
+    }
+
+    /// Returns the paragraph and poetry structure of this chapter.
+    ///
+    /// The slice is empty when the source data carries no structure.
+    pub fn paragraphs(&self) -> &[Paragraph] {
+        &self.paragraphs
+    }
+
+    /// Returns the verses of `paragraph`: those whose number falls in its
+    /// range. Verses are matched by number rather than position, since a
+    /// subset, omitted verses or a bridge leave gaps in the slice.
+    pub(crate) fn paragraph_verses(&self, paragraph: &Paragraph) -> &[Verse] {
+        let start = self
+            .verses
+            .partition_point(|v| v.number() < paragraph.first_verse);
+        let end = self
+            .verses
+            .partition_point(|v| v.number() <= paragraph.last_verse);
+        &self.verses[start..end.max(start)]
+    }
+
+    pub(crate) fn paragraph_markers(&self) -> Vec<ParagraphMarker> {
+        self.paragraphs
+            .iter()
+            .map(|p| ParagraphMarker {
+                start: p.first_verse,
+                kind: p.kind,
+                indent: p.indent,
+            })
+            .collect()
+    }
+
+    /// Returns the section headings of this chapter, ordered by the verse
+    /// they precede.
+    pub fn headings(&self) -> &[Heading] {
+        &self.headings
+    }
+
+    /// Iterates over the verses with each heading placed before its verse.
+    pub fn iter_with_headings(&self) -> impl Iterator<Item = ChapterItem<'_>> + '_ {
+        let mut verses = self.verses.iter().peekable();
+        let mut headings = self.headings.iter().peekable();
+        std::iter::from_fn(move || {
+            let next_verse = verses.peek()?.number();
+            match headings.next_if(|h| h.verse <= next_verse) {
+                Some(heading) => Some(ChapterItem::Heading(heading)),
+                None => verses.next().map(ChapterItem::Verse),
+            }
+        })
+    }
+
+    /// Sets the heading before `verse_number`, replacing any existing one.
+    pub(crate) fn set_heading(&mut self, verse_number: usize, text: String) {
+        match self
+            .headings
+            .binary_search_by_key(&verse_number, |h| h.verse)
+        {
+            Ok(i) => self.headings[i].text = text,
+            Err(i) => self.headings.insert(
+                i,
+                Heading {
+                    verse: verse_number,
+                    text,
+                },
+            ),
         }
     }
 
@@ -39,6 +331,46 @@ impl Chapter {
         &self.verses
     }
 
+    /// Returns the highest verse number in this chapter, or 0 if it has no
+    /// verses. Translations that omit verses, such as Mark 15:28, have fewer
+    /// verses than this number.
+    pub fn max_verse(&self) -> usize {
+        self.verses
+            .iter()
+            .map(Verse::last_number)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the text of all verses joined by `separator`, without verse numbers.
+    ///
+    /// Surrounding whitespace is trimmed from each verse.
+    pub fn text_joined(&self, separator: &str) -> String {
+        let len = self.verses.iter().map(|v| v.text().len()).sum::<usize>()
+            + separator.len() * self.verses.len().saturating_sub(1);
+        let mut text = String::with_capacity(len);
+        for (i, verse) in self.verses.iter().enumerate() {
+            if i > 0 {
+                text.push_str(separator);
+            }
+            text.push_str(verse.text().trim());
+        }
+        text
+    }
+
+    /// Returns the verses of this chapter that contain every term of `query`.
+    ///
+    /// Matching follows the same rules as [`Bible::search`](crate::Bible::search),
+    /// but scans the chapter directly instead of building an index.
+    pub fn search(&self, query: &str) -> Vec<&Verse> {
+        SearchIndex::scan(self.verses.iter(), query)
+    }
+
+    /// Iterates over the verses in order, each paired with its full reference.
+    pub fn iter_verses(&self) -> impl Iterator<Item = (Reference, &Verse)> + '_ {
+        self.verses.iter().map(|v| (v.reference(), v))
+    }
+
     /// Returns a specific verse by its verse number.
     ///
     /// # Arguments
@@ -49,22 +381,62 @@ impl Chapter {
     ///
     /// An optional reference to the verse if found, None otherwise.
     pub fn get_verse(&self, verse_number: usize) -> Option<&Verse> {
-        if verse_number == 0 {
-            return None;
+        self.verses.get(self.verse_position(verse_number)?)
+    }
+
+    pub(crate) fn get_verse_mut(&mut self, verse_number: usize) -> Option<&mut Verse> {
+        let position = self.verse_position(verse_number)?;
+        self.verses.get_mut(position)
+    }
+
+    /// Finds the position of a verse. Verses are normally stored at
+    /// `verse_number - 1`; a [subset](crate::Bible::subset), omitted verses
+    /// or a bridge may move them, in which case the verse is looked up by
+    /// number and any number within a bridge finds the bridged verse.
+    fn verse_position(&self, verse_number: usize) -> Option<usize> {
+        let position = verse_number.checked_sub(1)?;
+        match self.verses.get(position) {
+            Some(verse) if verse.number() == verse_number => Some(position),
+            _ => self.verses.iter().position(|v| v.covers(verse_number)),
+        }
+    }
+
+    /// Sorts the verses by number, returning `true` if any moved.
+    pub(crate) fn sort_verses(&mut self) -> bool {
+        if self.verses.is_sorted_by_key(Verse::number) {
+            return false;
+        }
+        self.verses.sort_by_key(Verse::number);
+        true
+    }
+
+    /// Keeps only the verses for which `keep` returns `true`, shrinking
+    /// paragraphs to the remaining verses. The superscription is kept only
+    /// with verse 1.
+    pub(crate) fn retain_verses(&mut self, keep: impl FnMut(&Verse) -> bool) {
+        self.verses.retain(keep);
+        let numbers: Vec<usize> = self.verses.iter().map(Verse::number).collect();
+        self.paragraphs.retain_mut(|p| {
+            let mut kept = numbers
+                .iter()
+                .filter(|&&n| (p.first_verse..=p.last_verse).contains(&n));
+            let Some(&first) = kept.next() else {
+                return false;
+            };
+            p.last_verse = kept.next_back().copied().unwrap_or(first);
+            p.first_verse = first;
+            true
+        });
+        self.headings.retain(|h| numbers.contains(&h.verse));
+        if numbers.first() != Some(&1) {
+            self.superscription = None;
         }
-        self.verses.get(verse_number - 1)
     }
 }
 
 impl fmt::Display for Chapter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let verses_str = self
-            .verses
-            .iter()
-            .map(|v| format!("{}", v))
-            .collect::<Vec<String>>()
-            .join("\n");
-        write!(f, "Chapter {}:\n{}", self.chapter_number, verses_str)
+        f.write_str(&self.format_with(&ChapterFormat::default()))
     }
 }
 
@@ -83,6 +455,82 @@ mod tests {
         assert!(chapter.get_verse(0).is_none());
     }
 
+    #[test]
+    fn test_paragraphs_from_markers() {
+        let verses = (1..=5)
+            .map(|v| Verse::new(BibleBook::Psalms, 1, v, "Line".into()))
+            .collect();
+        let marker = |start, kind, indent| ParagraphMarker {
+            start,
+            kind,
+            indent,
+        };
+        let chapter = Chapter::new(verses, 1).with_paragraph_markers(vec![
+            marker(4, ParagraphKind::Poetry, 2),
+            marker(2, ParagraphKind::Poetry, 1),
+            marker(9, ParagraphKind::Prose, 0),
+        ]);
+
+        let paragraphs = chapter.paragraphs();
+        assert_eq!(paragraphs.len(), 3);
+        assert_eq!(paragraphs[0].kind(), ParagraphKind::Prose);
+        assert_eq!(paragraphs[0].verse_numbers(), 1..=1);
+        assert_eq!(paragraphs[1].verse_numbers(), 2..=3);
+        assert_eq!(paragraphs[2].verse_numbers(), 4..=5);
+        assert_eq!(paragraphs[2].indent(), 2);
+    }
+
+    #[test]
+    fn test_text_joined() {
+        let verses = vec![
+            Verse::new(BibleBook::John, 11, 35, "Jesus wept. ".into()),
+            Verse::new(BibleBook::John, 11, 36, "Then said the Jews".into()),
+        ];
+        let chapter = Chapter::new(verses, 11);
+        assert_eq!(chapter.text_joined(" "), "Jesus wept. Then said the Jews");
+        assert_eq!(Chapter::new(vec![], 1).text_joined(" "), "");
+    }
+
+    #[test]
+    fn test_superscription_and_hebrew_numbering() {
+        let mut first = "[A Psalm of David.] The LORD is my shepherd".to_string();
+        let superscription = take_bracketed_superscription(&mut first);
+        assert_eq!(superscription.as_deref(), Some("A Psalm of David."));
+        assert_eq!(first, "The LORD is my shepherd");
+
+        let verses = vec![
+            Verse::new(BibleBook::Psalms, 23, 1, first),
+            Verse::new(BibleBook::Psalms, 23, 2, "He maketh me".into()),
+        ];
+        let chapter = Chapter::new(verses, 23).with_superscription(superscription);
+        assert_eq!(chapter.superscription(), Some("A Psalm of David."));
+
+        use VerseNumbering::{English, Hebrew};
+        assert_eq!(chapter.convert_verse_number(1, English, Hebrew), Some(2));
+        assert_eq!(chapter.convert_verse_number(3, Hebrew, English), Some(2));
+        assert_eq!(chapter.convert_verse_number(1, Hebrew, English), None);
+        assert_eq!(chapter.convert_verse_number(3, English, Hebrew), None);
+
+        let mut plain = "No title here".to_string();
+        assert_eq!(take_bracketed_superscription(&mut plain), None);
+        assert_eq!(plain, "No title here");
+
+        // The superscription of Psalm 51 is Hebrew verses 1 and 2.
+        let verses = (1..=3)
+            .map(|v| Verse::new(BibleBook::Psalms, 51, v, format!("v{v}")))
+            .collect();
+        let psalm_51 =
+            Chapter::new(verses, 51).with_superscription(Some("To the chief Musician".into()));
+        assert_eq!(psalm_51.convert_verse_number(1, English, Hebrew), Some(3));
+        assert_eq!(psalm_51.convert_verse_number(3, Hebrew, English), Some(1));
+        assert_eq!(psalm_51.convert_verse_number(2, Hebrew, English), None);
+        assert_eq!(psalm_51.convert_verse_number(5, Hebrew, English), Some(3));
+
+        let mut title_only = "[A Psalm of David.]".to_string();
+        assert_eq!(take_bracketed_superscription(&mut title_only), None);
+        assert_eq!(title_only, "[A Psalm of David.]");
+    }
+
     #[test]
     fn test_clone_independence() {
         let verses = vec![Verse::new(BibleBook::Genesis, 1, 1, "Clone".into())];
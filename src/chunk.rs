@@ -0,0 +1,218 @@
+//! Verse-aware text chunking for embedding and retrieval pipelines.
+
+use crate::{bible::Bible, reference::ReferenceRange, verse::Verse};
+
+/// How verses are grouped into chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkStrategy {
+    /// A fixed number of verses per chunk.
+    Verses(usize),
+    /// One chunk per [`Paragraph`](crate::Paragraph). Chapters without
+    /// paragraph data form a single paragraph.
+    Paragraphs,
+    /// As many whole verses as fit within an estimated token budget.
+    ///
+    /// Tokens are estimated at four bytes of text each. A verse larger than the
+    /// budget becomes a chunk on its own.
+    Tokens(usize),
+}
+
+/// Options for [`Bible::chunks`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkOptions {
+    strategy: ChunkStrategy,
+    within_chapters: bool,
+}
+
+impl ChunkOptions {
+    /// Chunks of `count` verses each.
+    pub fn verses(count: usize) -> Self {
+        ChunkOptions {
+            strategy: ChunkStrategy::Verses(count.max(1)),
+            within_chapters: false,
+        }
+    }
+
+    /// One chunk per paragraph.
+    pub fn paragraphs() -> Self {
+        ChunkOptions {
+            strategy: ChunkStrategy::Paragraphs,
+            within_chapters: true,
+        }
+    }
+
+    /// Chunks of at most `max_tokens` estimated tokens.
+    pub fn tokens(max_tokens: usize) -> Self {
+        ChunkOptions {
+            strategy: ChunkStrategy::Tokens(max_tokens.max(1)),
+            within_chapters: false,
+        }
+    }
+
+    /// Prevents chunks from spanning chapter boundaries. Chunks never span books.
+    pub fn within_chapters(mut self, within_chapters: bool) -> Self {
+        self.within_chapters = within_chapters;
+        self
+    }
+
+    /// Returns the grouping strategy.
+    pub fn strategy(&self) -> ChunkStrategy {
+        self.strategy
+    }
+}
+
+/// A contiguous run of verse text tagged with the range it covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    range: ReferenceRange,
+    text: String,
+}
+
+impl Chunk {
+    /// Returns the verses covered by this chunk.
+    pub fn range(&self) -> &ReferenceRange {
+        &self.range
+    }
+
+    /// Returns the verse texts joined by single spaces.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn from_verses(verses: &[&Verse]) -> Self {
+        let first = verses[0].reference();
+        let last = verses[verses.len() - 1].reference();
+        Chunk {
+            range: ReferenceRange::new(first, last),
+            text: verses
+                .iter()
+                .map(|v| v.text().trim())
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+fn estimate_tokens(text: &str) -> usize {
+    text.len().div_ceil(4)
+}
+
+impl Bible {
+    /// Splits the whole Bible into text chunks according to `options`.
+    ///
+    /// Chunks are produced in book order and never span two books.
+    pub fn chunks(&self, options: &ChunkOptions) -> Vec<Chunk> {
+        let mut chunks = Vec::new();
+
+        for book in self.books() {
+            let units: Vec<Vec<&Verse>> = if options.strategy == ChunkStrategy::Paragraphs {
+                book.chapters()
+                    .iter()
+                    .flat_map(|c| {
+                        if c.paragraphs().is_empty() {
+                            vec![c.get_verses().iter().collect()]
+                        } else {
+                            c.paragraphs()
+                                .iter()
+                                .map(|p| c.paragraph_verses(p).iter().collect())
+                                .collect()
+                        }
+                    })
+                    .collect()
+            } else if options.within_chapters {
+                book.chapters()
+                    .iter()
+                    .map(|c| c.get_verses().iter().collect())
+                    .collect()
+            } else {
+                vec![book
+                    .chapters()
+                    .iter()
+                    .flat_map(|c| c.get_verses())
+                    .collect()]
+            };
+
+            for unit in units.iter().filter(|u| !u.is_empty()) {
+                match options.strategy {
+                    ChunkStrategy::Verses(count) => {
+                        chunks.extend(unit.chunks(count).map(Chunk::from_verses));
+                    }
+                    ChunkStrategy::Paragraphs => chunks.push(Chunk::from_verses(unit)),
+                    ChunkStrategy::Tokens(max_tokens) => {
+                        let mut start = 0;
+                        let mut budget = 0;
+                        for (i, verse) in unit.iter().enumerate() {
+                            let cost = estimate_tokens(verse.text());
+                            if i > start && budget + cost > max_tokens {
+                                chunks.push(Chunk::from_verses(&unit[start..i]));
+                                start = i;
+                                budget = 0;
+                            }
+                            budget += cost;
+                        }
+                        chunks.push(Chunk::from_verses(&unit[start..]));
+                    }
+                }
+            }
+        }
+
+        chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, reference::Reference,
+        test_support::TestBible,
+    };
+
+    fn create_test_bible() -> Bible {
+        let chapters = [["aaaa aaaa", "bbbb"], ["cccc cccc cccc cccc cccc", "dddd"]]
+            .iter()
+            .enumerate()
+            .map(|(c, texts)| {
+                let verses = texts
+                    .iter()
+                    .enumerate()
+                    .map(|(v, t)| Verse::new(BibleBook::Genesis, c + 1, v + 1, t.to_string()))
+                    .collect();
+                Chapter::new(verses, c + 1)
+            })
+            .collect();
+        let book = Book::new("gn".into(), "Genesis".into(), chapters);
+        TestBible::new(vec![book]).build()
+    }
+
+    #[test]
+    fn test_verse_chunks_cross_chapters_unless_asked() {
+        let bible = create_test_bible();
+
+        let chunks = bible.chunks(&ChunkOptions::verses(3));
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].range().to_string(), "Genesis 1:1-2:1");
+
+        let chunks = bible.chunks(&ChunkOptions::verses(3).within_chapters(true));
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].range().to_string(), "Genesis 1:1-2");
+        assert_eq!(chunks[0].text(), "aaaa aaaa bbbb");
+    }
+
+    #[test]
+    fn test_token_budget_groups_whole_verses() {
+        let bible = create_test_bible();
+
+        // 3 + 1 tokens fit together; the 6-token verse stands alone.
+        let chunks = bible.chunks(&ChunkOptions::tokens(4));
+        let ends: Vec<&Reference> = chunks.iter().map(|c| c.range().end()).collect();
+        assert_eq!(
+            ends,
+            [
+                &Reference::new(BibleBook::Genesis, 1, 2),
+                &Reference::new(BibleBook::Genesis, 2, 1),
+                &Reference::new(BibleBook::Genesis, 2, 2),
+            ]
+        );
+    }
+}
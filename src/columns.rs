@@ -0,0 +1,117 @@
+//! Column-oriented views of a Bible's verses.
+//!
+//! [`Bible::verse_columns`] lays the verses out as parallel columns, the
+//! shape expected by Arrow `RecordBatch` builders and dataframe libraries
+//! such as polars, so a table can be assembled without walking books and
+//! chapters:
+//!
+//! ```text
+//! RecordBatch::try_from_iter([
+//!     ("book", Arc::new(StringArray::from(columns.book().to_vec())) as ArrayRef),
+//!     ("chapter", Arc::new(UInt32Array::from(columns.chapter().to_vec()))),
+//!     ...
+//! ])
+//! ```
+
+use crate::bible::Bible;
+
+/// The verses of a Bible as parallel columns, one row per verse in reading
+/// order. All columns have [`len`](VerseColumns::len) entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerseColumns<'a> {
+    book: Vec<&'a str>,
+    chapter: Vec<u32>,
+    verse: Vec<u32>,
+    last_verse: Vec<u32>,
+    text: Vec<&'a str>,
+    word_count: Vec<u32>,
+}
+
+impl<'a> VerseColumns<'a> {
+    /// Returns each row's book abbreviation, such as `"gn"`.
+    pub fn book(&self) -> &[&'a str] {
+        &self.book
+    }
+
+    /// Returns each row's chapter number.
+    pub fn chapter(&self) -> &[u32] {
+        &self.chapter
+    }
+
+    /// Returns each row's verse number.
+    pub fn verse(&self) -> &[u32] {
+        &self.verse
+    }
+
+    /// Returns each row's last verse number, which differs from
+    /// [`verse`](VerseColumns::verse) only for a verse bridge.
+    pub fn last_verse(&self) -> &[u32] {
+        &self.last_verse
+    }
+
+    /// Returns each row's verse text.
+    pub fn text(&self) -> &[&'a str] {
+        &self.text
+    }
+
+    /// Returns each row's [word count](crate::Verse::word_count).
+    pub fn word_count(&self) -> &[u32] {
+        &self.word_count
+    }
+
+    /// Returns the number of rows.
+    pub fn len(&self) -> usize {
+        self.book.len()
+    }
+
+    /// Returns `true` if there are no rows.
+    pub fn is_empty(&self) -> bool {
+        self.book.is_empty()
+    }
+}
+
+impl Bible {
+    /// Returns every verse as [`VerseColumns`].
+    pub fn verse_columns(&self) -> VerseColumns<'_> {
+        let mut columns = VerseColumns::default();
+        for verse in self.all_verses() {
+            columns.book.push(verse.book().as_str());
+            columns.chapter.push(verse.chapter() as u32);
+            columns.verse.push(verse.number() as u32);
+            columns.last_verse.push(verse.last_number() as u32);
+            columns.text.push(verse.text());
+            columns.word_count.push(verse.word_count() as u32);
+        }
+        columns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+        verse::Verse,
+    };
+
+    #[test]
+    fn test_verse_columns() {
+        let verses = vec![
+            Verse::new(BibleBook::John, 11, 35, "Jesus wept.".into()),
+            Verse::new(BibleBook::John, 11, 36, "Then said the Jews,".into()).with_bridge_end(37),
+        ];
+        let bible = TestBible::new(vec![Book::new(
+            "jo".into(),
+            "John".into(),
+            vec![Chapter::new(verses, 11)],
+        )])
+        .build();
+        let columns = bible.verse_columns();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns.book(), ["jo", "jo"]);
+        assert_eq!(columns.chapter(), [11, 11]);
+        assert_eq!(columns.verse(), [35, 36]);
+        assert_eq!(columns.last_verse(), [35, 37]);
+        assert_eq!(columns.text()[0], "Jesus wept.");
+        assert_eq!(columns.word_count(), [2, 4]);
+    }
+}
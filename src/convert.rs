@@ -0,0 +1,409 @@
+//! Conversion between the formats this crate reads and writes.
+//!
+//! [`convert`] loads a [`SourceSpec`], writes it as a [`TargetSpec`], and
+//! returns a [`ConversionSummary`] listing what the target format could not
+//! keep, such as paragraph breaks lost when writing verse-per-line text.
+//! Text targets are streamed chapter by chapter to the output file.
+
+use std::{
+    error::Error,
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Write},
+};
+
+use crate::{
+    bible::{Bible, BibleError},
+    book::Book,
+    json_schema::JsonSchema,
+    plain_text::PlainTextFormat,
+    render::{chapter_to_html, chapter_to_markdown},
+    search_export::SearchExportFormat,
+    verse::SpanKind,
+};
+
+/// Where to read a Bible from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SourceSpec {
+    /// A JSON file in the given layout; see [`Bible::new_from_json_with_schema`].
+    Json { path: String, schema: JsonSchema },
+    /// A Digital Bible Library bundle directory; see [`Bible::from_dbl_bundle`].
+    DblBundle { path: String },
+    /// A verse-per-line or Forge text file; see [`Bible::from_plain_text`].
+    PlainText {
+        path: String,
+        format: PlainTextFormat,
+    },
+}
+
+impl SourceSpec {
+    /// Loads the Bible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the source cannot be read.
+    pub fn load(&self) -> Result<Bible, BibleError> {
+        match self {
+            SourceSpec::Json { path, schema } => Bible::new_from_json_with_schema(path, *schema),
+            SourceSpec::DblBundle { path } => Bible::from_dbl_bundle(path),
+            SourceSpec::PlainText { path, format } => Bible::from_plain_text(path, *format),
+        }
+    }
+}
+
+/// Where and how to write a Bible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TargetSpec {
+    /// This crate's JSON layout; see [`Bible::to_json`].
+    Json { path: String },
+    /// One verse per line, as `"Genesis 1:1 In the beginning..."`.
+    VersePerLine { path: String },
+    /// One JSON record per verse; see [`Bible::to_jsonl`].
+    JsonLines { path: String },
+    /// An HTML document with one `<article>` per book.
+    Html { path: String },
+    /// Markdown with a heading per book.
+    Markdown { path: String },
+    /// Search engine documents; see [`Bible::write_search_documents`].
+    SearchDocuments {
+        path: String,
+        format: SearchExportFormat,
+    },
+}
+
+impl TargetSpec {
+    /// Returns the output path.
+    pub fn path(&self) -> &str {
+        match self {
+            TargetSpec::Json { path }
+            | TargetSpec::VersePerLine { path }
+            | TargetSpec::JsonLines { path }
+            | TargetSpec::Html { path }
+            | TargetSpec::Markdown { path }
+            | TargetSpec::SearchDocuments { path, .. } => path,
+        }
+    }
+
+    /// Writes `bible` to the target and reports what the format dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or the file cannot be written.
+    pub fn write(&self, bible: &Bible) -> Result<ConversionSummary, Box<dyn Error>> {
+        if let TargetSpec::Json { path } = self {
+            bible.save_json(path)?;
+        } else {
+            let mut writer = BufWriter::new(File::create(self.path())?);
+            self.write_text(bible, &mut writer)?;
+            writer.flush()?;
+        }
+        let present = present_features(bible);
+        let dropped = present
+            .into_iter()
+            .filter(|(feature, _)| !self.keeps(*feature))
+            .collect();
+        Ok(ConversionSummary {
+            books: bible.books().len(),
+            verses: bible.all_verses().count(),
+            dropped,
+        })
+    }
+
+    fn write_text<W: Write>(&self, bible: &Bible, writer: &mut W) -> io::Result<()> {
+        let direction = bible.direction();
+        match self {
+            TargetSpec::Json { .. } => {}
+            TargetSpec::VersePerLine { .. } => {
+                for verse in bible.all_verses() {
+                    writeln!(writer, "{} {}", verse.reference(), verse.text().trim())?;
+                }
+            }
+            TargetSpec::JsonLines { .. } => bible.to_jsonl(writer)?,
+            TargetSpec::Html { .. } => {
+                writeln!(writer, "<!DOCTYPE html>")?;
+                writeln!(
+                    writer,
+                    "<html lang=\"{}\" dir=\"{direction}\">",
+                    escape_html(bible.language())
+                )?;
+                writeln!(
+                    writer,
+                    "<head><meta charset=\"utf-8\"><title>{}</title></head>",
+                    escape_html(bible.name())
+                )?;
+                writeln!(writer, "<body>")?;
+                for book in bible.books() {
+                    writeln!(
+                        writer,
+                        "<article class=\"book\" data-book=\"{}\">\n<h1>{}</h1>",
+                        book.id(),
+                        escape_html(book.title())
+                    )?;
+                    for chapter in book.chapters() {
+                        writeln!(writer, "{}", chapter_to_html(chapter, direction))?;
+                    }
+                    writeln!(writer, "</article>")?;
+                }
+                writeln!(writer, "</body>\n</html>")?;
+            }
+            TargetSpec::Markdown { .. } => {
+                for book in bible.books() {
+                    writeln!(writer, "# {}\n", book.title())?;
+                    for chapter in book.chapters() {
+                        writeln!(writer, "{}\n", chapter_to_markdown(chapter, direction))?;
+                    }
+                }
+            }
+            TargetSpec::SearchDocuments { format, .. } => {
+                bible.write_search_documents(writer, *format)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn keeps(&self, feature: DroppedFeature) -> bool {
+        use DroppedFeature::*;
+        match self {
+            TargetSpec::Json { .. } => feature != Headings,
+            TargetSpec::Html { .. } | TargetSpec::Markdown { .. } => {
+                matches!(feature, SuppliedWords | Paragraphs | Superscriptions)
+            }
+            TargetSpec::VersePerLine { .. }
+            | TargetSpec::JsonLines { .. }
+            | TargetSpec::SearchDocuments { .. } => false,
+        }
+    }
+}
+
+/// Content that a target format could not represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DroppedFeature {
+    /// Markers of translator-supplied words.
+    SuppliedWords,
+    /// Paragraph and poetry structure.
+    Paragraphs,
+    /// Psalm superscriptions.
+    Superscriptions,
+    /// Section headings attached to chapters.
+    Headings,
+    /// Book introductions.
+    Introductions,
+    /// Book outlines.
+    Outlines,
+    /// Local book names and abbreviations.
+    Aliases,
+    /// Copyright, license and source details.
+    Metadata,
+}
+
+impl fmt::Display for DroppedFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DroppedFeature::SuppliedWords => "supplied-word markers",
+            DroppedFeature::Paragraphs => "paragraph breaks",
+            DroppedFeature::Superscriptions => "superscriptions",
+            DroppedFeature::Headings => "section headings",
+            DroppedFeature::Introductions => "book introductions",
+            DroppedFeature::Outlines => "book outlines",
+            DroppedFeature::Aliases => "book aliases",
+            DroppedFeature::Metadata => "metadata fields",
+        })
+    }
+}
+
+/// The result of a [`convert`] run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionSummary {
+    books: usize,
+    verses: usize,
+    dropped: Vec<(DroppedFeature, usize)>,
+}
+
+impl ConversionSummary {
+    /// Returns the number of books written.
+    pub fn books(&self) -> usize {
+        self.books
+    }
+
+    /// Returns the number of verses written.
+    pub fn verses(&self) -> usize {
+        self.verses
+    }
+
+    /// Returns each feature of the source the target dropped, with how many
+    /// verses, chapters, books or fields carried it.
+    pub fn dropped(&self) -> &[(DroppedFeature, usize)] {
+        &self.dropped
+    }
+
+    /// Returns `true` if everything in the source was written.
+    pub fn is_lossless(&self) -> bool {
+        self.dropped.is_empty()
+    }
+}
+
+impl fmt::Display for ConversionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Converted {} books ({} verses)", self.books, self.verses)?;
+        for (i, (feature, count)) in self.dropped.iter().enumerate() {
+            let separator = if i == 0 { "; dropped " } else { ", " };
+            write!(f, "{separator}{feature} ({count})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Loads `source`, writes it to `target`, and reports what was dropped.
+///
+/// # Errors
+///
+/// Returns an error if the source cannot be loaded or the target cannot be
+/// written.
+pub fn convert(
+    source: &SourceSpec,
+    target: &TargetSpec,
+) -> Result<ConversionSummary, Box<dyn Error>> {
+    let bible = source.load()?;
+    target.write(&bible)
+}
+
+/// Counts the optional content of `bible`, leaving out features it lacks.
+fn present_features(bible: &Bible) -> Vec<(DroppedFeature, usize)> {
+    let books = bible.books();
+    let chapters = || books.iter().flat_map(|book| book.chapters());
+    let count_books = |has: fn(&Book) -> bool| books.iter().filter(|b| has(b)).count();
+    let metadata = bible.metadata();
+    let metadata_fields = [
+        metadata.copyright.is_some(),
+        metadata.license.is_some(),
+        metadata.publisher.is_some(),
+        metadata.source_url.is_some(),
+        metadata.versification.is_some(),
+        metadata.direction.is_some(),
+    ];
+    [
+        (
+            DroppedFeature::SuppliedWords,
+            bible
+                .all_verses()
+                .filter(|v| v.spans().iter().any(|s| s.kind == SpanKind::SuppliedWords))
+                .count(),
+        ),
+        (
+            DroppedFeature::Paragraphs,
+            chapters().filter(|c| !c.paragraphs().is_empty()).count(),
+        ),
+        (
+            DroppedFeature::Superscriptions,
+            chapters().filter(|c| c.superscription().is_some()).count(),
+        ),
+        (
+            DroppedFeature::Headings,
+            chapters().map(|c| c.headings().len()).sum(),
+        ),
+        (
+            DroppedFeature::Introductions,
+            count_books(|b| b.introduction().is_some()),
+        ),
+        (
+            DroppedFeature::Outlines,
+            count_books(|b| !b.outline().is_empty()),
+        ),
+        (
+            DroppedFeature::Aliases,
+            count_books(|b| !b.aliases().is_empty()),
+        ),
+        (
+            DroppedFeature::Metadata,
+            metadata_fields.iter().filter(|&&set| set).count(),
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, count)| *count > 0)
+    .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::{
+        bible::BibleMetadata, bible_books_enum::BibleBook, chapter::Chapter,
+        test_support::TestBible, verse::Verse,
+    };
+
+    fn create_test_bible() -> Bible {
+        let verses = vec![
+            Verse::new(BibleBook::Ruth, 1, 1, "Now it came to pass".into()),
+            Verse::new(BibleBook::Ruth, 1, 2, "And the name {was} Elimelech".into()),
+        ];
+        let metadata = BibleMetadata {
+            copyright: Some("Public domain".into()),
+            ..BibleMetadata::default()
+        };
+        TestBible::new(vec![Book::new(
+            "rt".into(),
+            "Ruth".into(),
+            vec![Chapter::new(verses, 1)],
+        )
+        .with_aliases(vec!["Rth".into()])])
+        .with_metadata(metadata)
+        .build()
+    }
+
+    #[test]
+    fn test_write_reports_dropped_features() {
+        let bible = create_test_bible();
+        let dir = std::env::temp_dir();
+        let vpl = dir.join("bible_io_convert_test.txt");
+        let json = dir.join("bible_io_convert_test.json");
+        let vpl_target = TargetSpec::VersePerLine {
+            path: vpl.to_string_lossy().into_owned(),
+        };
+        let json_target = TargetSpec::Json {
+            path: json.to_string_lossy().into_owned(),
+        };
+
+        let summary = vpl_target.write(&bible).unwrap();
+        let text = fs::read_to_string(&vpl).unwrap();
+        assert_eq!(
+            text,
+            "Ruth 1:1 Now it came to pass\nRuth 1:2 And the name was Elimelech\n"
+        );
+        assert_eq!(
+            summary.dropped(),
+            [
+                (DroppedFeature::SuppliedWords, 1),
+                (DroppedFeature::Aliases, 1),
+                (DroppedFeature::Metadata, 1)
+            ]
+        );
+        assert_eq!(
+            summary.to_string(),
+            "Converted 1 books (2 verses); dropped supplied-word markers (1), \
+             book aliases (1), metadata fields (1)"
+        );
+
+        assert!(json_target.write(&bible).unwrap().is_lossless());
+        let source = SourceSpec::Json {
+            path: json_target.path().to_string(),
+            schema: JsonSchema::Auto,
+        };
+        let summary = convert(&source, &vpl_target).unwrap();
+        assert_eq!(summary.verses(), 2);
+        assert_eq!(fs::read_to_string(&vpl).unwrap(), text);
+        fs::remove_file(vpl).ok();
+        fs::remove_file(json).ok();
+    }
+}
@@ -0,0 +1,318 @@
+//! Corpus statistics: n-gram frequencies, collocations, vocabulary
+//! comparison, and keywords.
+//!
+//! Text is tokenized with the same rules as the search index, and n-grams
+//! never span two verses.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{
+    bible::{Bible, BibleError},
+    book::Book,
+    book_id::BookId,
+    chapter::Chapter,
+    search_index::SearchIndex,
+    verse::Verse,
+};
+
+/// A sequence of `n` consecutive terms and how often it occurs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NGram {
+    pub terms: Vec<String>,
+    pub count: usize,
+}
+
+/// A word pair that co-occurs more often than chance would predict.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collocation {
+    pub first: String,
+    pub second: String,
+    /// Number of times `second` directly follows `first`.
+    pub count: usize,
+    /// Pointwise mutual information of the pair, in bits.
+    pub pmi: f64,
+}
+
+/// The vocabularies of two books split into shared and distinct terms.
+///
+/// Each list is sorted alphabetically.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VocabularyComparison {
+    pub shared: Vec<String>,
+    pub only_in_first: Vec<String>,
+    pub only_in_second: Vec<String>,
+}
+
+/// A term that distinguishes a chapter or book from the rest of the Bible.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyword {
+    pub term: String,
+    /// Number of times the term occurs in the chapter or book.
+    pub count: usize,
+    /// The TF-IDF score: `count` weighted by the term's rarity.
+    pub score: f64,
+}
+
+/// How rare each term of a Bible is, for scoring [`Keyword`]s.
+///
+/// Each chapter counts as one document, so a term's weight is the natural
+/// log of the number of chapters over the number containing the term.
+/// Terms found in every chapter, such as "the", weigh nothing.
+#[derive(Debug, Clone, Default)]
+pub struct TermWeights {
+    chapters: usize,
+    chapter_counts: HashMap<String, usize>,
+}
+
+impl TermWeights {
+    /// Returns the weight of `term`, which must already be tokenized. Terms
+    /// the Bible lacks weigh as much as terms found in a single chapter.
+    pub fn weight(&self, term: &str) -> f64 {
+        let containing = self.chapter_counts.get(term).copied().unwrap_or(1);
+        (self.chapters.max(1) as f64 / containing as f64).ln()
+    }
+
+    /// Returns the `n` highest scoring terms of `verses`, best first, with
+    /// ties ordered alphabetically.
+    fn keywords<'a>(&self, verses: impl Iterator<Item = &'a Verse>, n: usize) -> Vec<Keyword> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for verse in verses {
+            for term in SearchIndex::tokenize(verse.text()) {
+                *counts.entry(term).or_default() += 1;
+            }
+        }
+        let mut keywords: Vec<Keyword> = counts
+            .into_iter()
+            .map(|(term, count)| Keyword {
+                score: count as f64 * self.weight(&term),
+                term,
+                count,
+            })
+            .filter(|keyword| keyword.score > 0.0)
+            .collect();
+        keywords.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.term.cmp(&b.term))
+        });
+        keywords.truncate(n);
+        keywords
+    }
+}
+
+impl Chapter {
+    /// Returns the `n` terms most distinctive of this chapter, scored by
+    /// TF-IDF against `weights`, typically from [`Bible::term_weights`].
+    pub fn keywords(&self, weights: &TermWeights, n: usize) -> Vec<Keyword> {
+        weights.keywords(self.get_verses().iter(), n)
+    }
+}
+
+impl Book {
+    /// Returns the `n` terms most distinctive of this book, scored by TF-IDF
+    /// against `weights`, typically from [`Bible::term_weights`].
+    pub fn keywords(&self, weights: &TermWeights, n: usize) -> Vec<Keyword> {
+        let verses = self.chapters().iter().flat_map(|c| c.get_verses());
+        weights.keywords(verses, n)
+    }
+}
+
+impl Bible {
+    /// Measures how rare each term is across the chapters of this Bible, for
+    /// [`Chapter::keywords`] and [`Book::keywords`].
+    pub fn term_weights(&self) -> TermWeights {
+        let mut weights = TermWeights::default();
+        for chapter in self.books().iter().flat_map(|b| b.chapters()) {
+            weights.chapters += 1;
+            let terms: BTreeSet<String> = chapter
+                .get_verses()
+                .iter()
+                .flat_map(|v| SearchIndex::tokenize(v.text()))
+                .collect();
+            for term in terms {
+                *weights.chapter_counts.entry(term).or_default() += 1;
+            }
+        }
+        weights
+    }
+
+    /// Counts every `n`-gram in the Bible, most frequent first.
+    ///
+    /// Ties are ordered alphabetically. Returns an empty list when `n` is zero.
+    pub fn ngrams(&self, n: usize) -> Vec<NGram> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut counts: HashMap<Vec<String>, usize> = HashMap::new();
+        for terms in self.verse_terms() {
+            for window in terms.windows(n) {
+                *counts.entry(window.to_vec()).or_default() += 1;
+            }
+        }
+
+        let mut ngrams: Vec<NGram> = counts
+            .into_iter()
+            .map(|(terms, count)| NGram { terms, count })
+            .collect();
+        ngrams.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.terms.cmp(&b.terms)));
+        ngrams
+    }
+
+    /// Scores adjacent word pairs occurring at least `min_count` times by
+    /// pointwise mutual information, strongest association first.
+    pub fn collocations(&self, min_count: usize) -> Vec<Collocation> {
+        let mut unigrams: HashMap<String, usize> = HashMap::new();
+        let mut bigrams: HashMap<(String, String), usize> = HashMap::new();
+        let mut total_unigrams = 0usize;
+        let mut total_bigrams = 0usize;
+
+        for terms in self.verse_terms() {
+            total_unigrams += terms.len();
+            for term in &terms {
+                *unigrams.entry(term.clone()).or_default() += 1;
+            }
+            for pair in terms.windows(2) {
+                total_bigrams += 1;
+                *bigrams
+                    .entry((pair[0].clone(), pair[1].clone()))
+                    .or_default() += 1;
+            }
+        }
+
+        let mut collocations: Vec<Collocation> = bigrams
+            .into_iter()
+            .filter(|(_, count)| *count >= min_count.max(1))
+            .map(|((first, second), count)| {
+                let p_pair = count as f64 / total_bigrams as f64;
+                let p_first = unigrams[&first] as f64 / total_unigrams as f64;
+                let p_second = unigrams[&second] as f64 / total_unigrams as f64;
+                Collocation {
+                    pmi: (p_pair / (p_first * p_second)).log2(),
+                    first,
+                    second,
+                    count,
+                }
+            })
+            .collect();
+
+        collocations.sort_by(|a, b| {
+            b.pmi
+                .total_cmp(&a.pmi)
+                .then_with(|| (&a.first, &a.second).cmp(&(&b.first, &b.second)))
+        });
+        collocations
+    }
+
+    /// Compares the distinct terms used by two books.
+    pub fn compare_vocabulary(
+        &self,
+        first: impl Into<BookId>,
+        second: impl Into<BookId>,
+    ) -> Result<VocabularyComparison, BibleError> {
+        let first = vocabulary(self.get_book(first)?);
+        let second = vocabulary(self.get_book(second)?);
+
+        Ok(VocabularyComparison {
+            shared: first.intersection(&second).cloned().collect(),
+            only_in_first: first.difference(&second).cloned().collect(),
+            only_in_second: second.difference(&first).cloned().collect(),
+        })
+    }
+
+    fn verse_terms(&self) -> impl Iterator<Item = Vec<String>> + '_ {
+        self.books()
+            .iter()
+            .flat_map(|b| b.chapters())
+            .flat_map(|c| c.get_verses())
+            .map(|v| SearchIndex::tokenize(v.text()))
+    }
+}
+
+fn vocabulary(book: &Book) -> BTreeSet<String> {
+    book.chapters()
+        .iter()
+        .flat_map(|c| c.get_verses())
+        .flat_map(|v| SearchIndex::tokenize(v.text()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, chapter::Chapter, test_support::TestBible, verse::Verse,
+    };
+
+    fn create_test_bible() -> Bible {
+        let book = |abbrev: &str, id: BibleBook, texts: &[&str]| {
+            let verses = texts
+                .iter()
+                .enumerate()
+                .map(|(i, t)| Verse::new(id, 1, i + 1, t.to_string()))
+                .collect();
+            Book::new(
+                abbrev.into(),
+                id.full_name().into(),
+                vec![Chapter::new(verses, 1)],
+            )
+        };
+        TestBible::new(vec![
+            book(
+                "gn",
+                BibleBook::Genesis,
+                &["the holy spirit moved", "the holy spirit"],
+            ),
+            book("ex", BibleBook::Exodus, &["the spirit of wisdom"]),
+        ])
+        .build()
+    }
+
+    #[test]
+    fn test_ngrams_count_within_verses() {
+        let bible = create_test_bible();
+        let bigrams = bible.ngrams(2);
+
+        assert_eq!(bigrams[0].terms, ["holy", "spirit"]);
+        assert_eq!(bigrams[0].count, 2);
+        // "spirit the" would only appear if n-grams crossed verse boundaries.
+        assert!(bigrams.iter().all(|g| g.terms != ["spirit", "the"]));
+        assert!(bible.ngrams(0).is_empty());
+    }
+
+    #[test]
+    fn test_collocations_and_vocabulary() {
+        let bible = create_test_bible();
+        let collocations = bible.collocations(2);
+        // "holy spirit" and "the holy" are equally associated; ties sort alphabetically.
+        assert_eq!(collocations.len(), 2);
+        assert_eq!(collocations[0].first, "holy");
+        assert_eq!(collocations[1].first, "the");
+        assert_eq!(collocations[0].pmi, collocations[1].pmi);
+
+        let comparison = bible
+            .compare_vocabulary(BibleBook::Genesis, BibleBook::Exodus)
+            .unwrap();
+        assert_eq!(comparison.shared, ["spirit", "the"]);
+        assert_eq!(comparison.only_in_first, ["holy", "moved"]);
+        assert_eq!(comparison.only_in_second, ["of", "wisdom"]);
+    }
+
+    #[test]
+    fn test_keywords() {
+        let bible = create_test_bible();
+        let weights = bible.term_weights();
+        let genesis = bible.get_book(BibleBook::Genesis).unwrap();
+
+        let keywords = genesis.chapters()[0].keywords(&weights, 2);
+        let terms: Vec<&str> = keywords.iter().map(|k| k.term.as_str()).collect();
+        assert_eq!(terms, ["holy", "moved"]);
+        assert_eq!(keywords[0].count, 2);
+        // "the" and "spirit" occur in every chapter, so they never score.
+        assert!(genesis
+            .keywords(&weights, 10)
+            .iter()
+            .all(|k| k.term != "the"));
+        assert_eq!(genesis.keywords(&weights, 10).len(), 2);
+    }
+}
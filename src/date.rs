@@ -0,0 +1,192 @@
+//! A calendar date without time zone, used by reading schedules.
+
+use std::{
+    fmt,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A day in the proleptic Gregorian calendar, written as `YYYY-MM-DD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// Days since 1970-01-01.
+    days: i64,
+}
+
+impl Date {
+    /// 1970-01-01, the start of Unix time.
+    pub(crate) const EPOCH: Date = Date { days: 0 };
+
+    /// Creates a date, or returns `None` if the month or day is out of range.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self {
+            days: days_from_civil(i64::from(year), i64::from(month), i64::from(day)),
+        })
+    }
+
+    /// Returns the current date in UTC.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Self {
+            days: (seconds / 86_400) as i64,
+        }
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        civil_from_days(self.days).0
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u32 {
+        civil_from_days(self.days).1
+    }
+
+    /// Returns the day of the month, from 1.
+    pub fn day(&self) -> u32 {
+        civil_from_days(self.days).2
+    }
+
+    /// Returns the date `days` days later, or earlier if negative.
+    pub fn add_days(&self, days: i64) -> Self {
+        Self {
+            days: self.days + days,
+        }
+    }
+
+    /// Returns the number of days from `earlier` to this date, negative if
+    /// `earlier` is actually later.
+    pub fn days_since(&self, earlier: Date) -> i64 {
+        self.days - earlier.days
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Conversions between civil dates and day counts follow Howard Hinnant's
+// `days_from_civil` and `civil_from_days`.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month as u32, day as u32)
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = civil_from_days(self.days);
+        write!(f, "{year:04}-{month:02}-{day:02}")
+    }
+}
+
+/// The error returned when a string is not a `YYYY-MM-DD` date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDateError;
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid date; expected YYYY-MM-DD")
+    }
+}
+
+impl std::error::Error for ParseDateError {}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().splitn(3, '-');
+        let mut next = || parts.next().ok_or(ParseDateError);
+        let year = next()?.parse().map_err(|_| ParseDateError)?;
+        let month = next()?.parse().map_err(|_| ParseDateError)?;
+        let day = next()?.parse().map_err(|_| ParseDateError)?;
+        Date::new(year, month, day).ok_or(ParseDateError)
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendar_arithmetic() {
+        let date = Date::new(2024, 2, 28).unwrap();
+        assert_eq!(date.add_days(1).to_string(), "2024-02-29");
+        assert_eq!(date.add_days(2).to_string(), "2024-03-01");
+        assert_eq!(
+            Date::new(1970, 1, 1).unwrap().add_days(-1).to_string(),
+            "1969-12-31"
+        );
+        assert_eq!(
+            Date::new(2025, 1, 1)
+                .unwrap()
+                .days_since(Date::new(2024, 1, 1).unwrap()),
+            366
+        );
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert!(Date::new(1900, 2, 29).is_none());
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let date: Date = "2026-10-05".parse().unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2026, 10, 5));
+        assert_eq!(date.to_string(), "2026-10-05");
+        assert_eq!("2026-13-01".parse::<Date>(), Err(ParseDateError));
+        assert_eq!("2026-10".parse::<Date>(), Err(ParseDateError));
+        assert!(Date::today() > date.add_days(-365 * 100));
+    }
+}
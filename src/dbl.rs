@@ -0,0 +1,295 @@
+//! Loading Digital Bible Library (DBL) text bundles.
+//!
+//! A bundle is a directory holding `metadata.xml` and one USX file per book,
+//! usually under `release/USX_1/`. The translation's name, language,
+//! copyright, script direction, book order and local book names are read
+//! from the metadata; the text is read with [`Book::from_usx`].
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    bible::{Bible, BibleError, BibleMetadata, TextDirection},
+    bible_books_enum::BibleBook,
+    book::Book,
+    usx::{UsxError, XmlEvent, XmlEvents},
+};
+
+impl Bible {
+    /// Loads a DBL text bundle from the directory `bundle_path`.
+    ///
+    /// Books follow the order of the metadata's canonical content, and the
+    /// long, short and abbreviated book names it lists become aliases, so
+    /// references can use local-language names.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if `metadata.xml` or a USX file
+    /// cannot be read or parsed, or if the bundle has no USX files.
+    pub fn from_dbl_bundle(bundle_path: &str) -> Result<Self, BibleError> {
+        let root = Path::new(bundle_path);
+        let metadata_path = root.join("metadata.xml");
+        let metadata_path_str = metadata_path.to_string_lossy();
+        let xml = fs::read_to_string(&metadata_path)
+            .map_err(|e| BibleError::load_error(&metadata_path_str, e))?;
+        let metadata =
+            DblMetadata::parse(&xml).map_err(|e| BibleError::load_error(&metadata_path_str, e))?;
+
+        let mut files = Vec::new();
+        find_usx_files(root, &mut files).map_err(|e| BibleError::load_error(bundle_path, e))?;
+        if files.is_empty() {
+            return Err(BibleError::load_error(
+                bundle_path,
+                io::Error::new(io::ErrorKind::NotFound, "no USX files in DBL bundle"),
+            ));
+        }
+        files.sort();
+
+        let mut books = Vec::with_capacity(files.len());
+        for file in files {
+            let path = file.to_string_lossy();
+            let usx = fs::read_to_string(&file).map_err(|e| BibleError::load_error(&path, e))?;
+            let book = Book::from_usx(&usx).map_err(|e| BibleError::load_error(&path, e))?;
+            let aliases = metadata
+                .book_names
+                .get(book.id().as_str())
+                .cloned()
+                .unwrap_or_default();
+            books.push(book.with_aliases(aliases));
+        }
+        books.sort_by_key(|book| {
+            let listed = metadata
+                .book_order
+                .iter()
+                .position(|id| id == book.id().as_str());
+            (
+                listed.unwrap_or(usize::MAX),
+                book.id().known().map(|b| b.id()),
+            )
+        });
+
+        let bible = Bible::from_books(
+            books,
+            metadata.abbreviation.to_lowercase(),
+            metadata.name,
+            metadata.description,
+            metadata.language,
+            metadata.metadata,
+        );
+        Ok(bible.with_source(bundle_path))
+    }
+}
+
+/// The parts of a DBL `metadata.xml` used when loading a bundle.
+#[derive(Debug, Default)]
+struct DblMetadata {
+    name: String,
+    abbreviation: String,
+    description: String,
+    language: String,
+    metadata: BibleMetadata,
+    /// Book ids, as [`BookId::as_str`](crate::BookId::as_str), in canonical
+    /// order.
+    book_order: Vec<String>,
+    /// Local names of each book, by book id.
+    book_names: HashMap<String, Vec<String>>,
+}
+
+impl DblMetadata {
+    /// Reads both the 1.x and 2.x metadata layouts.
+    fn parse(xml: &str) -> Result<Self, UsxError> {
+        let mut metadata = DblMetadata::default();
+        let mut path: Vec<&str> = Vec::new();
+        // The book whose names are being read, inside <bookNames>.
+        let mut named_book: Option<String> = None;
+        for event in XmlEvents::new(xml) {
+            match event? {
+                XmlEvent::Start {
+                    name,
+                    attributes,
+                    empty,
+                } => {
+                    if name == "book" {
+                        let code = attributes
+                            .iter()
+                            .find(|(key, _)| *key == "code")
+                            .map(|(_, code)| book_id(code));
+                        if path.contains(&"bookNames") {
+                            named_book = code;
+                        } else if let Some(code) = code {
+                            if !metadata.book_order.contains(&code) {
+                                metadata.book_order.push(code);
+                            }
+                        }
+                    }
+                    if !empty {
+                        path.push(name);
+                    }
+                }
+                XmlEvent::End { .. } => {
+                    if path.pop() == Some("book") {
+                        named_book = None;
+                    }
+                }
+                XmlEvent::Text(text) => {
+                    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if text.is_empty() {
+                        continue;
+                    }
+                    metadata.read_text(&path, named_book.as_deref(), text);
+                }
+            }
+        }
+        Ok(metadata)
+    }
+
+    /// Stores `text` found inside the elements `path`.
+    fn read_text(&mut self, path: &[&str], named_book: Option<&str>, text: String) {
+        let within = |suffix: &[&str]| path.ends_with(suffix);
+        if within(&["identification", "name"]) {
+            set_once(&mut self.name, text);
+        } else if within(&["identification", "abbreviation"]) {
+            set_once(&mut self.abbreviation, text);
+        } else if within(&["identification", "description"]) {
+            set_once(&mut self.description, text);
+        } else if within(&["language", "iso"]) {
+            set_once(&mut self.language, text);
+        } else if within(&["language", "scriptDirection"]) {
+            self.metadata.direction = Some(if text.eq_ignore_ascii_case("rtl") {
+                TextDirection::Rtl
+            } else {
+                TextDirection::Ltr
+            });
+        } else if path.contains(&"copyright") {
+            // Statements may be split over several XHTML paragraphs.
+            let copyright = self.metadata.copyright.get_or_insert_default();
+            if !copyright.is_empty() {
+                copyright.push(' ');
+            }
+            copyright.push_str(&text);
+        } else if within(&["rightsHolder", "name"]) {
+            self.metadata.publisher.get_or_insert(text);
+        } else if let Some(book) = named_book {
+            if within(&["long"]) || within(&["short"]) || within(&["abbr"]) {
+                let names = self.book_names.entry(book.to_string()).or_default();
+                if !names.contains(&text) {
+                    names.push(text);
+                }
+            }
+        }
+    }
+}
+
+fn set_once(field: &mut String, text: String) {
+    if field.is_empty() {
+        *field = text;
+    }
+}
+
+/// Converts a USFM book code to the id used by [`Book::from_usx`].
+fn book_id(code: &str) -> String {
+    match BibleBook::from_usfm_id(code) {
+        Some(book) => book.as_str().to_string(),
+        None => code.to_ascii_lowercase(),
+    }
+}
+
+/// Collects the `.usx` files under `dir`, recursively.
+fn find_usx_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_usx_files(&path, files)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("usx"))
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<DBLMetadata id="2880c78491b2f8ce" revision="4" type="text" typeVersion="2.0">
+  <identification>
+    <name>Sample English Version</name>
+    <abbreviation>SEV</abbreviation>
+    <description>A sample bundle</description>
+  </identification>
+  <language>
+    <iso>eng</iso>
+    <scriptDirection>LTR</scriptDirection>
+  </language>
+  <copyright>
+    <fullStatement>
+      <statementContent type="xhtml"><p>© 2024 Sample Society</p></statementContent>
+    </fullStatement>
+  </copyright>
+  <publications>
+    <publication default="true">
+      <canonicalContent><book code="JON"/><book code="RUT"/></canonicalContent>
+    </publication>
+  </publications>
+  <names>
+  </names>
+  <bookNames>
+    <book code="RUT"><long>The Book of Ruth</long><short>Ruth</short><abbr>Rth</abbr></book>
+  </bookNames>
+</DBLMetadata>"#;
+
+    fn usx(code: &str, text: &str) -> String {
+        format!(
+            r#"<usx version="3.0"><book code="{code}" style="id"/><chapter number="1" style="c"/><para style="p"><verse number="1" style="v"/>{text}</para></usx>"#
+        )
+    }
+
+    #[test]
+    fn test_load_bundle() {
+        let root = std::env::temp_dir().join("bible_io_dbl_bundle_test");
+        let usx_dir = root.join("release").join("USX_1");
+        fs::create_dir_all(&usx_dir).unwrap();
+        fs::write(root.join("metadata.xml"), METADATA).unwrap();
+        fs::write(
+            usx_dir.join("RUT.usx"),
+            usx("RUT", "In the days when the judges ruled"),
+        )
+        .unwrap();
+        fs::write(
+            usx_dir.join("JON.usx"),
+            usx("JON", "Now the word of the LORD came"),
+        )
+        .unwrap();
+
+        let bible = Bible::from_dbl_bundle(root.to_str().unwrap());
+        fs::remove_dir_all(&root).ok();
+        let bible = bible.unwrap();
+
+        assert_eq!(bible.id(), "sev");
+        assert_eq!(bible.name(), "Sample English Version");
+        assert_eq!(bible.language(), "eng");
+        assert_eq!(bible.copyright(), Some("© 2024 Sample Society"));
+        let order: Vec<&str> = bible.books().iter().map(|b| b.id().as_str()).collect();
+        assert_eq!(order, ["jn", "rt"]);
+        assert_eq!(
+            bible.get_verse_by_reference("Rth 1:1").unwrap().text(),
+            "In the days when the judges ruled"
+        );
+    }
+
+    #[test]
+    fn test_missing_bundle() {
+        let missing = std::env::temp_dir().join("bible_io_missing_dbl_bundle");
+        assert!(matches!(
+            Bible::from_dbl_bundle(missing.to_str().unwrap()),
+            Err(BibleError::LoadError { .. })
+        ));
+    }
+}
@@ -0,0 +1,411 @@
+//! Daily devotional schedules.
+//!
+//! A [`DevotionalPlan`] is an ordered list of passages, one per day, stored
+//! as JSON with OSIS ranges:
+//!
+//! ```json
+//! { "name": "Gospels in a month", "readings": ["Matt.1.1-Matt.1.25", "Matt.2.1-Matt.2.23"] }
+//! ```
+//!
+//! A [`DevotionalSchedule`] starts a plan on a given date, works out which
+//! passage is due on any day, and records progress in a [`ScheduleState`]
+//! that can be saved and resumed.
+
+use std::{collections::BTreeSet, error::Error, fs};
+
+use serde::{Deserialize, Serialize};
+use simd_json::serde::{from_slice as simd_from_slice, to_string_pretty as simd_to_string_pretty};
+
+use crate::{
+    bible::{Bible, BibleError},
+    book::Book,
+    date::Date,
+    passage::Passage,
+    reference::{Reference, ReferenceRange},
+};
+
+/// An ordered list of daily readings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DevotionalPlan {
+    name: String,
+    description: String,
+    readings: Vec<ReferenceRange>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PlanFile {
+    name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    description: String,
+    readings: Vec<String>,
+}
+
+impl DevotionalPlan {
+    /// Creates an empty plan.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a plan reading `chapters_per_day` whole chapters a day through
+    /// `books` in order. A day never spans two books.
+    pub fn through_books<'a>(
+        name: impl Into<String>,
+        books: impl IntoIterator<Item = &'a Book>,
+        chapters_per_day: usize,
+    ) -> Self {
+        let mut plan = Self::new(name);
+        for book in books {
+            for day in book.chapters().chunks(chapters_per_day.max(1)) {
+                let (first, last) = (&day[0], &day[day.len() - 1]);
+                let start = Reference::new(book.id().clone(), first.number(), 1);
+                let end = Reference::new(book.id().clone(), last.number(), last.max_verse().max(1));
+                plan.readings.push(ReferenceRange::new(start, end));
+            }
+        }
+        plan
+    }
+
+    /// Sets a description of the plan.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Appends a day reading `passage`.
+    pub fn with_reading(mut self, passage: ReferenceRange) -> Self {
+        self.readings.push(passage);
+        self
+    }
+
+    /// Returns the plan name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the plan description.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the readings, one per day.
+    pub fn readings(&self) -> &[ReferenceRange] {
+        &self.readings
+    }
+
+    /// Returns the number of days in the plan.
+    pub fn len(&self) -> usize {
+        self.readings.len()
+    }
+
+    /// Returns `true` if the plan has no readings.
+    pub fn is_empty(&self) -> bool {
+        self.readings.is_empty()
+    }
+
+    /// Loads a plan from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or
+    /// parsed, or a reading is not an OSIS range.
+    pub fn from_json(json_path: &str) -> Result<Self, BibleError> {
+        let mut file_content =
+            fs::read(json_path).map_err(|e| BibleError::load_error(json_path, e))?;
+        let file: PlanFile =
+            simd_from_slice(&mut file_content).map_err(|e| BibleError::load_error(json_path, e))?;
+        let readings = file
+            .readings
+            .iter()
+            .map(|reading| ReferenceRange::from_osis_id(reading))
+            .collect::<Result<_, _>>()
+            .map_err(|e| BibleError::load_error(json_path, e))?;
+        Ok(Self {
+            name: file.name,
+            description: file.description,
+            readings,
+        })
+    }
+
+    /// Serializes the plan into the format read by
+    /// [`DevotionalPlan::from_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a reading is in a book without an OSIS identifier.
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        let readings = self
+            .readings
+            .iter()
+            .map(|reading| {
+                reading
+                    .to_osis_id()
+                    .ok_or_else(|| BibleError::InvalidReference {
+                        input: reading.to_string(),
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        let file = PlanFile {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            readings,
+        };
+        Ok(simd_to_string_pretty(&file)?)
+    }
+}
+
+/// Progress through a [`DevotionalPlan`], saved separately from the plan.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleState {
+    start: Date,
+    #[serde(default)]
+    postponed: u32,
+    #[serde(default)]
+    completed: BTreeSet<usize>,
+}
+
+impl ScheduleState {
+    /// Returns the date the plan was started.
+    pub fn start(&self) -> Date {
+        self.start
+    }
+
+    /// Returns the number of days the plan has been pushed back by
+    /// [`DevotionalSchedule::postpone_missed`].
+    pub fn postponed(&self) -> u32 {
+        self.postponed
+    }
+
+    /// Returns the zero-based days that have been read, in order.
+    pub fn completed(&self) -> impl Iterator<Item = usize> + '_ {
+        self.completed.iter().copied()
+    }
+
+    /// Serializes the state into the format read by
+    /// [`ScheduleState::from_json_str`].
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        Ok(simd_to_string_pretty(self)?)
+    }
+
+    /// Parses state saved by [`ScheduleState::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if `json` is not valid state.
+    pub fn from_json_str(json: &str) -> Result<Self, BibleError> {
+        simd_from_slice(&mut json.as_bytes().to_vec())
+            .map_err(|e| BibleError::load_error("schedule state", e))
+    }
+}
+
+/// A [`DevotionalPlan`] started on a particular date.
+///
+/// Day `n` of the plan falls `n` days after the start, plus any days the
+/// plan has been postponed. Missed days stay unread until marked, so they
+/// can either be caught up on alongside the current reading or pushed back
+/// with [`DevotionalSchedule::postpone_missed`].
+#[derive(Debug, Clone)]
+pub struct DevotionalSchedule {
+    plan: DevotionalPlan,
+    state: ScheduleState,
+}
+
+impl DevotionalSchedule {
+    /// Starts `plan` on `start`.
+    pub fn new(plan: DevotionalPlan, start: Date) -> Self {
+        Self::resume(
+            plan,
+            ScheduleState {
+                start,
+                postponed: 0,
+                completed: BTreeSet::new(),
+            },
+        )
+    }
+
+    /// Continues `plan` from saved progress. Completed days beyond the end of
+    /// the plan are dropped.
+    pub fn resume(plan: DevotionalPlan, mut state: ScheduleState) -> Self {
+        state.completed.retain(|&day| day < plan.len());
+        Self { plan, state }
+    }
+
+    /// Returns the plan.
+    pub fn plan(&self) -> &DevotionalPlan {
+        &self.plan
+    }
+
+    /// Returns the current progress, to be saved and passed to
+    /// [`DevotionalSchedule::resume`].
+    pub fn state(&self) -> &ScheduleState {
+        &self.state
+    }
+
+    /// Returns the zero-based plan day falling on `date`, or `None` before
+    /// the plan starts or after it ends.
+    pub fn day_on(&self, date: Date) -> Option<usize> {
+        let offset = date.days_since(self.first_day());
+        usize::try_from(offset)
+            .ok()
+            .filter(|&day| day < self.plan.len())
+    }
+
+    /// Returns the date on which `day` is scheduled.
+    pub fn date_of(&self, day: usize) -> Date {
+        self.first_day().add_days(day as i64)
+    }
+
+    /// Returns the passage scheduled for `date`.
+    pub fn due_on(&self, date: Date) -> Option<&ReferenceRange> {
+        self.day_on(date).map(|day| &self.plan.readings[day])
+    }
+
+    /// Looks up the passage scheduled for `date` in `bible`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bible` has none of the passage's verses.
+    pub fn passage_on<'b>(
+        &self,
+        bible: &'b Bible,
+        date: Date,
+    ) -> Result<Option<Passage<'b>>, BibleError> {
+        self.due_on(date)
+            .map(|range| bible.passage(range))
+            .transpose()
+    }
+
+    /// Returns the unread days scheduled before `date`, oldest first.
+    pub fn missed(&self, date: Date) -> Vec<usize> {
+        let elapsed = date.days_since(self.first_day()).max(0);
+        let elapsed = usize::try_from(elapsed).map_or(self.plan.len(), |d| d.min(self.plan.len()));
+        (0..elapsed).filter(|day| !self.is_read(*day)).collect()
+    }
+
+    /// Returns the days to read on `date`: every missed day followed by the
+    /// day scheduled for `date`, if it is unread.
+    pub fn readings_for(&self, date: Date) -> Vec<(usize, &ReferenceRange)> {
+        let mut days = self.missed(date);
+        days.extend(self.day_on(date).filter(|day| !self.is_read(*day)));
+        days.into_iter()
+            .map(|day| (day, &self.plan.readings[day]))
+            .collect()
+    }
+
+    /// Pushes the rest of the plan back so the oldest missed day falls on
+    /// `date`. Returns the number of days the plan moved.
+    pub fn postpone_missed(&mut self, date: Date) -> u32 {
+        let Some(&oldest) = self.missed(date).first() else {
+            return 0;
+        };
+        let shift = date.days_since(self.date_of(oldest)).max(0) as u32;
+        self.state.postponed += shift;
+        shift
+    }
+
+    /// Records `day` as read. Returns `false` if the plan has no such day.
+    pub fn mark_read(&mut self, day: usize) -> bool {
+        if day >= self.plan.len() {
+            return false;
+        }
+        self.state.completed.insert(day);
+        true
+    }
+
+    /// Records `day` as unread.
+    pub fn mark_unread(&mut self, day: usize) {
+        self.state.completed.remove(&day);
+    }
+
+    /// Returns `true` if `day` has been read.
+    pub fn is_read(&self, day: usize) -> bool {
+        self.state.completed.contains(&day)
+    }
+
+    /// Returns the number of days read.
+    pub fn completed_count(&self) -> usize {
+        self.state.completed.len()
+    }
+
+    /// Returns `true` once every day has been read.
+    pub fn is_finished(&self) -> bool {
+        self.completed_count() == self.plan.len()
+    }
+
+    fn first_day(&self) -> Date {
+        self.state.start.add_days(i64::from(self.state.postponed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan() -> DevotionalPlan {
+        [
+            "Gen.1.1-Gen.1.31",
+            "Gen.2.1-Gen.2.25",
+            "Gen.3.1-Gen.3.24",
+            "Ps.23.1-Ps.23.6",
+        ]
+        .into_iter()
+        .fold(DevotionalPlan::new("Test"), |plan, id| {
+            plan.with_reading(ReferenceRange::from_osis_id(id).unwrap())
+        })
+    }
+
+    fn date(day: u32) -> Date {
+        Date::new(2026, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_due_passages_and_catch_up() {
+        let mut schedule = DevotionalSchedule::new(plan(), date(1));
+        assert_eq!(schedule.day_on(date(1)), Some(0));
+        assert_eq!(
+            schedule.due_on(date(3)).unwrap().to_string(),
+            "Genesis 3:1-24"
+        );
+        assert!(schedule.due_on(date(5)).is_none());
+        assert!(schedule.due_on(Date::new(2025, 12, 31).unwrap()).is_none());
+
+        schedule.mark_read(0);
+        let due: Vec<usize> = schedule.readings_for(date(3)).iter().map(|r| r.0).collect();
+        assert_eq!(due, [1, 2]);
+        assert_eq!(schedule.missed(date(10)), [1, 2, 3]);
+
+        assert_eq!(schedule.postpone_missed(date(3)), 1);
+        assert_eq!(schedule.day_on(date(3)), Some(1));
+        assert_eq!(schedule.date_of(3), date(5));
+        assert!(schedule.missed(date(3)).is_empty());
+        assert!(!schedule.mark_read(4));
+    }
+
+    #[test]
+    fn test_state_and_plan_round_trip() {
+        let mut schedule = DevotionalSchedule::new(plan(), date(1));
+        schedule.mark_read(1);
+        schedule.postpone_missed(date(2));
+        let json = schedule.state().to_json().unwrap();
+        assert!(json.contains("\"2026-01-01\""));
+        let state = ScheduleState::from_json_str(&json).unwrap();
+        assert!(matches!(
+            ScheduleState::from_json_str("{\"start\": 3}"),
+            Err(BibleError::LoadError { .. })
+        ));
+        let resumed = DevotionalSchedule::resume(plan(), state);
+        assert!(resumed.is_read(1));
+        assert_eq!(resumed.state().postponed(), 1);
+        assert_eq!(resumed.day_on(date(2)), Some(0));
+
+        let plan_json = plan().with_description("Early Genesis").to_json().unwrap();
+        assert!(plan_json.contains("Ps.23.1-Ps.23.6"));
+        let path = std::env::temp_dir().join("bible_io_devotional_plan.json");
+        std::fs::write(&path, plan_json).unwrap();
+        let loaded = DevotionalPlan::from_json(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded.readings(), plan().readings());
+        assert_eq!(loaded.description(), "Early Genesis");
+    }
+}
@@ -0,0 +1,343 @@
+//! Word (DOCX) export of passages and books, behind the `docx` feature.
+//!
+//! [`Passage::to_docx`] and [`Book::to_docx`] write a minimal Office Open
+//! XML document that Word, LibreOffice and Google Docs open directly. The
+//! document defines named styles so a bulletin can be restyled in Word
+//! without touching each verse:
+//!
+//! | Style | Used for |
+//! |---|---|
+//! | `Title` | the passage citation or book title |
+//! | `Heading2` | chapter headings |
+//! | `Superscription` | Psalm titles |
+//! | `Poetry` | indented poetic lines |
+//! | `VerseNumber` | superscript verse numbers (character style) |
+//! | `SuppliedWords` | translator-supplied words, in italics (character style) |
+//!
+//! The package is written uncompressed, which keeps the exporter free of
+//! dependencies; documents are larger than Word's own but open the same.
+
+use crate::{
+    bible::TextDirection,
+    book::Book,
+    chapter::{Chapter, ParagraphKind},
+    passage::Passage,
+    reference::CitationStyle,
+    render::paragraph_groups,
+    verse::{SpanKind, Verse},
+};
+
+impl Passage<'_> {
+    /// Renders the passage as a DOCX document titled with its citation,
+    /// with a heading at each chapter. See the
+    /// [module documentation](crate::docx).
+    pub fn to_docx(&self) -> Vec<u8> {
+        let direction = self.bible().direction();
+        let mut body = String::new();
+        push_paragraph(
+            &mut body,
+            "Title",
+            direction,
+            &text_run(&self.range().to_citation(CitationStyle::FullName), None),
+        );
+        for chapter in self.split_at_chapter() {
+            let first = chapter.verses()[0];
+            let book = self.bible().get_book(first.book()).ok();
+            let title = book.map_or_else(|| first.book().to_string(), |b| b.title().to_string());
+            push_paragraph(
+                &mut body,
+                "Heading2",
+                direction,
+                &text_run(&format!("{} {}", title, first.chapter()), None),
+            );
+            // A Psalm title belongs before verse 1, so it is shown only
+            // when the passage includes the start of the chapter.
+            let superscription = book
+                .and_then(|b| b.get_chapter(first.chapter()).ok())
+                .and_then(Chapter::superscription)
+                .filter(|_| first.number() == 1);
+            if let Some(superscription) = superscription {
+                push_paragraph(
+                    &mut body,
+                    "Superscription",
+                    direction,
+                    &text_run(superscription, None),
+                );
+            }
+            let runs: String = chapter.verses().iter().map(|v| verse_runs(v)).collect();
+            push_paragraph(&mut body, "Normal", direction, &runs);
+        }
+        package(&body)
+    }
+}
+
+impl Book {
+    /// Renders the book as a DOCX document with a heading per chapter and
+    /// paragraphs following the chapter's paragraph data, laid out for
+    /// `direction`. See the [module documentation](crate::docx).
+    pub fn to_docx(&self, direction: TextDirection) -> Vec<u8> {
+        let mut body = String::new();
+        push_paragraph(&mut body, "Title", direction, &text_run(self.title(), None));
+        for chapter in self.chapters() {
+            push_chapter(&mut body, chapter, direction);
+        }
+        package(&body)
+    }
+}
+
+fn push_chapter(body: &mut String, chapter: &Chapter, direction: TextDirection) {
+    push_paragraph(
+        body,
+        "Heading2",
+        direction,
+        &text_run(&format!("Chapter {}", chapter.number()), None),
+    );
+    if let Some(superscription) = chapter.superscription() {
+        push_paragraph(
+            body,
+            "Superscription",
+            direction,
+            &text_run(superscription, None),
+        );
+    }
+    for (paragraph, verses) in paragraph_groups(chapter) {
+        let runs: String = verses.iter().map(verse_runs).collect();
+        match paragraph {
+            Some(p) if p.kind() == ParagraphKind::Poetry => {
+                // Each poetic indent level widens the indent by a quarter inch.
+                let indent = 360 * u32::from(p.indent().max(1));
+                body.push_str(&format!(
+                    "<w:p><w:pPr><w:pStyle w:val=\"Poetry\"/><w:ind w:left=\"{indent}\"/>{}</w:pPr>{runs}</w:p>",
+                    bidi(direction)
+                ));
+            }
+            _ => push_paragraph(body, "Normal", direction, &runs),
+        }
+    }
+}
+
+fn push_paragraph(body: &mut String, style: &str, direction: TextDirection, runs: &str) {
+    body.push_str(&format!(
+        "<w:p><w:pPr><w:pStyle w:val=\"{style}\"/>{}</w:pPr>{runs}</w:p>",
+        bidi(direction)
+    ));
+}
+
+fn bidi(direction: TextDirection) -> &'static str {
+    match direction {
+        TextDirection::Rtl => "<w:bidi/>",
+        TextDirection::Ltr => "",
+    }
+}
+
+/// The verse number followed by the verse text, with supplied words styled.
+fn verse_runs(verse: &Verse) -> String {
+    let mut runs = text_run(&verse.number_label(), Some("VerseNumber"));
+    runs.push_str(&text_run(" ", None));
+    for span in verse.spans() {
+        let style = match span.kind {
+            SpanKind::Plain => None,
+            SpanKind::SuppliedWords => Some("SuppliedWords"),
+        };
+        runs.push_str(&text_run(span.text, style));
+    }
+    runs.push_str(&text_run(" ", None));
+    runs
+}
+
+fn text_run(text: &str, style: Option<&str>) -> String {
+    let properties = style
+        .map(|style| format!("<w:rPr><w:rStyle w:val=\"{style}\"/></w:rPr>"))
+        .unwrap_or_default();
+    format!(
+        "<w:r>{properties}<w:t xml:space=\"preserve\">{}</w:t></w:r>",
+        xml_escaped(text)
+    )
+}
+
+fn xml_escaped(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            // Control characters are not allowed in XML 1.0.
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/><Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/></Types>"#;
+
+const ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+const DOCUMENT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>"#;
+
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/><w:pPr><w:spacing w:after="160" w:line="276" w:lineRule="auto"/></w:pPr><w:rPr><w:sz w:val="22"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:pPr><w:spacing w:after="240"/></w:pPr><w:rPr><w:b/><w:sz w:val="40"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Heading2"><w:name w:val="heading 2"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="240" w:after="120"/><w:outlineLvl w:val="1"/></w:pPr><w:rPr><w:b/><w:sz w:val="28"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Superscription"><w:name w:val="Superscription"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:rPr><w:i/><w:sz w:val="20"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Poetry"><w:name w:val="Poetry"/><w:basedOn w:val="Normal"/><w:pPr><w:spacing w:after="0"/><w:ind w:left="360"/></w:pPr></w:style><w:style w:type="character" w:styleId="VerseNumber"><w:name w:val="Verse Number"/><w:rPr><w:b/><w:vertAlign w:val="superscript"/></w:rPr></w:style><w:style w:type="character" w:styleId="SuppliedWords"><w:name w:val="Supplied Words"/><w:rPr><w:i/></w:rPr></w:style></w:styles>"#;
+
+fn package(body: &str) -> Vec<u8> {
+    let document = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+         <w:document xmlns:w=\"http://schemas.openxmlformats.org/wordprocessingml/2006/main\">\
+         <w:body>{body}<w:sectPr><w:pgSz w:w=\"11906\" w:h=\"16838\"/>\
+         <w:pgMar w:top=\"1440\" w:right=\"1440\" w:bottom=\"1440\" w:left=\"1440\"/></w:sectPr>\
+         </w:body></w:document>"
+    );
+    zip(&[
+        ("[Content_Types].xml", CONTENT_TYPES.as_bytes()),
+        ("_rels/.rels", ROOT_RELS.as_bytes()),
+        ("word/_rels/document.xml.rels", DOCUMENT_RELS.as_bytes()),
+        ("word/styles.xml", STYLES.as_bytes()),
+        ("word/document.xml", document.as_bytes()),
+    ])
+}
+
+/// Writes a ZIP archive with each file stored uncompressed.
+fn zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+    // 1980-01-01 00:00, the earliest DOS date, keeps output reproducible.
+    const DOS_DATE: u16 = (1 << 5) | 1;
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    for (name, data) in files {
+        let offset = out.len() as u32;
+        let crc = crc32(data);
+        let size = data.len() as u32;
+        let name_len = name.len() as u16;
+
+        out.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        for field in [20u16, 0, 0, 0, DOS_DATE] {
+            out.extend_from_slice(&field.to_le_bytes());
+        }
+        for field in [crc, size, size] {
+            out.extend_from_slice(&field.to_le_bytes());
+        }
+        out.extend_from_slice(&name_len.to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(data);
+
+        central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        for field in [20u16, 20, 0, 0, 0, DOS_DATE] {
+            central.extend_from_slice(&field.to_le_bytes());
+        }
+        for field in [crc, size, size] {
+            central.extend_from_slice(&field.to_le_bytes());
+        }
+        for field in [name_len, 0, 0, 0, 0] {
+            central.extend_from_slice(&field.to_le_bytes());
+        }
+        central.extend_from_slice(&0u32.to_le_bytes());
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+    let central_offset = out.len() as u32;
+    let central_size = central.len() as u32;
+    out.extend_from_slice(&central);
+    out.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    let count = files.len() as u16;
+    for field in [0u16, 0, count, count] {
+        out.extend_from_slice(&field.to_le_bytes());
+    }
+    out.extend_from_slice(&central_size.to_le_bytes());
+    out.extend_from_slice(&central_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out
+}
+
+/// The CRC-32 (IEEE) checksum ZIP archives use.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook,
+        reference::{Reference, ReferenceRange},
+        test_support::TestBible,
+    };
+
+    /// Returns the stored files of an archive written by [`zip`].
+    fn unzip(archive: &[u8]) -> Vec<(String, String)> {
+        let read_u16 = |at: usize| u16::from_le_bytes([archive[at], archive[at + 1]]) as usize;
+        let read_u32 =
+            |at: usize| u32::from_le_bytes(archive[at..at + 4].try_into().unwrap()) as usize;
+        let mut files = Vec::new();
+        let mut at = 0;
+        while read_u32(at) == 0x0403_4b50 {
+            let (crc, size, name_len) = (read_u32(at + 14), read_u32(at + 18), read_u16(at + 26));
+            let name = &archive[at + 30..at + 30 + name_len];
+            let data = &archive[at + 30 + name_len..at + 30 + name_len + size];
+            assert_eq!(crc32(data) as usize, crc);
+            files.push((
+                String::from_utf8(name.to_vec()).unwrap(),
+                String::from_utf8(data.to_vec()).unwrap(),
+            ));
+            at += 30 + name_len + size;
+        }
+        assert_eq!(read_u32(archive.len() - 22), 0x0605_4b50);
+        assert_eq!(read_u32(archive.len() - 6), at);
+        files
+    }
+
+    #[test]
+    fn test_passage_docx() {
+        let verses = vec![
+            Verse::new(
+                BibleBook::John,
+                3,
+                16,
+                "For God so loved {the} world".into(),
+            ),
+            Verse::new(BibleBook::John, 3, 17, "For God sent not <his> Son".into()),
+        ];
+        let book = Book::new("jo".into(), "John".into(), vec![Chapter::new(verses, 3)]);
+        let bible = TestBible::new(vec![book]).build();
+        let passage = bible
+            .passage(&ReferenceRange::new(
+                Reference::new(BibleBook::John, 3, 16),
+                Reference::new(BibleBook::John, 3, 17),
+            ))
+            .unwrap();
+
+        let files = unzip(&passage.to_docx());
+        let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names[0], "[Content_Types].xml");
+        assert!(names.contains(&"word/styles.xml"));
+        let document = &files
+            .iter()
+            .find(|(n, _)| n == "word/document.xml")
+            .unwrap()
+            .1;
+        assert!(document.contains("John 3:16–17"));
+        assert!(document.contains(
+            "<w:rStyle w:val=\"VerseNumber\"/></w:rPr><w:t xml:space=\"preserve\">16</w:t>"
+        ));
+        assert!(document.contains(
+            "<w:rStyle w:val=\"SuppliedWords\"/></w:rPr><w:t xml:space=\"preserve\">the</w:t>"
+        ));
+        assert!(document.contains("not &lt;his&gt; Son"));
+        assert!(!document.contains("<w:bidi/>"));
+    }
+
+    #[test]
+    fn test_crc32_reference_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+}
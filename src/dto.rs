@@ -0,0 +1,188 @@
+//! Owned, serializable response types for JSON APIs.
+//!
+//! The types here form a stable wire format: they own their data, derive
+//! both `Serialize` and `Deserialize` so clients can share them, and change
+//! only in a major release, however the crate's internal types evolve.
+//! Fields may be added in minor releases, so clients should ignore unknown
+//! fields (serde's default).
+//!
+//! | Type | JSON |
+//! |---|---|
+//! | [`VerseDto`] | `{"reference":"John 3:16","book":"jo","chapter":3,"verse":16,"text":"..."}` |
+//! | [`PassageDto`] | `{"reference":"John 3:16-17","translation":"kjv","verses":[...]}` |
+//! | [`SearchResponseDto`] | `{"query":"light","translation":"kjv","pagination":{...},"results":[...]}` |
+
+use serde::{Deserialize, Serialize};
+
+use crate::{passage::Passage, search_results::SearchResults, verse::Verse};
+
+/// One verse. A verse bridge also carries `"last_verse"`, the last verse it
+/// combines.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct VerseDto {
+    pub reference: String,
+    pub book: String,
+    pub chapter: usize,
+    pub verse: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_verse: Option<usize>,
+    pub text: String,
+}
+
+impl From<&Verse> for VerseDto {
+    fn from(verse: &Verse) -> Self {
+        VerseDto {
+            reference: verse.reference().to_string(),
+            book: verse.book().as_str().to_string(),
+            chapter: verse.chapter(),
+            verse: verse.number(),
+            last_verse: verse.is_bridge().then(|| verse.last_number()),
+            text: verse.text().trim().to_string(),
+        }
+    }
+}
+
+/// A passage, with the id of the translation it was taken from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PassageDto {
+    pub reference: String,
+    pub translation: String,
+    pub verses: Vec<VerseDto>,
+}
+
+impl From<&Passage<'_>> for PassageDto {
+    fn from(passage: &Passage<'_>) -> Self {
+        PassageDto {
+            reference: passage.range().to_string(),
+            translation: passage.bible().id().to_string(),
+            verses: passage
+                .verses()
+                .iter()
+                .map(|verse| VerseDto::from(*verse))
+                .collect(),
+        }
+    }
+}
+
+/// Where a page of results falls among all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Pagination {
+    /// The position of the first result on this page.
+    pub offset: usize,
+    /// The most results a page holds.
+    pub limit: usize,
+    /// The number of results across all pages.
+    pub total: usize,
+    /// The offset of the next page, or `None` on the last page.
+    #[serde(default)]
+    pub next_offset: Option<usize>,
+}
+
+impl Pagination {
+    /// Describes the page of up to `limit` results starting at `offset`, out
+    /// of `total`. A page with a `limit` of 0 has no next page, since paging
+    /// by it would never advance.
+    pub fn new(offset: usize, limit: usize, total: usize) -> Self {
+        let end = offset.saturating_add(limit);
+        Pagination {
+            offset,
+            limit,
+            total,
+            next_offset: (limit > 0 && end < total).then_some(end),
+        }
+    }
+}
+
+/// One page of search results.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SearchResponseDto {
+    pub query: String,
+    pub translation: String,
+    pub pagination: Pagination,
+    pub results: Vec<VerseDto>,
+}
+
+impl SearchResponseDto {
+    /// Takes the page of up to `limit` hits starting at `offset` from
+    /// `results`, the hits of `query`.
+    pub fn from_results(
+        query: &str,
+        results: &SearchResults<'_>,
+        offset: usize,
+        limit: usize,
+    ) -> Self {
+        SearchResponseDto {
+            query: query.to_string(),
+            translation: results.bible().id().to_string(),
+            pagination: Pagination::new(offset, limit, results.len()),
+            results: results
+                .hits()
+                .iter()
+                .skip(offset)
+                .take(limit)
+                .map(|verse| VerseDto::from(*verse))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+    };
+
+    #[test]
+    fn test_dto_round_trip() {
+        let verse = Verse::new(BibleBook::John, 11, 35, "Jesus wept. ".into()).with_bridge_end(36);
+        let dto = VerseDto::from(&verse);
+        let json = simd_json::serde::to_string(&dto).unwrap();
+        assert_eq!(
+            json,
+            "{\"reference\":\"John 11:35\",\"book\":\"jo\",\"chapter\":11,\"verse\":35,\"last_verse\":36,\"text\":\"Jesus wept.\"}"
+        );
+        let mut bytes = json.into_bytes();
+        assert_eq!(
+            simd_json::serde::from_slice::<VerseDto>(&mut bytes).unwrap(),
+            dto
+        );
+
+        // Fields added in later releases are ignored.
+        let mut newer = b"{\"reference\":\"John 11:35\",\"book\":\"jo\",\"chapter\":11,\
+            \"verse\":35,\"text\":\"Jesus wept.\",\"audio\":\"x.mp3\"}"
+            .to_vec();
+        assert!(simd_json::serde::from_slice::<VerseDto>(&mut newer).is_ok());
+    }
+
+    #[test]
+    fn test_search_pagination() {
+        let verses = (1..=5)
+            .map(|v| Verse::new(BibleBook::Genesis, 1, v, format!("light {v}")))
+            .collect();
+        let bible = TestBible::new(vec![Book::new(
+            "gn".into(),
+            "Genesis".into(),
+            vec![Chapter::new(verses, 1)],
+        )])
+        .with_id("kjv")
+        .build();
+        let index = bible.build_search_index();
+        let results = bible.search_results(&index, "light");
+
+        let page = SearchResponseDto::from_results("light", &results, 2, 2);
+        assert_eq!(page.translation, "kjv");
+        assert_eq!(page.pagination, Pagination::new(2, 2, 5));
+        assert_eq!(page.pagination.next_offset, Some(4));
+        let verses: Vec<usize> = page.results.iter().map(|v| v.verse).collect();
+        assert_eq!(verses, [3, 4]);
+
+        let last = SearchResponseDto::from_results("light", &results, 4, 2);
+        assert_eq!(last.results.len(), 1);
+        assert_eq!(last.pagination.next_offset, None);
+
+        let empty = SearchResponseDto::from_results("light", &results, 0, 0);
+        assert!(empty.results.is_empty());
+        assert_eq!(empty.pagination.next_offset, None);
+    }
+}
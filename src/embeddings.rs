@@ -0,0 +1,243 @@
+//! Semantic (vector) search over verses.
+//!
+//! An [`EmbeddingProvider`] turns text into vectors; a [`SemanticIndex`] stores
+//! one vector per verse and answers cosine-similarity queries. This sits beside
+//! the keyword [`SearchIndex`](crate::SearchIndex) rather than replacing it.
+//!
+//! Providers backed by external models are left to downstream crates. The
+//! `hashing-embedder` feature enables [`HashingEmbedder`], a dependency-free
+//! bag-of-words provider useful for tests and offline prototyping.
+
+use std::{error::Error, fs};
+
+use serde::{Deserialize, Serialize};
+use simd_json::serde::{from_slice as simd_from_slice, to_string as simd_to_string};
+
+use crate::{bible::Bible, book_id::BookId, reference::Reference};
+
+/// Produces fixed-size embedding vectors for text.
+pub trait EmbeddingProvider {
+    /// Returns the length of the vectors produced by [`embed`](Self::embed).
+    fn dimensions(&self) -> usize;
+
+    /// Embeds a single piece of text.
+    fn embed(&self, text: &str) -> Vec<f32>;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct SemanticEntry {
+    book: String,
+    chapter: usize,
+    verse: usize,
+    vector: Vec<f32>,
+}
+
+/// Per-verse embedding vectors with cosine-similarity search.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SemanticIndex {
+    dimensions: usize,
+    entries: Vec<SemanticEntry>,
+}
+
+impl SemanticIndex {
+    /// Embeds every verse of `bible` with `provider`.
+    pub fn build(bible: &Bible, provider: &dyn EmbeddingProvider) -> Self {
+        let entries = bible
+            .books()
+            .iter()
+            .flat_map(|b| b.chapters())
+            .flat_map(|c| c.get_verses())
+            .map(|verse| SemanticEntry {
+                book: verse.book().as_str().to_string(),
+                chapter: verse.chapter(),
+                verse: verse.number(),
+                vector: normalize(provider.embed(verse.text())),
+            })
+            .collect();
+
+        SemanticIndex {
+            dimensions: provider.dimensions(),
+            entries,
+        }
+    }
+
+    /// Returns the dimensionality of the stored vectors.
+    pub fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    /// Returns the number of indexed verses.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no verses are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the `k` verses most similar to `query`, best match first,
+    /// paired with their cosine similarity.
+    pub fn search(&self, query: &[f32], k: usize) -> Vec<(Reference, f32)> {
+        let query = normalize(query.to_vec());
+        let mut scored: Vec<(usize, f32)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (i, dot(&query, &e.vector)))
+            .collect();
+
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        scored
+            .into_iter()
+            .take(k)
+            .map(|(i, score)| {
+                let e = &self.entries[i];
+                (
+                    Reference::new(BookId::from_abbrev(&e.book), e.chapter, e.verse),
+                    score,
+                )
+            })
+            .collect()
+    }
+
+    /// Embeds `query` with `provider` and returns the `k` most similar verses.
+    pub fn search_text(
+        &self,
+        provider: &dyn EmbeddingProvider,
+        query: &str,
+        k: usize,
+    ) -> Vec<(Reference, f32)> {
+        self.search(&provider.embed(query), k)
+    }
+
+    /// Writes the index as JSON to the given path.
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        fs::write(path, simd_to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Reads an index previously written by [`SemanticIndex::save`].
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        let mut content = fs::read(path)?;
+        Ok(simd_from_slice(&mut content)?)
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn normalize(mut vector: Vec<f32>) -> Vec<f32> {
+    let norm = dot(&vector, &vector).sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|x| *x /= norm);
+    }
+    vector
+}
+
+/// A bag-of-words provider that hashes each search term into a fixed number of buckets.
+///
+/// It captures shared vocabulary only, not meaning, but needs no model files.
+#[cfg(feature = "hashing-embedder")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashingEmbedder {
+    dimensions: usize,
+}
+
+#[cfg(feature = "hashing-embedder")]
+impl HashingEmbedder {
+    /// Creates an embedder producing vectors of `dimensions` buckets.
+    pub fn new(dimensions: usize) -> Self {
+        HashingEmbedder {
+            dimensions: dimensions.max(1),
+        }
+    }
+}
+
+#[cfg(feature = "hashing-embedder")]
+impl EmbeddingProvider for HashingEmbedder {
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn embed(&self, text: &str) -> Vec<f32> {
+        use std::hash::{Hash, Hasher};
+
+        let mut vector = vec![0.0; self.dimensions];
+        for term in crate::search_index::SearchIndex::tokenize(text) {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            term.hash(&mut hasher);
+            vector[(hasher.finish() % self.dimensions as u64) as usize] += 1.0;
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    /// Embeds text by counting two marker words.
+    struct MarkerEmbedder;
+
+    impl EmbeddingProvider for MarkerEmbedder {
+        fn dimensions(&self) -> usize {
+            2
+        }
+
+        fn embed(&self, text: &str) -> Vec<f32> {
+            vec![
+                text.matches("fear").count() as f32,
+                text.matches("joy").count() as f32,
+            ]
+        }
+    }
+
+    fn create_test_index() -> SemanticIndex {
+        let entry = |verse, vector: Vec<f32>| SemanticEntry {
+            book: "ps".to_string(),
+            chapter: 1,
+            verse,
+            vector: normalize(vector),
+        };
+        SemanticIndex {
+            dimensions: 2,
+            entries: vec![entry(1, vec![1.0, 0.0]), entry(2, vec![0.2, 1.0])],
+        }
+    }
+
+    #[test]
+    fn test_cosine_search_orders_by_similarity() {
+        let index = create_test_index();
+        let results = index.search_text(&MarkerEmbedder, "joy and joy", 2);
+
+        assert_eq!(results[0].0, Reference::new(BibleBook::Psalms, 1, 2));
+        assert!(results[0].1 > results[1].1);
+        assert_eq!(index.search(&[1.0, 0.0], 1).len(), 1);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let index = create_test_index();
+        let path = std::env::temp_dir().join("bible_io_semantic_index_test.json");
+        let path = path.to_str().unwrap();
+
+        index.save(path).unwrap();
+        let loaded = SemanticIndex::load(path).unwrap();
+        fs::remove_file(path).ok();
+
+        assert_eq!(loaded, index);
+    }
+
+    #[cfg(feature = "hashing-embedder")]
+    #[test]
+    fn test_hashing_embedder_is_deterministic() {
+        let embedder = HashingEmbedder::new(64);
+        let a = embedder.embed("The LORD is my shepherd");
+        assert_eq!(a.len(), 64);
+        assert_eq!(a, embedder.embed("the lord is my SHEPHERD"));
+        assert_eq!(a.iter().sum::<f32>(), 5.0);
+    }
+}
@@ -0,0 +1,304 @@
+//! People and places mentioned in the text, for map and genealogy features.
+//!
+//! An [`EntitySet`] lists named entities with the verses that mention them
+//! and is stored as JSON:
+//!
+//! ```json
+//! { "entities": [{ "name": "Bethlehem", "kind": "place", "aliases": ["Ephratah"],
+//!                  "references": ["Genesis 35:19", "Micah 5:2"] }] }
+//! ```
+//!
+//! Once attached with [`Bible::attach_entities`], entities can be found by
+//! verse with [`Bible::entities_in`] and by name with
+//! [`Bible::references_for_entity`].
+
+use std::{collections::HashMap, error::Error, fs};
+
+use serde::{Deserialize, Serialize};
+use simd_json::serde::{from_slice as simd_from_slice, to_string_pretty as simd_to_string_pretty};
+
+use crate::{
+    bible::{Bible, BibleError},
+    reference::Reference,
+};
+
+/// What kind of thing an entity is.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum EntityKind {
+    Person,
+    Place,
+    /// Groups, objects, and anything else with a name.
+    #[default]
+    Other,
+}
+
+/// A named person, place, or other entity with the verses that mention it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entity {
+    name: String,
+    kind: EntityKind,
+    aliases: Vec<String>,
+    references: Vec<Reference>,
+}
+
+impl Entity {
+    /// Returns the entity's primary name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns whether the entity is a person, place, or other.
+    pub fn kind(&self) -> EntityKind {
+        self.kind
+    }
+
+    /// Returns other names for the entity, such as "Ephratah" for Bethlehem.
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    /// Returns the verses mentioning the entity, in canonical order.
+    pub fn references(&self) -> &[Reference] {
+        &self.references
+    }
+}
+
+/// An entity as stored in data files, with references written as for
+/// [`Bible::get_verse_by_reference`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+struct EntityRecord {
+    name: String,
+    #[serde(default)]
+    kind: EntityKind,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+    references: Vec<String>,
+}
+
+/// A dataset of named entities, attached with [`Bible::attach_entities`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntitySet {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    description: String,
+    entities: Vec<EntityRecord>,
+}
+
+impl EntitySet {
+    /// Creates an empty entity set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a description of the dataset, such as its source and license.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Adds an entity mentioned at `references`.
+    pub fn with_entity<R: Into<String>>(
+        mut self,
+        name: impl Into<String>,
+        kind: EntityKind,
+        references: impl IntoIterator<Item = R>,
+    ) -> Self {
+        self.entities.push(EntityRecord {
+            name: name.into(),
+            kind,
+            aliases: Vec::new(),
+            references: references.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Adds an alias to the most recently added entity.
+    pub fn with_alias(mut self, alias: impl Into<String>) -> Self {
+        if let Some(entity) = self.entities.last_mut() {
+            entity.aliases.push(alias.into());
+        }
+        self
+    }
+
+    /// Returns the description.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the number of entities.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if the set has no entities.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Reads an entity set from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or parsed.
+    pub fn from_json(json_path: &str) -> Result<Self, BibleError> {
+        let mut file_content =
+            fs::read(json_path).map_err(|e| BibleError::load_error(json_path, e))?;
+        simd_from_slice(&mut file_content).map_err(|e| BibleError::load_error(json_path, e))
+    }
+
+    /// Serializes the set into the format read by [`EntitySet::from_json`].
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        Ok(simd_to_string_pretty(self)?)
+    }
+}
+
+/// Entities attached to a [`Bible`], indexed by verse and by name.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EntityIndex {
+    entities: Vec<Entity>,
+    by_reference: HashMap<Reference, Vec<usize>>,
+    by_name: HashMap<String, usize>,
+}
+
+impl EntityIndex {
+    fn add(&mut self, entity: Entity) {
+        let slot = self.entities.len();
+        for reference in &entity.references {
+            let slots = self.by_reference.entry(reference.clone()).or_default();
+            if !slots.contains(&slot) {
+                slots.push(slot);
+            }
+        }
+        for name in std::iter::once(&entity.name).chain(&entity.aliases) {
+            self.by_name.entry(name.to_lowercase()).or_insert(slot);
+        }
+        self.entities.push(entity);
+    }
+}
+
+impl Bible {
+    /// Attaches a dataset of people and places, adding to any attached before.
+    ///
+    /// References to verses this Bible does not contain are skipped, so one
+    /// dataset serves partial translations and [subsets](Bible::subset).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::InvalidReference`] if a reference cannot be
+    /// parsed; no entities are attached in that case.
+    pub fn attach_entities(&mut self, set: &EntitySet) -> Result<(), BibleError> {
+        let mut resolved = Vec::with_capacity(set.entities.len());
+        for record in &set.entities {
+            let mut references = Vec::with_capacity(record.references.len());
+            for reference in &record.references {
+                match self.get_verse_by_reference(reference) {
+                    Ok(verse) => references.push(verse.reference()),
+                    Err(e @ BibleError::InvalidReference { .. }) => return Err(e),
+                    Err(_) => {}
+                }
+            }
+            references.sort();
+            references.dedup();
+            resolved.push(Entity {
+                name: record.name.clone(),
+                kind: record.kind,
+                aliases: record.aliases.clone(),
+                references,
+            });
+        }
+
+        for entity in resolved {
+            self.entities.add(entity);
+        }
+        Ok(())
+    }
+
+    /// Returns the attached entities, in the order they were attached.
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities.entities
+    }
+
+    /// Returns the entities mentioned in the verse at `reference`.
+    pub fn entities_in(&self, reference: &Reference) -> Vec<&Entity> {
+        self.entities
+            .by_reference
+            .get(reference)
+            .map(|slots| slots.iter().map(|&s| &self.entities.entities[s]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the entity with the given name or alias, ignoring case.
+    pub fn entity(&self, name: &str) -> Option<&Entity> {
+        let slot = self.entities.by_name.get(&name.trim().to_lowercase())?;
+        self.entities.entities.get(*slot)
+    }
+
+    /// Returns the verses mentioning the entity with the given name or
+    /// alias, or an empty slice if no such entity is attached.
+    pub fn references_for_entity(&self, name: &str) -> &[Reference] {
+        self.entity(name).map_or(&[], Entity::references)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+        verse::Verse,
+    };
+
+    fn create_test_bible() -> Bible {
+        let micah: Vec<Verse> = (1..=2)
+            .map(|v| Verse::new(BibleBook::Micah, 5, v, format!("Micah 5:{}", v)))
+            .collect();
+        let chapters = (1..=5)
+            .map(|n| Chapter::new(if n == 5 { micah.clone() } else { Vec::new() }, n))
+            .collect();
+        TestBible::new(vec![Book::new("mi".into(), "Micah".into(), chapters)]).build()
+    }
+
+    #[test]
+    fn test_attach_and_query_entities() {
+        let set = EntitySet::new()
+            .with_entity(
+                "Bethlehem",
+                EntityKind::Place,
+                ["Micah 5:2", "Mic 5:2", "Genesis 35:19"],
+            )
+            .with_alias("Ephratah")
+            .with_entity("Israel", EntityKind::Other, ["Micah 5:1", "Micah 5:2"]);
+        let mut bible = create_test_bible();
+        bible.attach_entities(&set).unwrap();
+
+        let micah_5_2 = Reference::new(BibleBook::Micah, 5, 2);
+        let names: Vec<&str> = bible
+            .entities_in(&micah_5_2)
+            .iter()
+            .map(|e| e.name())
+            .collect();
+        assert_eq!(names, ["Bethlehem", "Israel"]);
+        assert_eq!(bible.references_for_entity("ephratah"), [micah_5_2]);
+        assert_eq!(bible.entity("Bethlehem").unwrap().kind(), EntityKind::Place);
+        assert!(bible.references_for_entity("Nineveh").is_empty());
+
+        let invalid = EntitySet::new().with_entity("Zion", EntityKind::Place, ["Micah"]);
+        assert!(bible.attach_entities(&invalid).is_err());
+        assert_eq!(bible.entities().len(), 2);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let set = EntitySet::new().with_description("Places").with_entity(
+            "Bethlehem",
+            EntityKind::Place,
+            ["Micah 5:2"],
+        );
+        let path = std::env::temp_dir().join("bible_io_entities.json");
+        fs::write(&path, set.to_json().unwrap()).unwrap();
+        let loaded = EntitySet::from_json(path.to_str().unwrap()).unwrap();
+        fs::remove_file(&path).ok();
+        assert_eq!(loaded, set);
+        assert!(set.to_json().unwrap().contains("\"place\""));
+    }
+}
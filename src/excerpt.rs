@@ -0,0 +1,304 @@
+//! Quotation limits for redistributing restricted translations.
+//!
+//! Publishers commonly allow quoting a translation without permission up to a
+//! number of verses, provided no more than a share of any one book is quoted
+//! and a copyright notice accompanies the text. [`ExcerptPolicy`] describes
+//! such terms and [`Passage::excerpt_within_limits`] applies them.
+
+use std::fmt;
+
+use crate::{book_id::BookId, passage::Passage, verse::Verse};
+
+/// Quotation terms for a translation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExcerptPolicy {
+    max_verses: Option<usize>,
+    max_book_percent: Option<f64>,
+    citation: Option<String>,
+}
+
+impl ExcerptPolicy {
+    /// Creates a policy without limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits an excerpt to `max_verses` verses.
+    pub fn with_max_verses(mut self, max_verses: usize) -> Self {
+        self.max_verses = Some(max_verses);
+        self
+    }
+
+    /// Limits an excerpt to `percent` percent of the verses of any one book.
+    pub fn with_max_book_percent(mut self, percent: f64) -> Self {
+        self.max_book_percent = Some(percent);
+        self
+    }
+
+    /// Sets the notice that must accompany every excerpt. Without one, the
+    /// Bible's [`copyright`](crate::Bible::copyright) is used.
+    pub fn with_citation(mut self, citation: impl Into<String>) -> Self {
+        self.citation = Some(citation.into());
+        self
+    }
+
+    /// Returns the verse limit.
+    pub fn max_verses(&self) -> Option<usize> {
+        self.max_verses
+    }
+
+    /// Returns the per-book limit in percent.
+    pub fn max_book_percent(&self) -> Option<f64> {
+        self.max_book_percent
+    }
+
+    /// Returns the required notice, if set.
+    pub fn citation(&self) -> Option<&str> {
+        self.citation.as_deref()
+    }
+}
+
+/// A limit of an [`ExcerptPolicy`] that a passage exceeded.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ExcerptViolation {
+    /// The passage has more verses than allowed.
+    TooManyVerses { limit: usize, requested: usize },
+    /// The passage quotes more of `book` than allowed.
+    BookShareExceeded {
+        book: BookId,
+        limit_percent: f64,
+        requested_percent: f64,
+    },
+}
+
+impl fmt::Display for ExcerptViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExcerptViolation::TooManyVerses { limit, requested } => {
+                write!(f, "{requested} verses requested; at most {limit} allowed")
+            }
+            ExcerptViolation::BookShareExceeded {
+                book,
+                limit_percent,
+                requested_percent,
+            } => write!(
+                f,
+                "{requested_percent:.1}% of {book} requested; at most {limit_percent}% allowed"
+            ),
+        }
+    }
+}
+
+/// The part of a passage that may be quoted under an [`ExcerptPolicy`].
+#[derive(Debug, Clone)]
+pub struct Excerpt<'a> {
+    verses: Vec<&'a Verse>,
+    citation: Option<String>,
+    violations: Vec<ExcerptViolation>,
+}
+
+impl<'a> Excerpt<'a> {
+    /// Returns the verses allowed, from the start of the passage.
+    pub fn verses(&self) -> &[&'a Verse] {
+        &self.verses
+    }
+
+    /// Returns the notice to show with the excerpt.
+    pub fn citation(&self) -> Option<&str> {
+        self.citation.as_deref()
+    }
+
+    /// Returns the limits the full passage exceeded.
+    pub fn violations(&self) -> &[ExcerptViolation] {
+        &self.violations
+    }
+
+    /// Returns `true` if the whole passage could be quoted.
+    pub fn is_complete(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns the verse texts joined by single spaces, followed by the
+    /// citation in parentheses.
+    pub fn text(&self) -> String {
+        let text = self
+            .verses
+            .iter()
+            .map(|verse| verse.text().trim())
+            .collect::<Vec<_>>()
+            .join(" ");
+        match &self.citation {
+            Some(citation) => format!("{text} ({citation})"),
+            None => text,
+        }
+    }
+}
+
+impl<'a> Passage<'a> {
+    /// Trims the passage to what `policy` allows and reports every limit the
+    /// full passage exceeds.
+    ///
+    /// Verses are kept from the start of the passage until a limit is
+    /// reached; book shares are measured against the verse count of each
+    /// book in this Bible.
+    pub fn excerpt_within_limits(&self, policy: &ExcerptPolicy) -> Excerpt<'a> {
+        let mut violations = Vec::new();
+        if let Some(limit) = policy.max_verses.filter(|&limit| self.len_verses() > limit) {
+            violations.push(ExcerptViolation::TooManyVerses {
+                limit,
+                requested: self.len_verses(),
+            });
+        }
+
+        // Verse counts per book, in passage order: (book, quoted, allowed).
+        let mut books: Vec<(&BookId, usize, usize)> = Vec::new();
+        for verse in self.verses() {
+            match books.last_mut() {
+                Some((book, quoted, _)) if *book == verse.book() => *quoted += 1,
+                _ => books.push((verse.book(), 1, usize::MAX)),
+            }
+        }
+        if let Some(limit_percent) = policy.max_book_percent {
+            for (book, quoted, allowed) in &mut books {
+                let total = self.bible().get_book(*book).map_or(*quoted, |book| {
+                    book.chapters()
+                        .iter()
+                        .map(|chapter| chapter.get_verses().len())
+                        .sum()
+                });
+                *allowed = (total as f64 * limit_percent / 100.0).floor() as usize;
+                let requested_percent = *quoted as f64 * 100.0 / total.max(1) as f64;
+                if requested_percent > limit_percent {
+                    violations.push(ExcerptViolation::BookShareExceeded {
+                        book: (*book).clone(),
+                        limit_percent,
+                        requested_percent,
+                    });
+                }
+            }
+        }
+
+        let max_verses = policy.max_verses.unwrap_or(usize::MAX);
+        let mut verses = Vec::new();
+        let mut in_book = 0;
+        for verse in self.verses() {
+            if verses.len() == max_verses {
+                break;
+            }
+            if verses
+                .last()
+                .is_none_or(|last: &&Verse| last.book() != verse.book())
+            {
+                in_book = 0;
+            }
+            let allowed = books
+                .iter()
+                .find(|(book, _, _)| *book == verse.book())
+                .map_or(usize::MAX, |(_, _, allowed)| *allowed);
+            if in_book == allowed {
+                break;
+            }
+            in_book += 1;
+            verses.push(*verse);
+        }
+
+        let citation = policy
+            .citation
+            .clone()
+            .or_else(|| self.bible().copyright().map(str::to_string));
+        Excerpt {
+            verses,
+            citation,
+            violations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible::{Bible, BibleMetadata},
+        bible_books_enum::BibleBook,
+        book::Book,
+        chapter::Chapter,
+        reference::{Reference, ReferenceRange},
+        test_support::TestBible,
+    };
+
+    fn create_test_bible() -> Bible {
+        let book = |id: BibleBook, count: usize| {
+            let verses = (1..=count)
+                .map(|v| Verse::new(id, 1, v, format!("{} {v}", id.as_str())))
+                .collect();
+            Book::new(
+                id.as_str().into(),
+                id.full_name().into(),
+                vec![Chapter::new(verses, 1)],
+            )
+        };
+        let metadata = BibleMetadata {
+            copyright: Some("Test Translation, public domain".into()),
+            ..BibleMetadata::default()
+        };
+        TestBible::new(vec![book(BibleBook::Ruth, 20), book(BibleBook::Jude, 4)])
+            .with_metadata(metadata)
+            .build()
+    }
+
+    fn range(book: BibleBook, from: usize, to: usize) -> ReferenceRange {
+        ReferenceRange::new(Reference::new(book, 1, from), Reference::new(book, 1, to))
+    }
+
+    #[test]
+    fn test_excerpt_enforces_verse_and_book_limits() {
+        let bible = create_test_bible();
+        let policy = ExcerptPolicy::new()
+            .with_max_verses(8)
+            .with_max_book_percent(25.0);
+
+        let passage = bible.passage(&range(BibleBook::Ruth, 1, 4)).unwrap();
+        let excerpt = passage.excerpt_within_limits(&policy);
+        assert!(excerpt.is_complete());
+        assert_eq!(
+            excerpt.text(),
+            "rt 1 rt 2 rt 3 rt 4 (Test Translation, public domain)"
+        );
+
+        let passage = bible.passage(&range(BibleBook::Ruth, 1, 10)).unwrap();
+        let excerpt = passage.excerpt_within_limits(&policy);
+        assert_eq!(excerpt.verses().len(), 5);
+        assert_eq!(
+            excerpt.violations(),
+            [
+                ExcerptViolation::TooManyVerses {
+                    limit: 8,
+                    requested: 10
+                },
+                ExcerptViolation::BookShareExceeded {
+                    book: BibleBook::Ruth.into(),
+                    limit_percent: 25.0,
+                    requested_percent: 50.0
+                },
+            ]
+        );
+        assert_eq!(
+            excerpt.violations()[1].to_string(),
+            "50.0% of rt requested; at most 25% allowed"
+        );
+    }
+
+    #[test]
+    fn test_excerpt_custom_citation_and_short_book() {
+        let bible = create_test_bible();
+        let policy = ExcerptPolicy::new()
+            .with_max_book_percent(50.0)
+            .with_citation("Used by permission");
+        let passage = bible.passage(&range(BibleBook::Jude, 1, 4)).unwrap();
+        let excerpt = passage.excerpt_within_limits(&policy);
+        assert_eq!(excerpt.text(), "jd 1 jd 2 (Used by permission)");
+        assert!(!excerpt.is_complete());
+        assert!(bible.passage(&range(BibleBook::Genesis, 1, 4)).is_err());
+    }
+}
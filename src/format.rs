@@ -0,0 +1,242 @@
+//! Configurable plain-text formatting for verses, chapters, and books.
+//!
+//! The `Display` implementations of [`Verse`] and [`Chapter`] use the default
+//! [`VerseFormat`] and [`ChapterFormat`]; the `format_with` methods accept
+//! other options.
+
+use std::fmt::Write;
+
+use crate::{book::Book, chapter::Chapter, verse::Verse};
+
+/// How a verse number is printed before the verse text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum VerseNumberStyle {
+    /// `"16: For God so loved"`
+    #[default]
+    Colon,
+    /// `"[16] For God so loved"`
+    Brackets,
+    /// `"¹⁶For God so loved"`
+    Superscript,
+    /// `"For God so loved"`
+    Hidden,
+}
+
+/// Options for [`Verse::format_with`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerseFormat {
+    number_style: VerseNumberStyle,
+    include_reference: bool,
+    translation: Option<String>,
+}
+
+impl VerseFormat {
+    /// Creates the default format, `"16: For God so loved"`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how the verse number is printed.
+    pub fn with_number_style(mut self, number_style: VerseNumberStyle) -> Self {
+        self.number_style = number_style;
+        self
+    }
+
+    /// Prefixes the full reference (`"John 3:16 For God so loved"`) in place
+    /// of the verse number.
+    pub fn with_reference(mut self, include_reference: bool) -> Self {
+        self.include_reference = include_reference;
+        self
+    }
+
+    /// Appends a translation label, e.g. `" (KJV)"`.
+    pub fn with_translation(mut self, translation: impl Into<String>) -> Self {
+        self.translation = Some(translation.into());
+        self
+    }
+
+    /// Returns the verse number style.
+    pub fn number_style(&self) -> VerseNumberStyle {
+        self.number_style
+    }
+
+    fn write_verse(&self, out: &mut String, verse: &Verse) {
+        if self.include_reference {
+            let _ = write!(out, "{}", verse.reference());
+            if verse.is_bridge() {
+                let _ = write!(out, "-{}", verse.last_number());
+            }
+            out.push(' ');
+        } else {
+            match self.number_style {
+                VerseNumberStyle::Colon => {
+                    let _ = write!(out, "{}: ", verse.number_label());
+                }
+                VerseNumberStyle::Brackets => {
+                    let _ = write!(out, "[{}] ", verse.number_label());
+                }
+                VerseNumberStyle::Superscript => out.extend(superscript(&verse.number_label())),
+                VerseNumberStyle::Hidden => {}
+            }
+        }
+        out.push_str(verse.text());
+        if let Some(translation) = &self.translation {
+            let _ = write!(out, " ({})", translation);
+        }
+    }
+}
+
+/// Options for [`Chapter::format_with`] and [`Book::format_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChapterFormat {
+    verse: VerseFormat,
+    separator: String,
+    include_heading: bool,
+}
+
+impl Default for ChapterFormat {
+    fn default() -> Self {
+        ChapterFormat {
+            verse: VerseFormat::default(),
+            separator: "\n".to_string(),
+            include_heading: true,
+        }
+    }
+}
+
+impl ChapterFormat {
+    /// Creates the default format: a `"Chapter 3:"` heading followed by one
+    /// verse per line.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the format used for each verse.
+    pub fn with_verse_format(mut self, verse: VerseFormat) -> Self {
+        self.verse = verse;
+        self
+    }
+
+    /// Sets the text placed between verses.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Shows or hides the `"Chapter 3:"` heading.
+    pub fn with_heading(mut self, include_heading: bool) -> Self {
+        self.include_heading = include_heading;
+        self
+    }
+
+    /// Returns the format used for each verse.
+    pub fn verse_format(&self) -> &VerseFormat {
+        &self.verse
+    }
+
+    fn write_chapter(&self, out: &mut String, chapter: &Chapter) {
+        if self.include_heading {
+            let _ = writeln!(out, "Chapter {}:", chapter.number());
+        }
+        for (i, verse) in chapter.get_verses().iter().enumerate() {
+            if i > 0 {
+                out.push_str(&self.separator);
+            }
+            self.verse.write_verse(out, verse);
+        }
+    }
+}
+
+fn superscript(label: &str) -> impl Iterator<Item = char> + '_ {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    label.chars().map(|c| match c.to_digit(10) {
+        Some(digit) => DIGITS[digit as usize],
+        None => '⁻',
+    })
+}
+
+impl Verse {
+    /// Formats the verse according to `format`.
+    pub fn format_with(&self, format: &VerseFormat) -> String {
+        let mut out = String::with_capacity(self.text().len() + 16);
+        format.write_verse(&mut out, self);
+        out
+    }
+}
+
+impl Chapter {
+    /// Formats the chapter according to `format`.
+    pub fn format_with(&self, format: &ChapterFormat) -> String {
+        let mut out = String::new();
+        format.write_chapter(&mut out, self);
+        out
+    }
+}
+
+impl Book {
+    /// Formats the book as its title followed by each chapter, with chapters
+    /// separated by a blank line.
+    pub fn format_with(&self, format: &ChapterFormat) -> String {
+        let mut out = String::new();
+        out.push_str(self.title());
+        for chapter in self.chapters() {
+            out.push_str("\n\n");
+            format.write_chapter(&mut out, chapter);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    fn create_test_chapter() -> Chapter {
+        let verses = (15..=16)
+            .map(|v| Verse::new(BibleBook::John, 3, v, format!("Verse {}", v)))
+            .collect();
+        Chapter::new(verses, 3)
+    }
+
+    #[test]
+    fn test_verse_formats() {
+        let chapter = create_test_chapter();
+        let verse = chapter.get_verse(16).unwrap();
+
+        assert_eq!(verse.format_with(&VerseFormat::new()), verse.to_string());
+        let styled = |style| verse.format_with(&VerseFormat::new().with_number_style(style));
+        assert_eq!(styled(VerseNumberStyle::Brackets), "[16] Verse 16");
+        assert_eq!(styled(VerseNumberStyle::Superscript), "¹⁶Verse 16");
+        assert_eq!(styled(VerseNumberStyle::Hidden), "Verse 16");
+        assert_eq!(
+            verse.format_with(
+                &VerseFormat::new()
+                    .with_reference(true)
+                    .with_translation("KJV")
+            ),
+            "John 3:16 Verse 16 (KJV)"
+        );
+    }
+
+    #[test]
+    fn test_chapter_and_book_formats() {
+        let chapter = create_test_chapter();
+        assert_eq!(
+            chapter.format_with(&ChapterFormat::new()),
+            chapter.to_string()
+        );
+
+        let inline = ChapterFormat::new()
+            .with_heading(false)
+            .with_separator(" ")
+            .with_verse_format(VerseFormat::new().with_number_style(VerseNumberStyle::Brackets));
+        assert_eq!(chapter.format_with(&inline), "[15] Verse 15 [16] Verse 16");
+
+        let book = Book::new("jo".into(), "John".into(), vec![chapter]);
+        assert_eq!(
+            book.format_with(&inline),
+            "John\n\n[15] Verse 15 [16] Verse 16"
+        );
+    }
+}
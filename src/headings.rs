@@ -0,0 +1,220 @@
+//! Section headings distributed separately from the Bible text.
+//!
+//! Headings are often licensed apart from a public-domain translation, so a
+//! [`HeadingSet`] is kept in its own file and attached after loading. It is
+//! stored as JSON or as tab-separated `reference<TAB>heading` lines:
+//!
+//! ```json
+//! { "description": "Section headings", "headings": { "Genesis 1:1": "The Creation" } }
+//! ```
+
+use std::error::Error;
+
+use serde::{Deserialize, Serialize};
+use simd_json::serde::to_string_pretty as simd_to_string_pretty;
+
+use crate::{
+    bible::Bible,
+    bible::BibleError,
+    book_id::BookId,
+    keyed_text::{self, KeyedText},
+};
+
+/// Section headings keyed by the reference of the verse they precede,
+/// attached with [`Bible::attach_headings`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeadingSet {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    description: String,
+    headings: KeyedText,
+}
+
+impl HeadingSet {
+    /// Creates an empty heading set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a description of the headings, such as their source and license.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Adds a heading before the verse at `reference`, written as for
+    /// [`Bible::get_verse_by_reference`].
+    pub fn with_heading(mut self, reference: impl Into<String>, text: impl Into<String>) -> Self {
+        self.headings.insert(reference.into(), text.into());
+        self
+    }
+
+    /// Returns the description.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Iterates over (reference, heading) pairs in file order.
+    pub fn headings(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.headings.iter()
+    }
+
+    /// Returns the number of headings.
+    pub fn len(&self) -> usize {
+        self.headings.len()
+    }
+
+    /// Returns `true` if the set has no headings.
+    pub fn is_empty(&self) -> bool {
+        self.headings.is_empty()
+    }
+
+    /// Reads a heading set from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or parsed.
+    pub fn from_json(json_path: &str) -> Result<Self, BibleError> {
+        keyed_text::read_json(json_path)
+    }
+
+    /// Reads a heading set from a file of `reference<TAB>heading` lines.
+    /// Blank lines and lines starting with `#` are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or a line
+    /// has no tab.
+    pub fn from_tsv(tsv_path: &str) -> Result<Self, BibleError> {
+        Ok(HeadingSet {
+            description: String::new(),
+            headings: KeyedText::from_tsv(tsv_path, "reference<TAB>heading")?,
+        })
+    }
+
+    /// Serializes the set into the format read by [`HeadingSet::from_json`].
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        Ok(simd_to_string_pretty(self)?)
+    }
+}
+
+impl Bible {
+    /// Attaches section headings, which then appear in
+    /// [`Chapter::headings`](crate::Chapter::headings) and
+    /// [`Chapter::iter_with_headings`](crate::Chapter::iter_with_headings).
+    ///
+    /// A heading replaces any heading already attached before the same verse.
+    /// Headings are not written by [`Bible::to_json`], so separately licensed
+    /// headings stay out of exported text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any reference does not resolve to a verse; no
+    /// headings are attached in that case.
+    pub fn attach_headings(&mut self, headings: &HeadingSet) -> Result<(), BibleError> {
+        let targets: Vec<(BookId, usize, usize, &str)> = headings
+            .headings()
+            .map(|(reference, text)| {
+                let verse = self.get_verse_by_reference(reference)?;
+                Ok((verse.book().clone(), verse.chapter(), verse.number(), text))
+            })
+            .collect::<Result<_, BibleError>>()?;
+
+        for (book, chapter, verse, text) in targets {
+            if let Some(chapter) = self.chapter_mut(book, chapter) {
+                chapter.set_heading(verse, text.to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook,
+        book::Book,
+        chapter::{Chapter, ChapterItem},
+        test_support::TestBible,
+        verse::Verse,
+    };
+
+    fn create_test_bible() -> Bible {
+        let verses = (1..=4)
+            .map(|v| Verse::new(BibleBook::Genesis, 1, v, format!("Verse {}", v)))
+            .collect();
+        TestBible::new(vec![Book::new(
+            "gn".into(),
+            "Genesis".into(),
+            vec![Chapter::new(verses, 1)],
+        )])
+        .build()
+    }
+
+    #[test]
+    fn test_attach_headings() {
+        let mut bible = create_test_bible();
+        let set = HeadingSet::new()
+            .with_heading("Gen 1:3", "Light")
+            .with_heading("Genesis 1:1", "The Creation");
+        bible.attach_headings(&set).unwrap();
+
+        let chapter = bible
+            .get_book(BibleBook::Genesis)
+            .unwrap()
+            .get_chapter(1)
+            .unwrap();
+        let headings: Vec<(usize, &str)> = chapter
+            .headings()
+            .iter()
+            .map(|h| (h.verse(), h.text()))
+            .collect();
+        assert_eq!(headings, [(1, "The Creation"), (3, "Light")]);
+
+        let items: Vec<String> = chapter
+            .iter_with_headings()
+            .map(|item| match item {
+                ChapterItem::Heading(h) => format!("# {}", h.text()),
+                ChapterItem::Verse(v) => v.number().to_string(),
+            })
+            .collect();
+        assert_eq!(items, ["# The Creation", "1", "2", "# Light", "3", "4"]);
+
+        let bad = HeadingSet::new()
+            .with_heading("Genesis 1:2", "Void")
+            .with_heading("Genesis 9:1", "Noah");
+        assert!(bible.attach_headings(&bad).is_err());
+        let chapter = bible
+            .get_book(BibleBook::Genesis)
+            .unwrap()
+            .get_chapter(1)
+            .unwrap();
+        assert_eq!(chapter.headings().len(), 2);
+        assert!(!bible.to_json().unwrap().contains("Creation"));
+    }
+
+    #[test]
+    fn test_load_tsv_and_json() {
+        let tsv = std::env::temp_dir().join("bible_io_headings.tsv");
+        fs::write(
+            &tsv,
+            "# headings\nGenesis 1:1\tThe Creation\r\n\nGenesis 1:3\tLight\n",
+        )
+        .unwrap();
+        let set = HeadingSet::from_tsv(tsv.to_str().unwrap()).unwrap();
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.headings().nth(1), Some(("Genesis 1:3", "Light")));
+
+        let json = std::env::temp_dir().join("bible_io_headings.json");
+        fs::write(&json, set.to_json().unwrap()).unwrap();
+        assert_eq!(HeadingSet::from_json(json.to_str().unwrap()).unwrap(), set);
+
+        fs::write(&tsv, "Genesis 1:1 The Creation\n").unwrap();
+        let err = HeadingSet::from_tsv(tsv.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("line 1"));
+        fs::remove_file(&tsv).ok();
+        fs::remove_file(&json).ok();
+    }
+}
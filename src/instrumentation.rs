@@ -0,0 +1,364 @@
+//! Timing of loading, indexing and search, behind the `instrumentation`
+//! feature.
+//!
+//! Install a hook with [`set_instrumentation_hook`], or for a single load
+//! with [`LoadOptions::with_instrumentation_hook`](crate::LoadOptions::with_instrumentation_hook),
+//! to receive a [`StageEvent`] as each stage begins and ends. The hook may
+//! capture state such as a metrics handle, and each event carries the span
+//! id, the id of the enclosing span and the file or Bible it concerns, so
+//! it can be forwarded to `tracing`, `log` or metrics counters:
+//!
+//! ```text
+//! let latency = metrics.histogram("bible_stage_us");
+//! bible_io::instrumentation::set_instrumentation_hook(move |event| {
+//!     if let StageEvent::End(timing) = event {
+//!         latency.record(timing.duration().as_micros() as u64, &[("stage", timing.stage())]);
+//!     }
+//! });
+//! ```
+//!
+//! Without the feature, the timing points compile to nothing.
+
+use std::{fmt, sync::Arc, time::Duration};
+
+/// A stage of work reported to the instrumentation hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Stage {
+    /// Reading a data file; items are bytes read.
+    Read,
+    /// Parsing file contents; items are books parsed.
+    Parse,
+    /// Building the in-memory Bible; items are books built.
+    Build,
+    /// Building a search index; items are distinct terms.
+    Index,
+    /// Running a search; items are hits.
+    Search,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Stage::Read => "read",
+            Stage::Parse => "parse",
+            Stage::Build => "build",
+            Stage::Index => "index",
+            Stage::Search => "search",
+        })
+    }
+}
+
+/// One run of a [`Stage`], as reported when it begins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageSpan {
+    id: u64,
+    parent: Option<u64>,
+    stage: Stage,
+    context: String,
+}
+
+impl StageSpan {
+    /// Returns an id unique to this run within the process.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the id of the span this one runs within on the same thread,
+    /// such as the search that built an index shard.
+    pub fn parent(&self) -> Option<u64> {
+        self.parent
+    }
+
+    /// Returns the stage.
+    pub fn stage(&self) -> Stage {
+        self.stage
+    }
+
+    /// Returns what the stage works on: the file path for loading stages,
+    /// or the Bible's id for indexing and search.
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+}
+
+/// How long a [`Stage`] took and how much it processed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageTiming {
+    span: StageSpan,
+    duration: Duration,
+    items: usize,
+}
+
+impl StageTiming {
+    /// Returns the span that ended.
+    pub fn span(&self) -> &StageSpan {
+        &self.span
+    }
+
+    /// Returns the stage that finished.
+    pub fn stage(&self) -> Stage {
+        self.span.stage
+    }
+
+    /// Returns the wall-clock time the stage took.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the number of items processed, as described on each
+    /// [`Stage`], or 0 if the stage failed.
+    pub fn items(&self) -> usize {
+        self.items
+    }
+}
+
+/// A stage beginning or ending. Every `Begin` is followed by an `End` with
+/// the same span id, on the same thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StageEvent<'a> {
+    /// A stage has begun.
+    Begin(&'a StageSpan),
+    /// A stage has ended.
+    End(&'a StageTiming),
+}
+
+/// A function receiving [`StageEvent`]s.
+pub type InstrumentationHook = Arc<dyn Fn(&StageEvent<'_>) + Send + Sync>;
+
+#[cfg(feature = "instrumentation")]
+static HOOK: std::sync::RwLock<Option<InstrumentationHook>> = std::sync::RwLock::new(None);
+
+#[cfg(feature = "instrumentation")]
+static NEXT_SPAN: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+
+#[cfg(feature = "instrumentation")]
+thread_local! {
+    /// The hook of the load running on this thread, which takes precedence
+    /// over the global one.
+    static SCOPED_HOOK: std::cell::RefCell<Option<InstrumentationHook>> =
+        const { std::cell::RefCell::new(None) };
+    /// The innermost open span on this thread.
+    static CURRENT_SPAN: std::cell::Cell<Option<u64>> = const { std::cell::Cell::new(None) };
+}
+
+/// Installs the hook called as each stage begins and ends, replacing and
+/// returning any hook installed before.
+#[cfg(feature = "instrumentation")]
+pub fn set_instrumentation_hook(
+    hook: impl Fn(&StageEvent<'_>) + Send + Sync + 'static,
+) -> Option<InstrumentationHook> {
+    let mut slot = HOOK.write().unwrap_or_else(|e| e.into_inner());
+    slot.replace(Arc::new(hook))
+}
+
+/// Removes and returns the hook installed with [`set_instrumentation_hook`].
+#[cfg(feature = "instrumentation")]
+pub fn clear_instrumentation_hook() -> Option<InstrumentationHook> {
+    HOOK.write().unwrap_or_else(|e| e.into_inner()).take()
+}
+
+/// Routes the stages run on this thread to `hook` until dropped, as set by
+/// [`LoadOptions::with_instrumentation_hook`](crate::LoadOptions::with_instrumentation_hook).
+pub(crate) struct HookScope {
+    #[cfg(feature = "instrumentation")]
+    previous: Option<InstrumentationHook>,
+}
+
+impl HookScope {
+    #[cfg_attr(not(feature = "instrumentation"), allow(unused_variables))]
+    pub(crate) fn enter(hook: Option<&InstrumentationHook>) -> Self {
+        HookScope {
+            #[cfg(feature = "instrumentation")]
+            previous: match hook {
+                Some(hook) => SCOPED_HOOK.with(|scoped| scoped.replace(Some(Arc::clone(hook)))),
+                None => SCOPED_HOOK.with(|scoped| scoped.borrow().clone()),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "instrumentation")]
+impl Drop for HookScope {
+    fn drop(&mut self) {
+        SCOPED_HOOK.with(|scoped| *scoped.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Measures one stage from creation until [`StageTimer::finish`], or until
+/// dropped if the stage fails.
+pub(crate) struct StageTimer {
+    #[cfg(feature = "instrumentation")]
+    open: Option<(InstrumentationHook, StageSpan, std::time::Instant)>,
+    #[cfg(feature = "instrumentation")]
+    items: usize,
+}
+
+impl StageTimer {
+    /// Begins `stage` on `context`, reporting it to the hook if one is
+    /// installed.
+    #[cfg_attr(not(feature = "instrumentation"), allow(unused_variables))]
+    pub(crate) fn start(stage: Stage, context: &str) -> Self {
+        StageTimer {
+            #[cfg(feature = "instrumentation")]
+            open: Self::begin(stage, context),
+            #[cfg(feature = "instrumentation")]
+            items: 0,
+        }
+    }
+
+    #[cfg(feature = "instrumentation")]
+    fn begin(
+        stage: Stage,
+        context: &str,
+    ) -> Option<(InstrumentationHook, StageSpan, std::time::Instant)> {
+        let hook = SCOPED_HOOK
+            .with(|scoped| scoped.borrow().clone())
+            .or_else(|| HOOK.read().unwrap_or_else(|e| e.into_inner()).clone())?;
+        let id = NEXT_SPAN.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let span = StageSpan {
+            id,
+            parent: CURRENT_SPAN.with(|current| current.replace(Some(id))),
+            stage,
+            context: context.to_string(),
+        };
+        hook(&StageEvent::Begin(&span));
+        Some((hook, span, std::time::Instant::now()))
+    }
+
+    /// Ends the stage after processing `items`.
+    #[cfg_attr(not(feature = "instrumentation"), allow(unused_variables, unused_mut))]
+    pub(crate) fn finish(mut self, items: usize) {
+        #[cfg(feature = "instrumentation")]
+        {
+            self.items = items;
+        }
+    }
+}
+
+#[cfg(feature = "instrumentation")]
+impl Drop for StageTimer {
+    fn drop(&mut self) {
+        if let Some((hook, span, start)) = self.open.take() {
+            CURRENT_SPAN.with(|current| current.set(span.parent));
+            hook(&StageEvent::End(&StageTiming {
+                span,
+                duration: start.elapsed(),
+                items: self.items,
+            }));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "instrumentation"))]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::{bible::Bible, load_options::LoadOptions};
+
+    #[derive(Debug, PartialEq)]
+    enum Seen {
+        Begin(Stage, Option<u64>),
+        End(Stage, usize),
+    }
+
+    /// Records the events whose context is `context` or, for loading
+    /// stages, a path ending in it; other test threads may report too.
+    fn recorder(
+        context: &'static str,
+    ) -> (
+        Arc<Mutex<Vec<Seen>>>,
+        impl Fn(&StageEvent<'_>) + Send + Sync + 'static,
+    ) {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let hook = move |event: &StageEvent<'_>| {
+            let (span, entry) = match event {
+                StageEvent::Begin(span) => (*span, Seen::Begin(span.stage(), span.parent())),
+                StageEvent::End(timing) => {
+                    (timing.span(), Seen::End(timing.stage(), timing.items()))
+                }
+            };
+            if span.context().ends_with(context) {
+                sink.lock().unwrap().push(entry);
+            }
+        };
+        (seen, hook)
+    }
+
+    fn write_test_file(id: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(id);
+        std::fs::write(
+            &path,
+            format!(
+                r#"{{"id": "{id}", "books": {{"gn": {{"name": "Genesis", "chapters": [["In the beginning"]]}}}}}}"#
+            ),
+        )
+        .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_hook_receives_nested_spans() {
+        let id = "bible_io_instrumentation_test.json";
+        let (seen, hook) = recorder(id);
+        set_instrumentation_hook(hook);
+        let path = write_test_file(id);
+        let bible = Bible::new_from_json(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+        bible.search("beginning");
+        clear_instrumentation_hook();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen[0], Seen::Begin(Stage::Read, None));
+        assert!(seen.contains(&Seen::End(Stage::Build, 1)));
+        // The index shard is built within the search.
+        let search = seen
+            .iter()
+            .position(|s| *s == Seen::Begin(Stage::Search, None))
+            .unwrap();
+        assert!(matches!(
+            seen[search + 1],
+            Seen::Begin(Stage::Index, Some(_))
+        ));
+        assert!(matches!(seen[search + 2], Seen::End(Stage::Index, _)));
+        assert_eq!(seen[search + 3], Seen::End(Stage::Search, 1));
+    }
+
+    #[test]
+    fn test_hook_per_load_options() {
+        let id = "bible_io_instrumentation_options_test.json";
+        let (seen, hook) = recorder(id);
+        let path = write_test_file(id);
+        let options = LoadOptions::new()
+            .with_search_index(true)
+            .with_instrumentation_hook(hook);
+        Bible::new_from_json_with_options(path.to_str().unwrap(), &options).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let ended: Vec<Stage> = seen
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|s| match s {
+                Seen::End(stage, _) => Some(*stage),
+                Seen::Begin(..) => None,
+            })
+            .collect();
+        assert_eq!(
+            ended,
+            [Stage::Read, Stage::Parse, Stage::Build, Stage::Index]
+        );
+
+        // A stage that fails still ends its span.
+        seen.lock().unwrap().clear();
+        assert!(Bible::new_from_json_with_options(path.to_str().unwrap(), &options).is_err());
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [Seen::Begin(Stage::Read, None), Seen::End(Stage::Read, 0)]
+        );
+        CURRENT_SPAN.with(|current| assert_eq!(current.get(), None));
+    }
+}
@@ -0,0 +1,326 @@
+//! Alternative JSON layouts accepted by [`Bible::new_from_json_with_schema`].
+//!
+//! Widely circulated Bible files rarely follow this crate's own layout, so
+//! each supported [`JsonSchema`] is converted into it while loading.
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use indexmap::IndexMap;
+use serde::{de::Error as _, Deserialize};
+use simd_json::tape::Value;
+
+use crate::{
+    bible::{deserialize_chapters, BibleFileRoot, FileDataEntry, ALT_ABBREVS},
+    bible_books_enum::BibleBook,
+};
+
+#[cfg(doc)]
+use crate::bible::Bible;
+
+/// The layout of a Bible JSON file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum JsonSchema {
+    /// Detect the layout from the file's structure.
+    #[default]
+    Auto,
+    /// This crate's layout, written by [`Bible::to_json`]:
+    /// `{"id": "kjv", "name": ..., "books": {"gn": {"name": "Genesis", "chapters": [["..."]]}}}`.
+    /// Chapters may also be objects keyed by chapter and verse number.
+    Native,
+    /// An array of books, as used by thiagobodruk/bible:
+    /// `[{"abbrev": "gn", "chapters": [["In the beginning..."]]}]`.
+    BookArray,
+    /// Books keyed by name, with chapters and verses keyed by number:
+    /// `{"Genesis": {"1": {"1": "In the beginning..."}}}`.
+    NestedMap,
+    /// One object per verse, optionally wrapped as `{"verses": [...]}`:
+    /// `[{"book_name": "Genesis", "book": 1, "chapter": 1, "verse": 1, "text": "..."}]`.
+    /// `book` may be a name, an abbreviation, or a 1-based book number.
+    VerseArray,
+}
+
+/// Parses `bytes` in the given layout into the native file structure.
+pub(crate) fn parse(
+    bytes: &mut [u8],
+    schema: JsonSchema,
+) -> Result<BibleFileRoot, simd_json::Error> {
+    // Files saved by some editors start with a UTF-8 byte order mark.
+    let bytes = match bytes {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => rest,
+        bytes => bytes,
+    };
+    match schema {
+        JsonSchema::Native => simd_json::serde::from_slice(bytes),
+        JsonSchema::BookArray => simd_json::serde::from_slice(bytes).map(from_book_array),
+        JsonSchema::NestedMap => simd_json::serde::from_slice(bytes).map(from_nested_map),
+        JsonSchema::VerseArray => simd_json::serde::from_slice(bytes).and_then(from_verse_rows),
+        JsonSchema::Auto => {
+            // Parsing rewrites the buffer in place, so the layout is detected
+            // on a copy. The tape keeps object keys in file order, which
+            // decides book order.
+            let mut probe = bytes.to_vec();
+            match detect(simd_json::to_tape(&mut probe)?.as_value()) {
+                Some(schema) => parse(bytes, schema),
+                None => Err(simd_json::Error::custom(
+                    "unrecognized Bible JSON layout; expected an object with \"books\", \
+                     an array of books or verses, or books keyed by name",
+                )),
+            }
+        }
+    }
+}
+
+/// Infers the layout from the top-level structure and the first entry.
+pub(crate) fn detect(value: Value) -> Option<JsonSchema> {
+    if let Some(items) = value.as_array() {
+        return match items.get(0) {
+            None => Some(JsonSchema::BookArray),
+            Some(item) if item.contains_key("chapters") => Some(JsonSchema::BookArray),
+            Some(item) if item.contains_key("text") => Some(JsonSchema::VerseArray),
+            Some(_) => None,
+        };
+    }
+    if value.contains_key("books") {
+        Some(JsonSchema::Native)
+    } else if value.get("verses").is_some_and(|v| v.is_array()) {
+        Some(JsonSchema::VerseArray)
+    } else if value.is_object() {
+        Some(JsonSchema::NestedMap)
+    } else {
+        None
+    }
+}
+
+#[derive(Deserialize)]
+struct BookArrayEntry {
+    abbrev: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(deserialize_with = "deserialize_chapters")]
+    chapters: Vec<Vec<String>>,
+}
+
+fn from_book_array(entries: Vec<BookArrayEntry>) -> BibleFileRoot {
+    let books = entries
+        .into_iter()
+        .map(|entry| {
+            let (abbrev, title) = resolve_book_key(&entry.abbrev);
+            let entry = FileDataEntry {
+                chapters: entry.chapters,
+                name: entry.name.unwrap_or(title),
+                ..FileDataEntry::default()
+            };
+            (abbrev, entry)
+        })
+        .collect();
+    BibleFileRoot {
+        books,
+        ..BibleFileRoot::default()
+    }
+}
+
+#[derive(Deserialize)]
+struct NestedChapters(#[serde(deserialize_with = "deserialize_chapters")] Vec<Vec<String>>);
+
+fn from_nested_map(books: IndexMap<String, NestedChapters>) -> BibleFileRoot {
+    let books = books
+        .into_iter()
+        .map(|(key, NestedChapters(chapters))| {
+            let (abbrev, name) = resolve_book_key(&key);
+            let entry = FileDataEntry {
+                chapters,
+                name,
+                ..FileDataEntry::default()
+            };
+            (abbrev, entry)
+        })
+        .collect();
+    BibleFileRoot {
+        books,
+        ..BibleFileRoot::default()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum VerseRows {
+    Bare(Vec<VerseRow>),
+    Wrapped { verses: Vec<VerseRow> },
+}
+
+#[derive(Deserialize)]
+struct VerseRow {
+    #[serde(default)]
+    book: Option<BookField>,
+    #[serde(default)]
+    book_name: Option<String>,
+    chapter: usize,
+    verse: usize,
+    text: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BookField {
+    Number(usize),
+    Name(String),
+}
+
+fn from_verse_rows(rows: VerseRows) -> Result<BibleFileRoot, simd_json::Error> {
+    let rows = match rows {
+        VerseRows::Bare(rows) | VerseRows::Wrapped { verses: rows } => rows,
+    };
+
+    let mut grouped = NumberedBooks::new();
+    for row in rows {
+        if !is_valid_number(row.chapter) || !is_valid_number(row.verse) {
+            return Err(simd_json::Error::custom(format!(
+                "invalid chapter or verse number {}:{}; expected 1 to {MAX_NUMBER}",
+                row.chapter, row.verse
+            )));
+        }
+        let (abbrev, title) = match (&row.book, &row.book_name) {
+            (Some(BookField::Number(n)), name) => match BibleBook::from_number(*n) {
+                Some(book) => (
+                    book.as_str().to_string(),
+                    name.clone().unwrap_or_else(|| book.full_name().to_string()),
+                ),
+                None => resolve_book_key(name.as_deref().unwrap_or(&n.to_string())),
+            },
+            (Some(BookField::Name(name)), _) | (None, Some(name)) => resolve_book_key(name),
+            (None, None) => resolve_book_key(""),
+        };
+        grouped
+            .entry(abbrev)
+            .or_insert_with(|| (title, BTreeMap::new()))
+            .1
+            .entry(row.chapter)
+            .or_default()
+            .insert(row.verse, row.text);
+    }
+
+    Ok(BibleFileRoot {
+        books: numbered_books_to_entries(grouped),
+        ..BibleFileRoot::default()
+    })
+}
+
+/// The largest chapter or verse number accepted from numbered data. Gaps in
+/// numbering are padded with empty entries, so this bounds the memory a
+/// malformed file can claim.
+pub(crate) const MAX_NUMBER: usize = 10_000;
+
+/// Returns `true` if `number` is a usable chapter or verse number.
+pub(crate) fn is_valid_number(number: usize) -> bool {
+    (1..=MAX_NUMBER).contains(&number)
+}
+
+/// Verse texts by chapter number and verse number.
+pub(crate) type NumberedChapters = BTreeMap<usize, BTreeMap<usize, String>>;
+
+/// Titles and numbered chapters by book key, in first-seen order.
+pub(crate) type NumberedBooks = IndexMap<String, (String, NumberedChapters)>;
+
+/// Converts numbered books into native book entries.
+pub(crate) fn numbered_books_to_entries(books: NumberedBooks) -> IndexMap<String, FileDataEntry> {
+    books
+        .into_iter()
+        .map(|(abbrev, (name, chapters))| {
+            let entry = FileDataEntry {
+                chapters: numbered_to_positions(chapters),
+                name,
+                ..FileDataEntry::default()
+            };
+            (abbrev, entry)
+        })
+        .collect()
+}
+
+/// Lays out numbered chapters and verses by position, padding any gaps with
+/// empty chapters and verses so numbering is preserved.
+pub(crate) fn numbered_to_positions(chapters: NumberedChapters) -> Vec<Vec<String>> {
+    let mut positions: Vec<Vec<String>> = Vec::with_capacity(chapters.len());
+    for (chapter_number, verses) in chapters {
+        let padded = positions.len().max(chapter_number.saturating_sub(1));
+        positions.resize_with(padded, Vec::new);
+        let mut texts = Vec::with_capacity(verses.len());
+        for (verse_number, text) in verses {
+            let padded = texts.len().max(verse_number.saturating_sub(1));
+            texts.resize_with(padded, String::new);
+            texts.push(text);
+        }
+        positions.push(texts);
+    }
+    positions
+}
+
+/// Maps a book name or abbreviation from a data file to the key and title
+/// used in the native layout. Unknown books keep their name.
+pub(crate) fn resolve_book_key(key: &str) -> (String, String) {
+    let key = key.trim();
+    if let Some(book) = BibleBook::from_name(key) {
+        return (book.as_str().to_string(), key.to_string());
+    }
+    let lower = key.to_lowercase();
+    let known = BibleBook::from_str(&lower)
+        .ok()
+        .or_else(|| ALT_ABBREVS.get(lower.as_str()).copied())
+        .or_else(|| {
+            // "1 Jn", "Song of Sol."
+            let compact: String = lower.chars().filter(|c| !matches!(c, ' ' | '.')).collect();
+            BibleBook::from_str(&compact)
+                .ok()
+                .or_else(|| ALT_ABBREVS.get(compact.as_str()).copied())
+        });
+    match known {
+        Some(book) => (book.as_str().to_string(), book.full_name().to_string()),
+        None => (lower, key.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(json: &str, schema: JsonSchema) -> BibleFileRoot {
+        parse(&mut json.as_bytes().to_vec(), schema).unwrap()
+    }
+
+    #[test]
+    fn test_detects_and_converts_layouts() {
+        let book_array = "\u{feff}[{\"abbrev\": \"gn\", \"chapters\": [[\"In the beginning\"]]}]";
+        let root = parse_str(book_array, JsonSchema::Auto);
+        assert_eq!(root.books["gn"].name, "Genesis");
+        assert_eq!(root.books["gn"].chapters, [["In the beginning"]]);
+
+        let nested = r#"{"Genesis": {"1": {"2": "And the earth", "1": "In the beginning"}}}"#;
+        let root = parse_str(nested, JsonSchema::Auto);
+        assert_eq!(
+            root.books["gn"].chapters[0],
+            ["In the beginning", "And the earth"]
+        );
+
+        let verses = r#"{"verses": [
+            {"book_name": "John", "book": 43, "chapter": 11, "verse": 35, "text": "Jesus wept."},
+            {"book": "Gen", "chapter": 1, "verse": 1, "text": "In the beginning"}
+        ]}"#;
+        let root = parse_str(verses, JsonSchema::Auto);
+        let john = &root.books["jo"];
+        assert_eq!(john.chapters.len(), 11);
+        assert_eq!(john.chapters[10][34], "Jesus wept.");
+        assert_eq!(root.books["gn"].name, "Genesis");
+
+        let native =
+            r#"{"name": "KJV", "books": {"gn": {"name": "Genesis", "chapters": [["x"]]}}}"#;
+        assert_eq!(parse_str(native, JsonSchema::Auto).name, "KJV");
+    }
+
+    #[test]
+    fn test_explicit_schema_and_unknown_layout() {
+        let verses = r#"[{"book": "Genesis", "chapter": 1, "verse": 1, "text": "x"}]"#;
+        let root = parse_str(verses, JsonSchema::VerseArray);
+        assert_eq!(root.books["gn"].chapters, [["x"]]);
+        assert!(parse(&mut verses.as_bytes().to_vec(), JsonSchema::Native).is_err());
+        assert!(parse(&mut b"[1, 2]".to_vec(), JsonSchema::Auto).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+//! Text keyed by a human-readable reference, the shape shared by
+//! [`Patch`](crate::patch::Patch), [`HeadingSet`](crate::headings::HeadingSet)
+//! and [`ChapterSummarySet`](crate::summaries::ChapterSummarySet), with the
+//! JSON and tab-separated loaders they use.
+
+use std::{fs, io};
+
+use indexmap::IndexMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use simd_json::serde::from_slice as simd_from_slice;
+
+use crate::bible::BibleError;
+
+/// Text keyed by reference, in insertion order. Serializes as a JSON object.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub(crate) struct KeyedText(IndexMap<String, String>);
+
+impl KeyedText {
+    /// Sets the text of `key`, replacing any text it had.
+    pub(crate) fn insert(&mut self, key: String, text: String) {
+        self.0.insert(key, text);
+    }
+
+    /// Iterates over (key, text) pairs in insertion order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(key, text)| (key.as_str(), text.as_str()))
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Reads `key<TAB>text` lines, skipping blank lines and lines starting
+    /// with `#`. `line_format` names the columns in the error for a line
+    /// without a tab, such as `"reference<TAB>heading"`.
+    pub(crate) fn from_tsv(tsv_path: &str, line_format: &str) -> Result<Self, BibleError> {
+        let content =
+            fs::read_to_string(tsv_path).map_err(|e| BibleError::load_error(tsv_path, e))?;
+        let mut entries = KeyedText::default();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, text) = line.split_once('\t').ok_or_else(|| {
+                let message = format!("line {}: expected \"{}\"", i + 1, line_format);
+                BibleError::load_error(
+                    tsv_path,
+                    io::Error::new(io::ErrorKind::InvalidData, message),
+                )
+            })?;
+            entries.insert(key.trim().to_string(), text.trim().to_string());
+        }
+        Ok(entries)
+    }
+}
+
+/// Reads a keyed-text dataset from a JSON file.
+pub(crate) fn read_json<T: DeserializeOwned>(json_path: &str) -> Result<T, BibleError> {
+    let mut file_content = fs::read(json_path).map_err(|e| BibleError::load_error(json_path, e))?;
+    simd_from_slice(&mut file_content).map_err(|e| BibleError::load_error(json_path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_tsv() {
+        let path = std::env::temp_dir().join("bible_io_keyed_text.tsv");
+        fs::write(
+            &path,
+            "# notes\nGenesis 1:1 \t First \r\n\nGenesis 1:1\tLast\n",
+        )
+        .unwrap();
+        let entries = KeyedText::from_tsv(path.to_str().unwrap(), "key<TAB>text").unwrap();
+        // A repeated key keeps its first position but takes the later text.
+        assert_eq!(
+            entries.iter().collect::<Vec<_>>(),
+            [("Genesis 1:1", "Last")]
+        );
+
+        fs::write(&path, "ok\tfine\nmissing tab\n").unwrap();
+        let err = KeyedText::from_tsv(path.to_str().unwrap(), "key<TAB>text").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("line 2: expected \"key<TAB>text\""));
+        fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,373 @@
+//! Line-break hints for print and PDF pipelines.
+//!
+//! Print layouts need to know where lines break and where a column may end
+//! before they place any text. [`wrap_text`] breaks text for a column of a
+//! given width, and [`Passage::layout`] lays a passage out in columns of a
+//! fixed height, splitting a verse across columns only where neither part
+//! is left shorter than [`LayoutOptions::with_min_lines`] (no widows or
+//! orphans).
+//!
+//! Widths are counted in characters, which suits monospaced output and is a
+//! fair approximation for proportional fonts at a known average width.
+
+use std::ops::Range;
+
+use crate::{passage::Passage, verse::Verse};
+
+/// Settings for [`Passage::layout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutOptions {
+    width: usize,
+    column_lines: Option<usize>,
+    min_lines: usize,
+    verse_numbers: bool,
+}
+
+impl LayoutOptions {
+    /// Creates options for a column `width` characters wide, as one column
+    /// of unlimited height, keeping at least two lines of a verse together
+    /// and starting each verse with its number.
+    pub fn new(width: usize) -> Self {
+        LayoutOptions {
+            width: width.max(2),
+            column_lines: None,
+            min_lines: 2,
+            verse_numbers: true,
+        }
+    }
+
+    /// Sets the number of lines in a column, so the passage is split into
+    /// columns (or pages).
+    pub fn with_column_lines(mut self, lines: usize) -> Self {
+        self.column_lines = Some(lines.max(1));
+        self
+    }
+
+    /// Sets the fewest lines of a verse that may stand alone at the end or
+    /// start of a column. Use 1 to allow any split.
+    pub fn with_min_lines(mut self, lines: usize) -> Self {
+        self.min_lines = lines.max(1);
+        self
+    }
+
+    /// Starts each verse with its number, as in `"16 For God..."`.
+    pub fn with_verse_numbers(mut self, verse_numbers: bool) -> Self {
+        self.verse_numbers = verse_numbers;
+        self
+    }
+
+    /// Returns the column width in characters.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of lines in a column, if columns are limited.
+    pub fn column_lines(&self) -> Option<usize> {
+        self.column_lines
+    }
+
+    /// Returns the fewest lines of a verse kept together at a column break.
+    pub fn min_lines(&self) -> usize {
+        self.min_lines
+    }
+}
+
+/// Breaks `text` into lines of at most `width` characters.
+///
+/// Lines break between words. A word that does not fit is broken after
+/// one of its own hyphens if that helps ("Beth-" / "lehem"), and a word
+/// longer than a whole line is broken at its own hyphens or, failing that,
+/// hyphenated wherever the line ends.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(2);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_len = 0;
+    for word in text.split_whitespace() {
+        let mut word = word;
+        let mut word_len = word.chars().count();
+        let gap = usize::from(line_len > 0);
+        if line_len + gap + word_len > width && line_len > 0 {
+            // Break after a hyphen within the word if its first part fits.
+            let room = width.saturating_sub(line_len + gap);
+            if let Some((at, _)) = word
+                .char_indices()
+                .take(room)
+                .filter(|&(i, c)| c == '-' && i + 1 < word.len())
+                .last()
+            {
+                line.push(' ');
+                line.push_str(&word[..=at]);
+                word = &word[at + 1..];
+                word_len = word.chars().count();
+            }
+            lines.push(std::mem::take(&mut line));
+            line_len = 0;
+        }
+        while word_len > width {
+            // Prefer the word's own last hyphen that fits.
+            let hyphen = word
+                .char_indices()
+                .take(width)
+                .filter(|&(_, c)| c == '-')
+                .last();
+            let at = match hyphen {
+                Some((i, _)) => i + 1,
+                None => word
+                    .char_indices()
+                    .nth(width - 1)
+                    .map_or(word.len(), |(i, _)| i),
+            };
+            let piece = &word[..at];
+            lines.push(match hyphen {
+                Some(_) => piece.to_string(),
+                None => format!("{piece}-"),
+            });
+            word = &word[at..];
+            word_len = word.chars().count();
+        }
+        if line_len > 0 {
+            line.push(' ');
+            line_len += 1;
+        }
+        line.push_str(word);
+        line_len += word_len;
+    }
+    if line_len > 0 {
+        lines.push(line);
+    }
+    lines
+}
+
+/// A verse broken into lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerseLayout<'a> {
+    verse: &'a Verse,
+    lines: Vec<String>,
+}
+
+impl<'a> VerseLayout<'a> {
+    /// Returns the verse.
+    pub fn verse(&self) -> &'a Verse {
+        self.verse
+    }
+
+    /// Returns the lines of the verse, including its number when enabled.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+}
+
+/// Consecutive lines of one verse placed in a column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineRun {
+    verse: usize,
+    lines: Range<usize>,
+}
+
+impl LineRun {
+    /// Returns the position of the verse in [`Layout::verses`].
+    pub fn verse(&self) -> usize {
+        self.verse
+    }
+
+    /// Returns the lines of the verse in this run.
+    pub fn lines(&self) -> Range<usize> {
+        self.lines.clone()
+    }
+}
+
+/// A passage broken into lines and columns by [`Passage::layout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout<'a> {
+    verses: Vec<VerseLayout<'a>>,
+    columns: Vec<Vec<LineRun>>,
+}
+
+impl<'a> Layout<'a> {
+    /// Returns each verse broken into lines, in reading order.
+    pub fn verses(&self) -> &[VerseLayout<'a>] {
+        &self.verses
+    }
+
+    /// Returns the runs of verse lines placed in each column.
+    pub fn columns(&self) -> &[Vec<LineRun>] {
+        &self.columns
+    }
+
+    /// Returns the text lines of the column at `index`.
+    pub fn column_lines(&self, index: usize) -> Vec<&str> {
+        self.columns
+            .get(index)
+            .into_iter()
+            .flatten()
+            .flat_map(|run| &self.verses[run.verse].lines[run.lines()])
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+impl<'a> Passage<'a> {
+    /// Breaks the passage into lines and columns for print. See the
+    /// [module documentation](crate::layout).
+    pub fn layout(&self, options: &LayoutOptions) -> Layout<'a> {
+        let verses: Vec<VerseLayout<'a>> = self
+            .verses()
+            .iter()
+            .map(|&verse| {
+                let text = if options.verse_numbers {
+                    format!("{} {}", verse.number_label(), verse.text().trim())
+                } else {
+                    verse.text().trim().to_string()
+                };
+                VerseLayout {
+                    verse,
+                    lines: wrap_text(&text, options.width),
+                }
+            })
+            .collect();
+        let columns = match options.column_lines {
+            Some(height) => fill_columns(&verses, height, options.min_lines),
+            None => vec![verses
+                .iter()
+                .enumerate()
+                .map(|(verse, layout)| LineRun {
+                    verse,
+                    lines: 0..layout.lines.len(),
+                })
+                .collect()],
+        };
+        Layout { verses, columns }
+    }
+}
+
+fn fill_columns(verses: &[VerseLayout<'_>], height: usize, min_lines: usize) -> Vec<Vec<LineRun>> {
+    let mut columns = vec![Vec::new()];
+    let mut used = 0;
+    for (verse, layout) in verses.iter().enumerate() {
+        let total = layout.lines.len();
+        let mut start = 0;
+        while start < total {
+            let rest = total - start;
+            let room = height - used;
+            let take = if rest <= room {
+                rest
+            } else {
+                // Leave at least `min_lines` on both sides of the break,
+                // unless the verse is too long for that to be possible.
+                let split = room.min(rest.saturating_sub(min_lines));
+                if split >= min_lines || (used == 0 && split > 0) {
+                    split
+                } else if used == 0 {
+                    room
+                } else {
+                    0
+                }
+            };
+            if take > 0 {
+                columns.last_mut().unwrap().push(LineRun {
+                    verse,
+                    lines: start..start + take,
+                });
+                start += take;
+                used += take;
+            }
+            if start < total || used == height {
+                columns.push(Vec::new());
+                used = 0;
+            }
+        }
+    }
+    if columns.last().is_some_and(Vec::is_empty) && columns.len() > 1 {
+        columns.pop();
+    }
+    columns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook,
+        book::Book,
+        chapter::Chapter,
+        reference::{Reference, ReferenceRange},
+        test_support::TestBible,
+    };
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(
+            wrap_text("In the beginning God created the heaven", 12),
+            ["In the", "beginning", "God created", "the heaven"]
+        );
+        assert_eq!(
+            wrap_text("they came to Beth-lehem", 16),
+            ["they came to", "Beth-lehem"]
+        );
+        assert_eq!(
+            wrap_text("and came unto Beth-lehem", 19),
+            ["and came unto Beth-", "lehem"]
+        );
+        assert_eq!(
+            wrap_text("Mahershalalhashbaz", 8),
+            ["Mahersh-", "alalhas-", "hbaz"]
+        );
+        assert_eq!(wrap_text("abcd ef-gh", 4), ["abcd", "ef-", "gh"]);
+        assert!(wrap_text("   ", 10).is_empty());
+    }
+
+    #[test]
+    fn test_columns_avoid_widows_and_orphans() {
+        let lengths = [3, 5, 3];
+        let verses = lengths
+            .iter()
+            .enumerate()
+            .map(|(i, &words)| {
+                let text = vec!["abcdefgh"; words].join(" ");
+                Verse::new(BibleBook::Genesis, 1, i + 1, text)
+            })
+            .collect();
+        let bible = TestBible::new(vec![Book::new(
+            "gn".into(),
+            "Genesis".into(),
+            vec![Chapter::new(verses, 1)],
+        )])
+        .build();
+        let passage = bible
+            .passage(&ReferenceRange::new(
+                Reference::new(BibleBook::Genesis, 1, 1),
+                Reference::new(BibleBook::Genesis, 1, 3),
+            ))
+            .unwrap();
+
+        // Each word fills a line.
+        let options = LayoutOptions::new(10).with_verse_numbers(false);
+        let layout = passage.layout(&options.with_column_lines(4));
+        let runs: Vec<Vec<(usize, Range<usize>)>> = layout
+            .columns()
+            .iter()
+            .map(|column| column.iter().map(|r| (r.verse(), r.lines())).collect())
+            .collect();
+        // Verse 2 would leave one line at the foot of the first column, so
+        // it moves on; four lines would leave its last line alone, so two
+        // carry over. Verse 3 then moves on like verse 2.
+        assert_eq!(
+            runs,
+            [
+                vec![(0, 0..3)],
+                vec![(1, 0..3)],
+                vec![(1, 3..5)],
+                vec![(2, 0..3)],
+            ]
+        );
+        assert_eq!(layout.column_lines(2), ["abcdefgh", "abcdefgh"]);
+
+        let numbered = passage.layout(&LayoutOptions::new(12));
+        assert_eq!(
+            numbered.verses()[0].lines(),
+            ["1 abcdefgh", "abcdefgh", "abcdefgh"]
+        );
+        assert_eq!(numbered.columns().len(), 1);
+    }
+}
@@ -0,0 +1,357 @@
+//! Word-definition datasets such as Easton's Bible Dictionary or Strong's
+//! Hebrew and Greek dictionaries, for study tools alongside the text.
+//!
+//! Entries are looked up by headword or by Strong's number. Two JSON layouts
+//! are read by [`Lexicon::from_json`]: a list of entries,
+//!
+//! ```json
+//! { "name": "Easton's", "entries": [{ "headword": "Bethlehem", "definition": "House of bread..." }] }
+//! ```
+//!
+//! or entries keyed by headword or Strong's number, as in the Open Scriptures
+//! Strong's dictionaries:
+//!
+//! ```json
+//! { "H430": { "lemma": "אֱלֹהִים", "xlit": "ʼĕlôhîym", "strongs_def": "gods" } }
+//! ```
+
+use std::{collections::HashMap, fs};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use simd_json::serde::from_slice as simd_from_slice;
+
+use crate::{bible::BibleError, verse::Verse};
+
+/// A dictionary or lexicon article.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LexiconEntry {
+    headword: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    strongs: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    transliteration: Option<String>,
+    definition: String,
+}
+
+impl LexiconEntry {
+    /// Creates an entry defining `headword`.
+    pub fn new(headword: impl Into<String>, definition: impl Into<String>) -> Self {
+        LexiconEntry {
+            headword: headword.into(),
+            strongs: None,
+            transliteration: None,
+            definition: definition.into(),
+        }
+    }
+
+    /// Sets the Strong's number, such as `"H430"` or `"G26"`.
+    pub fn with_strongs(mut self, strongs: impl Into<String>) -> Self {
+        self.strongs = Some(strongs.into());
+        self
+    }
+
+    /// Sets the Latin transliteration of an original-language headword.
+    pub fn with_transliteration(mut self, transliteration: impl Into<String>) -> Self {
+        self.transliteration = Some(transliteration.into());
+        self
+    }
+
+    /// Returns the headword: an English term or an original-language lemma.
+    pub fn headword(&self) -> &str {
+        &self.headword
+    }
+
+    /// Returns the Strong's number, if the entry has one.
+    pub fn strongs(&self) -> Option<&str> {
+        self.strongs.as_deref()
+    }
+
+    /// Returns the transliteration of the headword, if known.
+    pub fn transliteration(&self) -> Option<&str> {
+        self.transliteration.as_deref()
+    }
+
+    /// Returns the definition text.
+    pub fn definition(&self) -> &str {
+        &self.definition
+    }
+}
+
+/// A collection of [`LexiconEntry`] values indexed by headword and Strong's number.
+#[derive(Debug, Clone, Default)]
+pub struct Lexicon {
+    name: String,
+    entries: Vec<LexiconEntry>,
+    by_headword: HashMap<String, usize>,
+    by_strongs: HashMap<String, usize>,
+}
+
+impl Lexicon {
+    /// Creates an empty lexicon.
+    pub fn new(name: impl Into<String>) -> Self {
+        Lexicon {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds an entry. A later entry with the same headword or Strong's
+    /// number takes precedence in lookups.
+    pub fn with_entry(mut self, entry: LexiconEntry) -> Self {
+        self.insert(entry);
+        self
+    }
+
+    fn insert(&mut self, entry: LexiconEntry) {
+        let slot = self.entries.len();
+        self.by_headword.insert(entry.headword.to_lowercase(), slot);
+        if let Some(number) = entry.strongs.as_deref().and_then(normalize_strongs) {
+            self.by_strongs.insert(number, slot);
+        }
+        self.entries.push(entry);
+    }
+
+    /// Reads a lexicon from a JSON file in either supported layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or parsed.
+    pub fn from_json(json_path: &str) -> Result<Self, BibleError> {
+        let mut file_content =
+            fs::read(json_path).map_err(|e| BibleError::load_error(json_path, e))?;
+        let file: LexiconFile =
+            simd_from_slice(&mut file_content).map_err(|e| BibleError::load_error(json_path, e))?;
+
+        Ok(match file {
+            LexiconFile::List { name, entries } => entries
+                .into_iter()
+                .fold(Lexicon::new(name), Lexicon::with_entry),
+            LexiconFile::Keyed(articles) => {
+                let mut lexicon = Lexicon::default();
+                for (key, article) in articles {
+                    lexicon.insert(article.into_entry(key));
+                }
+                lexicon
+            }
+        })
+    }
+
+    /// Returns the lexicon's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the entries in the order they were added.
+    pub fn entries(&self) -> &[LexiconEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the lexicon has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Looks up a Strong's number (`"H430"`, `"h0430"`, `"G26"`) or, failing
+    /// that, a headword, ignoring case.
+    pub fn get(&self, key: &str) -> Option<&LexiconEntry> {
+        self.get_strongs(key).or_else(|| self.get_headword(key))
+    }
+
+    /// Looks up an entry by headword, ignoring case.
+    pub fn get_headword(&self, headword: &str) -> Option<&LexiconEntry> {
+        let slot = self.by_headword.get(&headword.trim().to_lowercase())?;
+        self.entries.get(*slot)
+    }
+
+    /// Looks up an entry by Strong's number, ignoring case and leading zeros.
+    pub fn get_strongs(&self, number: &str) -> Option<&LexiconEntry> {
+        let slot = self.by_strongs.get(&normalize_strongs(number)?)?;
+        self.entries.get(*slot)
+    }
+
+    /// Returns the entries for a verse, in text order without repeats.
+    ///
+    /// Strong's tags in the verse text, as in `"beginning<H7225>"`, resolve
+    /// by number; other words resolve by headword, so untagged text finds
+    /// dictionary articles such as "Bethlehem". Multi-word headwords are not
+    /// matched.
+    pub fn entries_for_verse(&self, verse: &Verse) -> Vec<&LexiconEntry> {
+        let mut slots: Vec<usize> = Vec::new();
+        for word in verse.words() {
+            let slot = match normalize_strongs(word) {
+                Some(number) => self.by_strongs.get(&number),
+                None => self.by_headword.get(&word.to_lowercase()),
+            };
+            if let Some(&slot) = slot {
+                if !slots.contains(&slot) {
+                    slots.push(slot);
+                }
+            }
+        }
+        slots.into_iter().map(|slot| &self.entries[slot]).collect()
+    }
+}
+
+/// Returns the canonical form of a Strong's number (`"H430"`), or `None` if
+/// `text` is not one.
+fn normalize_strongs(text: &str) -> Option<String> {
+    let text = text.trim();
+    let prefix = text.chars().next()?.to_ascii_uppercase();
+    if prefix != 'H' && prefix != 'G' {
+        return None;
+    }
+    let rest = &text[1..];
+    let digits_end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (digits, suffix) = rest.split_at(digits_end);
+    // One optional letter marks a sub-entry ("H1234a").
+    if digits.is_empty() || suffix.len() > 1 || !suffix.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let number = match digits.trim_start_matches('0') {
+        "" => "0",
+        number => number,
+    };
+    Some(format!(
+        "{}{}{}",
+        prefix,
+        number,
+        suffix.to_ascii_lowercase()
+    ))
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LexiconFile {
+    List {
+        #[serde(default)]
+        name: String,
+        entries: Vec<LexiconEntry>,
+    },
+    Keyed(IndexMap<String, KeyedArticle>),
+}
+
+/// An article keyed by headword or Strong's number.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KeyedArticle {
+    Definition(String),
+    Fields {
+        #[serde(default)]
+        lemma: Option<String>,
+        #[serde(default)]
+        xlit: Option<String>,
+        #[serde(default, alias = "strongs_def")]
+        definition: Option<String>,
+        #[serde(default)]
+        kjv_def: Option<String>,
+    },
+}
+
+impl KeyedArticle {
+    fn into_entry(self, key: String) -> LexiconEntry {
+        let strongs = normalize_strongs(&key).map(|_| key.clone());
+        let mut entry = match self {
+            KeyedArticle::Definition(definition) => LexiconEntry::new(key, definition),
+            KeyedArticle::Fields {
+                lemma,
+                xlit,
+                definition,
+                kjv_def,
+            } => {
+                let definition = definition.or(kjv_def).unwrap_or_default();
+                let mut entry = LexiconEntry::new(lemma.unwrap_or(key), definition);
+                entry.transliteration = xlit;
+                entry
+            }
+        };
+        entry.strongs = strongs;
+        entry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    #[test]
+    fn test_lookup_and_verse_entries() {
+        let lexicon = Lexicon::new("test")
+            .with_entry(LexiconEntry::new("Bethlehem", "House of bread."))
+            .with_entry(LexiconEntry::new("אֱלֹהִים", "God, gods.").with_strongs("H0430"))
+            .with_entry(LexiconEntry::new("רֵאשִׁית", "Beginning.").with_strongs("H7225"));
+
+        assert_eq!(lexicon.get("h430").unwrap().definition(), "God, gods.");
+        assert_eq!(lexicon.get("BETHLEHEM").unwrap().headword(), "Bethlehem");
+        assert!(lexicon.get("H9999").is_none());
+
+        let tagged = Verse::new(
+            BibleBook::Genesis,
+            1,
+            1,
+            "In the beginning<H7225> God<H430> created; God<H430>".into(),
+        );
+        let found: Vec<&str> = lexicon
+            .entries_for_verse(&tagged)
+            .iter()
+            .map(|e| e.definition())
+            .collect();
+        assert_eq!(found, ["Beginning.", "God, gods."]);
+
+        let plain = Verse::new(
+            BibleBook::Micah,
+            5,
+            2,
+            "But thou, Bethlehem Ephratah".into(),
+        );
+        assert_eq!(lexicon.entries_for_verse(&plain).len(), 1);
+    }
+
+    #[test]
+    fn test_load_layouts() {
+        assert_eq!(normalize_strongs("g0026").as_deref(), Some("G26"));
+        assert_eq!(normalize_strongs("H1234A").as_deref(), Some("H1234a"));
+        assert_eq!(normalize_strongs("Hebrew"), None);
+        assert_eq!(normalize_strongs("H"), None);
+
+        let dir = std::env::temp_dir();
+        let list = dir.join("bible_io_lexicon_list.json");
+        fs::write(
+            &list,
+            r#"{"name": "Easton's", "entries": [{"headword": "Abba", "definition": "Father."}]}"#,
+        )
+        .unwrap();
+        let lexicon = Lexicon::from_json(list.to_str().unwrap()).unwrap();
+        assert_eq!(lexicon.name(), "Easton's");
+        assert_eq!(lexicon.get("abba").unwrap().definition(), "Father.");
+
+        let keyed = dir.join("bible_io_lexicon_keyed.json");
+        fs::write(
+            &keyed,
+            r#"{"G26": {"lemma": "ἀγάπη", "xlit": "agápē", "strongs_def": "love"},
+                "Zion": "A hill in Jerusalem."}"#,
+        )
+        .unwrap();
+        let lexicon = Lexicon::from_json(keyed.to_str().unwrap()).unwrap();
+        let agape = lexicon.get("G26").unwrap();
+        assert_eq!(
+            (
+                agape.headword(),
+                agape.transliteration(),
+                agape.definition()
+            ),
+            ("ἀγάπη", Some("agápē"), "love")
+        );
+        assert_eq!(lexicon.get("zion").unwrap().strongs(), None);
+        fs::remove_file(&list).ok();
+        fs::remove_file(&keyed).ok();
+    }
+}
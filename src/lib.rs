@@ -2,18 +2,148 @@
 //!
 //! This library provides structures and functionality for parsing and working with Bible text data,
 //! including books, chapters, and verses.
+//!
+//! # Ordering
+//!
+//! Every public iteration and output order is deterministic, so snapshot
+//! tests and generated exports are reproducible:
+//!
+//! - books follow the data file ([`Bible::books`]), or canonical order after
+//!   [`Bible::canonicalize`] and in [`Bible::books_in_canonical_order`];
+//! - chapters and verses follow the data file, or ascending number after
+//!   [`Bible::canonicalize`];
+//! - search results are in canonical book order, then file order within a
+//!   book; embedding and popularity rankings break score ties by position
+//!   or reference;
+//! - n-grams, collocations, postings exports and JSON outputs sort ties and
+//!   keys explicitly rather than following hash map order.
 
+pub mod annotation;
+pub mod attribution;
 pub mod bible;
 pub mod bible_books_enum;
 pub mod book;
+pub mod book_id;
 pub mod chapter;
+pub mod chunk;
+pub mod columns;
+pub mod convert;
+pub mod corpus;
+pub mod date;
+pub mod dbl;
+pub mod devotional;
+#[cfg(feature = "docx")]
+pub mod docx;
+pub mod dto;
+pub mod embeddings;
+pub mod entities;
+pub mod excerpt;
+pub mod format;
+pub mod headings;
+pub mod instrumentation;
+pub mod json_schema;
+mod keyed_text;
+pub mod layout;
+pub mod lexicon;
+pub mod library;
+pub mod load_options;
+pub mod load_report;
+pub mod memorization;
+pub mod notifications;
+pub mod passage;
+pub mod patch;
+#[cfg(feature = "pdf")]
+pub mod pdf;
+pub mod plain_text;
+pub mod popularity;
+pub mod provenance;
+mod query_cache;
+pub mod quiz;
+pub mod reference;
+pub mod reference_parser;
+pub mod reference_set;
+pub mod render;
+pub mod render_cache;
+pub mod search_export;
 pub mod search_index;
+pub mod search_results;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod share;
+pub mod similarity;
+mod snapshot;
+pub mod speech;
+pub mod summaries;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod text_check;
+pub mod text_policy;
+pub mod transliteration;
+pub mod truncate;
+pub mod typography;
+pub mod usx;
 pub mod verse;
+pub mod verse_ref;
+pub mod web;
 
 // Re-export main types for easier access
-pub use bible::{Bible, BibleError};
-pub use bible_books_enum::BibleBook;
-pub use book::Book;
-pub use chapter::Chapter;
-pub use search_index::SearchIndex;
-pub use verse::Verse;
+pub use annotation::{AnchorMatch, TextAnchor};
+pub use attribution::{Attribution, CompositionPeriod};
+pub use bible::{Bible, BibleError, BibleMetadata, MergePolicy, TextDirection};
+pub use bible_books_enum::{
+    AbbreviationStyle, BibleBook, Canon, CanonProfile, DeuterocanonPlacement, Division,
+};
+pub use book::{Book, BookChapter, OutlineSection};
+pub use book_id::BookId;
+pub use chapter::{Chapter, ChapterItem, Heading, Paragraph, ParagraphKind, VerseNumbering};
+pub use chunk::{Chunk, ChunkOptions, ChunkStrategy};
+pub use columns::VerseColumns;
+pub use convert::{convert, ConversionSummary, DroppedFeature, SourceSpec, TargetSpec};
+pub use corpus::{Collocation, Keyword, NGram, TermWeights, VocabularyComparison};
+pub use date::Date;
+pub use devotional::{DevotionalPlan, DevotionalSchedule, ScheduleState};
+pub use dto::{Pagination, PassageDto, SearchResponseDto, VerseDto};
+pub use embeddings::{EmbeddingProvider, SemanticIndex};
+pub use entities::{Entity, EntityKind, EntitySet};
+pub use excerpt::{Excerpt, ExcerptPolicy, ExcerptViolation};
+pub use format::{ChapterFormat, VerseFormat, VerseNumberStyle};
+pub use headings::HeadingSet;
+pub use instrumentation::{InstrumentationHook, Stage, StageEvent, StageSpan, StageTiming};
+pub use json_schema::JsonSchema;
+pub use layout::{wrap_text, Layout, LayoutOptions, LineRun, VerseLayout};
+pub use lexicon::{Lexicon, LexiconEntry};
+pub use library::BibleLibrary;
+pub use load_options::{
+    CollapseWhitespace, DecodeEntities, DecodeEscapes, LoadOptions, LoadProgress, StripMarkup,
+    TextProcessor,
+};
+pub use load_report::{LoadIssue, LoadIssueKind, LoadReport};
+pub use memorization::{Cloze, ReviewLog, ReviewState, VersePack};
+pub use notifications::{DailyNotifications, FixedOffset, Notification, TimeOfDay, TimeZone};
+pub use passage::Passage;
+pub use patch::Patch;
+pub use plain_text::PlainTextFormat;
+pub use popularity::PopularityTable;
+pub use provenance::Provenance;
+pub use quiz::{
+    Difficulty, FillInBlankQuestion, QuizGenerator, QuizOptions, QuizQuestion, WhichBookQuestion,
+};
+pub use reference::{CitationStyle, Reference, ReferenceRange};
+pub use reference_parser::ReferenceParsing;
+pub use reference_set::ReferenceSet;
+pub use render_cache::{RenderCacheConfig, RenderFormat};
+pub use search_export::SearchExportFormat;
+pub use search_index::{SearchBackend, SearchIndex, SearchIndexOptions, SearchIter};
+pub use search_results::{BookHits, ChapterHits, SearchResults};
+pub use share::{ShareCard, ShareOptions};
+pub use speech::SpeechOptions;
+pub use summaries::ChapterSummarySet;
+pub use text_check::{TextIssue, TextIssueKind};
+pub use text_policy::TextPolicy;
+pub use transliteration::{GreekTransliterator, HebrewTransliterator, Transliterator};
+pub use truncate::TruncatedPassage;
+pub use typography::{NormalizeQuotes, QuoteStyle};
+pub use usx::UsxError;
+pub use verse::{SpanKind, TextSpan, Verse};
+pub use verse_ref::{ChapterRef, VerseRef};
+pub use web::{PassageResponse, ReferenceParam, VerseResponse};
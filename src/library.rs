@@ -0,0 +1,234 @@
+//! A collection of translations loaded together.
+//!
+//! Apps that ship several translations keep them as JSON files in one
+//! directory. [`BibleLibrary::load_dir_parallel`] parses them on all
+//! available cores, so startup takes about as long as the largest file
+//! rather than the sum of all of them.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use crate::bible::{Bible, BibleError};
+
+/// Translations addressable by their id.
+#[derive(Debug, Default)]
+pub struct BibleLibrary {
+    bibles: Vec<Bible>,
+}
+
+impl BibleLibrary {
+    /// Creates an empty library.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a translation, replacing any with the same id.
+    pub fn with_bible(mut self, bible: Bible) -> Self {
+        self.insert(bible);
+        self
+    }
+
+    /// Adds a translation, returning the one it replaced with the same id.
+    pub fn insert(&mut self, bible: Bible) -> Option<Bible> {
+        match self.bibles.iter().position(|b| b.id() == bible.id()) {
+            Some(at) => Some(std::mem::replace(&mut self.bibles[at], bible)),
+            None => {
+                self.bibles.push(bible);
+                None
+            }
+        }
+    }
+
+    /// Loads every `.json` file in `dir`, one after another, in file name
+    /// order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the directory cannot be read or
+    /// a file fails to load.
+    pub fn load_dir(dir: impl AsRef<Path>) -> Result<Self, BibleError> {
+        let mut library = Self::new();
+        for path in translation_files(dir.as_ref())? {
+            library.insert(Bible::new_from_json(&path.to_string_lossy())?);
+        }
+        Ok(library)
+    }
+
+    /// Loads every `.json` file in `dir` like [`BibleLibrary::load_dir`],
+    /// parsing files on as many threads as there are cores.
+    ///
+    /// The result is the same as loading in sequence: translations are kept
+    /// in file name order, so a later file replaces an earlier one with the
+    /// same id.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the directory cannot be read or
+    /// a file fails to load. Loading stops at the first failure, and the
+    /// error of the first failing file in name order is returned.
+    pub fn load_dir_parallel(dir: impl AsRef<Path>) -> Result<Self, BibleError> {
+        let paths = translation_files(dir.as_ref())?;
+        let workers = thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .min(paths.len());
+        let next = AtomicUsize::new(0);
+        let failed = AtomicUsize::new(usize::MAX);
+        let loaded: Mutex<Vec<Option<Result<Bible, BibleError>>>> =
+            Mutex::new(paths.iter().map(|_| None).collect());
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| loop {
+                    let slot = next.fetch_add(1, Ordering::Relaxed);
+                    // Files after a failure are skipped; earlier ones still
+                    // load so the reported error is the first by name.
+                    if slot >= paths.len() || slot > failed.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    let result = Bible::new_from_json(&paths[slot].to_string_lossy());
+                    if result.is_err() {
+                        failed.fetch_min(slot, Ordering::Relaxed);
+                    }
+                    loaded.lock().unwrap_or_else(|e| e.into_inner())[slot] = Some(result);
+                });
+            }
+        });
+
+        let mut library = Self::new();
+        let loaded = loaded.into_inner().unwrap_or_else(|e| e.into_inner());
+        for result in loaded.into_iter().flatten() {
+            library.insert(result?);
+        }
+        Ok(library)
+    }
+
+    /// Returns the translation with the given id.
+    pub fn get(&self, id: &str) -> Option<&Bible> {
+        self.bibles.iter().find(|bible| bible.id() == id)
+    }
+
+    /// Returns the translation with the given id for changes such as
+    /// [`Bible::set_verse_text`].
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut Bible> {
+        self.bibles.iter_mut().find(|bible| bible.id() == id)
+    }
+
+    /// Returns the translations in the order they were added.
+    pub fn bibles(&self) -> &[Bible] {
+        &self.bibles
+    }
+
+    /// Iterates over the translation ids.
+    pub fn ids(&self) -> impl Iterator<Item = &str> + '_ {
+        self.bibles.iter().map(Bible::id)
+    }
+
+    /// Returns the number of translations.
+    pub fn len(&self) -> usize {
+        self.bibles.len()
+    }
+
+    /// Returns `true` if the library holds no translations.
+    pub fn is_empty(&self) -> bool {
+        self.bibles.is_empty()
+    }
+}
+
+/// Lists the `.json` files directly inside `dir`, sorted by name.
+fn translation_files(dir: &Path) -> Result<Vec<PathBuf>, BibleError> {
+    let error = |e: io::Error| BibleError::load_error(&dir.to_string_lossy(), e);
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(error)? {
+        let path = entry.map_err(error)?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_library(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(&dir).unwrap();
+        for (file, contents) in files {
+            fs::write(dir.join(file), contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_load_dir_parallel_matches_sequential() {
+        let translation = |id: &str, text: &str| {
+            format!(
+                r#"{{"id": "{id}", "name": "{id}", "books": {{"gn": {{"name": "Genesis", "chapters": [["{text}"]]}}}}}}"#
+            )
+        };
+        let dir = write_library(
+            "bible_io_library_test",
+            &[
+                ("b.json", &translation("web", "In the beginning, God")),
+                ("a.json", &translation("kjv", "In the beginning God")),
+                ("c.json", &translation("asv", "In the beginning God")),
+                ("notes.txt", "not a translation"),
+            ],
+        );
+
+        let parallel = BibleLibrary::load_dir_parallel(&dir).unwrap();
+        let sequential = BibleLibrary::load_dir(&dir).unwrap();
+        fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(parallel.ids().collect::<Vec<_>>(), ["kjv", "web", "asv"]);
+        assert_eq!(
+            parallel.ids().collect::<Vec<_>>(),
+            sequential.ids().collect::<Vec<_>>()
+        );
+        assert!(parallel.get("web").is_some());
+        assert!(parallel.get("niv").is_none());
+    }
+
+    #[test]
+    fn test_load_dir_parallel_reports_failures() {
+        let dir = write_library(
+            "bible_io_library_error_test",
+            &[("a.json", r#"{"books": {}}"#), ("b.json", "{ not json")],
+        );
+        let result = BibleLibrary::load_dir_parallel(&dir);
+        fs::remove_dir_all(&dir).ok();
+
+        match result {
+            Err(BibleError::LoadError { path, .. }) => assert!(path.ends_with("b.json")),
+            other => panic!("expected a load error, got {:?}", other.map(|l| l.len())),
+        }
+        assert!(BibleLibrary::load_dir_parallel("/nonexistent/bibles").is_err());
+    }
+
+    #[test]
+    fn test_shared_library_can_be_searched() {
+        use crate::{bible_books_enum::BibleBook, test_support::*};
+
+        let library = BibleLibrary::new()
+            .with_bible(TestBible::new(vec![one_verse_book(BibleBook::John)]).build());
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    let bible = library.get("id").unwrap();
+                    assert_eq!(bible.search("text").len(), 1);
+                });
+            }
+        });
+        assert_eq!(library.get("id").unwrap().indexed_book_count(), 1);
+    }
+}
@@ -0,0 +1,434 @@
+//! Options for loading a Bible, including the verse text pipeline.
+//!
+//! [`LoadOptions`] selects the [`JsonSchema`] and a chain of
+//! [`TextProcessor`]s applied to each verse as it is loaded, in the order
+//! they were added. Processors see the text as stored in the file, before
+//! translator-supplied words marked with `{}` are recorded, so a processor
+//! may also rewrite or remove the braces.
+//!
+//! Any `Fn(&str) -> String` closure is a processor, next to the built-in
+//! [`StripMarkup`], [`DecodeEntities`], [`DecodeEscapes`],
+//! [`NormalizeQuotes`](crate::NormalizeQuotes) and [`CollapseWhitespace`].
+//!
+//! [`LoadOptions::on_progress`] reports [`LoadProgress`] as books are built
+//! and, with [`LoadOptions::with_search_index`], as verses are indexed, so
+//! an app can show a progress bar while a large translation loads.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use phf::phf_map;
+
+use crate::{bible::BibleFileRoot, instrumentation::InstrumentationHook, json_schema::JsonSchema};
+
+/// Rewrites the text of each verse at load time.
+pub trait TextProcessor: Send + Sync {
+    /// Returns the processed text of one verse.
+    fn process(&self, text: &str) -> String;
+}
+
+impl<F> TextProcessor for F
+where
+    F: Fn(&str) -> String + Send + Sync,
+{
+    fn process(&self, text: &str) -> String {
+        self(text)
+    }
+}
+
+/// Removes HTML or XML tags such as `<i>` and decodes entities as
+/// [`DecodeEntities`] does.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StripMarkup;
+
+impl TextProcessor for StripMarkup {
+    fn process(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find('<') {
+            out.push_str(&rest[..start]);
+            let tag = &rest[start + 1..];
+            match tag.find('>') {
+                Some(end)
+                    if tag
+                        .trim_start_matches('/')
+                        .starts_with(|c: char| c.is_ascii_alphabetic()) =>
+                {
+                    rest = &tag[end + 1..];
+                }
+                _ => {
+                    out.push('<');
+                    rest = tag;
+                }
+            }
+        }
+        out.push_str(rest);
+        decode_entities(&out)
+    }
+}
+
+/// Decodes HTML character references: numeric ones such as `&#8217;` and
+/// `&#x2019;`, and named ones such as `&amp;`, `&rsquo;`, `&mdash;` and the
+/// accented letters. Each reference is decoded once, so `&amp;lt;` becomes
+/// `&lt;`.
+///
+/// Unknown names are kept as written, and [`Bible::check_text`] reports
+/// them as [`TextIssueKind::HtmlEntity`].
+///
+/// [`Bible::check_text`]: crate::Bible::check_text
+/// [`TextIssueKind::HtmlEntity`]: crate::TextIssueKind::HtmlEntity
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DecodeEntities;
+
+impl TextProcessor for DecodeEntities {
+    fn process(&self, text: &str) -> String {
+        decode_entities(text)
+    }
+}
+
+/// Decodes backslash escapes left over from text that was escaped twice,
+/// such as `\"`, `\'`, `\\` and `\u2019`. The whitespace escapes `\n`,
+/// `\r` and `\t` become spaces.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DecodeEscapes;
+
+impl TextProcessor for DecodeEscapes {
+    fn process(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                out.push(c);
+                continue;
+            }
+            let rest = chars.as_str();
+            let decoded = match rest.chars().next() {
+                Some(e @ ('"' | '\'' | '\\' | '/')) => Some((e, 1)),
+                Some('n' | 'r' | 't') => Some((' ', 1)),
+                Some('u') => rest
+                    .get(1..5)
+                    .filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+                    .and_then(|hex| char::from_u32(u32::from_str_radix(hex, 16).ok()?))
+                    .map(|c| (c, 5)),
+                _ => None,
+            };
+            match decoded {
+                Some((decoded, len)) => {
+                    out.push(decoded);
+                    chars = rest[len..].chars();
+                }
+                None => out.push(c),
+            }
+        }
+        out
+    }
+}
+
+/// Named character references likely in scripture text.
+static NAMED_ENTITIES: phf::Map<&'static str, char> = phf_map! {
+    "amp" => '&', "lt" => '<', "gt" => '>', "quot" => '"', "apos" => '\'',
+    "nbsp" => '\u{a0}', "shy" => '\u{ad}', "ensp" => '\u{2002}', "emsp" => '\u{2003}',
+    "thinsp" => '\u{2009}', "zwnj" => '\u{200c}', "zwj" => '\u{200d}',
+    "lrm" => '\u{200e}', "rlm" => '\u{200f}',
+    "ndash" => '–', "mdash" => '—', "hellip" => '…', "middot" => '·', "bull" => '•',
+    "lsquo" => '‘', "rsquo" => '’', "sbquo" => '‚', "ldquo" => '“', "rdquo" => '”',
+    "bdquo" => '„', "laquo" => '«', "raquo" => '»', "lsaquo" => '‹', "rsaquo" => '›',
+    "prime" => '′', "Prime" => '″', "dagger" => '†', "Dagger" => '‡',
+    "para" => '¶', "sect" => '§', "copy" => '©', "reg" => '®', "trade" => '™',
+    "deg" => '°', "plusmn" => '±', "times" => '×', "divide" => '÷',
+    "iexcl" => '¡', "iquest" => '¿',
+    "Agrave" => 'À', "Aacute" => 'Á', "Acirc" => 'Â', "Atilde" => 'Ã', "Auml" => 'Ä',
+    "Aring" => 'Å', "AElig" => 'Æ', "Ccedil" => 'Ç', "Egrave" => 'È', "Eacute" => 'É',
+    "Ecirc" => 'Ê', "Euml" => 'Ë', "Igrave" => 'Ì', "Iacute" => 'Í', "Icirc" => 'Î',
+    "Iuml" => 'Ï', "Ntilde" => 'Ñ', "Ograve" => 'Ò', "Oacute" => 'Ó', "Ocirc" => 'Ô',
+    "Otilde" => 'Õ', "Ouml" => 'Ö', "Oslash" => 'Ø', "Ugrave" => 'Ù', "Uacute" => 'Ú',
+    "Ucirc" => 'Û', "Uuml" => 'Ü', "Yacute" => 'Ý', "szlig" => 'ß',
+    "agrave" => 'à', "aacute" => 'á', "acirc" => 'â', "atilde" => 'ã', "auml" => 'ä',
+    "aring" => 'å', "aelig" => 'æ', "ccedil" => 'ç', "egrave" => 'è', "eacute" => 'é',
+    "ecirc" => 'ê', "euml" => 'ë', "igrave" => 'ì', "iacute" => 'í', "icirc" => 'î',
+    "iuml" => 'ï', "ntilde" => 'ñ', "ograve" => 'ò', "oacute" => 'ó', "ocirc" => 'ô',
+    "otilde" => 'õ', "ouml" => 'ö', "oslash" => 'ø', "ugrave" => 'ù', "uacute" => 'ú',
+    "ucirc" => 'û', "uuml" => 'ü', "yacute" => 'ý', "yuml" => 'ÿ',
+    "OElig" => 'Œ', "oelig" => 'œ', "Scaron" => 'Š', "scaron" => 'š',
+};
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let decoded = after
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((entity_char(&after[..end])?, end)));
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn entity_char(name: &str) -> Option<char> {
+    let code = match name.strip_prefix('#') {
+        None => return NAMED_ENTITIES.get(name).copied(),
+        Some(hex) if hex.starts_with(['x', 'X']) => u32::from_str_radix(&hex[1..], 16).ok()?,
+        Some(decimal) => decimal.parse().ok()?,
+    };
+    char::from_u32(code).filter(|&c| c != '\0')
+}
+
+/// Collapses runs of whitespace into single spaces and trims both ends.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CollapseWhitespace;
+
+impl TextProcessor for CollapseWhitespace {
+    fn process(&self, text: &str) -> String {
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// A step of loading reported to [`LoadOptions::on_progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LoadProgress {
+    /// `done` of the file's `total` books have been built.
+    BooksParsed { done: usize, total: usize },
+    /// `done` of the Bible's `total` verses have been added to the search
+    /// index, reported once per book.
+    VersesIndexed { done: usize, total: usize },
+}
+
+type ProgressCallback = Arc<dyn Fn(LoadProgress) + Send + Sync>;
+
+/// Settings for [`Bible::new_from_json_with_options`](crate::Bible::new_from_json_with_options).
+#[derive(Clone, Default)]
+pub struct LoadOptions {
+    schema: JsonSchema,
+    processors: Vec<Arc<dyn TextProcessor>>,
+    search_index: bool,
+    on_progress: Option<ProgressCallback>,
+    cache_dir: Option<PathBuf>,
+    bracketed_superscriptions: bool,
+    instrumentation_hook: Option<InstrumentationHook>,
+}
+
+impl LoadOptions {
+    /// Creates options that detect the layout and keep the text as stored.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the layout of the file.
+    pub fn with_schema(mut self, schema: JsonSchema) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// Appends `processor` to the text pipeline.
+    pub fn with_processor(mut self, processor: impl TextProcessor + 'static) -> Self {
+        self.processors.push(Arc::new(processor));
+        self
+    }
+
+    /// Sets whether the search index of every book is built while loading,
+    /// as by [`Bible::warm_index`](crate::Bible::warm_index), instead of on
+    /// the first search. Off by default.
+    pub fn with_search_index(mut self, search_index: bool) -> Self {
+        self.search_index = search_index;
+        self
+    }
+
+    /// Calls `callback` after each book is built and, when the search index
+    /// is built while loading, after each book is indexed.
+    pub fn on_progress(mut self, callback: impl Fn(LoadProgress) + Send + Sync + 'static) -> Self {
+        self.on_progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Caches a binary snapshot of each parsed file in `dir`, so loading
+    /// the same file again skips JSON parsing.
+    ///
+    /// Snapshots are named after a hash of the file's bytes and layout, so
+    /// an edited file gets a new one and stale snapshots are never read;
+    /// old ones may be deleted at any time. Text processors still run on
+    /// every load. A snapshot that cannot be written is skipped.
+    pub fn with_cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets whether a Psalm with no listed superscription takes a leading
+    /// `[...]` of verse 1 as its title, as in files that prefix titles such
+    /// as "[A Psalm of David.]" to the text. Off by default, since brackets
+    /// also mark alternate readings; a verse 1 holding only the bracketed
+    /// text is kept as it is.
+    pub fn with_bracketed_superscriptions(mut self, bracketed_superscriptions: bool) -> Self {
+        self.bracketed_superscriptions = bracketed_superscriptions;
+        self
+    }
+
+    /// Sends the [`StageEvent`](crate::instrumentation::StageEvent)s of
+    /// loads with these options, including indexing while loading, to
+    /// `hook` instead of the hook installed with
+    /// [`set_instrumentation_hook`](crate::instrumentation::set_instrumentation_hook).
+    #[cfg(feature = "instrumentation")]
+    pub fn with_instrumentation_hook(
+        mut self,
+        hook: impl Fn(&crate::instrumentation::StageEvent<'_>) + Send + Sync + 'static,
+    ) -> Self {
+        self.instrumentation_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Returns the layout of the file.
+    pub fn schema(&self) -> JsonSchema {
+        self.schema
+    }
+
+    /// Returns `true` if the search index is built while loading.
+    pub fn search_index(&self) -> bool {
+        self.search_index
+    }
+
+    /// Returns `true` if bracketed Psalm titles are taken from verse 1.
+    pub fn bracketed_superscriptions(&self) -> bool {
+        self.bracketed_superscriptions
+    }
+
+    /// Returns the snapshot cache directory, if caching is enabled.
+    pub fn cache_dir(&self) -> Option<&Path> {
+        self.cache_dir.as_deref()
+    }
+
+    pub(crate) fn instrumentation_hook(&self) -> Option<&InstrumentationHook> {
+        self.instrumentation_hook.as_ref()
+    }
+
+    pub(crate) fn report(&self, progress: LoadProgress) {
+        if let Some(callback) = &self.on_progress {
+            callback(progress);
+        }
+    }
+
+    /// Runs the pipeline over every verse of `root`. Empty verses mark
+    /// omitted verses and are left alone.
+    pub(crate) fn process(&self, root: &mut BibleFileRoot) {
+        if self.processors.is_empty() {
+            return;
+        }
+        let verses = root
+            .books
+            .values_mut()
+            .flat_map(|entry| entry.chapters.iter_mut().flatten());
+        for verse in verses.filter(|verse| !verse.is_empty()) {
+            for processor in &self.processors {
+                *verse = processor.process(verse);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for LoadOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoadOptions")
+            .field("schema", &self.schema)
+            .field("processors", &self.processors.len())
+            .field("search_index", &self.search_index)
+            .field("on_progress", &self.on_progress.is_some())
+            .field("cache_dir", &self.cache_dir)
+            .field("bracketed_superscriptions", &self.bracketed_superscriptions)
+            .field("instrumentation_hook", &self.instrumentation_hook.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bible::Bible, bible_books_enum::BibleBook, verse::SpanKind};
+
+    #[test]
+    fn test_built_in_processors() {
+        assert_eq!(
+            StripMarkup.process("<p>Abram &amp; <i>Sarai</i> &lt;3</p>"),
+            "Abram & Sarai <3"
+        );
+        assert_eq!(StripMarkup.process("a < b"), "a < b");
+        assert_eq!(
+            DecodeEntities.process("the LORD&#8217;s &ldquo;house&#x201D; &amp;lt; &bogus; &"),
+            "the LORD’s “house” &lt; &bogus; &"
+        );
+        assert_eq!(
+            DecodeEscapes.process(r#"\"Fear not,\" he said\u2019s \\ \q"#),
+            r#""Fear not," he said’s \ \q"#
+        );
+        assert_eq!(CollapseWhitespace.process(" a  b\t c "), "a b c");
+    }
+
+    #[test]
+    fn test_pipeline_runs_in_order_before_supplied_words() {
+        let path = std::env::temp_dir().join("bible_io_load_options_test.json");
+        std::fs::write(
+            &path,
+            r#"{"books": {"gn": {"name": "Genesis", "chapters": [["<b>In</b>  the {beginning}"]]}}}"#,
+        )
+        .unwrap();
+        let options = LoadOptions::new()
+            .with_processor(StripMarkup)
+            .with_processor(CollapseWhitespace)
+            .with_processor(|text: &str| text.replace("the", "THE"));
+        let bible = Bible::new_from_json_with_options(path.to_str().unwrap(), &options);
+        std::fs::remove_file(&path).ok();
+
+        let verse = bible
+            .unwrap()
+            .get_verse(BibleBook::Genesis, 1, 1)
+            .unwrap()
+            .clone();
+        assert_eq!(verse.text(), "In THE beginning");
+        assert_eq!(verse.spans()[1].kind, SpanKind::SuppliedWords);
+    }
+
+    #[test]
+    fn test_progress_reports_books_and_indexed_verses() {
+        let path = std::env::temp_dir().join("bible_io_load_progress_test.json");
+        std::fs::write(
+            &path,
+            r#"{"books": {
+                "gn": {"name": "Genesis", "chapters": [["In the beginning", "And the earth"]]},
+                "ex": {"name": "Exodus", "chapters": [["Now these are the names"]]}
+            }}"#,
+        )
+        .unwrap();
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let options = LoadOptions::new()
+            .with_search_index(true)
+            .on_progress(move |progress| sink.lock().unwrap().push(progress));
+        let bible = Bible::new_from_json_with_options(path.to_str().unwrap(), &options);
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(bible.unwrap().indexed_book_count(), 2);
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                LoadProgress::BooksParsed { done: 1, total: 2 },
+                LoadProgress::BooksParsed { done: 2, total: 2 },
+                LoadProgress::VersesIndexed { done: 2, total: 3 },
+                LoadProgress::VersesIndexed { done: 3, total: 3 },
+            ]
+        );
+    }
+}
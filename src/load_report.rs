@@ -0,0 +1,546 @@
+//! Tolerant loading that records problems in the data instead of failing.
+//!
+//! Community-maintained Bible files often have gaps: a missing translation
+//! name, a chapter left empty, a verse stored as `null`. The strict loaders
+//! reject such files at the first problem; [`Bible::new_from_json_with_report`]
+//! loads everything it can and lists each anomaly in a [`LoadReport`].
+
+use std::{collections::BTreeMap, fmt, fs, str::FromStr};
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use simd_json::{prelude::*, tape::Value, value::lazy, BorrowedValue};
+
+use crate::{
+    bible::{check_bridges, Bible, BibleError, BibleFileRoot, BibleMetadata, FileDataEntry},
+    bible_books_enum::BibleBook,
+    json_schema::{self, is_valid_number, JsonSchema},
+};
+
+/// What is wrong with one part of a Bible file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LoadIssueKind {
+    /// A field is absent; a default value was used.
+    MissingField(&'static str),
+    /// A field has the wrong type or shape and was ignored.
+    InvalidField {
+        field: &'static str,
+        message: String,
+    },
+    /// A book entry is not an object and was skipped.
+    InvalidBook,
+    /// A chapter is `null` or not a list of verses; it was loaded empty.
+    InvalidChapter,
+    /// A chapter has no verses.
+    EmptyChapter,
+    /// A verse is `null`; it was loaded as empty text.
+    NullVerse,
+    /// A verse is not a string; it was loaded as empty text.
+    InvalidVerse,
+    /// A chapter or verse key is not a positive integer; its content was skipped.
+    InvalidKey(String),
+    /// A verse bridge does not fit the chapter's verses and was ignored.
+    InvalidBridge {
+        first: usize,
+        last: usize,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for LoadIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadIssueKind::MissingField(field) => write!(f, "missing field '{}'", field),
+            LoadIssueKind::InvalidField { field, message } => {
+                write!(f, "invalid field '{}': {}", field, message)
+            }
+            LoadIssueKind::InvalidBook => f.write_str("book entry is not an object"),
+            LoadIssueKind::InvalidChapter => f.write_str("chapter is not a list of verses"),
+            LoadIssueKind::EmptyChapter => f.write_str("chapter has no verses"),
+            LoadIssueKind::NullVerse => f.write_str("verse is null"),
+            LoadIssueKind::InvalidVerse => f.write_str("verse is not a string"),
+            LoadIssueKind::InvalidKey(key) => {
+                write!(f, "key '{}' is not a positive integer", key)
+            }
+            LoadIssueKind::InvalidBridge {
+                first,
+                last,
+                reason,
+            } => write!(f, "bridge {}-{} {}", first, last, reason),
+        }
+    }
+}
+
+/// An anomaly found while loading, with the book, chapter, and verse it
+/// occurred in where applicable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadIssue {
+    book: Option<String>,
+    chapter: Option<usize>,
+    verse: Option<usize>,
+    kind: LoadIssueKind,
+}
+
+impl LoadIssue {
+    /// Returns the book key from the file, or `None` for translation-level issues.
+    pub fn book(&self) -> Option<&str> {
+        self.book.as_deref()
+    }
+
+    /// Returns the 1-based chapter number, if the issue is within a chapter.
+    pub fn chapter(&self) -> Option<usize> {
+        self.chapter
+    }
+
+    /// Returns the 1-based verse number, if the issue concerns a single verse.
+    pub fn verse(&self) -> Option<usize> {
+        self.verse
+    }
+
+    /// Returns what is wrong.
+    pub fn kind(&self) -> &LoadIssueKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for LoadIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.book, self.chapter, self.verse) {
+            (Some(book), Some(chapter), Some(verse)) => {
+                write!(f, "{} {}:{}: ", book, chapter, verse)?
+            }
+            (Some(book), Some(chapter), None) => write!(f, "{} {}: ", book, chapter)?,
+            (Some(book), None, _) => write!(f, "{}: ", book)?,
+            (None, _, _) => {}
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+/// Every anomaly found by [`Bible::new_from_json_with_report`], in file order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadReport {
+    issues: Vec<LoadIssue>,
+}
+
+impl LoadReport {
+    /// Returns the issues in the order they occur in the file.
+    pub fn issues(&self) -> &[LoadIssue] {
+        &self.issues
+    }
+
+    /// Returns `true` if the file loaded without any anomalies.
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns the number of issues.
+    pub fn len(&self) -> usize {
+        self.issues.len()
+    }
+
+    /// Returns `true` if there are no issues.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn push(
+        &mut self,
+        book: Option<&str>,
+        chapter: Option<usize>,
+        verse: Option<usize>,
+        kind: LoadIssueKind,
+    ) {
+        self.issues.push(LoadIssue {
+            book: book.map(str::to_string),
+            chapter,
+            verse,
+            kind,
+        });
+    }
+}
+
+impl fmt::Display for LoadReport {
+    /// Writes one issue per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl Bible {
+    /// Loads a Bible from a JSON file, tolerating missing translation
+    /// details, empty or malformed chapters, and `null` verses.
+    ///
+    /// Everything that can be read is loaded; each problem is recorded in the
+    /// returned [`LoadReport`] with its book and chapter. Missing verses are
+    /// left out without renumbering later verses, so looking one up reports
+    /// [`BibleError::VerseMissing`]. Files in one of
+    /// the other [`JsonSchema`] layouts are loaded as by
+    /// [`Bible::new_from_json`] and produce an empty report.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] only if the file cannot be read, is
+    /// not valid JSON, or is not in a recognized layout.
+    pub fn new_from_json_with_report(json_path: &str) -> Result<(Self, LoadReport), BibleError> {
+        let mut file_content =
+            fs::read(json_path).map_err(|e| BibleError::load_error(json_path, e))?;
+        let mut report = LoadReport::default();
+
+        let mut probe = file_content.clone();
+        let tape =
+            simd_json::to_tape(&mut probe).map_err(|e| BibleError::load_error(json_path, e))?;
+        let root = match json_schema::detect(tape.as_value()) {
+            Some(JsonSchema::Native) => read_root(tape.as_value(), &mut report),
+            _ => json_schema::parse(&mut file_content, JsonSchema::Auto)
+                .map_err(|e| BibleError::load_error(json_path, e))?,
+        };
+
+        let bible = Bible::new_from_map_with_meta(
+            root.books,
+            root.id,
+            root.name,
+            root.description,
+            root.language,
+            root.metadata,
+        )
+        .with_source(json_path);
+        Ok((bible, report))
+    }
+}
+
+fn read_root(root: Value, report: &mut LoadReport) -> BibleFileRoot {
+    let mut file = BibleFileRoot::default();
+    for (field, slot) in [
+        ("id", &mut file.id),
+        ("name", &mut file.name),
+        ("description", &mut file.description),
+        ("language", &mut file.language),
+    ] {
+        match root.get(field) {
+            Some(value) => match value.as_str() {
+                Some(text) => *slot = text.to_string(),
+                None => report.push(None, None, None, invalid(field, "expected a string")),
+            },
+            None => report.push(None, None, None, LoadIssueKind::MissingField(field)),
+        }
+    }
+
+    // Metadata fields sit beside the books at the top level.
+    if let Some(object) = root.as_object() {
+        let header: simd_json::borrowed::Object = object
+            .iter()
+            .filter(|(key, _)| *key != "books")
+            .map(|(key, value)| (key.into(), to_borrowed(value)))
+            .collect();
+        match BibleMetadata::deserialize(BorrowedValue::from(header)) {
+            Ok(metadata) => file.metadata = metadata,
+            Err(e) => report.push(None, None, None, invalid("metadata", e)),
+        }
+    }
+
+    match root.get("books").and_then(|books| books.as_object()) {
+        Some(books) => {
+            for (key, entry) in books.iter() {
+                if let Some(entry) = read_book(key, entry, report) {
+                    file.books.insert(key.to_string(), entry);
+                }
+            }
+        }
+        None => report.push(None, None, None, invalid("books", "expected an object")),
+    }
+    file
+}
+
+fn read_book(key: &str, entry: Value, report: &mut LoadReport) -> Option<FileDataEntry> {
+    let book = Some(key);
+    if !entry.is_object() {
+        report.push(book, None, None, LoadIssueKind::InvalidBook);
+        return None;
+    }
+
+    let name = match entry
+        .get("name")
+        .map(|name| name.as_str().map(str::to_string))
+    {
+        Some(Some(name)) => name,
+        found => {
+            let kind = match found {
+                Some(_) => invalid("name", "expected a string"),
+                None => LoadIssueKind::MissingField("name"),
+            };
+            report.push(book, None, None, kind);
+            BibleBook::from_str(key).map_or_else(|_| key.to_string(), |b| b.full_name().to_string())
+        }
+    };
+    let mut data = FileDataEntry {
+        name,
+        ..FileDataEntry::default()
+    };
+
+    match entry.get("chapters") {
+        Some(chapters) => {
+            if let Some(list) = chapters.as_array() {
+                data.chapters = list
+                    .iter()
+                    .enumerate()
+                    .map(|(i, chapter)| read_chapter(key, i + 1, chapter, report))
+                    .collect();
+            } else if let Some(map) = chapters.as_object() {
+                let mut numbered = BTreeMap::new();
+                for (chapter_key, chapter) in map.iter() {
+                    match parse_number(chapter_key) {
+                        Some(number) => {
+                            numbered.insert(number, read_chapter(key, number, chapter, report));
+                        }
+                        None => report.push(book, None, None, invalid_key(chapter_key)),
+                    }
+                }
+                data.chapters = by_position(numbered);
+            } else {
+                report.push(
+                    book,
+                    None,
+                    None,
+                    invalid("chapters", "expected a list or an object"),
+                );
+            }
+        }
+        None => report.push(book, None, None, LoadIssueKind::MissingField("chapters")),
+    }
+
+    if let Some(value) = entry.get("aliases") {
+        match Vec::deserialize(to_borrowed(value)) {
+            Ok(aliases) => data.aliases = aliases,
+            Err(e) => report.push(book, None, None, invalid("aliases", e)),
+        }
+    }
+    if let Some(value) = entry.get("superscriptions") {
+        match IndexMap::deserialize(to_borrowed(value)) {
+            Ok(superscriptions) => data.superscriptions = superscriptions,
+            Err(e) => report.push(book, None, None, invalid("superscriptions", e)),
+        }
+    }
+    if let Some(value) = entry.get("paragraphs") {
+        match IndexMap::deserialize(to_borrowed(value)) {
+            Ok(paragraphs) => data.paragraphs = paragraphs,
+            Err(e) => report.push(book, None, None, invalid("paragraphs", e)),
+        }
+    }
+    if let Some(value) = entry.get("bridges") {
+        match IndexMap::deserialize(to_borrowed(value)) {
+            Ok(bridges) => data.bridges = bridges,
+            Err(e) => report.push(book, None, None, invalid("bridges", e)),
+        }
+    }
+    for (chapter_key, bridges) in &data.bridges {
+        let chapter = parse_number(chapter_key);
+        let Some(verses) = chapter.and_then(|n| data.chapters.get(n - 1)) else {
+            continue;
+        };
+        for ([first, last], reason) in check_bridges(verses, bridges).1 {
+            let kind = LoadIssueKind::InvalidBridge {
+                first,
+                last,
+                reason,
+            };
+            report.push(book, chapter, Some(first), kind);
+        }
+    }
+    if let Some(value) = entry.get("introduction") {
+        match Option::deserialize(to_borrowed(value)) {
+            Ok(introduction) => data.introduction = introduction,
+            Err(e) => report.push(book, None, None, invalid("introduction", e)),
+        }
+    }
+    if let Some(value) = entry.get("outline") {
+        match Vec::deserialize(to_borrowed(value)) {
+            Ok(outline) => data.outline = outline,
+            Err(e) => report.push(book, None, None, invalid("outline", e)),
+        }
+    }
+    Some(data)
+}
+
+/// Reads a chapter given as a list of verses or as verses keyed by number.
+fn read_chapter(book: &str, chapter: usize, value: Value, report: &mut LoadReport) -> Vec<String> {
+    let at = (Some(book), Some(chapter));
+    let verses = if let Some(list) = value.as_array() {
+        list.iter()
+            .enumerate()
+            .map(|(i, verse)| read_verse(at, i + 1, verse, report))
+            .collect()
+    } else if let Some(map) = value.as_object() {
+        let mut numbered = BTreeMap::new();
+        for (verse_key, verse) in map.iter() {
+            match parse_number(verse_key) {
+                Some(number) => {
+                    numbered.insert(number, read_verse(at, number, verse, report));
+                }
+                None => report.push(at.0, at.1, None, invalid_key(verse_key)),
+            }
+        }
+        by_position(numbered)
+    } else {
+        report.push(at.0, at.1, None, LoadIssueKind::InvalidChapter);
+        return Vec::new();
+    };
+
+    if verses.is_empty() {
+        report.push(at.0, at.1, None, LoadIssueKind::EmptyChapter);
+    }
+    verses
+}
+
+fn read_verse(
+    (book, chapter): (Option<&str>, Option<usize>),
+    verse: usize,
+    value: Value,
+    report: &mut LoadReport,
+) -> String {
+    if let Some(text) = value.as_str() {
+        return text.to_string();
+    }
+    let kind = if value.is_null() {
+        LoadIssueKind::NullVerse
+    } else {
+        LoadIssueKind::InvalidVerse
+    };
+    report.push(book, chapter, Some(verse), kind);
+    String::new()
+}
+
+/// Lays out numbered entries by position, padding gaps with empty values
+/// so numbering is preserved.
+fn by_position<T: Default>(numbered: BTreeMap<usize, T>) -> Vec<T> {
+    let mut positions = Vec::with_capacity(numbered.len());
+    for (number, value) in numbered {
+        let padded = positions.len().max(number - 1);
+        positions.resize_with(padded, T::default);
+        positions.push(value);
+    }
+    positions
+}
+
+fn parse_number(key: &str) -> Option<usize> {
+    key.trim().parse().ok().filter(|&n| is_valid_number(n))
+}
+
+fn to_borrowed<'input>(value: Value<'_, 'input>) -> BorrowedValue<'input> {
+    lazy::Value::from_tape(value).into_value()
+}
+
+fn invalid(field: &'static str, message: impl fmt::Display) -> LoadIssueKind {
+    LoadIssueKind::InvalidField {
+        field,
+        message: message.to_string(),
+    }
+}
+
+fn invalid_key(key: &str) -> LoadIssueKind {
+    LoadIssueKind::InvalidKey(key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(name: &str, json: &str) -> Result<(Bible, LoadReport), BibleError> {
+        let path = std::env::temp_dir().join(name);
+        fs::write(&path, json).unwrap();
+        let result = Bible::new_from_json_with_report(path.to_str().unwrap());
+        fs::remove_file(&path).ok();
+        result
+    }
+
+    #[test]
+    fn test_report_lists_anomalies_with_context() {
+        let json = r#"{
+            "name": "Damaged",
+            "books": {
+                "gn": {"chapters": [["In the beginning", null, "And God said"], []]},
+                "ex": 5,
+                "jo": {"name": "John", "chapters": {"11": {"35": "Jesus wept.", "x": "?"}, "2": null}}
+            }
+        }"#;
+        let (bible, report) = load("bible_io_damaged.json", json).unwrap();
+
+        let messages: Vec<String> = report.issues().iter().map(|i| i.to_string()).collect();
+        assert_eq!(
+            messages,
+            [
+                "missing field 'id'",
+                "missing field 'description'",
+                "missing field 'language'",
+                "gn: missing field 'name'",
+                "gn 1:2: verse is null",
+                "gn 2: chapter has no verses",
+                "ex: book entry is not an object",
+                "jo 11: key 'x' is not a positive integer",
+                "jo 2: chapter is not a list of verses",
+            ]
+        );
+        assert_eq!(report.issues()[4].verse(), Some(2));
+
+        assert_eq!(bible.name(), "Damaged");
+        assert_eq!(bible.books().len(), 2);
+        let genesis = bible.get_book(BibleBook::Genesis).unwrap();
+        assert_eq!(genesis.title(), "Genesis");
+        assert_eq!(
+            bible.get_verse(BibleBook::Genesis, 1, 3).unwrap().text(),
+            "And God said"
+        );
+        assert_eq!(
+            bible.get_verse(BibleBook::John, 11, 35).unwrap().text(),
+            "Jesus wept."
+        );
+    }
+
+    #[test]
+    fn test_invalid_bridges_reported_and_ignored() {
+        let json = r#"{"id": "t", "name": "T", "description": "", "language": "en",
+            "books": {"rt": {"name": "Ruth",
+                "chapters": [["One", "Two", "", "", "Five"], ["One", "Two", "Three", ""]],
+                "bridges": {"1": [[2, 4], [1, 2], [2, 3]], "2": [[3, 100000], [2, 3]]}}}}"#;
+        let (bible, report) = load("bible_io_bridges.json", json).unwrap();
+
+        let messages: Vec<String> = report.issues().iter().map(|i| i.to_string()).collect();
+        assert_eq!(
+            messages,
+            [
+                "rt 1:1: bridge 1-2 overlaps another bridge",
+                "rt 1:2: bridge 2-3 overlaps another bridge",
+                "rt 2:3: bridge 3-100000 runs past the end of the chapter",
+                "rt 2:2: bridge 2-3 covers a verse with text",
+            ]
+        );
+        let ruth = bible.get_book(BibleBook::Ruth).unwrap();
+        assert_eq!(ruth.get_verse(1, 3).unwrap().number_label(), "2-4");
+        assert!(!ruth.get_verse(1, 1).unwrap().is_bridge());
+        assert_eq!(ruth.get_chapter(2).unwrap().max_verse(), 3);
+    }
+
+    #[test]
+    fn test_clean_files_and_fatal_errors() {
+        let json = r#"{"id": "t", "name": "T", "description": "", "language": "en",
+            "books": {"gn": {"name": "Genesis", "chapters": [["In the beginning"]]}}}"#;
+        let (bible, report) = load("bible_io_clean.json", json).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(
+            bible
+                .provenance()
+                .source()
+                .map(|s| s.ends_with("bible_io_clean.json")),
+            Some(true)
+        );
+
+        assert!(load("bible_io_truncated.json", "{\"books\": {").is_err());
+    }
+}
@@ -0,0 +1,359 @@
+//! Scripture memory support: verse packs, cloze exercises and spaced
+//! repetition.
+//!
+//! A [`VersePack`] is a named list of references, written as for
+//! [`Bible::get_verse_by_reference`], and is stored as JSON:
+//!
+//! ```json
+//! { "name": "Romans Road", "references": ["Romans 3:23", "Romans 6:23"] }
+//! ```
+//!
+//! Review history lives in a separate [`ReviewLog`], which schedules each
+//! reference with the SM-2 algorithm.
+
+use std::{error::Error, fs};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use simd_json::serde::{from_slice as simd_from_slice, to_string_pretty as simd_to_string_pretty};
+
+use crate::{
+    bible::{Bible, BibleError},
+    date::Date,
+    verse::{word_ranges, Verse},
+};
+
+/// The text shown in place of a hidden word.
+pub const CLOZE_BLANK: &str = "____";
+
+/// A named, ordered set of verses to memorize.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct VersePack {
+    name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    description: String,
+    references: Vec<String>,
+}
+
+impl VersePack {
+    /// Creates an empty pack.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets a description of the pack.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Appends `reference`, written as for [`Bible::get_verse_by_reference`].
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.references.push(reference.into());
+        self
+    }
+
+    /// Returns the pack name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the pack description.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the references in order.
+    pub fn references(&self) -> &[String] {
+        &self.references
+    }
+
+    /// Returns the number of references in the pack.
+    pub fn len(&self) -> usize {
+        self.references.len()
+    }
+
+    /// Returns `true` if the pack has no references.
+    pub fn is_empty(&self) -> bool {
+        self.references.is_empty()
+    }
+
+    /// Looks up every verse in the pack, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from [`Bible::get_verse_by_reference`].
+    pub fn verses<'a>(&self, bible: &'a Bible) -> Result<Vec<&'a Verse>, BibleError> {
+        self.references
+            .iter()
+            .map(|reference| bible.get_verse_by_reference(reference))
+            .collect()
+    }
+
+    /// Returns the references due for review on `date`, including those never
+    /// reviewed, in pack order.
+    pub fn due<'a>(&'a self, log: &ReviewLog, date: Date) -> Vec<&'a str> {
+        self.references
+            .iter()
+            .filter(|reference| log.get(reference).is_none_or(|state| state.is_due(date)))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Loads a pack from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or parsed.
+    pub fn from_json(json_path: &str) -> Result<Self, BibleError> {
+        let mut file_content =
+            fs::read(json_path).map_err(|e| BibleError::load_error(json_path, e))?;
+        simd_from_slice(&mut file_content).map_err(|e| BibleError::load_error(json_path, e))
+    }
+
+    /// Serializes the pack into the format read by [`VersePack::from_json`].
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        Ok(simd_to_string_pretty(self)?)
+    }
+}
+
+/// Verse text with some words replaced by [`CLOZE_BLANK`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cloze {
+    text: String,
+    answers: Vec<String>,
+}
+
+impl Cloze {
+    /// Hides every `every`th word of `text`, starting with the word at
+    /// zero-based position `offset`. Varying `offset` from `0` to `every - 1`
+    /// gives exercises that together hide each word once. Punctuation is
+    /// kept, and an `every` of `0` hides nothing.
+    pub fn new(text: &str, every: usize, offset: usize) -> Self {
+        let mut cloze = Self {
+            text: String::with_capacity(text.len()),
+            answers: Vec::new(),
+        };
+        let mut pos = 0;
+        for (index, range) in word_ranges(text).enumerate() {
+            if every == 0 || index < offset || !(index - offset).is_multiple_of(every) {
+                continue;
+            }
+            cloze.text.push_str(&text[pos..range.start]);
+            cloze.text.push_str(CLOZE_BLANK);
+            cloze.answers.push(text[range.clone()].to_string());
+            pos = range.end;
+        }
+        cloze.text.push_str(&text[pos..]);
+        cloze
+    }
+
+    /// Returns the text with hidden words blanked out.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the hidden words in order.
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    /// Returns `true` if `guess` matches the hidden word at `blank`, ignoring
+    /// case and surrounding whitespace.
+    pub fn check(&self, blank: usize, guess: &str) -> bool {
+        self.answers
+            .get(blank)
+            .is_some_and(|answer| answer.to_lowercase() == guess.trim().to_lowercase())
+    }
+}
+
+impl Verse {
+    /// Returns a [`Cloze`] exercise for this verse; see [`Cloze::new`].
+    pub fn cloze(&self, every: usize, offset: usize) -> Cloze {
+        Cloze::new(self.text(), every, offset)
+    }
+}
+
+/// SM-2 scheduling state for one reference.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReviewState {
+    repetitions: u32,
+    interval: u32,
+    ease: f64,
+    due: Date,
+}
+
+impl ReviewState {
+    /// The ease factor of a new item.
+    pub const INITIAL_EASE: f64 = 2.5;
+    /// The lowest ease factor SM-2 allows.
+    pub const MIN_EASE: f64 = 1.3;
+
+    /// Creates the state of an item first seen on `date`, due immediately.
+    pub fn new(date: Date) -> Self {
+        Self {
+            repetitions: 0,
+            interval: 0,
+            ease: Self::INITIAL_EASE,
+            due: date,
+        }
+    }
+
+    /// Records a review on `date` graded `quality`, from 0 (forgotten) to 5
+    /// (perfect recall), and schedules the next one. Grades below 3 restart
+    /// the repetition count; grades above 5 count as 5.
+    pub fn review(&mut self, quality: u8, date: Date) {
+        let quality = quality.min(5);
+        if quality >= 3 {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (f64::from(self.interval) * self.ease).round() as u32,
+            };
+            self.repetitions += 1;
+        } else {
+            self.repetitions = 0;
+            self.interval = 1;
+        }
+        let miss = f64::from(5 - quality);
+        self.ease = (self.ease + 0.1 - miss * (0.08 + miss * 0.02)).max(Self::MIN_EASE);
+        self.due = date.add_days(i64::from(self.interval));
+    }
+
+    /// Returns the number of successful reviews in a row.
+    pub fn repetitions(&self) -> u32 {
+        self.repetitions
+    }
+
+    /// Returns the current interval between reviews in days.
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Returns the ease factor.
+    pub fn ease(&self) -> f64 {
+        self.ease
+    }
+
+    /// Returns the date of the next review.
+    pub fn due(&self) -> Date {
+        self.due
+    }
+
+    /// Returns `true` if a review is due on or before `date`.
+    pub fn is_due(&self, date: Date) -> bool {
+        self.due <= date
+    }
+}
+
+/// Review history for memorized references, keyed by reference text.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ReviewLog {
+    states: IndexMap<String, ReviewState>,
+}
+
+impl ReviewLog {
+    /// Creates an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the state of `reference`, or `None` if it was never reviewed.
+    pub fn get(&self, reference: &str) -> Option<&ReviewState> {
+        self.states.get(reference)
+    }
+
+    /// Records a review of `reference`; see [`ReviewState::review`].
+    pub fn review(&mut self, reference: &str, quality: u8, date: Date) -> &ReviewState {
+        let state = self
+            .states
+            .entry(reference.to_string())
+            .or_insert_with(|| ReviewState::new(date));
+        state.review(quality, date);
+        state
+    }
+
+    /// Iterates over (reference, state) pairs in the order first reviewed.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ReviewState)> {
+        self.states
+            .iter()
+            .map(|(reference, state)| (reference.as_str(), state))
+    }
+
+    /// Returns the number of references reviewed.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns `true` if nothing has been reviewed.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Serializes the log into the format read by
+    /// [`ReviewLog::from_json_str`].
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        Ok(simd_to_string_pretty(self)?)
+    }
+
+    /// Parses a log saved by [`ReviewLog::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if `json` is not a valid log.
+    pub fn from_json_str(json: &str) -> Result<Self, BibleError> {
+        simd_from_slice(&mut json.as_bytes().to_vec())
+            .map_err(|e| BibleError::load_error("review log", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cloze_hides_every_nth_word() {
+        let text = "For God so loved the world, that he gave";
+        let cloze = Cloze::new(text, 3, 0);
+        assert_eq!(cloze.text(), "____ God so ____ the world, ____ he gave");
+        assert_eq!(cloze.answers(), ["For", "loved", "that"]);
+        assert!(cloze.check(1, " Loved "));
+        assert!(!cloze.check(3, "gave"));
+
+        let shifted = Cloze::new(text, 3, 2);
+        assert_eq!(shifted.answers(), ["so", "world", "gave"]);
+        assert_eq!(shifted.text(), "For God ____ loved the ____, that he ____");
+        assert_eq!(Cloze::new(text, 0, 0).text(), text);
+    }
+
+    #[test]
+    fn test_sm2_schedule_and_log_round_trip() {
+        let today = Date::new(2026, 3, 1).unwrap();
+        let mut log = ReviewLog::new();
+        assert_eq!(log.review("John 3:16", 5, today).interval(), 1);
+        let state = log.review("John 3:16", 4, today.add_days(1));
+        assert_eq!((state.repetitions(), state.interval()), (2, 6));
+        assert_eq!(state.due(), today.add_days(7));
+        let state = log.review("John 3:16", 1, today.add_days(7));
+        assert_eq!((state.repetitions(), state.interval()), (0, 1));
+        assert!(state.ease() < ReviewState::INITIAL_EASE);
+
+        let pack = VersePack::new("Test")
+            .with_reference("John 3:16")
+            .with_reference("Romans 6:23");
+        assert_eq!(pack.due(&log, today.add_days(7)), ["Romans 6:23"]);
+        assert_eq!(pack.due(&log, today.add_days(8)).len(), 2);
+
+        let json = log.to_json().unwrap();
+        assert_eq!(ReviewLog::from_json_str(&json).unwrap(), log);
+        assert!(matches!(
+            ReviewLog::from_json_str("[1, 2]"),
+            Err(BibleError::LoadError { .. })
+        ));
+        let pack_json = pack.to_json().unwrap();
+        assert!(pack_json.contains("Romans 6:23"));
+    }
+}
@@ -0,0 +1,415 @@
+//! Times for daily reading notifications.
+//!
+//! [`DailyNotifications`] turns a [`DevotionalSchedule`] or a rotating list
+//! of passages into the next few notifications to register with the
+//! operating system, each with its exact time and passage:
+//!
+//! ```text
+//! let zone = FixedOffset::east(120);
+//! let upcoming = DailyNotifications::for_schedule(&schedule, TimeOfDay::new(7, 0)?, &zone)
+//!     .next(&bible, SystemTime::now(), 7)?;
+//! ```
+//!
+//! The crate carries no time zone database. A [`TimeZone`] reports the UTC
+//! offset on a given date; [`FixedOffset`] suits zones without daylight
+//! saving time, and any `Fn(Date) -> i32` closure can look offsets up in a
+//! database the app already has.
+
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    bible::{Bible, BibleError},
+    date::Date,
+    devotional::DevotionalSchedule,
+    passage::Passage,
+    reference::ReferenceRange,
+};
+
+/// A local time of day, to the minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeOfDay {
+    hour: u32,
+    minute: u32,
+}
+
+impl TimeOfDay {
+    /// Creates a time, or returns `None` if the hour or minute is out of
+    /// range.
+    pub fn new(hour: u32, minute: u32) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(TimeOfDay { hour, minute })
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    fn seconds(&self) -> i64 {
+        i64::from(self.hour * 3_600 + self.minute * 60)
+    }
+}
+
+/// Writes the time as `HH:MM`.
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// A time zone, as the offset from UTC in force on each date.
+pub trait TimeZone: Send + Sync {
+    /// Returns the offset from UTC in minutes on `date`, positive east of
+    /// Greenwich, such as 120 for Central European Summer Time.
+    fn utc_offset_minutes(&self, date: Date) -> i32;
+}
+
+impl<F> TimeZone for F
+where
+    F: Fn(Date) -> i32 + Send + Sync,
+{
+    fn utc_offset_minutes(&self, date: Date) -> i32 {
+        self(date)
+    }
+}
+
+/// A time zone with the same UTC offset all year.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FixedOffset {
+    minutes: i32,
+}
+
+impl FixedOffset {
+    /// Coordinated Universal Time.
+    pub const UTC: FixedOffset = FixedOffset { minutes: 0 };
+
+    /// Creates a zone `minutes` east of UTC; negative values are west.
+    pub fn east(minutes: i32) -> Self {
+        FixedOffset { minutes }
+    }
+
+    /// Returns the offset in minutes east of UTC.
+    pub fn minutes(&self) -> i32 {
+        self.minutes
+    }
+}
+
+impl TimeZone for FixedOffset {
+    fn utc_offset_minutes(&self, _date: Date) -> i32 {
+        self.minutes
+    }
+}
+
+/// A reading notification due at a particular moment.
+#[derive(Debug, Clone)]
+pub struct Notification<'a> {
+    day: usize,
+    date: Date,
+    time: TimeOfDay,
+    offset_minutes: i32,
+    passage: Passage<'a>,
+}
+
+impl<'a> Notification<'a> {
+    /// Returns the zero-based day of the plan, or of the rotation for a list
+    /// of passages.
+    pub fn day(&self) -> usize {
+        self.day
+    }
+
+    /// Returns the local date.
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Returns the local time.
+    pub fn time(&self) -> TimeOfDay {
+        self.time
+    }
+
+    /// Returns the UTC offset in minutes on the notification's date.
+    pub fn utc_offset_minutes(&self) -> i32 {
+        self.offset_minutes
+    }
+
+    /// Returns the moment as seconds since the Unix epoch.
+    pub fn unix_timestamp(&self) -> i64 {
+        unix_timestamp(self.date, self.time, self.offset_minutes)
+    }
+
+    /// Returns the moment as a [`SystemTime`].
+    pub fn system_time(&self) -> SystemTime {
+        let seconds = self.unix_timestamp();
+        match u64::try_from(seconds) {
+            Ok(after) => UNIX_EPOCH + Duration::from_secs(after),
+            Err(_) => UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs()),
+        }
+    }
+
+    /// Returns the local date and time with its offset, such as
+    /// `"2026-10-15T07:00:00+02:00"`.
+    pub fn to_rfc3339(&self) -> String {
+        let offset = self.offset_minutes.unsigned_abs();
+        let sign = if self.offset_minutes < 0 { '-' } else { '+' };
+        format!(
+            "{}T{}:00{sign}{:02}:{:02}",
+            self.date,
+            self.time,
+            offset / 60,
+            offset % 60
+        )
+    }
+
+    /// Returns the passage to read.
+    pub fn passage(&self) -> &Passage<'a> {
+        &self.passage
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Readings<'s> {
+    Schedule(&'s DevotionalSchedule),
+    Rotation {
+        passages: &'s [ReferenceRange],
+        start: Date,
+    },
+}
+
+/// Works out upcoming daily notifications at a fixed local time.
+#[derive(Clone, Copy)]
+pub struct DailyNotifications<'s> {
+    readings: Readings<'s>,
+    time: TimeOfDay,
+    zone: &'s dyn TimeZone,
+}
+
+impl fmt::Debug for DailyNotifications<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DailyNotifications")
+            .field("readings", &self.readings)
+            .field("time", &self.time)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'s> DailyNotifications<'s> {
+    /// Notifies at `time` in `zone` on each day of `schedule` that has not
+    /// been read.
+    pub fn for_schedule(
+        schedule: &'s DevotionalSchedule,
+        time: TimeOfDay,
+        zone: &'s dyn TimeZone,
+    ) -> Self {
+        DailyNotifications {
+            readings: Readings::Schedule(schedule),
+            time,
+            zone,
+        }
+    }
+
+    /// Notifies at `time` in `zone` every day from `start`, going through
+    /// `passages` in turn and starting over after the last.
+    pub fn for_passages(
+        passages: &'s [ReferenceRange],
+        start: Date,
+        time: TimeOfDay,
+        zone: &'s dyn TimeZone,
+    ) -> Self {
+        DailyNotifications {
+            readings: Readings::Rotation { passages, start },
+            time,
+            zone,
+        }
+    }
+
+    /// Returns up to `count` notifications due after `after`, earliest first,
+    /// with their passages looked up in `bible`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bible` has none of the verses of a passage.
+    pub fn next<'b>(
+        &self,
+        bible: &'b Bible,
+        after: SystemTime,
+        count: usize,
+    ) -> Result<Vec<Notification<'b>>, BibleError> {
+        let after = match after.duration_since(UNIX_EPOCH) {
+            Ok(elapsed) => elapsed.as_secs() as i64,
+            Err(before) => -(before.duration().as_secs() as i64),
+        };
+        let candidates: Box<dyn Iterator<Item = (usize, Date, &ReferenceRange)>> =
+            match self.readings {
+                Readings::Schedule(schedule) => {
+                    let readings = schedule.plan().readings();
+                    Box::new(
+                        (0..readings.len())
+                            .filter(|&day| !schedule.is_read(day))
+                            .map(move |day| (day, schedule.date_of(day), &readings[day])),
+                    )
+                }
+                Readings::Rotation { passages: [], .. } => Box::new(std::iter::empty()),
+                Readings::Rotation { passages, start } => {
+                    // Begin a day early: local dates run up to a day ahead
+                    // of or behind UTC.
+                    let today = Date::EPOCH.add_days(after.div_euclid(86_400));
+                    let first = today.days_since(start).saturating_sub(1).max(0) as usize;
+                    Box::new((first..).map(move |day| {
+                        (
+                            day,
+                            start.add_days(day as i64),
+                            &passages[day % passages.len()],
+                        )
+                    }))
+                }
+            };
+
+        let mut notifications = Vec::new();
+        for (day, date, range) in candidates {
+            if notifications.len() == count {
+                break;
+            }
+            // Only resolve the passages of notifications still to come.
+            let offset_minutes = self.zone.utc_offset_minutes(date);
+            if unix_timestamp(date, self.time, offset_minutes) <= after {
+                continue;
+            }
+            notifications.push(Notification {
+                day,
+                date,
+                time: self.time,
+                offset_minutes,
+                passage: bible.passage(range)?,
+            });
+        }
+        Ok(notifications)
+    }
+}
+
+fn unix_timestamp(date: Date, time: TimeOfDay, offset_minutes: i32) -> i64 {
+    date.days_since(Date::EPOCH) * 86_400 + time.seconds() - i64::from(offset_minutes) * 60
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, devotional::DevotionalPlan,
+        reference::Reference, test_support::TestBible, verse::Verse,
+    };
+
+    fn create_test_bible() -> Bible {
+        let verses = (1..=5)
+            .map(|v| Verse::new(BibleBook::Psalms, 23, v, format!("Verse {v}")))
+            .collect();
+        TestBible::new(vec![Book::new(
+            "ps".into(),
+            "Psalms".into(),
+            vec![Chapter::new(verses, 23)],
+        )])
+        .build()
+    }
+
+    fn verse(v: usize) -> ReferenceRange {
+        ReferenceRange::single(Reference::new(BibleBook::Psalms, 23, v))
+    }
+
+    fn at(date: Date, hour: u64) -> SystemTime {
+        let days = date.days_since(Date::EPOCH) as u64;
+        UNIX_EPOCH + Duration::from_secs(days * 86_400 + hour * 3_600)
+    }
+
+    #[test]
+    fn test_schedule_notifications() {
+        let bible = create_test_bible();
+        let start = Date::new(2026, 10, 15).unwrap();
+        let plan = (1..=4).fold(DevotionalPlan::new("Psalm 23"), |plan, v| {
+            plan.with_reading(verse(v))
+        });
+        let mut schedule = DevotionalSchedule::new(plan, start);
+        schedule.mark_read(2);
+        let zone = FixedOffset::east(120);
+        let time = TimeOfDay::new(7, 30).unwrap();
+
+        // At 06:00 UTC, 08:00 local, today's 07:30 has passed.
+        let upcoming = DailyNotifications::for_schedule(&schedule, time, &zone)
+            .next(&bible, at(start, 6), 5)
+            .unwrap();
+        let days: Vec<usize> = upcoming.iter().map(Notification::day).collect();
+        assert_eq!(days, [1, 3]);
+        assert_eq!(upcoming[0].to_rfc3339(), "2026-10-16T07:30:00+02:00");
+        assert_eq!(
+            upcoming[0].system_time(),
+            at(start.add_days(1), 5) + Duration::from_secs(30 * 60)
+        );
+        assert_eq!(upcoming[1].passage().text(), "Verse 4");
+
+        let early = DailyNotifications::for_schedule(&schedule, time, &zone)
+            .next(&bible, at(start, 5), 1)
+            .unwrap();
+        assert_eq!(early[0].day(), 0);
+    }
+
+    #[test]
+    fn test_past_readings_not_resolved() {
+        let bible = create_test_bible();
+        let start = Date::new(2026, 10, 15).unwrap();
+        // The first reading is missing from this Bible but already past.
+        let missing = ReferenceRange::single(Reference::new(BibleBook::Psalms, 99, 1));
+        let plan = DevotionalPlan::new("Psalms")
+            .with_reading(missing)
+            .with_reading(verse(1));
+        let schedule = DevotionalSchedule::new(plan, start);
+        let time = TimeOfDay::new(7, 0).unwrap();
+        let notifications = DailyNotifications::for_schedule(&schedule, time, &FixedOffset::UTC);
+
+        let upcoming = notifications.next(&bible, at(start, 12), 5).unwrap();
+        let days: Vec<usize> = upcoming.iter().map(Notification::day).collect();
+        assert_eq!(days, [1]);
+        assert!(notifications.next(&bible, at(start, 6), 5).is_err());
+    }
+
+    #[test]
+    fn test_rotating_passages_across_offset_change() {
+        let bible = create_test_bible();
+        let start = Date::new(2026, 10, 1).unwrap();
+        let change = Date::new(2026, 10, 25).unwrap();
+        // Summer time ends on 25 October.
+        let zone = move |date: Date| if date < change { 120 } else { 60 };
+        let passages = [verse(1), verse(2), verse(3)];
+        let time = TimeOfDay::new(7, 0).unwrap();
+
+        let upcoming = DailyNotifications::for_passages(&passages, start, time, &zone)
+            .next(&bible, at(Date::new(2026, 10, 23).unwrap(), 12), 3)
+            .unwrap();
+        let stamps: Vec<String> = upcoming.iter().map(Notification::to_rfc3339).collect();
+        assert_eq!(
+            stamps,
+            [
+                "2026-10-24T07:00:00+02:00",
+                "2026-10-25T07:00:00+01:00",
+                "2026-10-26T07:00:00+01:00"
+            ]
+        );
+        // Day 23 of the rotation is its third passage; then it starts over.
+        let texts: Vec<String> = upcoming.iter().map(|n| n.passage().text()).collect();
+        assert_eq!(texts, ["Verse 3", "Verse 1", "Verse 2"]);
+        assert_eq!(
+            upcoming[1].unix_timestamp() - upcoming[0].unix_timestamp(),
+            25 * 3_600
+        );
+
+        assert!(DailyNotifications::for_passages(&[], start, time, &zone)
+            .next(&bible, at(start, 0), 3)
+            .unwrap()
+            .is_empty());
+        assert!(TimeOfDay::new(24, 0).is_none());
+    }
+}
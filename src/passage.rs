@@ -0,0 +1,240 @@
+//! Contiguous passages of verses looked up from a [`Bible`].
+//!
+//! [`Passage`] is the common result of range lookups and reading plans; it
+//! can be split by chapter, merged with adjacent passages, and rendered.
+
+use crate::{
+    bible::{Bible, BibleError},
+    reference::{Reference, ReferenceRange},
+    render::{verse_to_html, verse_to_markdown},
+    verse::Verse,
+};
+
+/// The verses of a Bible within a [`ReferenceRange`], in reading order.
+#[derive(Debug, Clone)]
+pub struct Passage<'a> {
+    bible: &'a Bible,
+    range: ReferenceRange,
+    verses: Vec<&'a Verse>,
+}
+
+impl<'a> Passage<'a> {
+    /// Returns the Bible the passage was taken from.
+    pub fn bible(&self) -> &'a Bible {
+        self.bible
+    }
+
+    /// Returns the range requested.
+    pub fn range(&self) -> &ReferenceRange {
+        &self.range
+    }
+
+    /// Returns the verses in reading order.
+    pub fn verses(&self) -> &[&'a Verse] {
+        &self.verses
+    }
+
+    /// Returns the number of verses.
+    pub fn len_verses(&self) -> usize {
+        self.verses.len()
+    }
+
+    /// Returns `true` if the passage has no verses.
+    pub fn is_empty(&self) -> bool {
+        self.verses.is_empty()
+    }
+
+    /// Returns the verse texts joined by single spaces.
+    pub fn text(&self) -> String {
+        self.verses
+            .iter()
+            .map(|verse| verse.text().trim())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Splits the passage into one passage per chapter, in reading order.
+    pub fn split_at_chapter(&self) -> Vec<Passage<'a>> {
+        self.verses
+            .chunk_by(|a, b| a.book() == b.book() && a.chapter() == b.chapter())
+            .map(|verses| Passage::from_verses(self.bible, verses.to_vec()))
+            .collect()
+    }
+
+    /// Joins this passage with `other` when they overlap or `other` begins
+    /// right where this one ends, in either order. Returns `None` for
+    /// passages of different Bibles or with verses between them.
+    pub fn merge(&self, other: &Passage<'a>) -> Option<Passage<'a>> {
+        if !std::ptr::eq(self.bible, other.bible) {
+            return None;
+        }
+        let (first, second) = if self.range.start() <= other.range.start() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let gap = ReferenceRange::new(
+            first.verses.last()?.reference(),
+            second.verses.first()?.reference(),
+        );
+        let overlapping = second.range.start() <= first.range.end();
+        if !overlapping && self.bible.verses_in(&gap).count() > 2 {
+            return None;
+        }
+        let end = first.range.end().max(second.range.end()).clone();
+        self.bible
+            .passage(&ReferenceRange::new(first.range.start().clone(), end))
+            .ok()
+    }
+
+    /// Renders each verse with `render_verse` and joins the results with
+    /// `separator`, for output formats not covered by
+    /// [`to_html`](Self::to_html) and [`to_markdown`](Self::to_markdown).
+    pub fn render(&self, render_verse: impl FnMut(&Verse) -> String, separator: &str) -> String {
+        self.verses
+            .iter()
+            .copied()
+            .map(render_verse)
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// Renders the passage as an HTML `<div>` of verses as produced by
+    /// [`verse_to_html`], with a `dir` attribute matching the Bible.
+    pub fn to_html(&self) -> String {
+        format!(
+            "<div class=\"passage\" data-range=\"{}\" dir=\"{}\">\n{}\n</div>",
+            self.range,
+            self.bible.direction(),
+            self.render(verse_to_html, "\n")
+        )
+    }
+
+    /// Renders the passage as Markdown, one verse per line as produced by
+    /// [`verse_to_markdown`].
+    pub fn to_markdown(&self) -> String {
+        self.render(verse_to_markdown, "  \n")
+    }
+
+    /// Wraps `verses`, which must not be empty, as a passage spanning them.
+    pub(crate) fn from_verses(bible: &'a Bible, verses: Vec<&'a Verse>) -> Self {
+        let range =
+            ReferenceRange::new(verses[0].reference(), verses[verses.len() - 1].reference());
+        Passage {
+            bible,
+            range,
+            verses,
+        }
+    }
+}
+
+impl Bible {
+    /// Returns the verses within `range`. Verses missing from this Bible are
+    /// skipped, and a bridge is included whole if any of its verse numbers
+    /// falls within `range`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::InvalidReference`] if no verse of this Bible
+    /// falls within `range`.
+    pub fn passage(&self, range: &ReferenceRange) -> Result<Passage<'_>, BibleError> {
+        let verses: Vec<&Verse> = self.verses_in(range).collect();
+        if verses.is_empty() {
+            return Err(BibleError::InvalidReference {
+                input: range.to_string(),
+            });
+        }
+        Ok(Passage {
+            bible: self,
+            range: range.clone(),
+            verses,
+        })
+    }
+
+    /// Iterates over the verses within `range` in reading order.
+    pub(crate) fn verses_in<'a: 'r, 'r>(
+        &'a self,
+        range: &'r ReferenceRange,
+    ) -> impl Iterator<Item = &'a Verse> + 'r {
+        self.books()
+            .iter()
+            .filter(|book| range.start().book() <= book.id() && book.id() <= range.end().book())
+            .flat_map(|book| {
+                // Skip whole chapters outside the range before testing verses.
+                book.chapters().iter().filter(|chapter| {
+                    let number = chapter.number();
+                    range.start() <= &Reference::new(book.id(), number, usize::MAX)
+                        && &Reference::new(book.id(), number, 0) <= range.end()
+                })
+            })
+            .flat_map(|chapter| chapter.get_verses())
+            .filter(|verse| {
+                // A bridge is included if any of its numbers is in range.
+                let last = Reference::new(verse.book(), verse.chapter(), verse.last_number());
+                range.start() <= &last && &verse.reference() <= range.end()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+    };
+
+    fn create_test_bible() -> Bible {
+        let chapters = (1..=3)
+            .map(|c| {
+                let verses = (1..=3)
+                    .map(|v| Verse::new(BibleBook::Ruth, c, v, format!("{c}.{v}")))
+                    .collect();
+                Chapter::new(verses, c)
+            })
+            .collect();
+        TestBible::new(vec![Book::new("rt".into(), "Ruth".into(), chapters)]).build()
+    }
+
+    fn range(from: (usize, usize), to: (usize, usize)) -> ReferenceRange {
+        ReferenceRange::new(
+            Reference::new(BibleBook::Ruth, from.0, from.1),
+            Reference::new(BibleBook::Ruth, to.0, to.1),
+        )
+    }
+
+    #[test]
+    fn test_split_and_merge() {
+        let bible = create_test_bible();
+        let passage = bible.passage(&range((1, 2), (3, 1))).unwrap();
+        assert_eq!(passage.len_verses(), 6);
+        let chapters = passage.split_at_chapter();
+        let ranges: Vec<String> = chapters.iter().map(|p| p.range().to_string()).collect();
+        assert_eq!(ranges, ["Ruth 1:2-3", "Ruth 2:1-3", "Ruth 3:1"]);
+
+        let merged = chapters[2].merge(&chapters[1]).unwrap();
+        assert_eq!(merged.range().to_string(), "Ruth 2:1-3:1");
+        assert_eq!(merged.text(), "2.1 2.2 2.3 3.1");
+        assert!(chapters[0].merge(&chapters[2]).is_none());
+
+        let overlapping = bible.passage(&range((1, 1), (2, 2))).unwrap();
+        assert_eq!(overlapping.merge(&passage).unwrap().len_verses(), 7);
+        let other = create_test_bible();
+        assert!(passage
+            .merge(&other.passage(passage.range()).unwrap())
+            .is_none());
+    }
+
+    #[test]
+    fn test_rendering_hooks() {
+        let bible = create_test_bible();
+        let passage = bible.passage(&range((1, 1), (1, 2))).unwrap();
+        assert_eq!(
+            passage.render(|v| format!("[{}] {}", v.number(), v.text()), " "),
+            "[1] 1.1 [2] 1.2"
+        );
+        assert_eq!(passage.to_markdown(), "**1** 1.1  \n**2** 1.2");
+        assert!(passage
+            .to_html()
+            .starts_with("<div class=\"passage\" data-range=\"Ruth 1:1-2\" dir=\"ltr\">"));
+    }
+}
@@ -0,0 +1,181 @@
+//! Verse corrections distributed separately from the base Bible data.
+//!
+//! A [`Patch`] maps human-readable references to replacement text and is
+//! stored as a small JSON file:
+//!
+//! ```json
+//! { "description": "KJV typo fixes", "corrections": { "Genesis 1:1": "In the beginning..." } }
+//! ```
+
+use std::error::Error;
+
+use serde::{Deserialize, Serialize};
+use simd_json::serde::to_string_pretty as simd_to_string_pretty;
+
+use crate::{
+    bible::Bible,
+    bible::BibleError,
+    book_id::BookId,
+    keyed_text::{self, KeyedText},
+};
+
+/// A list of verse corrections, applied with [`Bible::with_patch`] or
+/// [`Bible::apply_patch`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Patch {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    description: String,
+    corrections: KeyedText,
+}
+
+impl Patch {
+    /// Creates an empty patch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a description of what the patch corrects.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Adds a correction replacing the text at `reference`, written as for
+    /// [`Bible::get_verse_by_reference`]. Supplied words may be wrapped in
+    /// `{}` as in the Bible data.
+    pub fn with_correction(
+        mut self,
+        reference: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        self.corrections.insert(reference.into(), text.into());
+        self
+    }
+
+    /// Returns the patch description.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Iterates over (reference, replacement text) pairs in file order.
+    pub fn corrections(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.corrections.iter()
+    }
+
+    /// Returns the number of corrections.
+    pub fn len(&self) -> usize {
+        self.corrections.len()
+    }
+
+    /// Returns `true` if the patch has no corrections.
+    pub fn is_empty(&self) -> bool {
+        self.corrections.is_empty()
+    }
+
+    /// Reads a patch from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or parsed.
+    pub fn from_json(json_path: &str) -> Result<Self, BibleError> {
+        keyed_text::read_json(json_path)
+    }
+
+    /// Serializes the patch into the format read by [`Patch::from_json`].
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        Ok(simd_to_string_pretty(self)?)
+    }
+}
+
+impl Bible {
+    /// Returns this Bible with the corrections in `patch` applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any reference in the patch does not resolve to a
+    /// verse; no corrections are applied in that case.
+    pub fn with_patch(mut self, patch: &Patch) -> Result<Self, BibleError> {
+        self.apply_patch(patch)?;
+        Ok(self)
+    }
+
+    /// Applies the corrections in `patch` in place.
+    ///
+    /// Every reference is resolved before any text changes, so a patch that
+    /// does not match this translation leaves it untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any reference in the patch does not resolve to a verse.
+    pub fn apply_patch(&mut self, patch: &Patch) -> Result<(), BibleError> {
+        let targets: Vec<(BookId, usize, usize, &str)> = patch
+            .corrections()
+            .map(|(reference, text)| {
+                let verse = self.get_verse_by_reference(reference)?;
+                Ok((verse.book().clone(), verse.chapter(), verse.number(), text))
+            })
+            .collect::<Result<_, BibleError>>()?;
+
+        for (book, chapter, verse, text) in targets {
+            self.set_verse_text(book, chapter, verse, text)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+        verse::Verse,
+    };
+
+    fn create_test_bible() -> Bible {
+        let verses = vec![
+            Verse::new(BibleBook::John, 11, 1, "Jesus wpet.".into()),
+            Verse::new(BibleBook::John, 11, 2, "Then said the Jews".into()),
+        ];
+        let chapters = (1..=11)
+            .map(|n| Chapter::new(if n == 11 { verses.clone() } else { Vec::new() }, n))
+            .collect();
+        TestBible::new(vec![Book::new("jo".into(), "John".into(), chapters)]).build()
+    }
+
+    #[test]
+    fn test_apply_patch() {
+        let patch = Patch::new()
+            .with_description("typo fixes")
+            .with_correction("John 11:1", "Jesus wept.");
+        let bible = create_test_bible().with_patch(&patch).unwrap();
+        assert_eq!(
+            bible.get_verse(BibleBook::John, 11, 1).unwrap().text(),
+            "Jesus wept."
+        );
+
+        let bad = patch.clone().with_correction("Hezekiah 1:1", "x");
+        let mut bible = create_test_bible();
+        assert!(bible.apply_patch(&bad).is_err());
+        assert_eq!(
+            bible.get_verse(BibleBook::John, 11, 1).unwrap().text(),
+            "Jesus wpet."
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let patch = Patch::new().with_correction("Jn 11:2", "Then said {the} Jews");
+        let path = std::env::temp_dir().join("bible_io_patch_round_trip.json");
+        fs::write(&path, patch.to_json().unwrap()).unwrap();
+        let loaded = Patch::from_json(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded, patch);
+        assert_eq!(loaded.len(), 1);
+
+        let bible = create_test_bible().with_patch(&loaded).unwrap();
+        let verse = bible.get_verse(BibleBook::John, 11, 2).unwrap();
+        assert_eq!(verse.text(), "Then said the Jews");
+        assert_eq!(verse.spans().len(), 3);
+    }
+}
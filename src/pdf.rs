@@ -0,0 +1,379 @@
+//! Printable PDF handouts of passages, behind the `pdf` feature.
+//!
+//! [`Passage::to_pdf`] writes a self-contained PDF 1.4 document using the
+//! standard Helvetica fonts, which every PDF viewer provides, so nothing is
+//! embedded and no dependencies are needed. Each page carries a running head
+//! with the book and chapters on it and a page number at the foot. Lines and
+//! column breaks come from [`Passage::layout`], so a verse is never split
+//! leaving a single line at the foot or head of a column.
+//!
+//! Text is written in the fonts' Latin (WinAnsi) encoding, which covers
+//! English and Western European translations including typographic quotes
+//! and dashes; other characters print as `?`. Line widths are estimated
+//! from the average width of a Helvetica character.
+
+use std::fmt::Write as _;
+
+use crate::{
+    layout::{Layout, LayoutOptions},
+    passage::Passage,
+    verse::Verse,
+};
+
+/// Average advance of a Helvetica character, as a fraction of the font size.
+const AVERAGE_CHAR_WIDTH: f32 = 0.5;
+
+/// Settings for [`Passage::to_pdf`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdfOptions {
+    page_width: f32,
+    page_height: f32,
+    margin: f32,
+    font_size: f32,
+    two_columns: bool,
+}
+
+impl Default for PdfOptions {
+    fn default() -> Self {
+        PdfOptions {
+            page_width: 595.0,
+            page_height: 842.0,
+            margin: 54.0,
+            font_size: 11.0,
+            two_columns: false,
+        }
+    }
+}
+
+impl PdfOptions {
+    /// Creates options for A4 pages in one column of 11-point text.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the page size in points (1/72 inch), such as 612 by 792 for US
+    /// Letter.
+    pub fn with_page_size(mut self, width: f32, height: f32) -> Self {
+        self.page_width = width;
+        self.page_height = height;
+        self
+    }
+
+    /// Sets the margin around the text in points.
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the size of the verse text in points.
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size.max(1.0);
+        self
+    }
+
+    /// Sets text in two columns per page instead of one.
+    pub fn with_two_columns(mut self, two_columns: bool) -> Self {
+        self.two_columns = two_columns;
+        self
+    }
+
+    fn columns(&self) -> usize {
+        if self.two_columns {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn gutter(&self) -> f32 {
+        self.font_size * 2.0
+    }
+
+    fn leading(&self) -> f32 {
+        self.font_size * 1.3
+    }
+
+    fn column_width(&self) -> f32 {
+        let columns = self.columns() as f32;
+        let text_width = self.page_width - 2.0 * self.margin;
+        (text_width - self.gutter() * (columns - 1.0)) / columns
+    }
+
+    /// The top of the text, below the running head.
+    fn text_top(&self) -> f32 {
+        self.page_height - self.margin - 2.0 * self.leading()
+    }
+
+    fn layout_options(&self) -> LayoutOptions {
+        let chars = self.column_width() / (self.font_size * AVERAGE_CHAR_WIDTH);
+        let lines = (self.text_top() - self.margin) / self.leading();
+        LayoutOptions::new(chars.max(1.0) as usize).with_column_lines(lines.max(1.0) as usize)
+    }
+}
+
+impl Passage<'_> {
+    /// Renders the passage as a PDF document. See the
+    /// [module documentation](crate::pdf).
+    pub fn to_pdf(&self, options: &PdfOptions) -> Vec<u8> {
+        let layout = self.layout(&options.layout_options());
+        let columns = layout.columns();
+        let pages: Vec<String> = columns
+            .chunks(options.columns())
+            .enumerate()
+            .map(|(page, chunk)| {
+                let first = page * options.columns();
+                self.page_content(&layout, first..first + chunk.len(), page + 1, options)
+            })
+            .collect();
+        write_document(&pages, options)
+    }
+
+    fn page_content(
+        &self,
+        layout: &Layout<'_>,
+        columns: std::ops::Range<usize>,
+        number: usize,
+        options: &PdfOptions,
+    ) -> String {
+        let verses: Vec<&Verse> = columns
+            .clone()
+            .flat_map(|column| &layout.columns()[column])
+            .map(|run| layout.verses()[run.verse()].verse())
+            .collect();
+        let mut content = String::new();
+        let head_y = options.page_height - options.margin;
+        text_line(
+            &mut content,
+            "F2",
+            options.font_size,
+            options.margin,
+            head_y,
+            &self.running_head(&verses),
+        );
+        for (i, column) in columns.enumerate() {
+            let x = options.margin + i as f32 * (options.column_width() + options.gutter());
+            for (line, text) in layout.column_lines(column).iter().enumerate() {
+                let y = options.text_top() - line as f32 * options.leading();
+                text_line(&mut content, "F1", options.font_size, x, y, text);
+            }
+        }
+        let footer = number.to_string();
+        let x = (options.page_width - footer.len() as f32 * options.font_size * AVERAGE_CHAR_WIDTH)
+            / 2.0;
+        text_line(
+            &mut content,
+            "F1",
+            options.font_size * 0.8,
+            x,
+            options.margin / 2.0,
+            &footer,
+        );
+        content
+    }
+
+    /// Names the book and chapters on a page, as "John 3" or "John 3–4",
+    /// with each book named once when a page spans books.
+    fn running_head(&self, verses: &[&Verse]) -> String {
+        let mut parts: Vec<(String, usize, usize)> = Vec::new();
+        for verse in verses {
+            let title = self
+                .bible()
+                .get_book(verse.book())
+                .map_or_else(|_| verse.book().to_string(), |b| b.title().to_string());
+            match parts.last_mut() {
+                Some((book, _, last)) if *book == title => *last = verse.chapter(),
+                _ => parts.push((title, verse.chapter(), verse.chapter())),
+            }
+        }
+        parts
+            .iter()
+            .map(|(book, first, last)| {
+                if first == last {
+                    format!("{book} {first}")
+                } else {
+                    format!("{book} {first}\u{2013}{last}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+fn text_line(content: &mut String, font: &str, size: f32, x: f32, y: f32, text: &str) {
+    let _ = writeln!(
+        content,
+        "BT /{font} {size:.1} Tf {x:.2} {y:.2} Td ({}) Tj ET",
+        pdf_string(text)
+    );
+}
+
+/// Encodes text for a WinAnsi string literal, escaping the delimiters.
+fn pdf_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        let byte = match c {
+            '(' | ')' | '\\' => {
+                out.push('\\');
+                c as u8
+            }
+            ' '..='~' => c as u8,
+            '\u{a0}'..='\u{ff}' => c as u32 as u8,
+            '\u{2026}' => 0x85,
+            '\u{2018}' => 0x91,
+            '\u{2019}' => 0x92,
+            '\u{201c}' => 0x93,
+            '\u{201d}' => 0x94,
+            '\u{2022}' => 0x95,
+            '\u{2013}' => 0x96,
+            '\u{2014}' => 0x97,
+            '\u{2020}' => 0x86,
+            '\u{2021}' => 0x87,
+            _ => b'?',
+        };
+        if byte.is_ascii() {
+            out.push(byte as char);
+        } else {
+            // Octal escapes keep the content stream ASCII.
+            let _ = write!(out, "\\{byte:03o}");
+        }
+    }
+    out
+}
+
+/// Writes the document: catalog, page tree, two fonts, then a page and
+/// content stream per page, followed by the cross-reference table.
+fn write_document(pages: &[String], options: &PdfOptions) -> Vec<u8> {
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            (0..pages.len())
+                .map(|i| format!("{} 0 R", 5 + 2 * i))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        ),
+        font_object("Helvetica"),
+        font_object("Helvetica-Bold"),
+    ];
+    for (i, content) in pages.iter().enumerate() {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {:.0} {:.0}] \
+             /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            options.page_width,
+            options.page_height,
+            6 + 2 * i
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ));
+    }
+
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        let _ = write!(pdf, "{} 0 obj\n{}\nendobj\n", i + 1, object);
+    }
+    let xref = pdf.len();
+    let _ = write!(pdf, "xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        let _ = writeln!(pdf, "{offset:010} 00000 n ");
+    }
+    let _ = write!(
+        pdf,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref
+    );
+    pdf.into_bytes()
+}
+
+fn font_object(name: &str) -> String {
+    format!("<< /Type /Font /Subtype /Type1 /BaseFont /{name} /Encoding /WinAnsiEncoding >>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible::Bible,
+        bible_books_enum::BibleBook,
+        book::Book,
+        chapter::Chapter,
+        reference::{Reference, ReferenceRange},
+        test_support::TestBible,
+    };
+
+    fn create_test_bible() -> Bible {
+        let chapters = (1..=3)
+            .map(|c| {
+                let verses = (1..=60)
+                    .map(|v| {
+                        let text = format!(
+                            "“Verse {c}:{v}” (of the shepherd’s psalm) is long enough to \
+                             wrap onto a second line"
+                        );
+                        Verse::new(BibleBook::John, c, v, text)
+                    })
+                    .collect();
+                Chapter::new(verses, c)
+            })
+            .collect();
+        TestBible::new(vec![Book::new("jo".into(), "John".into(), chapters)]).build()
+    }
+
+    #[test]
+    fn test_pdf_structure() {
+        let bible = create_test_bible();
+        let passage = bible
+            .passage(&ReferenceRange::new(
+                Reference::new(BibleBook::John, 1, 1),
+                Reference::new(BibleBook::John, 3, 60),
+            ))
+            .unwrap();
+        let one = String::from_utf8(passage.to_pdf(&PdfOptions::new())).unwrap();
+        let two =
+            String::from_utf8(passage.to_pdf(&PdfOptions::new().with_two_columns(true))).unwrap();
+
+        assert!(one.starts_with("%PDF-1.4\n") && one.ends_with("%%EOF\n"));
+        assert!(one.contains("(1 \\223Verse 1:1\\224 \\(of the shepherd\\222s psalm\\)"));
+        assert!(one.contains("(John 1) Tj"));
+        assert!(one.contains("(John 1\\2262) Tj"));
+
+        // The second column starts after half the text width and the gutter.
+        assert!(two.contains("Tf 308.50 ") && !one.contains("Tf 308.50 "));
+
+        // Every cross-reference entry points at its object.
+        for pdf in [&one, &two] {
+            let start: usize = pdf
+                .rsplit("startxref\n")
+                .next()
+                .unwrap()
+                .lines()
+                .next()
+                .unwrap()
+                .parse()
+                .unwrap();
+            assert!(pdf[start..].starts_with("xref\n"));
+            for (i, entry) in pdf[start..]
+                .lines()
+                .skip(3)
+                .take_while(|l| l.ends_with(" n "))
+                .enumerate()
+            {
+                let offset: usize = entry[..10].parse().unwrap();
+                assert!(pdf[offset..].starts_with(&format!("{} 0 obj", i + 1)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_pdf_string_encoding() {
+        assert_eq!(pdf_string("a (b) \\ c"), "a \\(b\\) \\\\ c");
+        assert_eq!(pdf_string("café—“x”"), "caf\\351\\227\\223x\\224");
+        assert_eq!(pdf_string("Ἰησοῦς"), "??????");
+    }
+}
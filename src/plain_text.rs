@@ -0,0 +1,240 @@
+//! Loading legacy plain-text Bibles.
+//!
+//! Two layouts are read, both naming books by English name or any common
+//! abbreviation:
+//!
+//! - verse per line (VPL), as exported by e-Sword and most Bible programs:
+//!   `Gen 1:1 In the beginning God created the heaven and the earth.`
+//! - SwordSearcher Forge, with a `$$ Ge 1:1` marker line before each verse's
+//!   text and `; TITLE:` / `; ABBREVIATION:` header comments.
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use crate::{
+    bible::{Bible, BibleError, BibleMetadata},
+    json_schema::{is_valid_number, numbered_books_to_entries, resolve_book_key, NumberedBooks},
+};
+
+/// The layout of a plain-text Bible file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PlainTextFormat {
+    /// Forge if the first line that is not a comment starts with `$$`,
+    /// otherwise verse per line.
+    #[default]
+    Auto,
+    /// One verse per line, led by its reference: `Gen 1:1 In the beginning...`.
+    /// Blank lines and lines starting with `#` or `;` are skipped.
+    VersePerLine,
+    /// SwordSearcher Forge: `$$ Ge 1:1` followed by the verse text on the
+    /// next lines. Lines starting with `;` are comments.
+    Forge,
+}
+
+impl Bible {
+    /// Loads a plain-text Bible from `path` in the given layout.
+    ///
+    /// Books are kept in the order they first appear. The Bible's id and name
+    /// come from Forge `ABBREVIATION` and `TITLE` headers when present, and
+    /// from the file name otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or a line
+    /// that should start with a reference does not, naming the line.
+    pub fn from_plain_text(path: &str, format: PlainTextFormat) -> Result<Self, BibleError> {
+        let text = fs::read_to_string(path).map_err(|e| BibleError::load_error(path, e))?;
+        let text = text.strip_prefix('\u{feff}').unwrap_or(&text);
+        let stem = Path::new(path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let format = match format {
+            PlainTextFormat::Auto => detect(text),
+            format => format,
+        };
+        let parsed = match format {
+            PlainTextFormat::Forge => parse_forge(text),
+            _ => parse_verse_per_line(text),
+        }
+        .map_err(|e| BibleError::load_error(path, e))?;
+
+        let name = parsed.title.unwrap_or_else(|| stem.clone());
+        let id = parsed.abbreviation.unwrap_or(stem).to_lowercase();
+        let bible = Bible::new_from_map_with_meta(
+            numbered_books_to_entries(parsed.books),
+            id,
+            name,
+            String::new(),
+            String::new(),
+            BibleMetadata::default(),
+        );
+        Ok(bible.with_source(path))
+    }
+}
+
+#[derive(Default)]
+struct ParsedText {
+    title: Option<String>,
+    abbreviation: Option<String>,
+    books: NumberedBooks,
+}
+
+impl ParsedText {
+    fn insert(&mut self, book: &str, chapter: usize, verse: usize, text: String) {
+        let (key, title) = resolve_book_key(book);
+        self.books
+            .entry(key)
+            .or_insert_with(|| (title, BTreeMap::new()))
+            .1
+            .entry(chapter)
+            .or_default()
+            .insert(verse, text);
+    }
+}
+
+fn detect(text: &str) -> PlainTextFormat {
+    let first = text
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with(';'));
+    match first {
+        Some(line) if line.starts_with("$$") => PlainTextFormat::Forge,
+        _ => PlainTextFormat::VersePerLine,
+    }
+}
+
+fn parse_verse_per_line(text: &str) -> Result<ParsedText, io::Error> {
+    let mut parsed = ParsedText::default();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(['#', ';']) {
+            continue;
+        }
+        let (book, chapter, verse, text) =
+            split_reference(line).ok_or_else(|| missing_reference(number, line))?;
+        parsed.insert(book, chapter, verse, text.to_string());
+    }
+    Ok(parsed)
+}
+
+fn parse_forge(text: &str) -> Result<ParsedText, io::Error> {
+    let mut parsed = ParsedText::default();
+    // The verse being read and its text so far.
+    let mut current: Option<(&str, usize, usize, String)> = None;
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if let Some(comment) = line.strip_prefix(';') {
+            if let Some((key, value)) = comment.split_once(':') {
+                let value = value.trim().to_string();
+                match key.trim().to_ascii_uppercase().as_str() {
+                    "TITLE" => parsed.title = Some(value),
+                    "ABBREVIATION" => parsed.abbreviation = Some(value),
+                    _ => {}
+                }
+            }
+        } else if let Some(marker) = line.strip_prefix("$$") {
+            if let Some((book, chapter, verse, text)) = current.take() {
+                parsed.insert(book, chapter, verse, text);
+            }
+            let (book, chapter, verse, rest) =
+                split_reference(marker.trim()).ok_or_else(|| missing_reference(number, line))?;
+            current = Some((book, chapter, verse, rest.to_string()));
+        } else if let Some((.., text)) = current.as_mut() {
+            if !line.is_empty() {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(line);
+            }
+        } else if !line.is_empty() {
+            return Err(missing_reference(number, line));
+        }
+    }
+    if let Some((book, chapter, verse, text)) = current {
+        parsed.insert(book, chapter, verse, text);
+    }
+    Ok(parsed)
+}
+
+/// Splits `"1 John 3:16 For God..."` into the book name, chapter, verse and
+/// the rest of the line.
+fn split_reference(line: &str) -> Option<(&str, usize, usize, &str)> {
+    for token in line.split_whitespace().skip(1) {
+        if let Some((chapter, verse)) = token.split_once(':') {
+            let (Ok(chapter), Ok(verse)) = (chapter.parse(), verse.parse()) else {
+                continue;
+            };
+            if !is_valid_number(chapter) || !is_valid_number(verse) {
+                return None;
+            }
+            let start = token.as_ptr() as usize - line.as_ptr() as usize;
+            let book = line[..start].trim_end();
+            let rest = line[start + token.len()..].trim_start();
+            return Some((book, chapter, verse, rest));
+        }
+    }
+    None
+}
+
+fn missing_reference(index: usize, line: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "line {}: expected a reference such as \"Gen 1:1\", found \"{line}\"",
+            index + 1
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    fn load(name: &str, contents: &str) -> Result<Bible, BibleError> {
+        let path = std::env::temp_dir().join(name);
+        fs::write(&path, contents).unwrap();
+        let bible = Bible::from_plain_text(path.to_str().unwrap(), PlainTextFormat::Auto);
+        fs::remove_file(&path).ok();
+        bible
+    }
+
+    #[test]
+    fn test_verse_per_line() {
+        let bible = load(
+            "bible_io_vpl_test.txt",
+            "# sample\nGen 1:1 In the beginning\nGenesis 1:2\tAnd the earth\n\n1 Jn 1:1 That which was\n",
+        )
+        .unwrap();
+        assert_eq!(bible.id(), "bible_io_vpl_test");
+        assert_eq!(bible.books().len(), 2);
+        assert_eq!(
+            bible.get_verse(BibleBook::Genesis, 1, 2).unwrap().text(),
+            "And the earth"
+        );
+        assert_eq!(
+            bible.get_verse(BibleBook::FirstJohn, 1, 1).unwrap().text(),
+            "That which was"
+        );
+
+        let err = load("bible_io_vpl_bad.txt", "Gen 1:1 ok\nno reference here\n").unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{err}");
+    }
+
+    #[test]
+    fn test_forge() {
+        let bible = load(
+            "bible_io_forge_test.txt",
+            "; TITLE: King James Version\n; ABBREVIATION: KJV\n$$ Ge 1:1\nIn the beginning\nGod created\n$$ Ru 1:1\nNow it came to pass\n",
+        )
+        .unwrap();
+        assert_eq!(bible.id(), "kjv");
+        assert_eq!(bible.name(), "King James Version");
+        assert_eq!(
+            bible.get_verse(BibleBook::Genesis, 1, 1).unwrap().text(),
+            "In the beginning God created"
+        );
+        assert_eq!(bible.books()[1].title(), "Ruth");
+    }
+}
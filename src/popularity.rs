@@ -0,0 +1,287 @@
+//! Verse popularity scores for ranking search results and picking well-known
+//! verses.
+//!
+//! A [`PopularityTable`] maps references to scores, where higher means better
+//! known. Tables can be loaded from JSON keyed by OSIS identifier, so any
+//! source of weights (page views, cross-reference counts) can be plugged in:
+//!
+//! ```json
+//! { "John.3.16": 100, "Ps.23.1": 97, "Rom.8.28": 96 }
+//! ```
+//!
+//! The `popularity-data` feature bundles a small table of widely quoted
+//! verses, available through [`PopularityTable::builtin`] and
+//! [`Verse::popularity`].
+
+use std::{borrow::Borrow, collections::HashMap, error::Error, fs};
+
+use simd_json::serde::{from_slice as simd_from_slice, to_string_pretty as simd_to_string_pretty};
+
+use crate::{bible::BibleError, date::Date, reference::Reference, verse::Verse};
+
+/// Popularity scores by reference. Verses without a score count as `0.0`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PopularityTable {
+    scores: HashMap<Reference, f64>,
+}
+
+impl PopularityTable {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the bundled table of widely quoted verses, scored from 1 to
+    /// 100.
+    #[cfg(feature = "popularity-data")]
+    pub fn builtin() -> Self {
+        let scores = BUILTIN_SCORES
+            .entries()
+            .filter_map(|(id, &score)| Some((Reference::from_osis_id(id).ok()?, f64::from(score))))
+            .collect();
+        Self { scores }
+    }
+
+    /// Sets the score of `reference`.
+    pub fn with_score(mut self, reference: Reference, score: f64) -> Self {
+        self.scores.insert(reference, score);
+        self
+    }
+
+    /// Returns the score of `reference`, or `0.0` if it has none.
+    pub fn score(&self, reference: &Reference) -> f64 {
+        self.scores.get(reference).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the number of scored references.
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    /// Returns `true` if no reference has a score.
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+
+    /// Sorts `verses` from most to least popular, keeping the existing order
+    /// among equal scores, so relevance-ordered search results only move
+    /// where popularity differs.
+    pub fn sort_by_popularity<V: Borrow<Verse>>(&self, verses: &mut [V]) {
+        verses.sort_by_cached_key(|verse| {
+            std::cmp::Reverse(OrderedScore(self.score(&verse.borrow().reference())))
+        });
+    }
+
+    /// Returns the `n` highest scored references, ties in canonical order.
+    pub fn top(&self, n: usize) -> Vec<Reference> {
+        let mut ranked: Vec<(&Reference, f64)> = self.scores.iter().map(|(r, &s)| (r, s)).collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        ranked.into_iter().take(n).map(|(r, _)| r.clone()).collect()
+    }
+
+    /// Picks a reference for `date` from the `pool` most popular, cycling
+    /// through them day by day. Returns `None` for an empty table or pool.
+    pub fn verse_of_the_day(&self, date: Date, pool: usize) -> Option<Reference> {
+        let top = self.top(pool);
+        let day = date.days_since(Date::new(1970, 1, 1)?);
+        let index = day.rem_euclid(top.len().max(1) as i64) as usize;
+        top.get(index).cloned()
+    }
+
+    /// Loads a table from a JSON object mapping OSIS identifiers to scores.
+    /// Entries whose identifier does not parse are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or parsed.
+    pub fn from_json(json_path: &str) -> Result<Self, BibleError> {
+        let mut file_content =
+            fs::read(json_path).map_err(|e| BibleError::load_error(json_path, e))?;
+        let raw: HashMap<String, f64> =
+            simd_from_slice(&mut file_content).map_err(|e| BibleError::load_error(json_path, e))?;
+        let scores = raw
+            .into_iter()
+            .filter_map(|(id, score)| Some((Reference::from_osis_id(&id).ok()?, score)))
+            .collect();
+        Ok(Self { scores })
+    }
+
+    /// Serializes the table into the format read by
+    /// [`PopularityTable::from_json`]. References in custom books, which have
+    /// no OSIS identifier, are left out.
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        let raw: std::collections::BTreeMap<String, f64> = self
+            .scores
+            .iter()
+            .filter_map(|(reference, &score)| Some((reference.to_osis_id()?, score)))
+            .collect();
+        Ok(simd_to_string_pretty(&raw)?)
+    }
+}
+
+/// Orders scores with [`f64::total_cmp`] so they can be used as sort keys.
+#[derive(PartialEq)]
+struct OrderedScore(f64);
+
+impl Eq for OrderedScore {}
+
+impl PartialOrd for OrderedScore {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedScore {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+#[cfg(feature = "popularity-data")]
+impl Verse {
+    /// Returns the verse's score in the bundled popularity table, from 1 to
+    /// 100, or `0` if it is not listed.
+    pub fn popularity(&self) -> u8 {
+        self.reference()
+            .to_osis_id()
+            .and_then(|id| BUILTIN_SCORES.get(id.as_str()).copied())
+            .unwrap_or(0)
+    }
+}
+
+/// Widely quoted verses, scored by how often they are looked up and cited.
+#[cfg(feature = "popularity-data")]
+static BUILTIN_SCORES: phf::Map<&'static str, u8> = phf::phf_map! {
+    "John.3.16" => 100,
+    "Jer.29.11" => 98,
+    "Phil.4.13" => 97,
+    "Rom.8.28" => 96,
+    "Ps.23.1" => 95,
+    "Gen.1.1" => 94,
+    "Prov.3.5" => 93,
+    "Prov.3.6" => 92,
+    "Phil.4.6" => 91,
+    "Isa.41.10" => 90,
+    "Matt.28.19" => 89,
+    "Rom.12.2" => 88,
+    "Eph.2.8" => 87,
+    "Gal.5.22" => 86,
+    "Rom.3.23" => 85,
+    "Rom.6.23" => 84,
+    "John.14.6" => 83,
+    "1Cor.13.4" => 82,
+    "Josh.1.9" => 81,
+    "Heb.11.1" => 80,
+    "2Tim.3.16" => 79,
+    "Matt.6.33" => 78,
+    "Isa.40.31" => 77,
+    "1John.1.9" => 76,
+    "Rom.10.9" => 75,
+    "Matt.11.28" => 74,
+    "2Cor.5.17" => 73,
+    "Phil.4.7" => 72,
+    "Eph.2.9" => 71,
+    "Gal.2.20" => 70,
+    "Rom.5.8" => 69,
+    "Ps.46.10" => 68,
+    "Mic.6.8" => 67,
+    "Matt.22.37" => 66,
+    "1Pet.5.7" => 65,
+    "Ps.119.105" => 64,
+    "Isa.53.5" => 63,
+    "Acts.1.8" => 62,
+    "Rom.12.1" => 61,
+    "John.1.1" => 60,
+    "Heb.12.2" => 59,
+    "2Chr.7.14" => 58,
+    "Matt.5.16" => 57,
+    "John.10.10" => 56,
+    "Deut.31.6" => 55,
+    "Ps.37.4" => 54,
+    "Jas.1.2" => 53,
+    "1Thess.5.18" => 52,
+    "Ps.139.14" => 51,
+    "John.11.35" => 50,
+    "Rom.15.13" => 49,
+    "Matt.7.7" => 48,
+    "Heb.4.12" => 47,
+    "Ps.118.24" => 46,
+    "Lam.3.22" => 45,
+    "Lam.3.23" => 44,
+    "Isa.26.3" => 43,
+    "Zeph.3.17" => 42,
+    "Ps.91.1" => 41,
+    "1Cor.10.13" => 40,
+    "Eph.6.10" => 39,
+    "Col.3.23" => 38,
+    "Num.6.24" => 37,
+    "Ps.27.1" => 36,
+    "Prov.22.6" => 35,
+    "Eccl.3.1" => 34,
+    "Matt.6.34" => 33,
+    "John.16.33" => 32,
+    "Rom.8.38" => 31,
+    "Rom.8.39" => 30,
+    "Heb.13.5" => 29,
+    "1John.4.19" => 28,
+    "Isa.9.6" => 27,
+    "Luke.2.11" => 26,
+    "Mark.16.15" => 25,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    #[test]
+    fn test_sort_and_pick_by_popularity() {
+        let john = Reference::new(BibleBook::John, 3, 16);
+        let psalm = Reference::new(BibleBook::Psalms, 23, 1);
+        let table = PopularityTable::new()
+            .with_score(psalm.clone(), 5.0)
+            .with_score(john.clone(), 9.0);
+
+        let verses = [
+            Verse::new(BibleBook::Genesis, 1, 2, String::new()),
+            Verse::new(BibleBook::Psalms, 23, 1, String::new()),
+            Verse::new(BibleBook::Genesis, 1, 1, String::new()),
+            Verse::new(BibleBook::John, 3, 16, String::new()),
+        ];
+        let mut ranked: Vec<&Verse> = verses.iter().collect();
+        table.sort_by_popularity(&mut ranked);
+        let order: Vec<String> = ranked.iter().map(|v| v.reference().to_string()).collect();
+        assert_eq!(
+            order,
+            ["John 3:16", "Psalms 23:1", "Genesis 1:2", "Genesis 1:1"]
+        );
+
+        assert_eq!(table.top(1)[0], john);
+        let day = Date::new(2026, 1, 1).unwrap();
+        let picks = [
+            table.verse_of_the_day(day, 2),
+            table.verse_of_the_day(day.add_days(1), 2),
+        ];
+        assert!(picks.contains(&Some(john)) && picks.contains(&Some(psalm)));
+        assert_eq!(PopularityTable::new().verse_of_the_day(day, 5), None);
+
+        let json = table.to_json().unwrap();
+        assert!(json.contains("\"John.3.16\""));
+    }
+
+    #[cfg(feature = "popularity-data")]
+    #[test]
+    fn test_builtin_table() {
+        let table = PopularityTable::builtin();
+        assert_eq!(table.len(), BUILTIN_SCORES.len());
+        assert_eq!(table.top(1), [Reference::new(BibleBook::John, 3, 16)]);
+        assert_eq!(
+            Verse::new(BibleBook::John, 3, 16, String::new()).popularity(),
+            100
+        );
+        assert_eq!(
+            Verse::new(BibleBook::John, 3, 17, String::new()).popularity(),
+            0
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Where a loaded Bible came from, and a fingerprint of its text.
+//!
+//! Caches, patches, and saved search indexes can store
+//! [`Bible::content_hash`] and compare it on reuse to detect that the
+//! underlying text changed.
+
+use std::time::SystemTime;
+
+use crate::{bible::Bible, reference::Reference, verse::Verse};
+
+/// Source and load time of a [`Bible`], returned by [`Bible::provenance`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Provenance {
+    source: Option<String>,
+    loaded_at: Option<SystemTime>,
+}
+
+impl Provenance {
+    pub(crate) fn loaded_from(source: impl Into<String>) -> Self {
+        Provenance {
+            source: Some(source.into()),
+            loaded_at: Some(SystemTime::now()),
+        }
+    }
+
+    /// Returns the path or URL the Bible was loaded from, if known.
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Returns when the Bible was loaded, if it was loaded from a source.
+    pub fn loaded_at(&self) -> Option<SystemTime> {
+        self.loaded_at
+    }
+}
+
+impl Bible {
+    /// Returns a 64-bit FNV-1a hash of the text: every book's abbreviation,
+    /// chapter and verse numbers, verse text (with supplied-word markers),
+    /// and superscriptions.
+    ///
+    /// The hash is stable across platforms and releases, but ignores names,
+    /// descriptions, and metadata. It is recomputed on each call.
+    pub fn content_hash(&self) -> u64 {
+        let mut hash = Fnv1a::new();
+        for book in self.books() {
+            hash.write(book.abbrev().as_bytes());
+            for chapter in book.chapters() {
+                hash.write(&(chapter.number() as u64).to_le_bytes());
+                hash.write(chapter.superscription().unwrap_or_default().as_bytes());
+                for verse in chapter.get_verses() {
+                    hash.write(&(verse.number() as u64).to_le_bytes());
+                    hash.write(verse.source_text().as_bytes());
+                }
+            }
+        }
+        hash.finish()
+    }
+
+    /// Returns the verses whose text differs between `other`, typically an
+    /// earlier release of the same translation, and this Bible.
+    ///
+    /// Verses added in this Bible and verses missing from it count as
+    /// changed. References follow this Bible's reading order, followed by
+    /// verses only found in `other`. Only the verse text is compared, so
+    /// text offsets into unchanged verses stay valid.
+    pub fn changed_verses_since(&self, other: &Bible) -> Vec<Reference> {
+        let mut changed: Vec<Reference> = self
+            .all_verses()
+            .filter(|verse| {
+                other
+                    .get_verse(verse.book(), verse.chapter(), verse.number())
+                    .map_or(true, |old| old.text() != verse.text())
+            })
+            .map(Verse::reference)
+            .collect();
+        changed.extend(
+            other
+                .all_verses()
+                .filter(|old| {
+                    self.get_verse(old.book(), old.chapter(), old.number())
+                        .is_err()
+                })
+                .map(Verse::reference),
+        );
+        changed
+    }
+
+    pub(crate) fn all_verses(&self) -> impl Iterator<Item = &Verse> + '_ {
+        self.books()
+            .iter()
+            .flat_map(|book| book.chapters())
+            .flat_map(|chapter| chapter.get_verses())
+    }
+}
+
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    pub(crate) fn new() -> Self {
+        Fnv1a(Self::OFFSET_BASIS)
+    }
+
+    /// Hashes `bytes` prefixed with their length, so field boundaries are
+    /// part of the hash.
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        for &byte in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+    };
+
+    fn create_test_bible(text: &str) -> Bible {
+        create_test_bible_with(&[text])
+    }
+
+    fn create_test_bible_with(texts: &[&str]) -> Bible {
+        let verses = texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| Verse::new(BibleBook::Genesis, 1, i + 1, text.to_string()))
+            .collect();
+        TestBible::new(vec![Book::new(
+            "gn".into(),
+            "Genesis".into(),
+            vec![Chapter::new(verses, 1)],
+        )])
+        .build()
+    }
+
+    #[test]
+    fn test_content_hash_tracks_text() {
+        let bible = create_test_bible("In {the} beginning");
+        assert_eq!(bible.content_hash(), bible.clone().content_hash());
+        assert_eq!(
+            bible.content_hash(),
+            create_test_bible("In {the} beginning").content_hash()
+        );
+        assert_ne!(
+            bible.content_hash(),
+            create_test_bible("In the beginning").content_hash()
+        );
+
+        let mut edited = bible.clone();
+        edited
+            .set_verse_text(BibleBook::Genesis, 1, 1, "In the beginning")
+            .unwrap();
+        assert_ne!(edited.content_hash(), bible.content_hash());
+    }
+
+    #[test]
+    fn test_changed_verses_since() {
+        let old = create_test_bible_with(&["In {the} beginning", "And the earth", "void"]);
+        let new = create_test_bible_with(&["In the beginning", "And the Earth"]);
+        let changed: Vec<String> = new
+            .changed_verses_since(&old)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(changed, ["Genesis 1:2", "Genesis 1:3"]);
+        assert!(old.changed_verses_since(&old.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_fnv1a_reference_value() {
+        let mut hash = Fnv1a::new();
+        hash.write(b"");
+        // FNV-1a of eight zero bytes (the length prefix of an empty field).
+        assert_eq!(hash.finish(), 0xa8c7_f832_281a_39c5);
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::VecDeque;
+
+/// A small least-recently-used cache for search results.
+///
+/// Entries are kept in recency order and looked up linearly, which is faster
+/// than hashing for the few dozen queries a UI re-runs while paging.
+#[derive(Debug, Clone)]
+pub(crate) struct QueryCache<K, V> {
+    capacity: usize,
+    entries: VecDeque<(K, V)>,
+}
+
+impl<K: PartialEq, V> QueryCache<K, V> {
+    /// Creates a cache holding up to `capacity` entries; zero disables caching.
+    pub(crate) fn new(capacity: usize) -> Self {
+        QueryCache {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the cached value for `key`, marking it most recently used.
+    pub(crate) fn get(&mut self, key: &K) -> Option<&V> {
+        let position = self.entries.iter().position(|(k, _)| k == key)?;
+        let entry = self.entries.remove(position)?;
+        self.entries.push_front(entry);
+        self.entries.front().map(|(_, v)| v)
+    }
+
+    /// Stores `value`, evicting the least recently used entry when full.
+    pub(crate) fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries.retain(|(k, _)| *k != key);
+        self.entries.push_front((key, value));
+        self.entries.truncate(self.capacity);
+    }
+
+    /// Changes the capacity, evicting the least recently used entries.
+    pub(crate) fn resize(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.entries.truncate(capacity);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = QueryCache::new(2);
+        cache.insert("light", 1);
+        cache.insert("darkness", 2);
+        assert_eq!(cache.get(&"light"), Some(&1));
+
+        cache.insert("water", 3);
+        assert_eq!(cache.get(&"darkness"), None);
+        assert_eq!(cache.get(&"light"), Some(&1));
+        assert_eq!(cache.len(), 2);
+
+        cache.resize(0);
+        cache.insert("earth", 4);
+        assert_eq!(cache.len(), 0);
+    }
+}
@@ -0,0 +1,462 @@
+//! Quiz questions generated from a loaded Bible.
+//!
+//! A [`QuizGenerator`] scores every verse by how common its words are across
+//! the whole Bible: verses made of everyday vocabulary count as easy, verses
+//! full of rare names and terms as hard. [`QuizOptions`] selects the
+//! difficulty, the number of choices, and a seed so question sets can be
+//! reproduced.
+
+use std::collections::HashMap;
+
+use crate::{
+    bible::Bible,
+    book_id::BookId,
+    memorization::CLOZE_BLANK,
+    reference::Reference,
+    verse::{word_ranges, Verse},
+};
+
+/// How hard generated questions should be.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Difficulty {
+    /// Verses in the most common vocabulary; book choices from anywhere.
+    Easy,
+    #[default]
+    Medium,
+    /// Verses with the rarest vocabulary; book choices from neighbouring
+    /// books and word choices of similar frequency.
+    Hard,
+}
+
+/// Settings for a [`QuizGenerator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuizOptions {
+    difficulty: Difficulty,
+    choices: usize,
+    seed: u64,
+}
+
+impl Default for QuizOptions {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::default(),
+            choices: 4,
+            seed: 0,
+        }
+    }
+}
+
+impl QuizOptions {
+    /// Creates options for medium questions with four choices.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the difficulty.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Sets the number of choices per question, including the answer. At
+    /// least two choices are always offered.
+    pub fn with_choices(mut self, choices: usize) -> Self {
+        self.choices = choices.max(2);
+        self
+    }
+
+    /// Sets the seed; the same seed, options and Bible give the same
+    /// questions.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns the difficulty.
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    /// Returns the number of choices per question.
+    pub fn choices(&self) -> usize {
+        self.choices
+    }
+}
+
+/// A verse with one word blanked out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FillInBlankQuestion {
+    reference: Reference,
+    prompt: String,
+    choices: Vec<String>,
+    answer: usize,
+}
+
+impl FillInBlankQuestion {
+    /// Returns the verse the question is taken from.
+    pub fn reference(&self) -> &Reference {
+        &self.reference
+    }
+
+    /// Returns the verse text with the missing word replaced by
+    /// [`CLOZE_BLANK`].
+    pub fn prompt(&self) -> &str {
+        &self.prompt
+    }
+
+    /// Returns the candidate words, one of which is the answer.
+    pub fn choices(&self) -> &[String] {
+        &self.choices
+    }
+
+    /// Returns the position of the answer in [`choices`](Self::choices).
+    pub fn answer_index(&self) -> usize {
+        self.answer
+    }
+
+    /// Returns the missing word.
+    pub fn answer(&self) -> &str {
+        &self.choices[self.answer]
+    }
+}
+
+/// A verse whose book must be identified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhichBookQuestion {
+    reference: Reference,
+    text: String,
+    choices: Vec<BookId>,
+    answer: usize,
+}
+
+impl WhichBookQuestion {
+    /// Returns the verse the question is taken from.
+    pub fn reference(&self) -> &Reference {
+        &self.reference
+    }
+
+    /// Returns the verse text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the candidate books, one of which contains the verse.
+    pub fn choices(&self) -> &[BookId] {
+        &self.choices
+    }
+
+    /// Returns the position of the answer in [`choices`](Self::choices).
+    pub fn answer_index(&self) -> usize {
+        self.answer
+    }
+
+    /// Returns the book containing the verse.
+    pub fn answer(&self) -> &BookId {
+        &self.choices[self.answer]
+    }
+}
+
+/// A generated question of any kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QuizQuestion {
+    FillInBlank(FillInBlankQuestion),
+    WhichBook(WhichBookQuestion),
+}
+
+/// Generates quiz questions from a Bible.
+pub struct QuizGenerator<'a> {
+    bible: &'a Bible,
+    options: QuizOptions,
+    /// Occurrences of each lowercase term, with its first spelling seen.
+    terms: HashMap<String, (usize, &'a str)>,
+    /// Terms of three or more letters, rarest first.
+    vocabulary: Vec<String>,
+    /// Verses with at least three words, easiest first.
+    verses: Vec<&'a Verse>,
+    rng: SplitMix64,
+}
+
+impl<'a> QuizGenerator<'a> {
+    /// Collects word frequencies from `bible` and ranks its verses by
+    /// difficulty.
+    pub fn new(bible: &'a Bible, options: QuizOptions) -> Self {
+        let all_verses = || {
+            bible
+                .books()
+                .iter()
+                .flat_map(|book| book.chapters())
+                .flat_map(|chapter| chapter.get_verses())
+        };
+
+        let mut terms: HashMap<String, (usize, &'a str)> = HashMap::new();
+        let mut total = 0usize;
+        for verse in all_verses() {
+            for range in word_ranges(verse.text()) {
+                let word = &verse.text()[range];
+                terms.entry(word.to_lowercase()).or_insert((0, word)).0 += 1;
+                total += 1;
+            }
+        }
+
+        let mut vocabulary: Vec<String> = terms
+            .keys()
+            .filter(|term| term.chars().count() >= 3)
+            .cloned()
+            .collect();
+        vocabulary.sort_by(|a, b| terms[a].0.cmp(&terms[b].0).then_with(|| a.cmp(b)));
+
+        // Mean surprisal of a verse's words: low for familiar wording.
+        let rarity = |verse: &Verse| {
+            let words: Vec<usize> = verse
+                .words()
+                .map(|word| terms[&word.to_lowercase()].0)
+                .collect();
+            let sum: f64 = words
+                .iter()
+                .map(|&count| (total as f64 / count as f64).ln())
+                .sum();
+            sum / words.len() as f64
+        };
+        let mut scored: Vec<(f64, &'a Verse)> = all_verses()
+            .filter(|verse| verse.word_count() >= 3)
+            .map(|verse| (rarity(verse), verse))
+            .collect();
+        scored.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Self {
+            bible,
+            rng: SplitMix64(options.seed),
+            options,
+            terms,
+            vocabulary,
+            verses: scored.into_iter().map(|(_, verse)| verse).collect(),
+        }
+    }
+
+    /// Returns the options in use.
+    pub fn options(&self) -> &QuizOptions {
+        &self.options
+    }
+
+    /// Generates a question asking for the rarest word of a verse, or `None`
+    /// if the Bible has too few words to offer the requested choices.
+    pub fn fill_in_blank(&mut self) -> Option<FillInBlankQuestion> {
+        let verse = self.pick_verse()?;
+        let text = verse.text();
+        let (range, term) = word_ranges(text)
+            .map(|range| (range.clone(), text[range].to_lowercase()))
+            .filter(|(_, term)| term.chars().count() >= 3)
+            .min_by_key(|(_, term)| self.terms[term].0)?;
+
+        let in_verse: Vec<String> = verse.words().map(str::to_lowercase).collect();
+        let position = self.vocabulary.binary_search_by(|other| {
+            self.terms[other]
+                .0
+                .cmp(&self.terms[&term].0)
+                .then_with(|| other.as_str().cmp(&term))
+        });
+        let position = position.unwrap_or_else(|p| p);
+        let by_distance = (1..self.vocabulary.len()).flat_map(|distance| {
+            [position.checked_sub(distance), Some(position + distance)]
+                .into_iter()
+                .flatten()
+                .filter_map(|i| self.vocabulary.get(i))
+        });
+        let pool: Vec<&str> = by_distance
+            .filter(|other| !in_verse.contains(other))
+            .map(|other| self.terms[other].1)
+            .take(self.pool_size(self.vocabulary.len()))
+            .collect();
+        let answer = text[range.clone()].to_string();
+        let (choices, answer) =
+            self.build_choices(answer, pool.into_iter().map(str::to_string).collect())?;
+
+        Some(FillInBlankQuestion {
+            reference: verse.reference(),
+            prompt: format!(
+                "{}{}{}",
+                &text[..range.start],
+                CLOZE_BLANK,
+                &text[range.end..]
+            ),
+            choices,
+            answer,
+        })
+    }
+
+    /// Generates a question asking which book a verse comes from, or `None`
+    /// if the Bible has too few books to offer the requested choices.
+    pub fn which_book(&mut self) -> Option<WhichBookQuestion> {
+        let verse = self.pick_verse()?;
+        let books = self.bible.books();
+        let position = books.iter().position(|book| book.id() == verse.book())?;
+        let mut others: Vec<usize> = (0..books.len()).filter(|&i| i != position).collect();
+        others.sort_by_key(|&i| i.abs_diff(position));
+        let pool = others
+            .into_iter()
+            .take(self.pool_size(books.len() - 1))
+            .map(|i| books[i].id().clone())
+            .collect();
+        let (choices, answer) = self.build_choices(verse.book().clone(), pool)?;
+
+        Some(WhichBookQuestion {
+            reference: verse.reference(),
+            text: verse.text().to_string(),
+            choices,
+            answer,
+        })
+    }
+
+    /// Generates `count` questions, alternating between the kinds.
+    pub fn quiz(&mut self, count: usize) -> Vec<QuizQuestion> {
+        (0..count)
+            .filter_map(|i| match i % 2 {
+                0 => self.fill_in_blank().map(QuizQuestion::FillInBlank),
+                _ => self.which_book().map(QuizQuestion::WhichBook),
+            })
+            .collect()
+    }
+
+    /// Picks a verse from the third of the ranking matching the difficulty.
+    fn pick_verse(&mut self) -> Option<&'a Verse> {
+        let len = self.verses.len();
+        let band = len / 3;
+        let range = match (band, self.options.difficulty) {
+            (0, _) => 0..len,
+            (_, Difficulty::Easy) => 0..band,
+            (_, Difficulty::Medium) => band..len - band,
+            (_, Difficulty::Hard) => len - band..len,
+        };
+        if range.is_empty() {
+            return None;
+        }
+        let index = range.start + self.rng.below(range.len());
+        Some(self.verses[index])
+    }
+
+    /// Returns how many of the closest distractors to draw from: all of them
+    /// when easy, a few times the number needed otherwise.
+    fn pool_size(&self, available: usize) -> usize {
+        let needed = self.options.choices - 1;
+        match self.options.difficulty {
+            Difficulty::Easy => available,
+            Difficulty::Medium => needed * 4,
+            Difficulty::Hard => needed,
+        }
+    }
+
+    /// Draws distractors from `pool`, adds the answer, and shuffles. Returns
+    /// the choices and the answer's position.
+    fn build_choices<T>(&mut self, answer: T, mut pool: Vec<T>) -> Option<(Vec<T>, usize)> {
+        let needed = self.options.choices - 1;
+        if pool.len() < needed {
+            return None;
+        }
+        self.rng.shuffle(&mut pool);
+        pool.truncate(needed);
+        let position = self.rng.below(needed + 1);
+        pool.insert(position, answer);
+        Some((pool, position))
+    }
+}
+
+/// A small deterministic generator, so questions depend only on the seed.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `0..n`; `n` must be non-zero.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+    };
+
+    fn create_test_bible() -> Bible {
+        let book = |id: BibleBook, texts: &[&str]| {
+            let verses = texts
+                .iter()
+                .enumerate()
+                .map(|(i, t)| Verse::new(id, 1, i + 1, t.to_string()))
+                .collect();
+            Book::new(
+                id.as_str().into(),
+                id.full_name().into(),
+                vec![Chapter::new(verses, 1)],
+            )
+        };
+        TestBible::new(vec![
+            book(
+                BibleBook::Genesis,
+                &["And God said, Let there be light", "And God saw the light"],
+            ),
+            book(BibleBook::Exodus, &["And Moses went up unto God"]),
+            book(BibleBook::Leviticus, &["And the LORD called unto Moses"]),
+            book(BibleBook::Numbers, &["And the LORD spake unto Moses"]),
+            book(BibleBook::John, &["Jesus wept and Lazarus slept"]),
+        ])
+        .build()
+    }
+
+    #[test]
+    fn test_fill_in_blank_hides_rarest_word() {
+        let bible = create_test_bible();
+        let options = QuizOptions::new().with_difficulty(Difficulty::Hard);
+        let mut quiz = QuizGenerator::new(&bible, options.clone());
+        let question = quiz.fill_in_blank().unwrap();
+        assert_eq!(question.reference().to_string(), "John 1:1");
+        assert!(question.prompt().contains(CLOZE_BLANK));
+        assert_eq!(question.choices().len(), 4);
+        let answer = question.answer();
+        assert!(["Jesus", "wept", "Lazarus", "slept"].contains(&answer));
+        assert_eq!(
+            question.prompt().replace(CLOZE_BLANK, answer),
+            "Jesus wept and Lazarus slept"
+        );
+
+        let again = QuizGenerator::new(&bible, options).fill_in_blank().unwrap();
+        assert_eq!(again, question);
+    }
+
+    #[test]
+    fn test_which_book_choices_and_difficulty() {
+        let bible = create_test_bible();
+        let mut quiz = QuizGenerator::new(
+            &bible,
+            QuizOptions::new()
+                .with_difficulty(Difficulty::Easy)
+                .with_choices(3)
+                .with_seed(7),
+        );
+        for _ in 0..10 {
+            let question = quiz.which_book().unwrap();
+            assert_eq!(question.choices().len(), 3);
+            assert_eq!(question.answer(), question.reference().book());
+            assert!(question.text().contains("LORD"));
+        }
+        assert_eq!(quiz.quiz(4).len(), 4);
+
+        let mut crowded = QuizGenerator::new(&bible, QuizOptions::new().with_choices(10));
+        assert!(crowded.which_book().is_none());
+    }
+}
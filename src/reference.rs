@@ -0,0 +1,503 @@
+use std::fmt;
+
+use crate::{
+    bible::{Bible, BibleError},
+    bible_books_enum::{AbbreviationStyle, BibleBook},
+    book_id::BookId,
+    verse::Verse,
+};
+
+/// An owned book/chapter/verse location that is independent of any loaded text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Reference {
+    book: BookId,
+    chapter: usize,
+    verse: usize,
+}
+
+impl Reference {
+    /// Creates a reference to the given book, chapter, and verse.
+    pub fn new(book: impl Into<BookId>, chapter: usize, verse: usize) -> Self {
+        Reference {
+            book: book.into(),
+            chapter,
+            verse,
+        }
+    }
+
+    /// Returns the referenced book.
+    pub fn book(&self) -> &BookId {
+        &self.book
+    }
+
+    /// Returns the chapter number.
+    pub fn chapter(&self) -> usize {
+        self.chapter
+    }
+
+    /// Returns the verse number within the chapter.
+    pub fn verse(&self) -> usize {
+        self.verse
+    }
+
+    /// Returns the OSIS identifier, such as `"Gen.1.1"`, or `None` for a
+    /// custom book, which has no standard code.
+    pub fn to_osis_id(&self) -> Option<String> {
+        let book = self.book.known()?;
+        Some(format!(
+            "{}.{}.{}",
+            book.osis_id(),
+            self.chapter,
+            self.verse
+        ))
+    }
+
+    /// Parses an OSIS identifier such as `"Gen.1.1"` or `"1Sam.3.10"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::InvalidReference`] if the identifier is not a
+    /// book code, chapter, and verse separated by dots.
+    pub fn from_osis_id(id: &str) -> Result<Self, BibleError> {
+        let mut parts = id.trim().split('.');
+        let parsed = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(book), Some(chapter), Some(verse), None) => {
+                parse_parts(BibleBook::from_osis_id(book), chapter, verse)
+            }
+            _ => None,
+        };
+        parsed.ok_or_else(|| invalid_reference(id))
+    }
+
+    /// Returns the USFM-style identifier, such as `"GEN 1:1"`, or `None` for
+    /// a custom book.
+    pub fn to_usfm_id(&self) -> Option<String> {
+        let book = self.book.known()?;
+        Some(format!(
+            "{} {}:{}",
+            book.usfm_id(),
+            self.chapter,
+            self.verse
+        ))
+    }
+
+    /// Parses a USFM-style identifier such as `"GEN 1:1"` or `"1SA 3:10"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::InvalidReference`] if the identifier is not a
+    /// book code followed by `chapter:verse`.
+    pub fn from_usfm_id(id: &str) -> Result<Self, BibleError> {
+        let parsed = id
+            .trim()
+            .split_once(char::is_whitespace)
+            .and_then(|(book, position)| {
+                let (chapter, verse) = position.trim().split_once(':')?;
+                parse_parts(BibleBook::from_usfm_id(book), chapter, verse)
+            });
+        parsed.ok_or_else(|| invalid_reference(id))
+    }
+
+    /// Returns a numeric key for this verse that is the same in every
+    /// translation, for joining user data such as highlights and notes
+    /// across translations: `BBCCCVVV`, the book's [id](BibleBook::id) then
+    /// the chapter and verse as three digits each, so John 3:16 is
+    /// `43003016`.
+    ///
+    /// Ids only agree between translations that share a versification
+    /// scheme; store [`Reference::stable_key`] where schemes may differ.
+    /// Returns `None` for a custom book, or a chapter or verse of 0 or above
+    /// 999, so every id round-trips through [`Reference::from_stable_id`].
+    pub fn stable_id(&self) -> Option<u64> {
+        let book = self.book.known()?;
+        if !(1..=999).contains(&self.chapter) || !(1..=999).contains(&self.verse) {
+            return None;
+        }
+        Some(u64::from(book.id()) * 1_000_000 + self.chapter as u64 * 1_000 + self.verse as u64)
+    }
+
+    /// Returns the reference with the given [`stable_id`](Reference::stable_id).
+    pub fn from_stable_id(id: u64) -> Option<Self> {
+        let book = BibleBook::from_id(u8::try_from(id / 1_000_000).ok()?)?;
+        let chapter = (id / 1_000 % 1_000) as usize;
+        let verse = (id % 1_000) as usize;
+        (chapter > 0 && verse > 0).then(|| Reference::new(book, chapter, verse))
+    }
+
+    /// Returns a text key for this verse qualified by the versification
+    /// scheme it is numbered in, such as `"kjv:JHN.3.16"`, using the USFM
+    /// book code. Pass [`Bible::versification`]; a Bible that declares none
+    /// is taken to follow the KJV scheme. Returns `None` for a custom book.
+    pub fn stable_key(&self, versification: Option<&str>) -> Option<String> {
+        let book = self.book.known()?;
+        let scheme = versification.map_or("kjv", str::trim).to_ascii_lowercase();
+        Some(format!(
+            "{scheme}:{}.{}.{}",
+            book.usfm_id(),
+            self.chapter,
+            self.verse
+        ))
+    }
+}
+
+fn parse_parts(book: Option<BibleBook>, chapter: &str, verse: &str) -> Option<Reference> {
+    let chapter = chapter.parse().ok().filter(|&n| n > 0)?;
+    let verse = verse.parse().ok().filter(|&n| n > 0)?;
+    Some(Reference::new(book?, chapter, verse))
+}
+
+fn invalid_reference(input: &str) -> BibleError {
+    BibleError::InvalidReference {
+        input: input.to_string(),
+    }
+}
+
+/// Known books display with their English name, custom books with their abbreviation.
+impl fmt::Display for Reference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.book.known() {
+            Some(book) => write!(f, "{} {}:{}", book.full_name(), self.chapter, self.verse),
+            None => write!(f, "{} {}:{}", self.book, self.chapter, self.verse),
+        }
+    }
+}
+
+/// How [`ReferenceRange::to_citation`] writes book names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CitationStyle {
+    /// Full English names: `"Genesis 1:1–2:3"`.
+    #[default]
+    FullName,
+    /// OSIS book abbreviations: `"Gen 1:1–2:3"`.
+    Abbreviated,
+    /// Abbreviations from a publishing standard, such as SBL:
+    /// `"1 Sam 3:10"`.
+    Abbreviations(AbbreviationStyle),
+}
+
+impl CitationStyle {
+    fn book_name(&self, book: &BookId) -> String {
+        match (book.known(), self) {
+            (Some(book), CitationStyle::FullName) => book.full_name().to_string(),
+            (Some(book), CitationStyle::Abbreviated) => book.osis_id().to_string(),
+            (Some(book), CitationStyle::Abbreviations(style)) => book.abbrev_in(*style).to_string(),
+            (None, _) => book.to_string(),
+        }
+    }
+}
+
+/// An inclusive range of verses, possibly spanning chapters or books.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReferenceRange {
+    start: Reference,
+    end: Reference,
+}
+
+impl ReferenceRange {
+    /// Creates a range from `start` to `end`, both inclusive.
+    ///
+    /// The bounds are swapped if `end` sorts before `start`.
+    pub fn new(start: Reference, end: Reference) -> Self {
+        if end < start {
+            ReferenceRange {
+                start: end,
+                end: start,
+            }
+        } else {
+            ReferenceRange { start, end }
+        }
+    }
+
+    /// Creates a range covering a single verse.
+    pub fn single(reference: Reference) -> Self {
+        ReferenceRange {
+            start: reference.clone(),
+            end: reference,
+        }
+    }
+
+    /// Returns the first verse of the range.
+    pub fn start(&self) -> &Reference {
+        &self.start
+    }
+
+    /// Returns the last verse of the range.
+    pub fn end(&self) -> &Reference {
+        &self.end
+    }
+
+    /// Returns `true` if `reference` lies within this range.
+    pub fn contains(&self, reference: &Reference) -> bool {
+        &self.start <= reference && reference <= &self.end
+    }
+
+    /// Iterates over the reference of every verse of `bible` within this
+    /// range, such as Matthew 5 to Luke 2, crossing chapter and book
+    /// boundaries in reading order. Verses missing from `bible` are skipped.
+    pub fn iter<'a>(&'a self, bible: &'a Bible) -> impl Iterator<Item = Reference> + 'a {
+        bible.verses_in(self).map(Verse::reference)
+    }
+
+    /// Formats the range as a compact citation, such as `"Gen 1:1–2:3"`,
+    /// `"Gen 1:1–5"`, or `"Gen 50:26–Exod 1:1"`, joined by an en dash.
+    ///
+    /// Chapter numbers are left out for single-chapter books, so a range in
+    /// Jude reads `"Jude 3–5"`.
+    pub fn to_citation(&self, style: CitationStyle) -> String {
+        let (start, end) = (&self.start, &self.end);
+        let single_chapter =
+            |r: &Reference| r.chapter == 1 && r.book.known().is_some_and(|b| b.is_single_chapter());
+        let location = |r: &Reference| {
+            if single_chapter(r) {
+                r.verse.to_string()
+            } else {
+                format!("{}:{}", r.chapter, r.verse)
+            }
+        };
+        let mut citation = format!("{} {}", style.book_name(&start.book), location(start));
+        if start == end {
+            return citation;
+        }
+        citation.push('\u{2013}');
+        if start.book != end.book {
+            citation.push_str(&style.book_name(&end.book));
+            citation.push(' ');
+            citation.push_str(&location(end));
+        } else if start.chapter != end.chapter {
+            citation.push_str(&format!("{}:{}", end.chapter, end.verse));
+        } else {
+            citation.push_str(&end.verse.to_string());
+        }
+        citation
+    }
+
+    /// Returns the OSIS identifier, such as `"Gen.1.1-Gen.2.3"` or
+    /// `"John.3.16"` for a single verse, or `None` if either end is in a
+    /// custom book.
+    pub fn to_osis_id(&self) -> Option<String> {
+        let start = self.start.to_osis_id()?;
+        if self.start == self.end {
+            return Some(start);
+        }
+        Some(format!("{}-{}", start, self.end.to_osis_id()?))
+    }
+
+    /// Parses an OSIS range such as `"Gen.1.1-Gen.2.3"` or a single verse
+    /// such as `"John.3.16"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::InvalidReference`] if either end is not a valid
+    /// OSIS verse identifier.
+    pub fn from_osis_id(id: &str) -> Result<Self, BibleError> {
+        let range = match id.split_once('-') {
+            Some((start, end)) => (Reference::from_osis_id(start), Reference::from_osis_id(end)),
+            None => (Reference::from_osis_id(id), Reference::from_osis_id(id)),
+        };
+        match range {
+            (Ok(start), Ok(end)) => Ok(ReferenceRange::new(start, end)),
+            _ => Err(invalid_reference(id)),
+        }
+    }
+}
+
+/// Displays as `"Genesis 1:1-5"`, `"Genesis 1:1-2:3"`, or `"Genesis 50:26-Exodus 1:1"`.
+impl fmt::Display for ReferenceRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else if self.start.book != self.end.book {
+            write!(f, "{}-{}", self.start, self.end)
+        } else if self.start.chapter != self.end.chapter {
+            write!(f, "{}-{}:{}", self.start, self.end.chapter, self.end.verse)
+        } else {
+            write!(f, "{}-{}", self.start, self.end.verse)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{book::Book, chapter::Chapter, test_support::TestBible};
+
+    #[test]
+    fn test_citations() {
+        let range = |a: (BibleBook, usize, usize), b: (BibleBook, usize, usize)| {
+            ReferenceRange::new(Reference::new(a.0, a.1, a.2), Reference::new(b.0, b.1, b.2))
+        };
+        let abbreviated = |r: ReferenceRange| r.to_citation(CitationStyle::Abbreviated);
+        use BibleBook::{Exodus, Genesis, Jonah, Jude, Obadiah};
+
+        assert_eq!(
+            abbreviated(range((Genesis, 1, 1), (Genesis, 2, 3))),
+            "Gen 1:1–2:3"
+        );
+        assert_eq!(
+            abbreviated(range((Genesis, 1, 1), (Genesis, 1, 5))),
+            "Gen 1:1–5"
+        );
+        assert_eq!(
+            abbreviated(range((Genesis, 1, 1), (Genesis, 1, 1))),
+            "Gen 1:1"
+        );
+        assert_eq!(abbreviated(range((Jude, 1, 3), (Jude, 1, 5))), "Jude 3–5");
+        assert_eq!(abbreviated(range((Jude, 1, 3), (Jude, 1, 3))), "Jude 3");
+        assert_eq!(
+            abbreviated(range((Genesis, 50, 26), (Exodus, 1, 1))),
+            "Gen 50:26–Exod 1:1"
+        );
+        assert_eq!(
+            range((Obadiah, 1, 21), (Jonah, 1, 2)).to_citation(CitationStyle::FullName),
+            "Obadiah 21–Jonah 1:2"
+        );
+        assert_eq!(
+            range((Genesis, 50, 26), (Exodus, 1, 1))
+                .to_citation(CitationStyle::Abbreviations(AbbreviationStyle::Logos)),
+            "Ge 50:26–Ex 1:1"
+        );
+        let custom = ReferenceRange::new(
+            Reference::new(BookId::from_abbrev("enoch"), 1, 1),
+            Reference::new(BookId::from_abbrev("enoch"), 1, 9),
+        );
+        assert_eq!(custom.to_citation(CitationStyle::FullName), "enoch 1:1–9");
+    }
+
+    #[test]
+    fn test_range_iterates_across_books() {
+        let book = |id: BibleBook, chapters: &[usize]| {
+            let chapters = chapters
+                .iter()
+                .enumerate()
+                .map(|(c, &count)| {
+                    let verses = (1..=count)
+                        .map(|v| Verse::new(id, c + 1, v, "text".to_string()))
+                        .collect();
+                    Chapter::new(verses, c + 1)
+                })
+                .collect();
+            Book::new(id.as_str().into(), id.full_name().into(), chapters)
+        };
+        let bible = TestBible::new(vec![
+            book(BibleBook::Matthew, &[2, 3]),
+            book(BibleBook::Mark, &[1]),
+            book(BibleBook::Luke, &[2, 2]),
+        ])
+        .build();
+        let range = ReferenceRange::new(
+            Reference::new(BibleBook::Matthew, 2, 2),
+            Reference::new(BibleBook::Luke, 2, 1),
+        );
+        let references: Vec<String> = range.iter(&bible).map(|r| r.to_string()).collect();
+        assert_eq!(
+            references,
+            [
+                "Matthew 2:2",
+                "Matthew 2:3",
+                "Mark 1:1",
+                "Luke 1:1",
+                "Luke 1:2",
+                "Luke 2:1"
+            ]
+        );
+        let beyond = ReferenceRange::single(Reference::new(BibleBook::John, 1, 1));
+        assert_eq!(beyond.iter(&bible).count(), 0);
+    }
+
+    #[test]
+    fn test_display_and_ordering() {
+        let a = Reference::new(BibleBook::Genesis, 1, 2);
+        let b = Reference::new(BibleBook::Genesis, 2, 1);
+        let c = Reference::new(BookId::from_abbrev("enoch"), 1, 1);
+
+        assert_eq!(a.to_string(), "Genesis 1:2");
+        assert_eq!(c.to_string(), "enoch 1:1");
+        assert!(a < b && b < c);
+    }
+
+    #[test]
+    fn test_range_contains_and_display() {
+        let start = Reference::new(BibleBook::Genesis, 1, 1);
+        let end = Reference::new(BibleBook::Genesis, 2, 3);
+        let range = ReferenceRange::new(end.clone(), start.clone());
+
+        assert_eq!(range.start(), &start);
+        assert!(range.contains(&Reference::new(BibleBook::Genesis, 1, 31)));
+        assert!(!range.contains(&Reference::new(BibleBook::Genesis, 2, 4)));
+        assert_eq!(range.to_string(), "Genesis 1:1-2:3");
+
+        let same_chapter =
+            ReferenceRange::new(start.clone(), Reference::new(BibleBook::Genesis, 1, 5));
+        assert_eq!(same_chapter.to_string(), "Genesis 1:1-5");
+        assert_eq!(ReferenceRange::single(start).to_string(), "Genesis 1:1");
+    }
+
+    #[test]
+    fn test_osis_and_usfm_ids() {
+        let reference = Reference::new(BibleBook::FirstSamuel, 3, 10);
+        assert_eq!(reference.to_osis_id().as_deref(), Some("1Sam.3.10"));
+        assert_eq!(reference.to_usfm_id().as_deref(), Some("1SA 3:10"));
+        assert_eq!(Reference::from_osis_id("1Sam.3.10").unwrap(), reference);
+        assert_eq!(Reference::from_usfm_id("1sa 3:10").unwrap(), reference);
+        assert_eq!(
+            Reference::from_osis_id("AddPs.1.1").unwrap().book(),
+            &BibleBook::Psalm151
+        );
+
+        assert!(Reference::new(BookId::from_abbrev("enoch"), 1, 1)
+            .to_osis_id()
+            .is_none());
+        for invalid in ["Gen.1", "Gen.1.1.1", "Xyz.1.1", "Gen.0.1"] {
+            assert!(matches!(
+                Reference::from_osis_id(invalid),
+                Err(BibleError::InvalidReference { .. })
+            ));
+        }
+        assert!(Reference::from_usfm_id("GEN 1.1").is_err());
+
+        let range = ReferenceRange::from_osis_id("Gen.1.1-Gen.2.3").unwrap();
+        assert_eq!(range.to_string(), "Genesis 1:1-2:3");
+        assert_eq!(range.to_osis_id().as_deref(), Some("Gen.1.1-Gen.2.3"));
+        let single = ReferenceRange::from_osis_id("John.3.16").unwrap();
+        assert_eq!(single.to_osis_id().as_deref(), Some("John.3.16"));
+        assert!(ReferenceRange::from_osis_id("Gen.1.1-").is_err());
+    }
+
+    #[test]
+    fn test_stable_ids() {
+        let reference = Reference::new(BibleBook::John, 3, 16);
+        assert_eq!(reference.stable_id(), Some(43_003_016));
+        assert_eq!(
+            Reference::from_stable_id(43_003_016),
+            Some(reference.clone())
+        );
+        assert_eq!(reference.stable_key(None).as_deref(), Some("kjv:JHN.3.16"));
+        assert_eq!(
+            reference.stable_key(Some("LXX")).as_deref(),
+            Some("lxx:JHN.3.16")
+        );
+
+        // Ids sort in canonical order.
+        let later = Reference::new(BibleBook::Acts, 1, 1);
+        assert!(reference.stable_id() < later.stable_id());
+
+        assert_eq!(Reference::new(BibleBook::Psalms, 1000, 1).stable_id(), None);
+        assert_eq!(
+            Reference::new(BookId::from_abbrev("enoch"), 1, 1).stable_id(),
+            None
+        );
+        for invalid in [0, 43_000_016, 43_003_000, 999_001_001] {
+            assert_eq!(Reference::from_stable_id(invalid), None);
+        }
+
+        // Every id given out reads back as the same reference.
+        for (chapter, verse) in [(0, 16), (3, 0), (1, 1), (999, 999)] {
+            let reference = Reference::new(BibleBook::John, chapter, verse);
+            match reference.stable_id() {
+                Some(id) => assert_eq!(Reference::from_stable_id(id), Some(reference)),
+                None => assert!(chapter == 0 || verse == 0),
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Parsing of human-readable references such as `"John 3:16"` and
+//! `"Gen 1:1-2:3"`.
+//!
+//! By default references must be written as `Book Chapter:Verse`. Under
+//! [`ReferenceParsing::Tolerant`] the forms common in copied citations are
+//! also accepted: `"John 3.16"`, European `"Jn 3,16"`, en and em dashes in
+//! ranges, a missing space as in `"Jn3:16"`, extra spaces, and trailing
+//! punctuation.
+
+use crate::{
+    bible::{Bible, BibleError},
+    reference::{Reference, ReferenceRange},
+};
+
+/// How strictly [`Bible::parse_reference`] and [`Bible::parse_range`] read
+/// their input; set with [`Bible::set_reference_parsing`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ReferenceParsing {
+    /// Only `Book Chapter:Verse`, with ranges joined by `-`.
+    #[default]
+    Strict,
+    /// Also accept the messier forms listed in the module documentation.
+    Tolerant,
+}
+
+impl Bible {
+    /// Parses a reference such as `"Genesis 1:1"` or `"Jn 3:16"`, resolving
+    /// the book as [`Bible::get_verse_by_reference`] does. The verse need not
+    /// exist in this Bible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::InvalidReference`] if the input is malformed, or
+    /// [`BibleError::BookNotFound`] if the book is not recognized.
+    pub fn parse_reference(&self, input: &str) -> Result<Reference, BibleError> {
+        self.parse_tolerantly(input, |text| self.parse_single(text, input))
+    }
+
+    /// Parses a range such as `"John 3:16-18"`, `"Gen 1:1-2:3"`, or
+    /// `"Gen 50:26-Exod 1:1"`. A single reference gives a one-verse range.
+    ///
+    /// # Errors
+    ///
+    /// As for [`Bible::parse_reference`].
+    pub fn parse_range(&self, input: &str) -> Result<ReferenceRange, BibleError> {
+        self.parse_tolerantly(input, |text| {
+            let Some((start, end)) = text.split_once('-') else {
+                return self.parse_single(text, input).map(ReferenceRange::single);
+            };
+            let start = self.parse_single(start.trim(), input)?;
+            let end = end.trim();
+            let end = if end.contains(char::is_alphabetic) {
+                self.parse_single(end, input)?
+            } else {
+                let (chapter, verse) = match end.split_once(':') {
+                    Some((chapter, verse)) => (parse_number(chapter, input)?, verse),
+                    None => (start.chapter(), end),
+                };
+                Reference::new(start.book(), chapter, parse_number(verse, input)?)
+            };
+            Ok(ReferenceRange::new(start, end))
+        })
+    }
+
+    /// Runs `parse` on the trimmed input and, in tolerant mode, retries on
+    /// the normalized input, keeping the first error.
+    fn parse_tolerantly<T>(
+        &self,
+        input: &str,
+        parse: impl Fn(&str) -> Result<T, BibleError>,
+    ) -> Result<T, BibleError> {
+        let strict = parse(input.trim());
+        match (strict, self.reference_parsing()) {
+            (Err(error), ReferenceParsing::Tolerant) => parse(&normalize(input)).map_err(|_| error),
+            (result, _) => result,
+        }
+    }
+
+    fn parse_single(&self, text: &str, input: &str) -> Result<Reference, BibleError> {
+        let (book_and_chapter, verse) = text
+            .rsplit_once(':')
+            .ok_or_else(|| invalid_reference(input))?;
+        let (book, chapter) = book_and_chapter
+            .trim_end()
+            .rsplit_once(' ')
+            .ok_or_else(|| invalid_reference(input))?;
+        let (chapter, verse) = (parse_number(chapter, input)?, parse_number(verse, input)?);
+        let book = book.trim();
+        let book = self
+            .resolve_book(book)
+            .ok_or_else(|| BibleError::BookNotFound {
+                suggestions: self.book_suggestions(book),
+                book_abbrev: book.to_ascii_lowercase(),
+                book_name: book.to_string(),
+                translation: self.name().to_string(),
+            })?;
+        Ok(Reference::new(book, chapter, verse))
+    }
+}
+
+/// Parses a chapter or verse number, which counts from 1.
+fn parse_number(text: &str, input: &str) -> Result<usize, BibleError> {
+    match text.trim().parse() {
+        Ok(0) | Err(_) => Err(invalid_reference(input)),
+        Ok(number) => Ok(number),
+    }
+}
+
+fn invalid_reference(input: &str) -> BibleError {
+    BibleError::InvalidReference {
+        input: input.trim().to_string(),
+    }
+}
+
+/// Rewrites a messy citation into the strict form: dashes become `-`, a `.`
+/// or `,` between digits becomes `:`, a space is inserted between a book
+/// name and its chapter, runs of whitespace collapse, spaces around `:` and
+/// `-` are dropped, and trailing punctuation is removed.
+fn normalize(input: &str) -> String {
+    let input = input.trim().trim_end_matches(['.', ',', ';']);
+    let mut chars = input.chars().peekable();
+    let mut out = String::with_capacity(input.len() + 1);
+    while let Some(c) = chars.next() {
+        let prev = out.chars().next_back();
+        let next = chars.peek().copied();
+        let c = match c {
+            '\u{2010}'..='\u{2015}' | '\u{2212}' => '-',
+            c => c,
+        };
+        match c {
+            '.' | ','
+                if prev.is_some_and(|p| p.is_ascii_digit())
+                    && next.is_some_and(|n| n.is_ascii_digit()) =>
+            {
+                out.push(':')
+            }
+            c if c.is_whitespace() => {
+                if !matches!(prev, Some(' ' | ':' | '-')) {
+                    out.push(' ');
+                }
+            }
+            ':' | '-' => {
+                if prev == Some(' ') {
+                    out.pop();
+                }
+                out.push(c);
+            }
+            c if c.is_ascii_digit() && prev.is_some_and(char::is_alphabetic) => {
+                out.push(' ');
+                out.push(c);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_messy_citations() {
+        assert_eq!(normalize("John 3.16"), "John 3:16");
+        assert_eq!(normalize("Jn 3,16"), "Jn 3:16");
+        assert_eq!(normalize("Jn3:16"), "Jn 3:16");
+        assert_eq!(normalize("1Co13:1"), "1Co 13:1");
+        assert_eq!(normalize("  Gen   1 : 1 \u{2013} 2 : 3. "), "Gen 1:1-2:3");
+        assert_eq!(
+            normalize("Gen 50:26\u{2014}Exod 1:1;"),
+            "Gen 50:26-Exod 1:1"
+        );
+        assert_eq!(normalize("Song of Solomon 2,1"), "Song of Solomon 2:1");
+    }
+}
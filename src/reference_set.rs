@@ -0,0 +1,379 @@
+//! Sets of references built from ranges, with union, intersection and
+//! difference.
+//!
+//! A [`ReferenceSet`] describes which locations are included, independent of
+//! any loaded text: "Genesis 1–3 except 2:4–25", "everything read so far", or
+//! "all of the Old Testament but the deuterocanon". Use
+//! [`ReferenceSet::verses`] to list the verses of a Bible that fall inside.
+
+use crate::{
+    bible::Bible,
+    book_id::BookId,
+    reference::{Reference, ReferenceRange},
+    verse::Verse,
+};
+
+/// A position within a book: the chapter and verse packed into one ordinal,
+/// so that consecutive verses of a chapter have consecutive ordinals.
+type Key = (BookId, u64);
+
+/// Chapters stop one short of `u32::MAX`, so the exclusive end of an
+/// interval (the last ordinal plus one) cannot overflow.
+fn key(reference: &Reference) -> Key {
+    let clamp = |n: usize, max: u32| n.min(max as usize) as u64;
+    (
+        reference.book().clone(),
+        clamp(reference.chapter(), u32::MAX - 1) << 32 | clamp(reference.verse(), u32::MAX),
+    )
+}
+
+fn reference((book, ordinal): &Key) -> Reference {
+    Reference::new(
+        book,
+        (ordinal >> 32) as usize,
+        (ordinal & 0xffff_ffff) as usize,
+    )
+}
+
+/// A set of references stored as ranges.
+///
+/// Ranges are kept sorted, disjoint and merged where they touch, so each
+/// location is stored once and containment is a binary search. Two sets are
+/// equal when they contain the same locations.
+///
+/// Without text the set cannot know where a chapter ends, so Genesis 1:1–31
+/// and 2:1–25 do not touch, and together differ from Genesis 1:1–2:25. Call
+/// [`ReferenceSet::aligned_to`] with a Bible to close such gaps before
+/// comparing sets or testing containment across chapters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ReferenceSet {
+    /// Half-open intervals `[start, end)` of verse ordinals.
+    intervals: Vec<(Key, Key)>,
+}
+
+impl ReferenceSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a set covering every chapter and verse of `books`, such as
+    /// the deuterocanonical books to exclude from a reading plan.
+    pub fn whole_books<B: Into<BookId>>(books: impl IntoIterator<Item = B>) -> Self {
+        books
+            .into_iter()
+            .map(|book| {
+                let book = book.into();
+                ReferenceRange::new(
+                    Reference::new(book.clone(), 0, 0),
+                    Reference::new(book, usize::MAX, usize::MAX),
+                )
+            })
+            .collect()
+    }
+
+    /// Adds every location in `range` to the set.
+    pub fn insert(&mut self, range: ReferenceRange) {
+        let (start, (book, end)) = (key(range.start()), key(range.end()));
+        let interval = (start, (book, end + 1));
+        let at = self.intervals.partition_point(|(s, _)| *s < interval.0);
+        self.intervals.insert(at, interval);
+        self.normalize();
+    }
+
+    /// Returns `true` if the set contains no locations.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns `true` if `reference` is in the set.
+    pub fn contains(&self, reference: &Reference) -> bool {
+        let key = key(reference);
+        let at = self.intervals.partition_point(|(start, _)| *start <= key);
+        at > 0 && key < self.intervals[at - 1].1
+    }
+
+    /// Returns `true` if every location in `range` is in the set.
+    pub fn contains_range(&self, range: &ReferenceRange) -> bool {
+        ReferenceSet::from(range.clone())
+            .difference(self)
+            .is_empty()
+    }
+
+    /// Returns `true` if any location in `range` is in the set.
+    pub fn overlaps(&self, range: &ReferenceRange) -> bool {
+        !self.intersection(&range.clone().into()).is_empty()
+    }
+
+    /// Returns the locations in either set.
+    pub fn union(&self, other: &ReferenceSet) -> ReferenceSet {
+        let mut intervals = Vec::with_capacity(self.intervals.len() + other.intervals.len());
+        let (mut a, mut b) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+        // Merge the two sorted lists, then join overlapping neighbours.
+        while let Some(next) = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) if y.0 < x.0 => b.next(),
+            (Some(_), _) => a.next(),
+            (None, _) => b.next(),
+        } {
+            intervals.push(next.clone());
+        }
+        let mut set = ReferenceSet { intervals };
+        set.normalize();
+        set
+    }
+
+    /// Returns the locations in both sets.
+    pub fn intersection(&self, other: &ReferenceSet) -> ReferenceSet {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let start = (&a.0).max(&b.0);
+            let end = (&a.1).min(&b.1);
+            if start < end {
+                intervals.push((start.clone(), end.clone()));
+            }
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        ReferenceSet { intervals }
+    }
+
+    /// Returns the locations in this set but not in `other`.
+    pub fn difference(&self, other: &ReferenceSet) -> ReferenceSet {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+        for (start, end) in &self.intervals {
+            let mut start = start.clone();
+            // Skip removals that end before this interval begins.
+            while other.intervals.get(j).is_some_and(|(_, e)| *e <= start) {
+                j += 1;
+            }
+            let mut k = j;
+            while let Some((s, e)) = other.intervals.get(k).filter(|(s, _)| s < end) {
+                if start < *s {
+                    intervals.push((start.clone(), s.clone()));
+                }
+                start = start.max(e.clone());
+                k += 1;
+            }
+            if start < *end {
+                intervals.push((start, end.clone()));
+            }
+        }
+        ReferenceSet { intervals }
+    }
+
+    /// Returns the set with every gap between two ranges of a book filled
+    /// where `bible` has no verse in the gap, such as between Genesis 1:31
+    /// and 2:1 or around an omitted verse, so that a set built chapter by
+    /// chapter equals one built from ranges spanning the chapters.
+    ///
+    /// Gaps in books `bible` does not have are left open.
+    pub fn aligned_to(&self, bible: &Bible) -> ReferenceSet {
+        let mut intervals: Vec<(Key, Key)> = Vec::with_capacity(self.intervals.len());
+        for (start, end) in &self.intervals {
+            match intervals.last_mut() {
+                Some((_, last_end)) if gap_is_empty(bible, last_end, start) => {
+                    *last_end = end.clone();
+                }
+                _ => intervals.push((start.clone(), end.clone())),
+            }
+        }
+        ReferenceSet { intervals }
+    }
+
+    /// Iterates over the set as inclusive ranges in canonical order.
+    pub fn ranges(&self) -> impl Iterator<Item = ReferenceRange> + '_ {
+        self.intervals.iter().map(|(start, (book, end))| {
+            ReferenceRange::new(reference(start), reference(&(book.clone(), end - 1)))
+        })
+    }
+
+    /// Iterates over the verses of `bible` in the set, in reading order.
+    pub fn verses<'a>(&'a self, bible: &'a Bible) -> impl Iterator<Item = &'a Verse> + 'a {
+        bible
+            .all_verses()
+            .filter(|verse| self.contains(&verse.reference()))
+    }
+
+    /// Merges intervals that overlap or touch. The intervals must already be
+    /// sorted by start.
+    fn normalize(&mut self) {
+        let mut merged: Vec<(Key, Key)> = Vec::with_capacity(self.intervals.len());
+        for (start, end) in self.intervals.drain(..) {
+            match merged.last_mut() {
+                Some((_, last_end)) if start <= *last_end => {
+                    if end > *last_end {
+                        *last_end = end;
+                    }
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        self.intervals = merged;
+    }
+}
+
+/// Returns `true` if `from` and `to` are in the same book and `bible` has
+/// that book but no verse in `[from, to)`.
+fn gap_is_empty(bible: &Bible, (book, from): &Key, (to_book, to): &Key) -> bool {
+    if book != to_book {
+        return false;
+    }
+    let Ok(book) = bible.get_book(book) else {
+        return false;
+    };
+    let chapters = (from >> 32) as usize..=(to >> 32) as usize;
+    book.chapters()
+        .iter()
+        .filter(|chapter| chapters.contains(&chapter.number()))
+        .flat_map(|chapter| chapter.get_verses())
+        .all(|verse| {
+            let (_, ordinal) = key(&verse.reference());
+            ordinal < *from || ordinal >= *to
+        })
+}
+
+impl From<ReferenceRange> for ReferenceSet {
+    fn from(range: ReferenceRange) -> Self {
+        let mut set = ReferenceSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<ReferenceRange> for ReferenceSet {
+    fn from_iter<I: IntoIterator<Item = ReferenceRange>>(ranges: I) -> Self {
+        let mut intervals: Vec<(Key, Key)> = ranges
+            .into_iter()
+            .map(|range| {
+                let (book, end) = key(range.end());
+                (key(range.start()), (book, end + 1))
+            })
+            .collect();
+        intervals.sort_unstable();
+        let mut set = ReferenceSet { intervals };
+        set.normalize();
+        set
+    }
+}
+
+impl Extend<ReferenceRange> for ReferenceSet {
+    fn extend<I: IntoIterator<Item = ReferenceRange>>(&mut self, ranges: I) {
+        *self = self.union(&ranges.into_iter().collect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+    use crate::test_support::TestBible;
+
+    fn range(
+        book: BibleBook,
+        (c1, v1): (usize, usize),
+        (c2, v2): (usize, usize),
+    ) -> ReferenceRange {
+        ReferenceRange::new(Reference::new(book, c1, v1), Reference::new(book, c2, v2))
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let genesis: ReferenceSet = [
+            range(BibleBook::Genesis, (1, 1), (1, 5)),
+            range(BibleBook::Genesis, (1, 6), (2, 3)),
+            range(BibleBook::Genesis, (1, 3), (1, 4)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            genesis.ranges().collect::<Vec<_>>(),
+            [range(BibleBook::Genesis, (1, 1), (2, 3))]
+        );
+
+        let removed = genesis.difference(&range(BibleBook::Genesis, (1, 10), (1, 20)).into());
+        assert!(removed.contains(&Reference::new(BibleBook::Genesis, 1, 9)));
+        assert!(!removed.contains(&Reference::new(BibleBook::Genesis, 1, 15)));
+        assert!(removed.contains(&Reference::new(BibleBook::Genesis, 1, 21)));
+        assert_eq!(removed.ranges().count(), 2);
+
+        let exodus = ReferenceSet::from(range(BibleBook::Exodus, (1, 1), (1, 1)));
+        let both = removed.union(&exodus);
+        assert!(both.contains_range(&range(BibleBook::Genesis, (1, 1), (1, 9))));
+        assert!(!both.contains_range(&range(BibleBook::Genesis, (1, 1), (1, 10))));
+        assert!(both.overlaps(&range(BibleBook::Genesis, (1, 15), (1, 25))));
+        assert_eq!(both.intersection(&exodus), exodus);
+        assert_eq!(both.difference(&both), ReferenceSet::new());
+        assert_eq!(both.difference(&removed), exodus);
+    }
+
+    #[test]
+    fn test_aligned_across_chapters() {
+        use crate::{book::Book, chapter::Chapter};
+
+        let chapters = [31, 25, 24]
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let verses = (1..=count)
+                    .map(|v| Verse::new(BibleBook::Genesis, i + 1, v, "Text".into()))
+                    .collect();
+                Chapter::new(verses, i + 1)
+            })
+            .collect();
+        let bible =
+            TestBible::new(vec![Book::new("gn".into(), "Genesis".into(), chapters)]).build();
+
+        let by_chapter: ReferenceSet = [
+            range(BibleBook::Genesis, (1, 1), (1, 31)),
+            range(BibleBook::Genesis, (2, 1), (2, 25)),
+        ]
+        .into_iter()
+        .collect();
+        let spanning = ReferenceSet::from(range(BibleBook::Genesis, (1, 1), (2, 25)));
+        let within = range(BibleBook::Genesis, (1, 1), (2, 3));
+        assert!(!by_chapter.contains_range(&within));
+        assert_ne!(by_chapter, spanning);
+
+        let aligned = by_chapter.aligned_to(&bible);
+        assert!(aligned.contains_range(&within));
+        assert_eq!(aligned, spanning);
+        assert_eq!(
+            aligned.ranges().collect::<Vec<_>>(),
+            [range(BibleBook::Genesis, (1, 1), (2, 25))]
+        );
+
+        // A gap holding real verses stays open.
+        let partial: ReferenceSet = [
+            range(BibleBook::Genesis, (1, 1), (1, 30)),
+            range(BibleBook::Genesis, (2, 1), (2, 25)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(partial.aligned_to(&bible).ranges().count(), 2);
+        assert!(!partial
+            .aligned_to(&bible)
+            .contains(&Reference::new(BibleBook::Genesis, 1, 31)));
+    }
+
+    #[test]
+    fn test_whole_books() {
+        let excluded = ReferenceSet::whole_books([BibleBook::Tobit, BibleBook::Judith]);
+        assert!(excluded.contains(&Reference::new(BibleBook::Tobit, 14, 15)));
+        assert!(!excluded.contains(&Reference::new(BibleBook::Esther, 1, 1)));
+
+        let reading =
+            ReferenceSet::whole_books([BibleBook::Esther, BibleBook::Tobit, BibleBook::Judith]);
+        assert_eq!(
+            reading.difference(&excluded),
+            ReferenceSet::whole_books([BibleBook::Esther])
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//! HTML and Markdown rendering of verses and chapters.
+//!
+//! Translator-supplied words (see [`SpanKind::SuppliedWords`]) are rendered in
+//! italics, matching the traditional typesetting of the KJV. Chapter renderers
+//! take the translation's [`TextDirection`] so right-to-left scripts display
+//! correctly downstream.
+
+use crate::{
+    bible::TextDirection,
+    chapter::{Chapter, Paragraph, ParagraphKind},
+    verse::{SpanKind, Verse},
+};
+
+/// Renders a verse as an HTML fragment.
+///
+/// The verse number is emitted as a `<sup>` element and supplied words are
+/// wrapped in `<i>` tags. Verse text is HTML-escaped.
+pub fn verse_to_html(verse: &Verse) -> String {
+    let mut out = format!(
+        "<span class=\"verse\" data-verse=\"{}\"><sup>{}</sup> ",
+        verse.number(),
+        verse.number_label()
+    );
+    for span in verse.spans() {
+        match span.kind {
+            SpanKind::Plain => push_html_escaped(&mut out, span.text),
+            SpanKind::SuppliedWords => {
+                out.push_str("<i>");
+                push_html_escaped(&mut out, span.text);
+                out.push_str("</i>");
+            }
+        }
+    }
+    out.push_str("</span>");
+    out
+}
+
+/// Renders a chapter as an HTML `<section>` containing each verse.
+///
+/// The section carries a `dir` attribute matching `direction`. When the
+/// chapter has paragraph data, verses are grouped into `<p class="prose">`
+/// and `<p class="poetry q1">` elements.
+pub fn chapter_to_html(chapter: &Chapter, direction: TextDirection) -> String {
+    let mut out = format!(
+        "<section class=\"chapter\" data-chapter=\"{}\" dir=\"{}\">\n",
+        chapter.number(),
+        direction
+    );
+    if let Some(superscription) = chapter.superscription() {
+        out.push_str("<p class=\"superscription\">");
+        push_html_escaped(&mut out, superscription);
+        out.push_str("</p>\n");
+    }
+    for (paragraph, verses) in paragraph_groups(chapter) {
+        match paragraph {
+            Some(p) if p.kind() == ParagraphKind::Poetry => {
+                out.push_str(&format!("<p class=\"poetry q{}\">\n", p.indent().max(1)))
+            }
+            Some(_) => out.push_str("<p class=\"prose\">\n"),
+            None => {}
+        }
+        for verse in verses {
+            out.push_str(&verse_to_html(verse));
+            out.push('\n');
+        }
+        if paragraph.is_some() {
+            out.push_str("</p>\n");
+        }
+    }
+    out.push_str("</section>");
+    out
+}
+
+/// Renders a verse as Markdown, with the verse number in bold and supplied
+/// words in italics.
+pub fn verse_to_markdown(verse: &Verse) -> String {
+    let mut out = format!("**{}** ", verse.number_label());
+    for span in verse.spans() {
+        match span.kind {
+            SpanKind::Plain => push_markdown_escaped(&mut out, span.text),
+            SpanKind::SuppliedWords => {
+                out.push('*');
+                push_markdown_escaped(&mut out, span.text);
+                out.push('*');
+            }
+        }
+    }
+    out
+}
+
+/// Renders a chapter as Markdown, one verse per line under a chapter heading.
+///
+/// Right-to-left chapters are wrapped in a `<div dir="rtl">` block, which
+/// common Markdown renderers pass through as HTML.
+pub fn chapter_to_markdown(chapter: &Chapter, direction: TextDirection) -> String {
+    let mut out = String::new();
+    if direction == TextDirection::Rtl {
+        out.push_str("<div dir=\"rtl\">\n\n");
+    }
+    out.push_str(&format!("## Chapter {}\n\n", chapter.number()));
+    if let Some(superscription) = chapter.superscription() {
+        out.push('*');
+        push_markdown_escaped(&mut out, superscription);
+        out.push_str("*\n\n");
+    }
+    for (i, (paragraph, verses)) in paragraph_groups(chapter).into_iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let indent = paragraph
+            .filter(|p| p.kind() == ParagraphKind::Poetry)
+            .map_or(0, |p| p.indent().max(1));
+        for verse in verses {
+            out.push_str(&"&emsp;".repeat(indent as usize));
+            out.push_str(&verse_to_markdown(verse));
+            out.push_str("  \n");
+        }
+    }
+    if direction == TextDirection::Rtl {
+        out.push_str("\n</div>\n");
+    }
+    out
+}
+
+/// Splits a chapter's verses by paragraph, or returns them as one
+/// unstructured group when the chapter has no paragraph data. Paragraphs
+/// left without verses are skipped.
+pub(crate) fn paragraph_groups(chapter: &Chapter) -> Vec<(Option<&Paragraph>, &[Verse])> {
+    if chapter.paragraphs().is_empty() {
+        return vec![(None, chapter.get_verses())];
+    }
+    chapter
+        .paragraphs()
+        .iter()
+        .map(|p| (Some(p), chapter.paragraph_verses(p)))
+        .filter(|(_, verses)| !verses.is_empty())
+        .collect()
+}
+
+pub(crate) fn push_html_escaped(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+fn push_markdown_escaped(out: &mut String, text: &str) {
+    for c in text.chars() {
+        if matches!(c, '*' | '_' | '`' | '\\') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+    use crate::test_support::TestBible;
+
+    #[test]
+    fn test_supplied_words_rendered_in_italics() {
+        let verse = Verse::new(BibleBook::Genesis, 1, 2, "darkness {was} here".into());
+        assert_eq!(
+            verse_to_html(&verse),
+            "<span class=\"verse\" data-verse=\"2\"><sup>2</sup> darkness <i>was</i> here</span>"
+        );
+        assert_eq!(verse_to_markdown(&verse), "**2** darkness *was* here");
+    }
+
+    #[test]
+    fn test_chapter_direction_attributes() {
+        let verses = vec![Verse::new(BibleBook::Genesis, 1, 1, "בראשית".into())];
+        let chapter = Chapter::new(verses, 1);
+
+        assert!(chapter_to_html(&chapter, TextDirection::Rtl).contains("dir=\"rtl\""));
+        assert!(chapter_to_html(&chapter, TextDirection::Ltr).contains("dir=\"ltr\""));
+        assert!(chapter_to_markdown(&chapter, TextDirection::Rtl).starts_with("<div dir=\"rtl\">"));
+        assert!(chapter_to_markdown(&chapter, TextDirection::Ltr).starts_with("## Chapter 1"));
+    }
+
+    #[test]
+    fn test_paragraph_structure_rendered() {
+        use crate::chapter::ParagraphMarker;
+
+        let verses = (1..=3)
+            .map(|v| Verse::new(BibleBook::Psalms, 1, v, format!("v{}", v)))
+            .collect();
+        let chapter = Chapter::new(verses, 1).with_paragraph_markers(vec![
+            ParagraphMarker {
+                start: 1,
+                kind: ParagraphKind::Prose,
+                indent: 0,
+            },
+            ParagraphMarker {
+                start: 2,
+                kind: ParagraphKind::Poetry,
+                indent: 2,
+            },
+        ]);
+
+        let html = chapter_to_html(&chapter, TextDirection::Ltr);
+        assert_eq!(html.matches("<p class=\"prose\">").count(), 1);
+        assert_eq!(html.matches("<p class=\"poetry q2\">").count(), 1);
+
+        let markdown = chapter_to_markdown(&chapter, TextDirection::Ltr);
+        assert!(markdown.contains("**1** v1  \n\n&emsp;&emsp;**2** v2"));
+    }
+
+    fn paragraphed_bible(texts: &[&str], starts: &[usize]) -> crate::Bible {
+        use crate::{book::Book, chapter::ParagraphMarker};
+
+        let verses = texts
+            .iter()
+            .enumerate()
+            .filter(|(_, text)| !text.is_empty())
+            .map(|(i, text)| Verse::new(BibleBook::Mark, 1, i + 1, text.to_string()))
+            .collect();
+        let markers = starts
+            .iter()
+            .map(|&start| ParagraphMarker {
+                start,
+                kind: ParagraphKind::Prose,
+                indent: 0,
+            })
+            .collect();
+        let chapter = Chapter::new(verses, 1).with_paragraph_markers(markers);
+        TestBible::new(vec![Book::new("mk".into(), "Mark".into(), vec![chapter])]).build()
+    }
+
+    #[test]
+    fn test_paragraphs_of_subset_chapter() {
+        use crate::{
+            chunk::ChunkOptions,
+            reference::{Reference, ReferenceRange},
+        };
+
+        let bible = paragraphed_bible(&["a", "b", "c", "d", "e"], &[1, 3]);
+        let subset = bible.subset(&[ReferenceRange::new(
+            Reference::new(BibleBook::Mark, 1, 3),
+            Reference::new(BibleBook::Mark, 1, 4),
+        )]);
+        let chapter = subset
+            .get_book(BibleBook::Mark)
+            .unwrap()
+            .get_chapter(1)
+            .unwrap();
+
+        let html = chapter_to_html(chapter, TextDirection::Ltr);
+        assert_eq!(html.matches("<p class=\"prose\">").count(), 1);
+        assert!(html.contains("data-verse=\"4\""));
+        let chunks = subset.chunks(&ChunkOptions::paragraphs());
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].range().to_string(), "Mark 1:3-4");
+    }
+
+    #[test]
+    fn test_paragraphs_with_omitted_verse() {
+        use crate::chunk::ChunkOptions;
+
+        let bible = paragraphed_bible(&["a", "", "c", "d"], &[1, 3]);
+        let chapter = bible
+            .get_book(BibleBook::Mark)
+            .unwrap()
+            .get_chapter(1)
+            .unwrap();
+
+        let html = chapter_to_html(chapter, TextDirection::Ltr);
+        assert_eq!(html.matches("<p class=\"prose\">").count(), 2);
+        assert!(!html.contains("data-verse=\"2\""));
+        let chunks = bible.chunks(&ChunkOptions::paragraphs());
+        let ranges: Vec<String> = chunks.iter().map(|c| c.range().to_string()).collect();
+        assert_eq!(ranges, ["Mark 1:1", "Mark 1:3-4"]);
+    }
+
+    #[test]
+    fn test_paragraphs_with_bridge() {
+        use crate::{book::Book, chapter::ParagraphMarker, chunk::ChunkOptions};
+
+        let verses = vec![
+            Verse::new(BibleBook::Ruth, 1, 1, "a".into()),
+            Verse::new(BibleBook::Ruth, 1, 2, "bc".into()).with_bridge_end(3),
+            Verse::new(BibleBook::Ruth, 1, 4, "d".into()),
+            Verse::new(BibleBook::Ruth, 1, 5, "e".into()),
+        ];
+        let markers = [1, 4]
+            .map(|start| ParagraphMarker {
+                start,
+                kind: ParagraphKind::Prose,
+                indent: 0,
+            })
+            .to_vec();
+        let chapter = Chapter::new(verses, 1).with_paragraph_markers(markers);
+
+        let html = chapter_to_html(&chapter, TextDirection::Ltr);
+        assert_eq!(html.matches("<p class=\"prose\">").count(), 2);
+        assert!(html.contains("<sup>2-3</sup>"));
+        let bible =
+            TestBible::new(vec![Book::new("ru".into(), "Ruth".into(), vec![chapter])]).build();
+        let chunks = bible.chunks(&ChunkOptions::paragraphs());
+        let ranges: Vec<String> = chunks.iter().map(|c| c.range().to_string()).collect();
+        assert_eq!(ranges, ["Ruth 1:1-2", "Ruth 1:4-5"]);
+    }
+
+    #[test]
+    fn test_html_escaping() {
+        let verse = Verse::new(BibleBook::Genesis, 1, 1, "a < b & c".into());
+        assert!(verse_to_html(&verse).contains("a &lt; b &amp; c"));
+    }
+}
@@ -0,0 +1,161 @@
+//! Caching of rendered passages.
+//!
+//! Servers tend to render the same popular chapters over and over. A Bible
+//! configured with [`Bible::with_cache`] keeps the most recently rendered
+//! passages, keyed by range and [`RenderFormat`], and evicts the least
+//! recently used once the configured capacity is reached. Edits to the text
+//! through [`Bible`] methods discard the cache.
+
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::{
+    bible::{Bible, BibleError},
+    query_cache::QueryCache,
+    reference::ReferenceRange,
+};
+
+/// The output of [`Bible::render_passage`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RenderFormat {
+    /// [`Passage::to_html`](crate::Passage::to_html).
+    #[default]
+    Html,
+    /// [`Passage::to_markdown`](crate::Passage::to_markdown).
+    Markdown,
+    /// [`Passage::text`](crate::Passage::text).
+    Text,
+}
+
+/// Settings for the render cache; see [`Bible::with_cache`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderCacheConfig {
+    capacity: usize,
+}
+
+impl RenderCacheConfig {
+    /// Creates a config keeping up to `capacity` rendered passages; zero
+    /// disables caching.
+    pub fn new(capacity: usize) -> Self {
+        RenderCacheConfig { capacity }
+    }
+
+    /// Returns the maximum number of cached passages.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+type RenderKey = (ReferenceRange, RenderFormat);
+
+/// Rendered passages shared behind a lock, so rendering can stay `&self`.
+#[derive(Debug)]
+pub(crate) struct RenderCache(Mutex<QueryCache<RenderKey, Arc<str>>>);
+
+impl RenderCache {
+    pub(crate) fn new(config: RenderCacheConfig) -> Self {
+        RenderCache(Mutex::new(QueryCache::new(config.capacity)))
+    }
+
+    fn with<T>(&self, f: impl FnOnce(&mut QueryCache<RenderKey, Arc<str>>) -> T) -> T {
+        f(&mut self.0.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    pub(crate) fn clear(&self) {
+        self.with(QueryCache::clear);
+    }
+}
+
+impl Default for RenderCache {
+    fn default() -> Self {
+        RenderCache::new(RenderCacheConfig::default())
+    }
+}
+
+impl Clone for RenderCache {
+    fn clone(&self) -> Self {
+        RenderCache(Mutex::new(self.with(|cache| cache.clone())))
+    }
+}
+
+impl Bible {
+    /// Enables the render cache used by [`Bible::render_passage`].
+    pub fn with_cache(mut self, config: RenderCacheConfig) -> Self {
+        self.render_cache = RenderCache::new(config);
+        self
+    }
+
+    /// Renders the passage `range` in `format`, reusing the cached output
+    /// when the same passage was rendered recently.
+    ///
+    /// # Errors
+    ///
+    /// As for [`Bible::passage`].
+    pub fn render_passage(
+        &self,
+        range: &ReferenceRange,
+        format: RenderFormat,
+    ) -> Result<Arc<str>, BibleError> {
+        let key = (range.clone(), format);
+        if let Some(rendered) = self.render_cache.with(|cache| cache.get(&key).cloned()) {
+            return Ok(rendered);
+        }
+        let passage = self.passage(range)?;
+        let rendered: Arc<str> = match format {
+            RenderFormat::Html => passage.to_html(),
+            RenderFormat::Markdown => passage.to_markdown(),
+            RenderFormat::Text => passage.text(),
+        }
+        .into();
+        self.render_cache
+            .with(|cache| cache.insert(key, Arc::clone(&rendered)));
+        Ok(rendered)
+    }
+
+    /// Discards all cached renderings.
+    pub fn clear_render_cache(&self) {
+        self.render_cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, reference::Reference,
+        test_support::TestBible, verse::Verse,
+    };
+
+    #[test]
+    fn test_render_passage_is_cached() {
+        let verses = vec![Verse::new(
+            BibleBook::Genesis,
+            1,
+            1,
+            "In the beginning".into(),
+        )];
+        let mut bible = TestBible::new(vec![Book::new(
+            "gn".into(),
+            "Genesis".into(),
+            vec![Chapter::new(verses, 1)],
+        )])
+        .build()
+        .with_cache(RenderCacheConfig::new(4));
+        let range = ReferenceRange::single(Reference::new(BibleBook::Genesis, 1, 1));
+        let first = bible.render_passage(&range, RenderFormat::Text).unwrap();
+        let second = bible.render_passage(&range, RenderFormat::Text).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_ne!(
+            bible.render_passage(&range, RenderFormat::Html).unwrap(),
+            first
+        );
+
+        bible
+            .set_verse_text(BibleBook::Genesis, 1, 1, "Changed")
+            .unwrap();
+        assert_eq!(
+            &*bible.render_passage(&range, RenderFormat::Text).unwrap(),
+            "Changed"
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! Exports for external search engines and data pipelines.
+//!
+//! [`Bible::write_search_documents`] writes one JSON document per verse,
+//! ready to load into Meilisearch, Tantivy or Elasticsearch:
+//!
+//! ```json
+//! {"id":"kjv-gn-1-1","translation":"kjv","language":"en","book":"gn","book_name":"Genesis","chapter":1,"verse":1,"reference":"Genesis 1:1","text":"In the beginning God created the heaven and the earth."}
+//! ```
+//!
+//! [`Bible::to_jsonl`] writes a leaner record per verse for data-science
+//! tools and database bulk loaders.
+//!
+//! [`SearchIndex::write_postings`] dumps the inverted index itself, one term
+//! per line, for engines that accept prebuilt postings.
+
+use std::io;
+
+use serde::Serialize;
+use simd_json::serde::to_string as simd_to_string;
+
+use crate::{bible::Bible, search_index::SearchIndex};
+
+/// The layout of a search document export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SearchExportFormat {
+    /// One document per line, as accepted by Meilisearch's
+    /// `application/x-ndjson` document route and Tantivy's `index` command.
+    #[default]
+    JsonLines,
+    /// The Elasticsearch (and OpenSearch) `_bulk` body: each document is
+    /// preceded by an `index` action naming the Bible's id, lowercased, as
+    /// the index.
+    ElasticsearchBulk,
+}
+
+#[derive(Serialize)]
+struct SearchDocument<'a> {
+    id: String,
+    translation: &'a str,
+    language: &'a str,
+    book: &'a str,
+    book_name: &'a str,
+    chapter: usize,
+    verse: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_verse: Option<usize>,
+    reference: String,
+    text: &'a str,
+}
+
+#[derive(Serialize)]
+struct CorpusRecord<'a> {
+    translation: &'a str,
+    book: &'a str,
+    chapter: usize,
+    verse: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_verse: Option<usize>,
+    text: &'a str,
+}
+
+#[derive(Serialize)]
+struct BulkAction<'a> {
+    index: BulkTarget<'a>,
+}
+
+#[derive(Serialize)]
+struct BulkTarget<'a> {
+    #[serde(rename = "_index")]
+    index: &'a str,
+    #[serde(rename = "_id")]
+    id: &'a str,
+}
+
+#[derive(Serialize)]
+struct PostingList<'a> {
+    term: &'a str,
+    postings: Vec<Posting<'a>>,
+}
+
+#[derive(Serialize)]
+struct Posting<'a> {
+    book: &'a str,
+    chapter: usize,
+    verse: usize,
+}
+
+impl Bible {
+    /// Writes every verse as a JSON search document in `format`, one per line
+    /// in reading order.
+    ///
+    /// Document ids combine the Bible id, book abbreviation, chapter and
+    /// verse (`"kjv-gn-1-1"`), using only the characters Meilisearch allows
+    /// in primary keys, so several translations can share one index.
+    /// A verse bridge also carries `"last_verse"`, the last verse it
+    /// combines.
+    pub fn write_search_documents<W: io::Write>(
+        &self,
+        writer: &mut W,
+        format: SearchExportFormat,
+    ) -> io::Result<()> {
+        let index_name = document_id(&[self.id()]).to_lowercase();
+        for book in self.books() {
+            for verse in book.chapters().iter().flat_map(|c| c.get_verses()) {
+                let id = document_id(&[
+                    self.id(),
+                    verse.book().as_str(),
+                    &verse.chapter().to_string(),
+                    &verse.number().to_string(),
+                ]);
+                if format == SearchExportFormat::ElasticsearchBulk {
+                    let action = BulkAction {
+                        index: BulkTarget {
+                            index: &index_name,
+                            id: &id,
+                        },
+                    };
+                    write_json_line(writer, &action)?;
+                }
+                let document = SearchDocument {
+                    reference: verse.reference().to_string(),
+                    id,
+                    translation: self.id(),
+                    language: self.language(),
+                    book: verse.book().as_str(),
+                    book_name: book.title(),
+                    chapter: verse.chapter(),
+                    verse: verse.number(),
+                    last_verse: verse.is_bridge().then(|| verse.last_number()),
+                    text: verse.text().trim(),
+                };
+                write_json_line(writer, &document)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Bible {
+    /// Writes every verse as a JSON object on its own line, in reading order:
+    /// `{"translation":"kjv","book":"gn","chapter":1,"verse":1,"text":"..."}`.
+    /// A verse bridge also carries `"last_verse"`, the last verse it
+    /// combines.
+    ///
+    /// The output is plain JSON Lines, readable by `pandas.read_json(...,
+    /// lines=True)`, DuckDB's `read_json` and `COPY`-style loaders. For a
+    /// compressed file, pass a compressing writer such as `flate2`'s
+    /// `GzEncoder`.
+    pub fn to_jsonl<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        for book in self.books() {
+            for verse in book.chapters().iter().flat_map(|c| c.get_verses()) {
+                let record = CorpusRecord {
+                    translation: self.id(),
+                    book: verse.book().as_str(),
+                    chapter: verse.chapter(),
+                    verse: verse.number(),
+                    last_verse: verse.is_bridge().then(|| verse.last_number()),
+                    text: verse.text().trim(),
+                };
+                write_json_line(writer, &record)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SearchIndex {
+    /// Writes the index as JSON lines of the form
+    /// `{"term":"light","postings":[{"book":"gn","chapter":1,"verse":3}]}`,
+    /// with terms sorted and postings in canonical order.
+    pub fn write_postings<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        for (term, locations) in self.postings() {
+            let postings = locations
+                .map(|(book, chapter, verse)| Posting {
+                    book: book.as_str(),
+                    chapter,
+                    verse,
+                })
+                .collect();
+            write_json_line(writer, &PostingList { term, postings })?;
+        }
+        Ok(())
+    }
+}
+
+/// Joins `parts` with `-`, replacing characters other than ASCII letters,
+/// digits, `-` and `_` with `_`.
+fn document_id(parts: &[&str]) -> String {
+    parts
+        .join("-")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn write_json_line<W: io::Write>(writer: &mut W, value: &impl Serialize) -> io::Result<()> {
+    let json = simd_to_string(value).map_err(io::Error::other)?;
+    writer.write_all(json.as_bytes())?;
+    writer.write_all(b"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+        verse::Verse,
+    };
+
+    fn create_test_bible() -> Bible {
+        let verses = vec![
+            Verse::new(BibleBook::Genesis, 1, 1, "In the beginning".into()),
+            Verse::new(BibleBook::Genesis, 1, 2, "And the earth".into()).with_bridge_end(3),
+        ];
+        TestBible::new(vec![Book::new(
+            "gn".into(),
+            "Genesis".into(),
+            vec![Chapter::new(verses, 1)],
+        )])
+        .with_id("KJV 1769")
+        .with_name("King James Version")
+        .build()
+    }
+
+    #[test]
+    fn test_search_documents() {
+        let bible = create_test_bible();
+        let mut lines = Vec::new();
+        bible
+            .write_search_documents(&mut lines, SearchExportFormat::JsonLines)
+            .unwrap();
+        let lines = String::from_utf8(lines).unwrap();
+        let first = lines.lines().next().unwrap();
+        assert_eq!(lines.lines().count(), 2);
+        assert_eq!(
+            first,
+            "{\"id\":\"KJV_1769-gn-1-1\",\"translation\":\"KJV 1769\",\"language\":\"en\",\
+             \"book\":\"gn\",\"book_name\":\"Genesis\",\"chapter\":1,\"verse\":1,\
+             \"reference\":\"Genesis 1:1\",\"text\":\"In the beginning\"}"
+        );
+
+        let mut bulk = Vec::new();
+        bible
+            .write_search_documents(&mut bulk, SearchExportFormat::ElasticsearchBulk)
+            .unwrap();
+        let bulk = String::from_utf8(bulk).unwrap();
+        assert_eq!(bulk.lines().count(), 4);
+        assert_eq!(
+            bulk.lines().next().unwrap(),
+            "{\"index\":{\"_index\":\"kjv_1769\",\"_id\":\"KJV_1769-gn-1-1\"}}"
+        );
+        assert_eq!(bulk.lines().nth(1).unwrap(), first);
+    }
+
+    #[test]
+    fn test_jsonl_export() {
+        let mut out = Vec::new();
+        create_test_bible().to_jsonl(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(
+            out.lines().collect::<Vec<_>>(),
+            [
+                "{\"translation\":\"KJV 1769\",\"book\":\"gn\",\"chapter\":1,\"verse\":1,\"text\":\"In the beginning\"}",
+                "{\"translation\":\"KJV 1769\",\"book\":\"gn\",\"chapter\":1,\"verse\":2,\"last_verse\":3,\"text\":\"And the earth\"}",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_postings_export() {
+        let mut out = Vec::new();
+        create_test_bible()
+            .build_search_index()
+            .write_postings(&mut out)
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.lines().any(|line| line
+            == "{\"term\":\"earth\",\"postings\":[{\"book\":\"gn\",\"chapter\":1,\"verse\":2}]}"));
+        let terms: Vec<&str> = out
+            .lines()
+            .map(|line| line.split('"').nth(3).unwrap())
+            .collect();
+        assert!(terms.is_sorted());
+    }
+}
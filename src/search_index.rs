@@ -1,51 +1,686 @@
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
-use crate::bible_books_enum::BibleBook;
+use crate::{
+    bible::edit_distance,
+    book_id::BookId,
+    transliteration::Transliterator,
+    verse::{is_combining_mark, split_words, Verse},
+};
+
+/// Options controlling how a [`SearchIndex`] is built and queried.
+///
+/// By default no stop words are used, so every term is indexed and searchable.
+/// Adding stop words shrinks the index and speeds up intersections, but
+/// changes results: stop words are dropped from queries too, so `"the"` alone
+/// matches nothing and `"the light"` matches every verse containing `"light"`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchIndexOptions {
+    stop_words: HashSet<String>,
+    fold_diacritics: bool,
+    transliterators: Vec<Arc<dyn Transliterator>>,
+}
+
+impl SearchIndexOptions {
+    /// Creates options without stop words.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the built-in stop-word list for a language code (`"en"`, `"es-MX"`)
+    /// or English language name (`"German"`). Unknown languages add nothing.
+    ///
+    /// Lists are provided for English, Spanish, French, German, and Portuguese.
+    pub fn with_language_stop_words(self, language: &str) -> Self {
+        self.with_stop_words(stop_words_for(language).iter().copied())
+    }
+
+    /// Adds custom stop words. They are normalized like indexed terms.
+    pub fn with_stop_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.stop_words.extend(
+            words
+                .into_iter()
+                .flat_map(|w| SearchIndex::tokenize(w.as_ref())),
+        );
+        self
+    }
+
+    /// Removes all stop words, indexing every term.
+    pub fn without_stop_words(mut self) -> Self {
+        self.stop_words.clear();
+        self
+    }
+
+    /// Ignores accents and other diacritics in indexed text and queries, so
+    /// `"regner"` finds `"régner"` and `"reino"` finds `"reíno"`.
+    ///
+    /// Folding covers precomposed Latin and Greek letters and strips combining
+    /// marks, including Hebrew points.
+    pub fn fold_diacritics(mut self, fold: bool) -> Self {
+        self.fold_diacritics = fold;
+        self
+    }
+
+    /// Returns `true` if diacritics are folded.
+    pub fn folds_diacritics(&self) -> bool {
+        self.fold_diacritics
+    }
+
+    /// Also indexes terms under the Latin spelling produced by
+    /// `transliterator`, so original-language text can be searched with Latin
+    /// letters. May be called once per script.
+    pub fn with_transliterator(mut self, transliterator: impl Transliterator + 'static) -> Self {
+        self.transliterators.push(Arc::new(transliterator));
+        self
+    }
+
+    /// Returns the Latin spellings of `term` (already lowercase) produced by
+    /// the configured transliterators, leaving out stop words and spellings
+    /// equal to the term itself.
+    pub fn transliterate(&self, term: &str) -> Vec<String> {
+        let mut spellings: Vec<String> = self
+            .transliterators
+            .iter()
+            .filter_map(|t| t.transliterate(term))
+            .filter(|latin| !latin.is_empty() && latin != term && !self.is_stop_word(latin))
+            .collect();
+        spellings.dedup();
+        spellings
+    }
+
+    /// Returns `true` if `term` (already lowercase) is a stop word.
+    pub fn is_stop_word(&self, term: &str) -> bool {
+        self.stop_words.contains(term)
+    }
+
+    /// Reduces a query to its sorted, distinct index keys joined by spaces, so
+    /// queries with the same results share one cache entry.
+    pub(crate) fn normalize_query(&self, query: &str) -> String {
+        let tokens = SearchIndex::tokenize(query);
+        let mut terms: Vec<Cow<str>> = tokens.iter().filter_map(|t| self.index_key(t)).collect();
+        terms.sort();
+        terms.dedup();
+        terms.join(" ")
+    }
+
+    /// Maps a tokenized term to its index key, or `None` for a stop word.
+    fn index_key<'a>(&self, term: &'a str) -> Option<Cow<'a, str>> {
+        if self.is_stop_word(term) {
+            return None;
+        }
+        if !self.fold_diacritics {
+            return Some(Cow::Borrowed(term));
+        }
+        let folded = fold_diacritics(term);
+        (!self.is_stop_word(&folded)).then_some(folded)
+    }
+}
+
+/// Removes diacritics from a lowercase term: `"régner"` becomes `"regner"`.
+pub(crate) fn fold_diacritics(term: &str) -> Cow<'_, str> {
+    if term.is_ascii() {
+        return Cow::Borrowed(term);
+    }
+    Cow::Owned(
+        term.chars()
+            .filter(|&c| !is_combining_mark(c))
+            .map(fold_char)
+            .collect(),
+    )
+}
+
+fn fold_char(c: char) -> char {
+    match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ĥ' | 'ħ' => 'h',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'ĵ' => 'j',
+        'ķ' => 'k',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'ţ' | 'ť' | 'ŧ' => 't',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ŵ' => 'w',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        // Greek with tonos and dialytika
+        'ά' => 'α',
+        'έ' => 'ε',
+        'ή' => 'η',
+        'ί' | 'ϊ' | 'ΐ' => 'ι',
+        'ό' => 'ο',
+        'ύ' | 'ϋ' | 'ΰ' => 'υ',
+        'ώ' => 'ω',
+        // Polytonic Greek
+        '\u{1F00}'..='\u{1F07}'
+        | '\u{1F70}'..='\u{1F71}'
+        | '\u{1F80}'..='\u{1F87}'
+        | '\u{1FB0}'..='\u{1FB4}'
+        | '\u{1FB6}'..='\u{1FB7}' => 'α',
+        '\u{1F10}'..='\u{1F15}' | '\u{1F72}'..='\u{1F73}' => 'ε',
+        '\u{1F20}'..='\u{1F27}'
+        | '\u{1F74}'..='\u{1F75}'
+        | '\u{1F90}'..='\u{1F97}'
+        | '\u{1FC2}'..='\u{1FC4}'
+        | '\u{1FC6}'..='\u{1FC7}' => 'η',
+        '\u{1F30}'..='\u{1F37}'
+        | '\u{1F76}'..='\u{1F77}'
+        | '\u{1FD0}'..='\u{1FD3}'
+        | '\u{1FD6}'..='\u{1FD7}' => 'ι',
+        '\u{1F40}'..='\u{1F45}' | '\u{1F78}'..='\u{1F79}' => 'ο',
+        '\u{1F50}'..='\u{1F57}'
+        | '\u{1F7A}'..='\u{1F7B}'
+        | '\u{1FE0}'..='\u{1FE3}'
+        | '\u{1FE6}'..='\u{1FE7}' => 'υ',
+        '\u{1FE4}'..='\u{1FE5}' => 'ρ',
+        '\u{1F60}'..='\u{1F67}'
+        | '\u{1F7C}'..='\u{1F7D}'
+        | '\u{1FA0}'..='\u{1FA7}'
+        | '\u{1FF2}'..='\u{1FF4}'
+        | '\u{1FF6}'..='\u{1FF7}' => 'ω',
+        _ => c,
+    }
+}
+
+fn stop_words_for(language: &str) -> &'static [&'static str] {
+    const ENGLISH: &[&str] = &[
+        "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "in", "into", "is",
+        "it", "of", "on", "or", "that", "the", "this", "to", "unto", "was", "were", "with",
+    ];
+    const SPANISH: &[&str] = &[
+        "a", "al", "con", "de", "del", "el", "en", "es", "la", "las", "lo", "los", "por", "que",
+        "se", "un", "una", "y",
+    ];
+    const FRENCH: &[&str] = &[
+        "à", "au", "aux", "de", "des", "du", "en", "est", "et", "la", "le", "les", "par", "pour",
+        "que", "un", "une",
+    ];
+    const GERMAN: &[&str] = &[
+        "als", "auf", "das", "dem", "den", "der", "des", "die", "ein", "eine", "in", "ist", "mit",
+        "und", "von", "zu",
+    ];
+    const PORTUGUESE: &[&str] = &[
+        "a", "ao", "as", "com", "da", "das", "de", "do", "dos", "e", "em", "na", "no", "o", "os",
+        "que", "um", "uma",
+    ];
+
+    let primary = language
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match primary.as_str() {
+        "en" | "english" => ENGLISH,
+        "es" | "spanish" => SPANISH,
+        "fr" | "french" => FRENCH,
+        "de" | "german" => GERMAN,
+        "pt" | "portuguese" => PORTUGUESE,
+        _ => &[],
+    }
+}
+
+/// A verse search engine that [`Bible::search_with`](crate::Bible::search_with)
+/// can query.
+///
+/// [`SearchIndex`] is the built-in implementation. Implement this trait to
+/// serve searches from an external engine, such as an on-disk Tantivy index
+/// built from [`Bible::write_search_documents`](crate::Bible::write_search_documents)
+/// output, without changing calling code.
+pub trait SearchBackend {
+    /// Returns the locations of the verses matching `query`, best match
+    /// first. Backends without ranking return canonical order.
+    fn search_verses(&self, query: &str) -> Vec<(BookId, usize, usize)>;
+}
+
+impl SearchBackend for SearchIndex {
+    fn search_verses(&self, query: &str) -> Vec<(BookId, usize, usize)> {
+        self.search(query)
+    }
+}
 
 /// Search index mapping normalized terms to verse locations.
+///
+/// Verses are numbered by ordinal in canonical order, and each term's
+/// postings are stored as delta-encoded ordinals packed into variable-length
+/// bytes, typically one or two bytes per posting.
 #[derive(Debug, Default, Clone)]
 pub struct SearchIndex {
-    index: HashMap<String, Vec<(BibleBook, usize, usize)>>,
+    index: HashMap<String, Box<[u8]>>,
+    /// Location of each verse ordinal as (book slot, chapter, verse).
+    verses: Vec<(u32, u32, u32)>,
+    books: Vec<BookId>,
+    options: SearchIndexOptions,
 }
 
 impl SearchIndex {
     /// Create a new search index from a map.
-    pub fn new(index: HashMap<String, Vec<(BibleBook, usize, usize)>>) -> Self {
-        SearchIndex { index }
+    ///
+    /// Posting lists may be given in any order and may contain duplicates.
+    pub fn new(index: HashMap<String, Vec<(BookId, usize, usize)>>) -> Self {
+        Self::with_options(index, SearchIndexOptions::default())
     }
 
-    /// Breaks a text into normalized lowercase terms.
-    pub(crate) fn tokenize(text: &str) -> Vec<String> {
-        text.split(|c: char| !c.is_ascii_alphanumeric())
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_ascii_lowercase())
-            .collect()
+    /// Create a new search index from a map, leaving out the stop words in
+    /// `options` and ignoring them in later queries.
+    pub fn with_options(
+        index: HashMap<String, Vec<(BookId, usize, usize)>>,
+        options: SearchIndexOptions,
+    ) -> Self {
+        let mut locations: Vec<&(BookId, usize, usize)> = index.values().flatten().collect();
+        locations.sort();
+        locations.dedup();
+
+        let mut books: Vec<BookId> = Vec::new();
+        let verses: Vec<(u32, u32, u32)> = locations
+            .iter()
+            .map(|(book, chapter, verse)| {
+                if books.last() != Some(book) {
+                    books.push(book.clone());
+                }
+                (books.len() as u32 - 1, to_u32(*chapter), to_u32(*verse))
+            })
+            .collect();
+
+        // Terms sharing a key after folding or transliteration are merged.
+        let mut ordinals_by_key: HashMap<Cow<str>, Vec<u32>> = HashMap::new();
+        for (term, postings) in &index {
+            let Some(key) = options.index_key(term) else {
+                continue;
+            };
+            let ordinals: Vec<u32> = postings
+                .iter()
+                .filter_map(|p| locations.binary_search(&p).ok())
+                .map(|i| i as u32)
+                .collect();
+            for latin in options.transliterate(term) {
+                ordinals_by_key
+                    .entry(Cow::Owned(latin))
+                    .or_default()
+                    .extend_from_slice(&ordinals);
+            }
+            ordinals_by_key.entry(key).or_default().extend(ordinals);
+        }
+        let index = ordinals_by_key
+            .into_iter()
+            .map(|(key, mut ordinals)| {
+                ordinals.sort_unstable();
+                ordinals.dedup();
+                (key.into_owned(), encode_postings(&ordinals))
+            })
+            .collect();
+
+        SearchIndex {
+            index,
+            verses,
+            books,
+            options,
+        }
+    }
+
+    /// Returns the options the index was built with.
+    pub fn options(&self) -> &SearchIndexOptions {
+        &self.options
+    }
+
+    /// Returns the approximate number of heap bytes used by the index.
+    pub fn memory_usage(&self) -> usize {
+        let entries: usize = self
+            .index
+            .iter()
+            .map(|(term, postings)| term.capacity() + postings.len())
+            .sum();
+        let table = self.index.capacity() * (size_of::<(String, Box<[u8]>)>() + 1);
+        entries
+            + table
+            + self.verses.capacity() * size_of::<(u32, u32, u32)>()
+            + self.books.capacity() * size_of::<BookId>()
+    }
+
+    /// Iterates over every term, sorted, with the locations of the verses
+    /// containing it in canonical order.
+    pub(crate) fn postings(
+        &self,
+    ) -> impl Iterator<Item = (&str, impl Iterator<Item = (&BookId, usize, usize)>)> {
+        let mut terms: Vec<(&String, &Box<[u8]>)> = self.index.iter().collect();
+        terms.sort_unstable_by_key(|(term, _)| *term);
+        terms.into_iter().map(|(term, bytes)| {
+            let locations = Postings::new(bytes).map(|ordinal| {
+                let (book, chapter, verse) = self.verses[ordinal as usize];
+                (&self.books[book as usize], chapter as usize, verse as usize)
+            });
+            (term.as_str(), locations)
+        })
+    }
+
+    /// Breaks a text into normalized lowercase terms, one per [`Verse::words`](crate::Verse::words) word.
+    ///
+    /// This is the tokenizer behind the index, queries and corpus statistics.
+    pub fn tokenize(text: &str) -> Vec<String> {
+        split_words(text).map(str::to_lowercase).collect()
     }
 
     /// Searches for verses containing all terms in the query.
-    pub fn search(&self, query: &str) -> Vec<(BibleBook, usize, usize)> {
+    ///
+    /// Results are ordered canonically: known books in enum order, then custom books.
+    pub fn search(&self, query: &str) -> Vec<(BookId, usize, usize)> {
+        self.search_iter(query).collect()
+    }
+
+    /// Lazily yields the verses containing all terms in the query, in the same
+    /// order as [`SearchIndex::search`].
+    ///
+    /// Postings are intersected as the iterator advances, so stopping early
+    /// (e.g. with [`Iterator::take`]) skips the remaining work.
+    pub fn search_iter(&self, query: &str) -> SearchIter<'_> {
+        let tokens = Self::tokenize(query);
+        let mut terms: Vec<Cow<str>> = tokens
+            .iter()
+            .filter_map(|term| self.options.index_key(term))
+            .collect();
+        terms.sort();
+        terms.dedup();
+
+        let mut lists: Vec<&[u8]> = terms
+            .iter()
+            .map(|term| self.index.get(term.as_ref()).map(|postings| &postings[..]))
+            .collect::<Option<_>>()
+            .unwrap_or_default();
+        // Drive the intersection from the rarest term; byte length tracks posting count.
+        lists.sort_by_key(|list| list.len());
+
+        let mut lists = lists.into_iter().map(Postings::new);
+        SearchIter {
+            index: self,
+            driver: lists.next().unwrap_or_else(|| Postings::new(&[])),
+            others: lists.map(|postings| (postings, None)).collect(),
+        }
+    }
+
+    /// Proposes a corrected query for a search that found nothing, as in
+    /// "Did you mean 'shepherd'?".
+    ///
+    /// Each term missing from the index is replaced by the indexed term
+    /// spelled most like it: one edit away for terms of up to four letters,
+    /// two for longer ones. Ties go to the term found in more verses.
+    /// Returns `None` if every term is indexed or a missing term has no
+    /// close match.
+    pub fn suggest(&self, query: &str) -> Option<String> {
+        let mut corrected = false;
+        let terms = Self::tokenize(query)
+            .into_iter()
+            .map(|term| {
+                let Some(key) = self.options.index_key(&term) else {
+                    return Some(term);
+                };
+                if self.index.contains_key(key.as_ref()) {
+                    return Some(term);
+                }
+                corrected = true;
+                self.closest_term(&key).map(str::to_string)
+            })
+            .collect::<Option<Vec<String>>>()?;
+        corrected.then(|| terms.join(" "))
+    }
+
+    /// Returns the indexed term closest to `key`, if any is close enough.
+    fn closest_term(&self, key: &str) -> Option<&str> {
+        let length = key.chars().count();
+        let max_distance = if length <= 4 { 1 } else { 2 };
+        self.index
+            .iter()
+            .filter(|(term, _)| term.chars().count().abs_diff(length) <= max_distance)
+            .filter_map(|(term, postings)| {
+                let distance = edit_distance(key, term);
+                // Each posting ends with a byte whose high bit is clear.
+                let verses = postings.iter().filter(|&&b| b & 0x80 == 0).count();
+                (distance <= max_distance).then_some((distance, Reverse(verses), term))
+            })
+            .min()
+            .map(|(_, _, term)| term.as_str())
+    }
+
+    /// Returns the verses among `verses` containing every term of `query`,
+    /// by scanning them directly rather than consulting an index.
+    pub(crate) fn scan<'a>(verses: impl Iterator<Item = &'a Verse>, query: &str) -> Vec<&'a Verse> {
         let terms = Self::tokenize(query);
         if terms.is_empty() {
             return Vec::new();
         }
+        verses
+            .filter(|verse| {
+                let words = Self::tokenize(verse.text());
+                terms.iter().all(|term| words.contains(term))
+            })
+            .collect()
+    }
+}
 
-        let mut iter = terms.into_iter();
-        let first = iter.next().unwrap();
-        let mut results = match self.index.get(&first) {
-            Some(v) => v.clone(),
-            None => return Vec::new(),
-        };
+fn to_u32(n: usize) -> u32 {
+    u32::try_from(n).unwrap_or(u32::MAX)
+}
 
-        for term in iter {
-            if let Some(list) = self.index.get(&term) {
-                results.retain(|item| list.contains(item));
-            } else {
-                return Vec::new();
+/// Packs ascending ordinals as LEB128-encoded gaps from the previous ordinal.
+fn encode_postings(ordinals: &[u32]) -> Box<[u8]> {
+    let mut bytes = Vec::with_capacity(ordinals.len());
+    let mut previous = 0;
+    for &ordinal in ordinals {
+        let mut gap = ordinal - previous;
+        previous = ordinal;
+        while gap >= 0x80 {
+            bytes.push((gap as u8) | 0x80);
+            gap >>= 7;
+        }
+        bytes.push(gap as u8);
+    }
+    bytes.into_boxed_slice()
+}
+
+/// Decodes postings written by [`encode_postings`].
+#[derive(Debug, Clone)]
+struct Postings<'a> {
+    bytes: &'a [u8],
+    previous: u32,
+}
+
+impl<'a> Postings<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Postings { bytes, previous: 0 }
+    }
+}
+
+impl Iterator for Postings<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let mut gap = 0u32;
+        let mut shift = 0;
+        loop {
+            let (&byte, rest) = self.bytes.split_first()?;
+            self.bytes = rest;
+            gap |= u32::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                break;
             }
+            shift += 7;
         }
+        self.previous += gap;
+        Some(self.previous)
+    }
+}
+
+/// Iterator over search hits returned by [`SearchIndex::search_iter`].
+#[derive(Debug, Clone)]
+pub struct SearchIter<'a> {
+    index: &'a SearchIndex,
+    driver: Postings<'a>,
+    /// The other terms' postings, each with the last ordinal read from it.
+    others: Vec<(Postings<'a>, Option<u32>)>,
+}
+
+impl Iterator for SearchIter<'_> {
+    type Item = (BookId, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        'candidates: while let Some(candidate) = self.driver.next() {
+            for (postings, current) in &mut self.others {
+                while current.is_none_or(|ordinal| ordinal < candidate) {
+                    match postings.next() {
+                        Some(ordinal) => *current = Some(ordinal),
+                        None => {
+                            self.driver = Postings::new(&[]);
+                            return None;
+                        }
+                    }
+                }
+                if *current != Some(candidate) {
+                    continue 'candidates;
+                }
+            }
+            let (book, chapter, verse) = self.index.verses[candidate as usize];
+            return Some((
+                self.index.books[book as usize].clone(),
+                chapter as usize,
+                verse as usize,
+            ));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    fn posting(chapter: usize, verse: usize) -> (BookId, usize, usize) {
+        (BibleBook::Genesis.into(), chapter, verse)
+    }
+
+    #[test]
+    fn test_search_iter_intersects_lazily() {
+        let mut map = HashMap::new();
+        map.insert(
+            "light".to_string(),
+            vec![posting(1, 5), posting(1, 3), posting(1, 4)],
+        );
+        map.insert(
+            "god".to_string(),
+            vec![posting(1, 1), posting(1, 3), posting(1, 4), posting(2, 1)],
+        );
+        let index = SearchIndex::new(map);
+
+        assert_eq!(index.search("God light"), [posting(1, 3), posting(1, 4)]);
+        let mut hits = index.search_iter("light god light");
+        assert_eq!(hits.next(), Some(posting(1, 3)));
+        assert_eq!(hits.next(), Some(posting(1, 4)));
+        assert_eq!(hits.next(), None);
+        assert_eq!(index.search_iter("god").take(1).count(), 1);
+        assert_eq!(index.search_iter("god darkness").next(), None);
+        assert_eq!(index.search_iter("").next(), None);
+    }
+
+    #[test]
+    fn test_suggest_corrects_missing_terms() {
+        let mut map = HashMap::new();
+        map.insert("shepherd".to_string(), vec![posting(1, 1), posting(1, 2)]);
+        map.insert("shepherds".to_string(), vec![posting(1, 3)]);
+        map.insert("good".to_string(), vec![posting(1, 1)]);
+        let index = SearchIndex::new(map);
+
+        assert_eq!(index.suggest("Shepard").as_deref(), Some("shepherd"));
+        assert_eq!(index.suggest("the goof sheperd").as_deref(), None);
+        assert_eq!(
+            index.suggest("goof sheperd").as_deref(),
+            Some("good shepherd")
+        );
+        assert_eq!(index.suggest("good shepherd"), None);
+        assert_eq!(index.suggest("zebra"), None);
+    }
+
+    #[test]
+    fn test_search_backend_matches_search() {
+        let mut map = HashMap::new();
+        map.insert("light".to_string(), vec![posting(1, 3), posting(1, 4)]);
+        let index = SearchIndex::new(map);
+        let backend: &dyn SearchBackend = &index;
+        assert_eq!(backend.search_verses("Light"), index.search("light"));
+    }
+
+    #[test]
+    fn test_stop_words_skipped_at_build_and_query() {
+        let mut map = HashMap::new();
+        map.insert("the".to_string(), vec![posting(1, 1), posting(1, 2)]);
+        map.insert("light".to_string(), vec![posting(1, 2)]);
+
+        let options = SearchIndexOptions::new().with_language_stop_words("en-US");
+        let index = SearchIndex::with_options(map.clone(), options);
+        assert_eq!(index.search("THE light"), [posting(1, 2)]);
+        assert!(index.search("the").is_empty());
+
+        let plain = SearchIndex::new(map);
+        assert_eq!(plain.search("the").len(), 2);
+        assert!(index.memory_usage() < plain.memory_usage());
+    }
+
+    #[test]
+    fn test_diacritic_folding() {
+        let mut map = HashMap::new();
+        map.insert("régner".to_string(), vec![posting(1, 1)]);
+        map.insert("regner".to_string(), vec![posting(1, 2)]);
+        // "ἀγάπη" with precomposed polytonic letters.
+        map.insert("ἀγάπη".to_string(), vec![posting(1, 3)]);
+
+        let folded =
+            SearchIndex::with_options(map.clone(), SearchIndexOptions::new().fold_diacritics(true));
+        assert_eq!(folded.search("REGNER"), [posting(1, 1), posting(1, 2)]);
+        assert_eq!(folded.search("re\u{301}gner").len(), 2);
+        assert_eq!(folded.search("αγαπη"), [posting(1, 3)]);
+
+        let exact = SearchIndex::new(map);
+        assert_eq!(exact.search("régner"), [posting(1, 1)]);
+        assert!(exact.search("αγαπη").is_empty());
+    }
+
+    #[test]
+    fn test_transliterated_terms() {
+        use crate::transliteration::{GreekTransliterator, HebrewTransliterator};
+
+        let mut map = HashMap::new();
+        map.insert("ἀγάπη".to_string(), vec![posting(1, 1)]);
+        map.insert("agape".to_string(), vec![posting(1, 2)]);
+        map.insert("שָׁלוֹם".to_string(), vec![posting(1, 3)]);
+
+        let options = SearchIndexOptions::new()
+            .with_transliterator(GreekTransliterator)
+            .with_transliterator(HebrewTransliterator);
+        assert_eq!(options.transliterate("ἀγάπη"), ["agape"]);
+        let index = SearchIndex::with_options(map, options);
+        assert_eq!(index.search("Agape"), [posting(1, 1), posting(1, 2)]);
+        assert_eq!(index.search("ἀγάπη"), [posting(1, 1)]);
+        assert_eq!(index.search("shalom"), [posting(1, 3)]);
+    }
 
-        results.sort_by_key(|&(b, c, v)| (b as usize, c, v));
-        results.dedup();
-        results
+    #[test]
+    fn test_postings_round_trip() {
+        let ordinals = [0, 1, 127, 128, 300, 70_000];
+        let encoded = encode_postings(&ordinals);
+        assert_eq!(encoded.len(), 1 + 1 + 1 + 1 + 2 + 3);
+        assert_eq!(Postings::new(&encoded).collect::<Vec<_>>(), ordinals);
     }
 }
@@ -0,0 +1,199 @@
+//! Search hits grouped for display.
+//!
+//! [`Bible::search_results`] wraps the hits of a search so interfaces can
+//! show the familiar facet list, "Genesis (12), Psalms (73)", and expand a
+//! book into its chapters without regrouping the hits themselves.
+
+use indexmap::IndexMap;
+
+use crate::{bible::Bible, book::Book, book_id::BookId, search_index::SearchBackend, verse::Verse};
+
+/// The verses matching a search, in the order the backend returned them.
+#[derive(Debug, Clone)]
+pub struct SearchResults<'a> {
+    bible: &'a Bible,
+    hits: Vec<&'a Verse>,
+}
+
+/// The hits within one book.
+#[derive(Debug, Clone)]
+pub struct BookHits<'a> {
+    book: &'a Book,
+    hits: Vec<&'a Verse>,
+}
+
+/// The hits within one chapter.
+#[derive(Debug, Clone)]
+pub struct ChapterHits<'a> {
+    book: &'a Book,
+    chapter: usize,
+    hits: Vec<&'a Verse>,
+}
+
+impl<'a> SearchResults<'a> {
+    /// Returns the Bible that was searched.
+    pub fn bible(&self) -> &'a Bible {
+        self.bible
+    }
+
+    /// Returns the matching verses.
+    pub fn hits(&self) -> &[&'a Verse] {
+        &self.hits
+    }
+
+    /// Returns the number of matching verses.
+    pub fn len(&self) -> usize {
+        self.hits.len()
+    }
+
+    /// Returns `true` if nothing matched.
+    pub fn is_empty(&self) -> bool {
+        self.hits.is_empty()
+    }
+
+    /// Groups the hits by book, in order of each book's first hit.
+    pub fn grouped_by_book(&self) -> Vec<BookHits<'a>> {
+        let mut groups: IndexMap<&BookId, BookHits<'a>> = IndexMap::new();
+        for &verse in &self.hits {
+            let Ok(book) = self.bible.get_book(verse.book()) else {
+                continue;
+            };
+            groups
+                .entry(verse.book())
+                .or_insert_with(|| BookHits {
+                    book,
+                    hits: Vec::new(),
+                })
+                .hits
+                .push(verse);
+        }
+        groups.into_values().collect()
+    }
+
+    /// Groups the hits by chapter, in order of each chapter's first hit.
+    pub fn grouped_by_chapter(&self) -> Vec<ChapterHits<'a>> {
+        self.grouped_by_book()
+            .iter()
+            .flat_map(BookHits::chapters)
+            .collect()
+    }
+}
+
+impl<'a> BookHits<'a> {
+    /// Returns the book.
+    pub fn book(&self) -> &'a Book {
+        self.book
+    }
+
+    /// Returns the number of hits in the book.
+    pub fn count(&self) -> usize {
+        self.hits.len()
+    }
+
+    /// Returns the hits in the book.
+    pub fn hits(&self) -> &[&'a Verse] {
+        &self.hits
+    }
+
+    /// Groups the book's hits by chapter, in order of each chapter's first
+    /// hit.
+    pub fn chapters(&self) -> Vec<ChapterHits<'a>> {
+        let mut groups: IndexMap<usize, ChapterHits<'a>> = IndexMap::new();
+        for &verse in &self.hits {
+            groups
+                .entry(verse.chapter())
+                .or_insert_with(|| ChapterHits {
+                    book: self.book,
+                    chapter: verse.chapter(),
+                    hits: Vec::new(),
+                })
+                .hits
+                .push(verse);
+        }
+        groups.into_values().collect()
+    }
+}
+
+impl<'a> ChapterHits<'a> {
+    /// Returns the book.
+    pub fn book(&self) -> &'a Book {
+        self.book
+    }
+
+    /// Returns the chapter number.
+    pub fn chapter(&self) -> usize {
+        self.chapter
+    }
+
+    /// Returns the number of hits in the chapter.
+    pub fn count(&self) -> usize {
+        self.hits.len()
+    }
+
+    /// Returns the hits in the chapter.
+    pub fn hits(&self) -> &[&'a Verse] {
+        &self.hits
+    }
+}
+
+impl Bible {
+    /// Searches with `backend` like [`Bible::search_with`], returning the
+    /// hits ready to be grouped by book or chapter.
+    pub fn search_results(&self, backend: &dyn SearchBackend, query: &str) -> SearchResults<'_> {
+        SearchResults {
+            bible: self,
+            hits: self.search_with(backend, query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bible_books_enum::BibleBook, chapter::Chapter, test_support::TestBible};
+
+    #[test]
+    fn test_grouped_hits() {
+        let book = |id: BibleBook, abbrev: &str, title: &str| {
+            let chapters = (1..=2)
+                .map(|c| {
+                    let verses = (1..=3)
+                        .map(|v| Verse::new(id, c, v, format!("the shepherd {c}:{v}")))
+                        .collect();
+                    Chapter::new(verses, c)
+                })
+                .collect();
+            Book::new(abbrev.into(), title.into(), chapters)
+        };
+        let bible = TestBible::new(vec![
+            book(BibleBook::Genesis, "gn", "Genesis"),
+            book(BibleBook::Psalms, "ps", "Psalms"),
+        ])
+        .build();
+        let index = bible.build_search_index();
+
+        let results = bible.search_results(&index, "shepherd");
+        assert_eq!(results.len(), 12);
+        let facets: Vec<(&str, usize)> = results
+            .grouped_by_book()
+            .iter()
+            .map(|group| (group.book().title(), group.count()))
+            .collect();
+        assert_eq!(facets, [("Genesis", 6), ("Psalms", 6)]);
+
+        let chapters = results.grouped_by_chapter();
+        assert_eq!(chapters.len(), 4);
+        assert_eq!(
+            (
+                chapters[3].book().title(),
+                chapters[3].chapter(),
+                chapters[3].count()
+            ),
+            ("Psalms", 2, 3)
+        );
+        assert!(bible
+            .search_results(&index, "wolf")
+            .grouped_by_book()
+            .is_empty());
+    }
+}
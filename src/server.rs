@@ -0,0 +1,444 @@
+//! A small JSON-over-HTTP scripture API, behind the `server` feature.
+//!
+//! [`BibleServer`] answers `GET` requests against the translations of a
+//! [`BibleLibrary`]:
+//!
+//! | Route | Response |
+//! |---|---|
+//! | `/bibles` | `[{"id":"kjv","name":"King James Version","language":"en"}]` |
+//! | `/bibles/{id}/verses/{reference}` | one verse, e.g. `/bibles/kjv/verses/John%203:16` or `/bibles/kjv/verses/jn/3/16` |
+//! | `/bibles/{id}/passages/{range}` | `{"reference":"John 3:16-17","verses":[...]}` |
+//! | `/bibles/{id}/search?q={query}&limit={n}` | matching verses, 50 by default |
+//!
+//! Verses are returned as
+//! `{"reference":"John 3:16","book":"jo","chapter":3,"verse":16,"text":"..."}`
+//! and failures as `{"error":"..."}` with a 400 or 404 status. References
+//! are parsed with each Bible's [`ReferenceParsing`](crate::ReferenceParsing)
+//! mode, and `+` may stand for a space.
+//!
+//! [`BibleServer::serve`] runs a blocking, single-threaded HTTP/1.1 server
+//! on the standard library, which suits local tools and small deployments.
+//! Each connection has a [timeout](BibleServer::with_timeout) and a cap on
+//! the size of the request, so a stalled client delays others by at most
+//! the timeout. Larger services can route requests to
+//! [`BibleServer::handle`] from their own web framework.
+
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use serde::Serialize;
+use simd_json::serde::to_string as simd_to_string;
+
+use crate::{
+    bible::{Bible, BibleError},
+    library::BibleLibrary,
+    search_index::SearchIndex,
+    web::{path_reference, PassageResponse, VerseResponse},
+};
+
+const DEFAULT_SEARCH_LIMIT: usize = 50;
+
+/// How long a connection may wait to send its request or read the response.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The most bytes read for the request line and headers together.
+const MAX_REQUEST_HEAD: u64 = 8 * 1024;
+
+/// Serves verse, passage and search queries over a [`BibleLibrary`].
+#[derive(Debug)]
+pub struct BibleServer {
+    library: BibleLibrary,
+    /// Search indexes keyed by Bible id.
+    indexes: HashMap<String, SearchIndex>,
+    timeout: Duration,
+}
+
+impl Default for BibleServer {
+    fn default() -> Self {
+        BibleServer::from_library(BibleLibrary::new())
+    }
+}
+
+impl From<BibleLibrary> for BibleServer {
+    fn from(library: BibleLibrary) -> Self {
+        BibleServer::from_library(library)
+    }
+}
+
+/// The status and JSON body of a [`BibleServer`] response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiResponse {
+    status: u16,
+    body: String,
+}
+
+impl ApiResponse {
+    /// Returns the HTTP status code.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Returns the JSON body.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    fn json(value: &impl Serialize) -> Self {
+        match simd_to_string(value) {
+            Ok(body) => ApiResponse { status: 200, body },
+            Err(e) => Self::error(500, &e.to_string()),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        let body = simd_to_string(&ErrorBody { error: message })
+            .unwrap_or_else(|_| "{\"error\":\"\"}".to_string());
+        ApiResponse { status, body }
+    }
+}
+
+impl From<BibleError> for ApiResponse {
+    fn from(error: BibleError) -> Self {
+        let status = match error {
+            BibleError::InvalidReference { .. } => 400,
+            _ => 404,
+        };
+        ApiResponse::error(status, &error.to_string())
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: &'a str,
+}
+
+#[derive(Serialize)]
+struct BibleSummary<'a> {
+    id: &'a str,
+    name: &'a str,
+    language: &'a str,
+}
+
+impl BibleServer {
+    /// Creates a server with no Bibles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a server for the translations of `library`, each served
+    /// under its [`id`](Bible::id), and builds their search indexes.
+    pub fn from_library(library: BibleLibrary) -> Self {
+        let indexes = library
+            .bibles()
+            .iter()
+            .map(|bible| (bible.id().to_string(), bible.build_search_index()))
+            .collect();
+        BibleServer {
+            library,
+            indexes,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Adds a Bible, replacing any with the same id, and builds its search
+    /// index.
+    pub fn with_bible(mut self, bible: Bible) -> Self {
+        self.indexes
+            .insert(bible.id().to_string(), bible.build_search_index());
+        self.library.insert(bible);
+        self
+    }
+
+    /// Sets how long [`serve`](BibleServer::serve) waits for a client to
+    /// send its request or accept the response before dropping the
+    /// connection; 10 seconds by default. A zero duration is treated as one
+    /// millisecond.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout.max(Duration::from_millis(1));
+        self
+    }
+
+    /// Returns the translations being served.
+    pub fn library(&self) -> &BibleLibrary {
+        &self.library
+    }
+
+    /// Answers a `GET` request for `target`, the path and optional query
+    /// string, such as `"/bibles/kjv/search?q=light"`.
+    pub fn handle(&self, target: &str) -> ApiResponse {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let segments: Vec<&str> = path.trim_matches('/').splitn(4, '/').collect();
+        match segments.as_slice() {
+            ["bibles"] => {
+                let summaries: Vec<BibleSummary> = self
+                    .library
+                    .bibles()
+                    .iter()
+                    .map(|bible| BibleSummary {
+                        id: bible.id(),
+                        name: bible.name(),
+                        language: bible.language(),
+                    })
+                    .collect();
+                ApiResponse::json(&summaries)
+            }
+            ["bibles", id, route, rest @ ..] => {
+                let (Some(bible), Some(index)) = (self.library.get(id), self.indexes.get(*id))
+                else {
+                    return ApiResponse::error(404, &format!("no Bible with id \"{id}\""));
+                };
+                let argument = rest.first().map(|arg| decode(arg)).unwrap_or_default();
+                match (*route, rest.is_empty()) {
+                    ("verses", false) => verse(bible, &argument),
+                    ("passages", false) => passage(bible, &argument),
+                    ("search", true) => search(bible, index, query),
+                    _ => ApiResponse::error(404, "no such route"),
+                }
+            }
+            _ => ApiResponse::error(404, "no such route"),
+        }
+    }
+
+    /// Listens on `addr` and answers requests until an error occurs while
+    /// accepting connections. Errors on a single connection are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address cannot be bound or a connection
+    /// cannot be accepted.
+    pub fn serve(&self, addr: impl ToSocketAddrs) -> io::Result<()> {
+        self.serve_listener(TcpListener::bind(addr)?)
+    }
+
+    fn serve_listener(&self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            // A client that disconnects early or stalls must not stop the
+            // server.
+            let _ = self.respond(stream?);
+        }
+        Ok(())
+    }
+
+    fn respond(&self, stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+        let mut reader = BufReader::new(&stream).take(MAX_REQUEST_HEAD);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        // Skip the headers; requests have no body.
+        let mut header = String::new();
+        let complete = loop {
+            header.clear();
+            if reader.read_line(&mut header)? == 0 {
+                break false;
+            }
+            if header.trim_end_matches(['\r', '\n']).is_empty() {
+                break true;
+            }
+        };
+
+        let mut parts = request_line.split_whitespace();
+        let response = match (parts.next(), parts.next()) {
+            _ if !complete && reader.limit() == 0 => {
+                ApiResponse::error(431, "request headers too large")
+            }
+            _ if !complete => ApiResponse::error(400, "malformed request"),
+            (Some("GET"), Some(target)) => self.handle(target),
+            (Some(_), Some(_)) => ApiResponse::error(405, "only GET is supported"),
+            _ => ApiResponse::error(400, "malformed request"),
+        };
+        let mut stream = &stream;
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response.status,
+            reason(response.status),
+            response.body.len(),
+            response.body
+        )?;
+        stream.flush()
+    }
+}
+
+fn verse(bible: &Bible, reference: &str) -> ApiResponse {
+    match bible.get_verse_by_reference(&path_reference(reference)) {
+        Ok(verse) => ApiResponse::json(&VerseResponse::from(verse)),
+        Err(e) => e.into(),
+    }
+}
+
+fn passage(bible: &Bible, range: &str) -> ApiResponse {
+    let passage = bible
+        .parse_range(range)
+        .and_then(|range| bible.passage(&range));
+    match passage {
+        Ok(passage) => ApiResponse::json(&PassageResponse::from(&passage)),
+        Err(e) => e.into(),
+    }
+}
+
+fn search(bible: &Bible, index: &SearchIndex, query: &str) -> ApiResponse {
+    let mut text = None;
+    let mut limit = DEFAULT_SEARCH_LIMIT;
+    for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+        match key {
+            "q" => text = Some(decode(value)),
+            "limit" => match value.parse() {
+                Ok(n) => limit = n,
+                Err(_) => return ApiResponse::error(400, "limit must be a number"),
+            },
+            _ => {}
+        }
+    }
+    let Some(text) = text else {
+        return ApiResponse::error(400, "missing query parameter q");
+    };
+    let verses: Vec<VerseResponse> = bible
+        .search_with(index, &text)
+        .into_iter()
+        .take(limit)
+        .map(VerseResponse::from)
+        .collect();
+    ApiResponse::json(&verses)
+}
+
+/// Decodes `%XX` escapes and `+` as a space. A `%` not followed by two hex
+/// digits is kept as it is.
+fn decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let escaped = bytes
+                    .get(i + 1..i + 3)
+                    .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                match escaped {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        431 => "Request Header Fields Too Large",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+        verse::Verse,
+    };
+
+    fn create_test_library() -> BibleLibrary {
+        let verses = vec![
+            Verse::new(BibleBook::John, 3, 16, "For God so loved the world".into()),
+            Verse::new(BibleBook::John, 3, 17, "For God sent not his Son".into()),
+        ];
+        let bible = TestBible::new(vec![Book::new(
+            "jo".into(),
+            "John".into(),
+            vec![Chapter::new(verses, 3)],
+        )])
+        .with_id("kjv")
+        .with_name("King James Version")
+        .build();
+        BibleLibrary::new().with_bible(bible)
+    }
+
+    fn create_test_server() -> BibleServer {
+        BibleServer::from_library(create_test_library())
+    }
+
+    #[test]
+    fn test_routes() {
+        let server = create_test_server();
+        assert_eq!(
+            server.handle("/bibles").body(),
+            "[{\"id\":\"kjv\",\"name\":\"King James Version\",\"language\":\"en\"}]"
+        );
+
+        let verse = server.handle("/bibles/kjv/verses/John%203:16");
+        assert_eq!(verse.status(), 200);
+        assert_eq!(
+            verse.body(),
+            "{\"reference\":\"John 3:16\",\"book\":\"jo\",\"chapter\":3,\"verse\":16,\
+             \"text\":\"For God so loved the world\"}"
+        );
+        assert_eq!(server.handle("/bibles/kjv/verses/Jn+3:16"), verse);
+        assert_eq!(server.handle("/bibles/kjv/verses/jn/3/16"), verse);
+
+        let passage = server.handle("/bibles/kjv/passages/John%203:16-17");
+        assert!(passage
+            .body()
+            .starts_with("{\"reference\":\"John 3:16-17\",\"verses\":["));
+        let search = server.handle("/bibles/kjv/search?q=sent&limit=5");
+        assert!(search.body().contains("\"verse\":17") && !search.body().contains("\"verse\":16"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let server = create_test_server();
+        assert_eq!(
+            server.handle("/bibles/web/verses/John%203:16").status(),
+            404
+        );
+        assert_eq!(server.handle("/bibles/kjv/verses/John%209:1").status(), 404);
+        assert_eq!(server.handle("/bibles/kjv/verses/John").status(), 400);
+        assert_eq!(server.handle("/bibles/kjv/search").status(), 400);
+        assert_eq!(server.handle("/nowhere").status(), 404);
+        assert!(server.handle("/nowhere").body().starts_with("{\"error\":"));
+    }
+
+    #[test]
+    fn test_decode_requires_two_hex_digits() {
+        assert_eq!(decode("John%203:16"), "John 3:16");
+        assert_eq!(decode("%+5"), "% 5");
+        assert_eq!(decode("%2"), "%2");
+        assert_eq!(decode("%e2%80%94"), "\u{2014}");
+    }
+
+    #[test]
+    fn test_stalled_client_times_out() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = create_test_server().with_timeout(Duration::from_millis(100));
+        std::thread::spawn(move || server.serve_listener(listener));
+
+        // Connects but never sends a request.
+        let _stalled = TcpStream::connect(addr).unwrap();
+        let mut client = TcpStream::connect(addr).unwrap();
+        client
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        client.write_all(b"GET /bibles HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+    }
+}
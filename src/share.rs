@@ -0,0 +1,299 @@
+//! Verse text formatted for sharing on social platforms.
+//!
+//! [`Passage::share_card`] lays a passage out as a post, fitted to a
+//! platform's length limit:
+//!
+//! ```text
+//! “For God so loved the world, that he gave his only begotten Son…” — John 3:16 (KJV)
+//! #John
+//! ```
+//!
+//! The verse text is cut first, with the citation narrowed to the verses
+//! actually quoted; the citation, translation, notice and hashtag are never
+//! cut. Under an [`ExcerptPolicy`] the quote also keeps to the policy's
+//! limits and carries its notice.
+
+use crate::{
+    book_id::BookId,
+    excerpt::ExcerptPolicy,
+    passage::Passage,
+    reference::{CitationStyle, ReferenceRange},
+};
+
+/// Settings for [`Passage::share_card`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShareOptions {
+    max_chars: usize,
+    citation_style: CitationStyle,
+    hashtag: bool,
+    policy: Option<ExcerptPolicy>,
+}
+
+impl Default for ShareOptions {
+    fn default() -> Self {
+        ShareOptions {
+            max_chars: 280,
+            citation_style: CitationStyle::FullName,
+            hashtag: false,
+            policy: None,
+        }
+    }
+}
+
+impl ShareOptions {
+    /// Creates options for posts of at most `max_chars` characters, such as
+    /// 280 for X or 500 for Mastodon, citing books by their full names.
+    pub fn new(max_chars: usize) -> Self {
+        ShareOptions {
+            max_chars,
+            ..Self::default()
+        }
+    }
+
+    /// Sets how the citation names books.
+    pub fn with_citation_style(mut self, style: CitationStyle) -> Self {
+        self.citation_style = style;
+        self
+    }
+
+    /// Adds a hashtag for the book, such as `#1Samuel`, on its own line.
+    pub fn with_hashtag(mut self, hashtag: bool) -> Self {
+        self.hashtag = hashtag;
+        self
+    }
+
+    /// Keeps the quote within `policy` and adds its notice.
+    pub fn with_excerpt_policy(mut self, policy: ExcerptPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Returns the length limit in characters.
+    pub fn max_chars(&self) -> usize {
+        self.max_chars
+    }
+}
+
+/// A passage formatted by [`Passage::share_card`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareCard {
+    quote: String,
+    citation: String,
+    translation: String,
+    notice: Option<String>,
+    hashtag: Option<String>,
+    truncated: bool,
+}
+
+impl ShareCard {
+    /// Returns the quoted verse text, ending in an ellipsis if it was cut to
+    /// fit the length limit.
+    pub fn quote(&self) -> &str {
+        &self.quote
+    }
+
+    /// Returns the citation of the verses quoted, such as `"John 3:16–17"`.
+    pub fn citation(&self) -> &str {
+        &self.citation
+    }
+
+    /// Returns the translation label: the Bible's id in capitals, such as
+    /// `"KJV"`.
+    pub fn translation(&self) -> &str {
+        &self.translation
+    }
+
+    /// Returns the notice required by the excerpt policy, if any.
+    pub fn notice(&self) -> Option<&str> {
+        self.notice.as_deref()
+    }
+
+    /// Returns the hashtag, including its `#`, if requested.
+    pub fn hashtag(&self) -> Option<&str> {
+        self.hashtag.as_deref()
+    }
+
+    /// Returns `true` if verse text was left out, to fit the length limit or
+    /// the excerpt policy.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the post: the quote with its citation, then the notice and
+    /// hashtag on lines of their own.
+    pub fn text(&self) -> String {
+        let mut lines = vec![format!(
+            "\u{201c}{}\u{201d} \u{2014} {} ({})",
+            self.quote, self.citation, self.translation
+        )];
+        lines.extend(self.notice.clone());
+        lines.extend(self.hashtag.clone());
+        lines.join("\n")
+    }
+}
+
+impl Passage<'_> {
+    /// Formats the passage for sharing within the limits of `options`. See
+    /// the [module documentation](crate::share).
+    ///
+    /// The quote is cut to fit [`ShareOptions::max_chars`]; if the citation,
+    /// notice and hashtag alone exceed it, the quote is just an ellipsis and
+    /// the post is longer than the limit.
+    pub fn share_card(&self, options: &ShareOptions) -> ShareCard {
+        let excerpt = options
+            .policy
+            .as_ref()
+            .map(|policy| self.excerpt_within_limits(policy));
+        let allowed = excerpt.as_ref().map_or(self.verses(), |e| e.verses());
+        let source = if allowed.is_empty() || allowed.len() == self.len_verses() {
+            self.clone()
+        } else {
+            Passage::from_verses(self.bible(), allowed.to_vec())
+        };
+        let notice = excerpt
+            .as_ref()
+            .and_then(|e| e.citation().map(str::to_string));
+        let hashtag = options
+            .hashtag
+            .then(|| {
+                self.verses()
+                    .first()
+                    .map(|verse| book_hashtag(self, verse.book()))
+            })
+            .flatten();
+
+        // A budget of 1 still leaves room for the ellipsis.
+        let mut budget = options.max_chars.max(1);
+        loop {
+            let cut = if allowed.is_empty() {
+                source.truncate_to_words(0)
+            } else {
+                source.truncate_to_chars(budget)
+            };
+            let range: ReferenceRange = cut.range().unwrap_or_else(|| source.range().clone());
+            let card = ShareCard {
+                quote: cut.text().to_string(),
+                citation: range.to_citation(options.citation_style),
+                translation: self.bible().id().to_uppercase(),
+                notice: notice.clone(),
+                hashtag: hashtag.clone(),
+                truncated: cut.is_truncated() || source.len_verses() < self.len_verses(),
+            };
+            let over = card
+                .text()
+                .chars()
+                .count()
+                .saturating_sub(options.max_chars);
+            if over == 0 || budget == 1 {
+                return card;
+            }
+            budget = budget.saturating_sub(over).max(1);
+        }
+    }
+}
+
+/// Names a book as a hashtag: its English name, or its title in this Bible
+/// for a custom book, in capitalized words without spaces or punctuation.
+fn book_hashtag(passage: &Passage<'_>, book: &BookId) -> String {
+    let name = match book.known() {
+        Some(book) => book.full_name().to_string(),
+        None => passage
+            .bible()
+            .get_book(book)
+            .map_or_else(|_| book.to_string(), |b| b.title().to_string()),
+    };
+    let mut tag = String::from("#");
+    for word in name.split_whitespace() {
+        let mut chars = word.chars().filter(|c| c.is_alphanumeric());
+        if let Some(first) = chars.next() {
+            tag.extend(first.to_uppercase());
+            tag.extend(chars);
+        }
+    }
+    tag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible::Bible, bible_books_enum::BibleBook, book::Book, chapter::Chapter,
+        reference::Reference, test_support::TestBible, verse::Verse,
+    };
+
+    fn create_test_bible() -> Bible {
+        let verses = vec![
+            Verse::new(
+                BibleBook::SongOfSolomon,
+                2,
+                1,
+                "I am the rose of Sharon, and the lily of the valleys.".into(),
+            ),
+            Verse::new(
+                BibleBook::SongOfSolomon,
+                2,
+                2,
+                "As the lily among thorns, so is my love among the daughters.".into(),
+            ),
+        ];
+        let chapters = vec![Chapter::new(Vec::new(), 1), Chapter::new(verses, 2)];
+        TestBible::new(vec![Book::new("so".into(), "Song".into(), chapters)])
+            .with_id("kjv")
+            .build()
+    }
+
+    fn passage(bible: &Bible) -> Passage<'_> {
+        bible
+            .passage(&ReferenceRange::new(
+                Reference::new(BibleBook::SongOfSolomon, 2, 1),
+                Reference::new(BibleBook::SongOfSolomon, 2, 2),
+            ))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_share_card_fits_limit() {
+        let bible = create_test_bible();
+        let passage = passage(&bible);
+
+        let card = passage.share_card(&ShareOptions::new(500).with_hashtag(true));
+        assert!(!card.is_truncated());
+        assert_eq!(
+            card.text(),
+            "“I am the rose of Sharon, and the lily of the valleys. As the lily among \
+             thorns, so is my love among the daughters.” — Song of Solomon 2:1–2 (KJV)\n\
+             #SongOfSolomon"
+        );
+
+        let short = passage.share_card(&ShareOptions::new(80));
+        assert!(short.is_truncated());
+        assert!(short.text().chars().count() <= 80);
+        assert_eq!(short.citation(), "Song of Solomon 2:1");
+        assert_eq!(
+            short.text(),
+            "“I am the rose of Sharon, and the lily of the…” — Song of Solomon 2:1 (KJV)"
+        );
+        // Too short even for the citation: the quote is just an ellipsis.
+        assert!(passage
+            .share_card(&ShareOptions::new(0))
+            .text()
+            .starts_with("“…” — "));
+    }
+
+    #[test]
+    fn test_share_card_with_excerpt_policy() {
+        let bible = create_test_bible();
+        let policy = ExcerptPolicy::new()
+            .with_max_verses(1)
+            .with_citation("Public domain.");
+        let card = passage(&bible).share_card(
+            &ShareOptions::new(280)
+                .with_citation_style(CitationStyle::Abbreviated)
+                .with_excerpt_policy(policy),
+        );
+        assert!(card.is_truncated());
+        assert_eq!(card.citation(), "Song 2:1");
+        assert_eq!(card.notice(), Some("Public domain."));
+        assert!(card.text().ends_with("(KJV)\nPublic domain."));
+    }
+}
@@ -0,0 +1,215 @@
+//! Near-duplicate verse detection using word shingles and MinHash.
+//!
+//! Verses are reduced to sets of overlapping three-word shingles. MinHash
+//! signatures bucketed with locality-sensitive hashing propose candidate
+//! pairs, which are then confirmed by their exact Jaccard similarity.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use crate::{bible::Bible, reference::Reference, search_index::SearchIndex};
+
+const SHINGLE_SIZE: usize = 3;
+const BANDS: usize = 16;
+const ROWS_PER_BAND: usize = 4;
+const SIGNATURE_LEN: usize = BANDS * ROWS_PER_BAND;
+
+impl Bible {
+    /// Groups verses whose wording is nearly identical, such as synoptic
+    /// gospel parallels or passages shared by Kings and Chronicles.
+    ///
+    /// Two verses are linked when the Jaccard similarity of their word
+    /// shingles is at least `threshold` (between `0.0` and `1.0`); linked
+    /// verses are then clustered transitively. Verses shorter than three
+    /// words are ignored. Each group is sorted, and groups are ordered by
+    /// their first reference.
+    pub fn find_parallel_passages(&self, threshold: f64) -> Vec<Vec<Reference>> {
+        let mut references = Vec::new();
+        let mut shingle_sets = Vec::new();
+
+        for verse in self
+            .books()
+            .iter()
+            .flat_map(|b| b.chapters())
+            .flat_map(|c| c.get_verses())
+        {
+            let shingles = shingles(verse.text());
+            if !shingles.is_empty() {
+                references.push(verse.reference());
+                shingle_sets.push(shingles);
+            }
+        }
+
+        let mut buckets: HashMap<(usize, u64), Vec<usize>> = HashMap::new();
+        for (i, set) in shingle_sets.iter().enumerate() {
+            let signature = minhash(set);
+            for (band, rows) in signature.chunks(ROWS_PER_BAND).enumerate() {
+                buckets.entry((band, hash_of(rows))).or_default().push(i);
+            }
+        }
+
+        let mut clusters = UnionFind::new(references.len());
+        for members in buckets.values() {
+            for (n, &a) in members.iter().enumerate() {
+                for &b in &members[n + 1..] {
+                    if clusters.find(a) != clusters.find(b)
+                        && jaccard(&shingle_sets[a], &shingle_sets[b]) >= threshold
+                    {
+                        clusters.union(a, b);
+                    }
+                }
+            }
+        }
+
+        let mut groups: HashMap<usize, Vec<Reference>> = HashMap::new();
+        for (i, reference) in references.into_iter().enumerate() {
+            groups.entry(clusters.find(i)).or_default().push(reference);
+        }
+
+        let mut groups: Vec<Vec<Reference>> =
+            groups.into_values().filter(|g| g.len() > 1).collect();
+        for group in &mut groups {
+            group.sort();
+        }
+        groups.sort();
+        groups
+    }
+}
+
+/// Returns the sorted, deduplicated hashes of the verse's word shingles.
+fn shingles(text: &str) -> Vec<u64> {
+    let terms = SearchIndex::tokenize(text);
+    let mut hashes: Vec<u64> = terms.windows(SHINGLE_SIZE).map(hash_of).collect();
+    hashes.sort_unstable();
+    hashes.dedup();
+    hashes
+}
+
+fn minhash(shingles: &[u64]) -> [u64; SIGNATURE_LEN] {
+    let mut signature = [u64::MAX; SIGNATURE_LEN];
+    for &shingle in shingles {
+        for (seed, slot) in signature.iter_mut().enumerate() {
+            *slot = (*slot).min(mix(
+                shingle ^ (seed as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ));
+        }
+    }
+    signature
+}
+
+/// SplitMix64 finalizer, used to derive independent hash functions from one hash.
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Jaccard similarity of two sorted, deduplicated sets.
+fn jaccard(a: &[u64], b: &[u64]) -> f64 {
+    let (mut i, mut j, mut shared) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                shared += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    shared as f64 / (a.len() + b.len() - shared) as f64
+}
+
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        self.parent[a.max(b)] = a.min(b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+        verse::Verse,
+    };
+
+    #[test]
+    fn test_parallel_verses_are_grouped() {
+        let book = |abbrev: &str, id: BibleBook, texts: &[&str]| {
+            let verses = texts
+                .iter()
+                .enumerate()
+                .map(|(i, t)| Verse::new(id, 1, i + 1, t.to_string()))
+                .collect();
+            Book::new(
+                abbrev.into(),
+                id.full_name().into(),
+                vec![Chapter::new(verses, 1)],
+            )
+        };
+        let bible = TestBible::new(vec![
+            book(
+                "mt",
+                BibleBook::Matthew,
+                &[
+                    "Blessed are the meek for they shall inherit the earth",
+                    "Jesus wept",
+                ],
+            ),
+            book(
+                "lk",
+                BibleBook::Luke,
+                &[
+                    "And he said unto them go ye into all the world",
+                    "Blessed are the meek for they shall inherit the whole earth",
+                ],
+            ),
+        ])
+        .build();
+
+        let groups = bible.find_parallel_passages(0.5);
+        assert_eq!(
+            groups,
+            vec![vec![
+                Reference::new(BibleBook::Matthew, 1, 1),
+                Reference::new(BibleBook::Luke, 1, 2),
+            ]]
+        );
+        assert!(bible.find_parallel_passages(0.9).is_empty());
+    }
+
+    #[test]
+    fn test_jaccard() {
+        assert_eq!(jaccard(&[1, 2, 3], &[2, 3, 4]), 0.5);
+        assert_eq!(jaccard(&[1], &[1]), 1.0);
+    }
+}
@@ -0,0 +1,191 @@
+//! Binary snapshots of parsed Bible files, cached on disk.
+//!
+//! With `LoadOptions::with_cache_dir`, the first load of a file stores its parsed content in the cache
+//! directory under a hash of the file's bytes and layout. Later loads of an
+//! identical file read the snapshot instead of parsing JSON, and an edited
+//! file simply gets a new snapshot.
+//!
+//! A snapshot holds a small JSON header with everything but the verse text
+//! (names, metadata, outlines), followed by each verse as length-prefixed
+//! UTF-8, so reading it is mostly copying strings. Text processors are not
+//! part of the snapshot and run on every load.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use simd_json::serde::{from_slice as simd_from_slice, to_vec as simd_to_vec};
+
+use crate::{bible::BibleFileRoot, json_schema::JsonSchema, provenance::Fnv1a};
+
+const MAGIC: &[u8; 8] = b"BIBSNAP1";
+
+/// Returns where the snapshot of a file with these bytes would be stored.
+pub(crate) fn snapshot_path(dir: &Path, file: &[u8], schema: JsonSchema) -> PathBuf {
+    let mut hash = Fnv1a::new();
+    hash.write(MAGIC);
+    hash.write(format!("{schema:?}").as_bytes());
+    hash.write(file);
+    dir.join(format!("{:016x}.bibsnap", hash.finish()))
+}
+
+/// Reads a snapshot, or returns `None` if it is missing or unreadable.
+pub(crate) fn read(path: &Path) -> Option<BibleFileRoot> {
+    decode(&fs::read(path).ok()?)
+}
+
+/// Stores a snapshot of `root`. The cache is an optimization, so a snapshot
+/// that cannot be written is skipped.
+pub(crate) fn write(path: &Path, root: &mut BibleFileRoot) {
+    let Some(bytes) = encode(root) else {
+        return;
+    };
+    let Some(dir) = path.parent() else {
+        return;
+    };
+    // Written under a temporary name first, so a concurrent load never
+    // reads half a snapshot.
+    let partial = path.with_extension(format!("{}.tmp", std::process::id()));
+    let stored = fs::create_dir_all(dir)
+        .and_then(|()| fs::write(&partial, bytes))
+        .and_then(|()| fs::rename(&partial, path));
+    if stored.is_err() {
+        fs::remove_file(&partial).ok();
+    }
+}
+
+fn encode(root: &mut BibleFileRoot) -> Option<Vec<u8>> {
+    // The header is serialized with the chapters taken out, then they are
+    // put back for the caller.
+    let chapters: Vec<Vec<Vec<String>>> = root
+        .books
+        .values_mut()
+        .map(|entry| std::mem::take(&mut entry.chapters))
+        .collect();
+    let header = simd_to_vec(root);
+    for (entry, verses) in root.books.values_mut().zip(&chapters) {
+        entry.chapters.clone_from(verses);
+    }
+    let header = header.ok()?;
+
+    let mut bytes = MAGIC.to_vec();
+    push_len(&mut bytes, header.len())?;
+    bytes.extend_from_slice(&header);
+    for book in &chapters {
+        push_len(&mut bytes, book.len())?;
+        for chapter in book {
+            push_len(&mut bytes, chapter.len())?;
+            for verse in chapter {
+                push_len(&mut bytes, verse.len())?;
+                bytes.extend_from_slice(verse.as_bytes());
+            }
+        }
+    }
+    Some(bytes)
+}
+
+fn decode(bytes: &[u8]) -> Option<BibleFileRoot> {
+    let mut reader = Reader {
+        bytes: bytes.strip_prefix(MAGIC)?,
+    };
+    let header_len = reader.len()?;
+    let mut header = reader.take(header_len)?.to_vec();
+    let mut root: BibleFileRoot = simd_from_slice(&mut header).ok()?;
+    for entry in root.books.values_mut() {
+        let chapters = reader.len()?;
+        entry.chapters = (0..chapters)
+            .map(|_| {
+                let verses = reader.len()?;
+                (0..verses)
+                    .map(|_| {
+                        let len = reader.len()?;
+                        String::from_utf8(reader.take(len)?.to_vec()).ok()
+                    })
+                    .collect()
+            })
+            .collect::<Option<_>>()?;
+    }
+    reader.bytes.is_empty().then_some(root)
+}
+
+fn push_len(bytes: &mut Vec<u8>, len: usize) -> Option<()> {
+    bytes.extend_from_slice(&u32::try_from(len).ok()?.to_le_bytes());
+    Some(())
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.bytes.len() {
+            return None;
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Some(taken)
+    }
+
+    fn len(&mut self) -> Option<usize> {
+        let bytes = self.take(4)?.try_into().ok()?;
+        Some(u32::from_le_bytes(bytes) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bible::Bible, bible_books_enum::BibleBook, load_options::LoadOptions};
+
+    const JSON: &str = r#"{"id": "kjv", "name": "King James Version", "copyright": "Public domain",
+        "books": {"ps": {"name": "Psalms", "aliases": ["Psalter"], "chapters": [
+            ["[A Psalm of David.] The LORD is my shepherd", "", "He {restoreth} my soul"]
+        ]}}}"#;
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut root =
+            crate::json_schema::parse(&mut JSON.as_bytes().to_vec(), JsonSchema::Auto).unwrap();
+        let bytes = encode(&mut root).unwrap();
+        assert_eq!(root.books["ps"].chapters[0].len(), 3);
+
+        let decoded = decode(&bytes).unwrap();
+        assert_eq!(decoded.books["ps"].chapters, root.books["ps"].chapters);
+        assert_eq!(decoded.books["ps"].aliases, ["Psalter"]);
+        assert_eq!(decoded.metadata, root.metadata);
+        assert!(decode(&bytes[..bytes.len() - 1]).is_none());
+        assert!(decode(b"BIBSNAP0").is_none());
+    }
+
+    #[test]
+    fn test_cached_load_matches_json_load() {
+        let dir = std::env::temp_dir().join("bible_io_snapshot_test");
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("kjv.json");
+        fs::write(&path, JSON).unwrap();
+        let cache = dir.join("cache");
+        let options = LoadOptions::new().with_bracketed_superscriptions(true);
+        let cached = options.clone().with_cache_dir(&cache);
+
+        let load = || Bible::new_from_json_with_options(path.to_str().unwrap(), &cached);
+        let first = load().unwrap();
+        let snapshots = fs::read_dir(&cache).unwrap().count();
+        let second = load().unwrap();
+        let uncached = Bible::new_from_json_with_options(path.to_str().unwrap(), &options).unwrap();
+        fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(snapshots, 1);
+        for bible in [&first, &second] {
+            assert_eq!(bible.content_hash(), uncached.content_hash());
+            assert_eq!(bible.metadata(), uncached.metadata());
+        }
+        let psalm = second.get_book(BibleBook::Psalms).unwrap();
+        assert_eq!(
+            psalm.chapters()[0].superscription(),
+            Some("A Psalm of David.")
+        );
+    }
+}
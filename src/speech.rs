@@ -0,0 +1,222 @@
+//! Text-to-speech friendly rendering of verses and chapters.
+//!
+//! References are spelled out the way a reader would say them ("First
+//! Samuel chapter three, verse sixteen"), verse numbers are kept out of the
+//! body text, and pauses can optionally be marked with SSML `<break>` tags.
+
+use crate::{book_id::BookId, chapter::Chapter, render::push_html_escaped, verse::Verse};
+
+/// Options for [`verse_to_speech`] and [`chapter_to_speech`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpeechOptions {
+    ssml: bool,
+    announce_verses: bool,
+}
+
+impl SpeechOptions {
+    /// Creates options producing plain text without verse announcements.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps output in `<speak>` and marks pauses with `<break>` tags.
+    pub fn with_ssml(mut self, ssml: bool) -> Self {
+        self.ssml = ssml;
+        self
+    }
+
+    /// Announces each verse ("verse sixteen") within a chapter.
+    pub fn with_verse_announcements(mut self, announce_verses: bool) -> Self {
+        self.announce_verses = announce_verses;
+        self
+    }
+}
+
+/// Renders a verse as its spoken reference followed by its text, e.g.
+/// `"John chapter three, verse sixteen. For God so loved the world..."`.
+pub fn verse_to_speech(verse: &Verse, options: &SpeechOptions) -> String {
+    let mut out = String::new();
+    push_text(
+        &mut out,
+        &format!(
+            "{} chapter {}, verse {}.",
+            spoken_book_name(verse.book()),
+            number_to_words(verse.chapter()),
+            number_to_words(verse.number())
+        ),
+        options,
+    );
+    push_pause(&mut out, options);
+    push_text(&mut out, verse.text().trim(), options);
+    finish(out, options)
+}
+
+/// Renders a chapter as a spoken heading followed by its verse text.
+pub fn chapter_to_speech(chapter: &Chapter, options: &SpeechOptions) -> String {
+    let mut out = String::new();
+    let book = chapter
+        .get_verses()
+        .first()
+        .map(|v| spoken_book_name(v.book()));
+    let heading = match book {
+        Some(book) => format!("{} chapter {}.", book, number_to_words(chapter.number())),
+        None => format!("Chapter {}.", number_to_words(chapter.number())),
+    };
+    push_text(&mut out, &heading, options);
+
+    for verse in chapter.get_verses() {
+        push_pause(&mut out, options);
+        if options.announce_verses {
+            push_text(
+                &mut out,
+                &format!("Verse {}.", number_to_words(verse.number())),
+                options,
+            );
+            out.push(' ');
+        }
+        push_text(&mut out, verse.text().trim(), options);
+    }
+    finish(out, options)
+}
+
+fn push_text(out: &mut String, text: &str, options: &SpeechOptions) {
+    if options.ssml {
+        push_html_escaped(out, text);
+    } else {
+        out.push_str(text);
+    }
+}
+
+fn push_pause(out: &mut String, options: &SpeechOptions) {
+    if options.ssml {
+        out.push_str(" <break time=\"500ms\"/> ");
+    } else {
+        out.push(' ');
+    }
+}
+
+fn finish(out: String, options: &SpeechOptions) -> String {
+    if options.ssml {
+        format!("<speak>{}</speak>", out)
+    } else {
+        out
+    }
+}
+
+/// Spells out numbered book names: "1 Samuel" becomes "First Samuel".
+fn spoken_book_name(book: &BookId) -> String {
+    let Some(known) = book.known() else {
+        return book.as_str().to_string();
+    };
+    let name = known.full_name();
+    let ordinal = match name.split_once(' ') {
+        Some(("1", rest)) => Some(("First", rest)),
+        Some(("2", rest)) => Some(("Second", rest)),
+        Some(("3", rest)) => Some(("Third", rest)),
+        Some(("4", rest)) => Some(("Fourth", rest)),
+        _ => None,
+    };
+    match ordinal {
+        Some((ordinal, rest)) => format!("{} {}", ordinal, rest),
+        None => name.to_string(),
+    }
+}
+
+/// Writes out a number in English words, e.g. `176` as "one hundred seventy-six".
+fn number_to_words(number: usize) -> String {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    let (high, low, unit) = match number {
+        0..=19 => return ONES[number].to_string(),
+        20..=99 => {
+            return match number % 10 {
+                0 => TENS[number / 10].to_string(),
+                ones => format!("{}-{}", TENS[number / 10], ONES[ones]),
+            }
+        }
+        100..=999 => (number / 100, number % 100, "hundred"),
+        1000..=999_999 => (number / 1000, number % 1000, "thousand"),
+        _ => return number.to_string(),
+    };
+    match low {
+        0 => format!("{} {}", number_to_words(high), unit),
+        _ => format!(
+            "{} {} {}",
+            number_to_words(high),
+            unit,
+            number_to_words(low)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    #[test]
+    fn test_verse_and_chapter_speech() {
+        let verse = Verse::new(
+            BibleBook::FirstSamuel,
+            3,
+            16,
+            "Then Eli called Samuel".into(),
+        );
+        assert_eq!(
+            verse_to_speech(&verse, &SpeechOptions::new()),
+            "First Samuel chapter three, verse sixteen. Then Eli called Samuel"
+        );
+
+        let chapter = Chapter::new(
+            vec![
+                Verse::new(BibleBook::John, 11, 35, "Jesus wept.".into()),
+                Verse::new(BibleBook::John, 11, 36, "Then said the Jews".into()),
+            ],
+            11,
+        );
+        let ssml = chapter_to_speech(
+            &chapter,
+            &SpeechOptions::new()
+                .with_ssml(true)
+                .with_verse_announcements(true),
+        );
+        assert_eq!(
+            ssml,
+            "<speak>John chapter eleven. <break time=\"500ms\"/> Verse thirty-five. Jesus wept. \
+             <break time=\"500ms\"/> Verse thirty-six. Then said the Jews</speak>"
+        );
+    }
+
+    #[test]
+    fn test_number_to_words() {
+        assert_eq!(number_to_words(3), "three");
+        assert_eq!(number_to_words(40), "forty");
+        assert_eq!(number_to_words(119), "one hundred nineteen");
+        assert_eq!(number_to_words(176), "one hundred seventy-six");
+        assert_eq!(number_to_words(2000), "two thousand");
+    }
+}
@@ -0,0 +1,209 @@
+//! Chapter summaries distributed separately from the Bible text.
+//!
+//! The crate ships no summaries; a [`ChapterSummarySet`] is written or
+//! licensed by the app and attached after loading, so chapter pickers can
+//! show a one-line synopsis from [`Chapter::summary`](crate::Chapter::summary).
+//! It is stored as JSON or as tab-separated `chapter<TAB>summary` lines:
+//!
+//! ```json
+//! { "description": "Chapter summaries", "summaries": { "Genesis 1": "The creation" } }
+//! ```
+
+use std::error::Error;
+
+use serde::{Deserialize, Serialize};
+use simd_json::serde::to_string_pretty as simd_to_string_pretty;
+
+use crate::{
+    bible::Bible,
+    bible::BibleError,
+    book_id::BookId,
+    keyed_text::{self, KeyedText},
+};
+
+/// Chapter summaries keyed by chapter, such as `"Genesis 1"` or `"Ps 23"`,
+/// attached with [`Bible::attach_chapter_summaries`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChapterSummarySet {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    description: String,
+    summaries: KeyedText,
+}
+
+impl ChapterSummarySet {
+    /// Creates an empty summary set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a description of the summaries, such as their source and license.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Adds the summary of `chapter`, written as a book and chapter number
+    /// such as `"Genesis 1"`.
+    pub fn with_summary(mut self, chapter: impl Into<String>, text: impl Into<String>) -> Self {
+        self.summaries.insert(chapter.into(), text.into());
+        self
+    }
+
+    /// Returns the description.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Iterates over (chapter, summary) pairs in file order.
+    pub fn summaries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.summaries.iter()
+    }
+
+    /// Returns the number of summaries.
+    pub fn len(&self) -> usize {
+        self.summaries.len()
+    }
+
+    /// Returns `true` if the set has no summaries.
+    pub fn is_empty(&self) -> bool {
+        self.summaries.is_empty()
+    }
+
+    /// Reads a summary set from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or parsed.
+    pub fn from_json(json_path: &str) -> Result<Self, BibleError> {
+        keyed_text::read_json(json_path)
+    }
+
+    /// Reads a summary set from a file of `chapter<TAB>summary` lines.
+    /// Blank lines and lines starting with `#` are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BibleError::LoadError`] if the file cannot be read or a line
+    /// has no tab.
+    pub fn from_tsv(tsv_path: &str) -> Result<Self, BibleError> {
+        Ok(ChapterSummarySet {
+            description: String::new(),
+            summaries: KeyedText::from_tsv(tsv_path, "chapter<TAB>summary")?,
+        })
+    }
+
+    /// Serializes the set into the format read by
+    /// [`ChapterSummarySet::from_json`].
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        Ok(simd_to_string_pretty(self)?)
+    }
+}
+
+impl Bible {
+    /// Attaches chapter summaries, which then appear in
+    /// [`Chapter::summary`](crate::Chapter::summary).
+    ///
+    /// A summary replaces any summary already attached to the same chapter.
+    /// Summaries are not written by [`Bible::to_json`], so separately
+    /// licensed summaries stay out of exported text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any key does not name a chapter of this Bible; no
+    /// summaries are attached in that case.
+    pub fn attach_chapter_summaries(
+        &mut self,
+        summaries: &ChapterSummarySet,
+    ) -> Result<(), BibleError> {
+        let targets: Vec<(BookId, usize, &str)> = summaries
+            .summaries()
+            .map(|(chapter, text)| {
+                // Reuse reference parsing for the book name and chapter.
+                let reference = self.parse_reference(&format!("{}:1", chapter.trim()))?;
+                self.get_book(reference.book())?
+                    .get_chapter(reference.chapter())?;
+                Ok((reference.book().clone(), reference.chapter(), text))
+            })
+            .collect::<Result<_, BibleError>>()?;
+
+        for (book, chapter, text) in targets {
+            if let Some(chapter) = self.chapter_mut(book, chapter) {
+                chapter.set_summary(text.to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+        verse::Verse,
+    };
+
+    fn create_test_bible() -> Bible {
+        let chapters = (1..=2)
+            .map(|c| {
+                let verses = (1..=3)
+                    .map(|v| Verse::new(BibleBook::Genesis, c, v, format!("Verse {}", v)))
+                    .collect();
+                Chapter::new(verses, c)
+            })
+            .collect();
+        TestBible::new(vec![Book::new("gn".into(), "Genesis".into(), chapters)]).build()
+    }
+
+    #[test]
+    fn test_attach_chapter_summaries() {
+        let mut bible = create_test_bible();
+        let set = ChapterSummarySet::new()
+            .with_summary("Genesis 1", "The creation")
+            .with_summary("Gen 2", "The garden of Eden");
+        bible.attach_chapter_summaries(&set).unwrap();
+
+        let book = bible.get_book(BibleBook::Genesis).unwrap();
+        assert_eq!(book.get_chapter(1).unwrap().summary(), Some("The creation"));
+        assert_eq!(
+            book.get_chapter(2).unwrap().summary(),
+            Some("The garden of Eden")
+        );
+
+        let bad = ChapterSummarySet::new()
+            .with_summary("Genesis 1", "Light")
+            .with_summary("Genesis 9", "Noah");
+        assert!(bible.attach_chapter_summaries(&bad).is_err());
+        let book = bible.get_book(BibleBook::Genesis).unwrap();
+        assert_eq!(book.get_chapter(1).unwrap().summary(), Some("The creation"));
+        assert!(!bible.to_json().unwrap().contains("creation"));
+    }
+
+    #[test]
+    fn test_load_tsv_and_json() {
+        let tsv = std::env::temp_dir().join("bible_io_summaries.tsv");
+        fs::write(
+            &tsv,
+            "# summaries\nGenesis 1\tThe creation\r\n\nGenesis 2\tEden\n",
+        )
+        .unwrap();
+        let set = ChapterSummarySet::from_tsv(tsv.to_str().unwrap()).unwrap();
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.summaries().nth(1), Some(("Genesis 2", "Eden")));
+
+        let json = std::env::temp_dir().join("bible_io_summaries.json");
+        fs::write(&json, set.to_json().unwrap()).unwrap();
+        assert_eq!(
+            ChapterSummarySet::from_json(json.to_str().unwrap()).unwrap(),
+            set
+        );
+
+        fs::write(&tsv, "Genesis 1 The creation\n").unwrap();
+        let err = ChapterSummarySet::from_tsv(tsv.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("line 1"));
+        fs::remove_file(&tsv).ok();
+        fs::remove_file(&json).ok();
+    }
+}
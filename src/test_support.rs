@@ -0,0 +1,76 @@
+//! Fixtures shared by the unit tests.
+
+use std::path::PathBuf;
+
+use crate::{
+    bible::{Bible, BibleMetadata},
+    bible_books_enum::BibleBook,
+    book::Book,
+    chapter::Chapter,
+    verse::Verse,
+};
+
+/// Builds a test Bible from books, with placeholder details that a test
+/// overrides only when it checks them.
+pub(crate) struct TestBible {
+    books: Vec<Book>,
+    id: String,
+    name: String,
+    metadata: BibleMetadata,
+}
+
+impl TestBible {
+    /// Starts a Bible of `books` with id `"id"`, name `"name"` and language
+    /// `"en"`.
+    pub(crate) fn new(books: Vec<Book>) -> Self {
+        TestBible {
+            books,
+            id: "id".into(),
+            name: "name".into(),
+            metadata: BibleMetadata::default(),
+        }
+    }
+
+    pub(crate) fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub(crate) fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub(crate) fn with_metadata(mut self, metadata: BibleMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub(crate) fn build(self) -> Bible {
+        Bible::from_books(
+            self.books,
+            self.id,
+            self.name,
+            "desc".into(),
+            "en".into(),
+            self.metadata,
+        )
+    }
+}
+
+/// Creates a book of one chapter 1 holding a single verse, keyed and named
+/// as in the standard canon.
+pub(crate) fn one_verse_book(id: BibleBook) -> Book {
+    let verse = Verse::new(id, 1, 1, "text".to_string());
+    Book::new(
+        id.as_str().to_string(),
+        id.full_name().to_string(),
+        vec![Chapter::new(vec![verse], 1)],
+    )
+}
+
+/// Returns a path in the temporary directory unique to this process and
+/// `name`, so concurrent test runs do not share files.
+pub(crate) fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("bible_io_{}_{name}", std::process::id()))
+}
@@ -0,0 +1,197 @@
+//! Quality checks for verse text.
+//!
+//! Translations converted from HTML, PDFs or scans often keep artifacts of
+//! the conversion: `&amp;` entities, `<i>` tags, doubled spaces, mis-decoded
+//! characters, footnote digits glued to words. [`Bible::check_text`] lists
+//! each suspicious verse so a curator can review it; nothing is changed.
+
+use std::fmt;
+
+use crate::{bible::Bible, reference::Reference};
+
+/// A kind of suspicious artifact in verse text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TextIssueKind {
+    /// An HTML character reference such as `&amp;` or `&#8217;`.
+    HtmlEntity(String),
+    /// A markup tag such as `<i>` or `</p>`.
+    Markup(String),
+    /// Two or more spaces in a row.
+    DoubleSpace,
+    /// Mis-decoded or unreadable text: the replacement character, control
+    /// characters, UTF-8 read as Latin-1 (`â€™`, `Ã©`), or characters that
+    /// rarely occur in scripture (`|`, `~`, `^`, `@`, `\`, `_`).
+    OcrJunk(String),
+    /// Digits joined to a word, like a footnote marker (`beginning1`), or
+    /// opening the verse, like a leaked verse number.
+    StrayDigits(String),
+}
+
+impl fmt::Display for TextIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TextIssueKind::HtmlEntity(entity) => write!(f, "HTML entity '{}'", entity),
+            TextIssueKind::Markup(tag) => write!(f, "markup '{}'", tag),
+            TextIssueKind::DoubleSpace => f.write_str("double space"),
+            TextIssueKind::OcrJunk(text) => write!(f, "unexpected characters '{}'", text),
+            TextIssueKind::StrayDigits(word) => write!(f, "stray digits in '{}'", word),
+        }
+    }
+}
+
+/// A suspicious artifact found by [`Bible::check_text`], with the verse it
+/// occurs in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextIssue {
+    reference: Reference,
+    kind: TextIssueKind,
+}
+
+impl TextIssue {
+    /// Returns the verse containing the artifact.
+    pub fn reference(&self) -> &Reference {
+        &self.reference
+    }
+
+    /// Returns what looks wrong.
+    pub fn kind(&self) -> &TextIssueKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for TextIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.reference, self.kind)
+    }
+}
+
+impl Bible {
+    /// Scans every verse for conversion artifacts, in reading order.
+    ///
+    /// Each kind of issue is reported at most once per verse, with the first
+    /// offending text. The checks are heuristics meant for review: a modern
+    /// translation that writes numbers as digits will see them flagged only
+    /// when they touch a letter or open the verse.
+    pub fn check_text(&self) -> Vec<TextIssue> {
+        self.all_verses()
+            .flat_map(|verse| {
+                check_verse(verse.text())
+                    .into_iter()
+                    .map(move |kind| TextIssue {
+                        reference: verse.reference(),
+                        kind,
+                    })
+            })
+            .collect()
+    }
+}
+
+fn check_verse(text: &str) -> Vec<TextIssueKind> {
+    [
+        html_entity(text).map(TextIssueKind::HtmlEntity),
+        markup(text).map(TextIssueKind::Markup),
+        text.trim()
+            .contains("  ")
+            .then_some(TextIssueKind::DoubleSpace),
+        ocr_junk(text).map(TextIssueKind::OcrJunk),
+        stray_digits(text).map(TextIssueKind::StrayDigits),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Finds `&name;`, `&#123;` or `&#x1F;`.
+fn html_entity(text: &str) -> Option<String> {
+    text.match_indices('&').find_map(|(start, _)| {
+        let rest = &text[start + 1..];
+        let end = rest.find(';')?;
+        let body = &rest[..end];
+        let valid = match body.strip_prefix('#') {
+            Some(number) => {
+                let digits = number.strip_prefix(['x', 'X']).unwrap_or(number);
+                !digits.is_empty() && digits.chars().all(|c| c.is_ascii_hexdigit())
+            }
+            None => {
+                !body.is_empty()
+                    && body.len() <= 8
+                    && body.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+        };
+        valid.then(|| text[start..start + end + 2].to_string())
+    })
+}
+
+/// Finds a tag such as `<i>`, `</span>` or `<br/>`.
+fn markup(text: &str) -> Option<String> {
+    text.match_indices('<').find_map(|(start, _)| {
+        let rest = &text[start + 1..];
+        let end = rest.find('>')?;
+        let name = rest[..end].trim_start_matches('/');
+        name.starts_with(|c: char| c.is_ascii_alphabetic())
+            .then(|| text[start..start + end + 2].to_string())
+    })
+}
+
+const MOJIBAKE: [&str; 3] = ["â€", "Ã", "Â"];
+const JUNK: [char; 6] = ['|', '~', '^', '@', '\\', '_'];
+
+fn ocr_junk(text: &str) -> Option<String> {
+    if let Some(found) = MOJIBAKE.iter().find(|m| text.contains(*m)) {
+        let start = text.find(found)?;
+        return Some(text[start..].chars().take(3).collect());
+    }
+    text.chars()
+        .find(|&c| c == '\u{fffd}' || JUNK.contains(&c) || (c.is_control() && c != '\n'))
+        .map(|c| c.escape_debug().to_string())
+}
+
+fn stray_digits(text: &str) -> Option<String> {
+    let mut words = text.split_whitespace();
+    let first = words.next()?;
+    if first.starts_with(|c: char| c.is_ascii_digit()) && text.len() > first.len() {
+        return Some(first.to_string());
+    }
+    std::iter::once(first).chain(words).find_map(|word| {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        let digit = word.chars().any(|c| c.is_ascii_digit());
+        let letter = word.chars().any(char::is_alphabetic);
+        (digit && letter).then(|| word.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flags_conversion_artifacts() {
+        assert!(check_verse("In the beginning God created the heaven and the earth.").is_empty());
+        assert!(check_verse("Jesus said, Let the little children come (Mark 10:14).").is_empty());
+        assert_eq!(
+            check_verse("Abram &amp; Sarai went <i>up</i>  into Egypt"),
+            [
+                TextIssueKind::HtmlEntity("&amp;".into()),
+                TextIssueKind::Markup("<i>".into()),
+                TextIssueKind::DoubleSpace,
+            ]
+        );
+        assert_eq!(
+            check_verse("the LORDâ€™s house"),
+            [TextIssueKind::OcrJunk("â€™".into())]
+        );
+        assert_eq!(
+            check_verse("in the beginning1 God | created"),
+            [
+                TextIssueKind::OcrJunk("|".into()),
+                TextIssueKind::StrayDigits("beginning1".into()),
+            ]
+        );
+        assert_eq!(
+            check_verse("2 And the earth was without form"),
+            [TextIssueKind::StrayDigits("2".into())]
+        );
+        assert!(check_verse("about 3000 men").is_empty());
+    }
+}
@@ -0,0 +1,201 @@
+//! Handling of inline tokens that some readers want and others do not.
+//!
+//! Verse text is stored exactly as loaded. A [`TextPolicy`] describes how to
+//! present it: whether to keep the liturgical "Selah", bracketed alternate
+//! readings, and the small-capital spelling of divine names ("LORD", "GOD").
+
+use crate::verse::Verse;
+
+/// Divine names printed in capitals to mark the Hebrew tetragrammaton.
+const DIVINE_NAMES: &[&str] = &["LORD", "GOD", "JEHOVAH", "JAH"];
+
+/// Options for [`Verse::text_with_policy`].
+///
+/// The default policy keeps everything, yielding [`Verse::text`] unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextPolicy {
+    keep_selah: bool,
+    keep_bracketed_text: bool,
+    keep_divine_name_capitals: bool,
+}
+
+impl Default for TextPolicy {
+    fn default() -> Self {
+        TextPolicy {
+            keep_selah: true,
+            keep_bracketed_text: true,
+            keep_divine_name_capitals: true,
+        }
+    }
+}
+
+impl TextPolicy {
+    /// Creates a policy that keeps the text as loaded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps or removes the word "Selah".
+    pub fn with_selah(mut self, keep: bool) -> Self {
+        self.keep_selah = keep;
+        self
+    }
+
+    /// Keeps or removes text enclosed in `[]`, such as alternate readings.
+    pub fn with_bracketed_text(mut self, keep: bool) -> Self {
+        self.keep_bracketed_text = keep;
+        self
+    }
+
+    /// Keeps divine names in capitals ("LORD"), or writes them in title case ("Lord").
+    pub fn with_divine_name_capitals(mut self, keep: bool) -> Self {
+        self.keep_divine_name_capitals = keep;
+        self
+    }
+
+    /// Returns `true` if "Selah" is kept.
+    pub fn keeps_selah(&self) -> bool {
+        self.keep_selah
+    }
+
+    /// Returns `true` if bracketed text is kept.
+    pub fn keeps_bracketed_text(&self) -> bool {
+        self.keep_bracketed_text
+    }
+
+    /// Returns `true` if divine names keep their capitals.
+    pub fn keeps_divine_name_capitals(&self) -> bool {
+        self.keep_divine_name_capitals
+    }
+}
+
+impl Verse {
+    /// Returns the verse text with inline tokens handled according to `policy`.
+    ///
+    /// Removing tokens collapses the whitespace around them.
+    pub fn text_with_policy(&self, policy: &TextPolicy) -> String {
+        if *policy == TextPolicy::default() {
+            return self.text().to_string();
+        }
+
+        let text = if policy.keep_bracketed_text {
+            self.text().to_string()
+        } else {
+            remove_bracketed(self.text())
+        };
+
+        text.split_whitespace()
+            .filter(|word| policy.keep_selah || !is_selah(word))
+            .map(|word| {
+                if policy.keep_divine_name_capitals {
+                    word.to_string()
+                } else {
+                    title_case_divine_names(word)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns `true` if the verse uses a capitalized divine name such as "LORD".
+    ///
+    /// "Lord" and "God" in ordinary case do not count.
+    pub fn contains_divine_name(&self) -> bool {
+        self.text()
+            .split(|c: char| !c.is_ascii_alphabetic())
+            .any(|word| DIVINE_NAMES.contains(&word))
+    }
+
+    /// Returns `true` if the verse contains the word "Selah".
+    pub fn contains_selah(&self) -> bool {
+        self.text().split_whitespace().any(is_selah)
+    }
+}
+
+fn is_selah(word: &str) -> bool {
+    word.trim_matches(|c: char| c.is_ascii_punctuation()) == "Selah"
+}
+
+/// Drops every `[...]` group along with the space before it. An unclosed `[`
+/// and what follows it are kept.
+fn remove_bracketed(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(open) = rest.find('[') {
+        let Some(close) = rest[open..].find(']') else {
+            break;
+        };
+        out.push_str(rest[..open].trim_end());
+        rest = &rest[open + close + 1..];
+        if !rest.starts_with(|c: char| c.is_ascii_punctuation()) {
+            out.push(' ');
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Rewrites "LORD" as "Lord" (and "LORD'S" as "Lord's") within a single word.
+fn title_case_divine_names(word: &str) -> String {
+    let mut out = String::with_capacity(word.len());
+    let mut after_name = false;
+    for part in word.split_inclusive(|c: char| !c.is_ascii_alphabetic()) {
+        let letters = part.trim_end_matches(|c: char| !c.is_ascii_alphabetic());
+        let tail = &part[letters.len()..];
+        if DIVINE_NAMES.contains(&letters) {
+            out.push_str(&letters[..1]);
+            out.push_str(&letters[1..].to_ascii_lowercase());
+            after_name = tail == "'";
+        } else if after_name && letters == "S" {
+            out.push('s');
+            after_name = false;
+        } else {
+            out.push_str(letters);
+            after_name = false;
+        }
+        out.push_str(tail);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    #[test]
+    fn test_policy_removes_and_normalizes_tokens() {
+        let verse = Verse::new(
+            BibleBook::Psalms,
+            3,
+            8,
+            "Salvation belongeth unto the LORD [the LORD'S]: thy blessing is upon thy people. Selah."
+                .into(),
+        );
+
+        assert_eq!(verse.text_with_policy(&TextPolicy::default()), verse.text());
+        assert_eq!(
+            verse.text_with_policy(&TextPolicy::new().with_selah(false)),
+            "Salvation belongeth unto the LORD [the LORD'S]: thy blessing is upon thy people."
+        );
+        assert_eq!(
+            verse.text_with_policy(&TextPolicy::new().with_bracketed_text(false)),
+            "Salvation belongeth unto the LORD: thy blessing is upon thy people. Selah."
+        );
+        assert_eq!(
+            verse.text_with_policy(&TextPolicy::new().with_divine_name_capitals(false)),
+            "Salvation belongeth unto the Lord [the Lord's]: thy blessing is upon thy people. Selah."
+        );
+    }
+
+    #[test]
+    fn test_divine_name_and_selah_queries() {
+        let verse = |text: &str| Verse::new(BibleBook::Psalms, 1, 1, text.into());
+
+        assert!(verse("O LORD, how are they increased").contains_divine_name());
+        assert!(verse("the LORD'S portion").contains_divine_name());
+        assert!(!verse("The Lord said unto my Lord").contains_divine_name());
+        assert!(verse("in God. Selah.").contains_selah());
+        assert!(!verse("Selahammahlekoth").contains_selah());
+    }
+}
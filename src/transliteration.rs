@@ -0,0 +1,181 @@
+//! Latin transliteration of original-language terms for search.
+//!
+//! A [`Transliterator`] added to [`SearchIndexOptions`](crate::SearchIndexOptions)
+//! indexes each Greek or Hebrew term under a Latin spelling as well, so
+//! `"agape"` finds `"ἀγάπη"` and `"shalom"` finds `"שָׁלוֹם"`. Schemes are
+//! approximate and meant for lookup, not scholarly transcription.
+
+use std::fmt;
+
+use crate::search_index::fold_diacritics;
+
+/// Converts a lowercase search term from another script into Latin letters.
+pub trait Transliterator: fmt::Debug + Send + Sync {
+    /// Returns the Latin spelling of `term`, or `None` if the term is not in
+    /// this transliterator's script.
+    fn transliterate(&self, term: &str) -> Option<String>;
+}
+
+/// Transliterates Greek following common lexicon conventions: `θ` as "th",
+/// `χ` as "ch", `γγ` as "ng", and rough breathing as a leading "h".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GreekTransliterator;
+
+impl Transliterator for GreekTransliterator {
+    fn transliterate(&self, term: &str) -> Option<String> {
+        let first = term.chars().next()?;
+        if !is_greek(first) {
+            return None;
+        }
+
+        let mut out = String::with_capacity(term.len());
+        if has_rough_breathing(term) {
+            out.push('h');
+        }
+        let folded: Vec<char> = fold_diacritics(term).chars().collect();
+        for (i, &c) in folded.iter().enumerate() {
+            let next = folded.get(i + 1).copied();
+            let latin = match c {
+                'α' => "a",
+                'β' => "b",
+                'γ' if matches!(next, Some('γ' | 'κ' | 'ξ' | 'χ')) => "n",
+                'γ' => "g",
+                'δ' => "d",
+                'ε' | 'η' => "e",
+                'ζ' => "z",
+                'θ' => "th",
+                'ι' => "i",
+                'κ' => "k",
+                'λ' => "l",
+                'μ' => "m",
+                'ν' => "n",
+                'ξ' => "x",
+                'ο' | 'ω' => "o",
+                'π' => "p",
+                'ρ' => "r",
+                'σ' | 'ς' => "s",
+                'τ' => "t",
+                'υ' => "u",
+                'φ' => "ph",
+                'χ' => "ch",
+                'ψ' => "ps",
+                _ if c.is_ascii_alphanumeric() => {
+                    out.push(c);
+                    continue;
+                }
+                _ => continue,
+            };
+            out.push_str(latin);
+        }
+        Some(out)
+    }
+}
+
+fn is_greek(c: char) -> bool {
+    matches!(c, '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}')
+}
+
+/// Rough breathing is marked on the first vowel (or rho) of a word, either
+/// precomposed or as combining U+0314.
+fn has_rough_breathing(term: &str) -> bool {
+    let mut chars = term.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    let precomposed = match first as u32 {
+        // Lowercase blocks of eight: odd offsets carry rough breathing.
+        code @ 0x1F00..=0x1F6F => code % 2 == 1 && code % 16 < 8,
+        // Rough breathing on iota-subscript vowels.
+        code @ 0x1F80..=0x1FAF => code % 2 == 1 && code % 16 < 8,
+        0x1FE5 => true,
+        _ => false,
+    };
+    precomposed || term.chars().take(3).any(|c| c == '\u{0314}')
+}
+
+/// Transliterates pointed or unpointed Hebrew using a simplified
+/// academic scheme: `ש` as "sh", `צ` as "ts", `ח` as "ch", with vowel points
+/// rendered as a, e, i, o, u. Alef and ayin are omitted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HebrewTransliterator;
+
+impl Transliterator for HebrewTransliterator {
+    fn transliterate(&self, term: &str) -> Option<String> {
+        let first = term.chars().next()?;
+        if !('\u{05D0}'..='\u{05EA}').contains(&first) {
+            return None;
+        }
+
+        let chars: Vec<char> = term.chars().collect();
+        let mut out = String::with_capacity(term.len());
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+            let latin = match c {
+                // Vav with holam or dagesh (shuruk) is a vowel letter.
+                'ו' if next == Some('\u{05B9}') => {
+                    i += 1;
+                    "o"
+                }
+                'ו' if next == Some('\u{05BC}') => {
+                    i += 1;
+                    "u"
+                }
+                'א' | 'ע' => "",
+                'ב' => "b",
+                'ג' => "g",
+                'ד' => "d",
+                'ה' => "h",
+                'ו' => "v",
+                'ז' => "z",
+                'ח' => "ch",
+                'ט' | 'ת' => "t",
+                'י' => "y",
+                'כ' | 'ך' => "k",
+                'ל' => "l",
+                'מ' | 'ם' => "m",
+                'נ' | 'ן' => "n",
+                'ס' => "s",
+                'פ' | 'ף' => "p",
+                'צ' | 'ץ' => "ts",
+                'ק' => "q",
+                'ר' => "r",
+                'ש' if next == Some('\u{05C2}') => "s",
+                'ש' => "sh",
+                '\u{05B1}' | '\u{05B5}' | '\u{05B6}' => "e",
+                '\u{05B2}' | '\u{05B7}' | '\u{05B8}' => "a",
+                '\u{05B4}' => "i",
+                '\u{05B3}' | '\u{05B9}' | '\u{05BA}' => "o",
+                '\u{05BB}' => "u",
+                _ => "",
+            };
+            out.push_str(latin);
+            i += 1;
+        }
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greek_transliteration() {
+        let greek = GreekTransliterator;
+        assert_eq!(greek.transliterate("ἀγάπη").as_deref(), Some("agape"));
+        assert_eq!(greek.transliterate("ἁμαρτία").as_deref(), Some("hamartia"));
+        assert_eq!(greek.transliterate("ἄγγελος").as_deref(), Some("angelos"));
+        assert_eq!(greek.transliterate("χριστός").as_deref(), Some("christos"));
+        assert_eq!(greek.transliterate("love"), None);
+    }
+
+    #[test]
+    fn test_hebrew_transliteration() {
+        let hebrew = HebrewTransliterator;
+        assert_eq!(hebrew.transliterate("שָׁלוֹם").as_deref(), Some("shalom"));
+        assert_eq!(hebrew.transliterate("תּוֹרָה").as_deref(), Some("torah"));
+        assert_eq!(hebrew.transliterate("ἀγάπη"), None);
+    }
+}
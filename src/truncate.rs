@@ -0,0 +1,253 @@
+//! Passages cut to a length budget, for share cards and notifications.
+//!
+//! [`Passage::truncate_to_words`] and [`Passage::truncate_to_chars`] keep
+//! whole words from the start of a passage, possibly ending within a verse,
+//! and mark a cut with a trailing [`ELLIPSIS`]. The result names the verse
+//! where the text was cut, so a citation can read "John 3:16–17" rather than
+//! the full range requested.
+
+use crate::{
+    passage::Passage,
+    reference::{Reference, ReferenceRange},
+    verse::Verse,
+};
+
+/// The mark appended to truncated text.
+pub const ELLIPSIS: char = '\u{2026}';
+
+/// The start of a passage that fits a length budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TruncatedPassage<'a> {
+    verses: Vec<&'a Verse>,
+    text: String,
+    truncated: bool,
+    cut: Option<Reference>,
+    ends_mid_verse: bool,
+}
+
+impl<'a> TruncatedPassage<'a> {
+    /// Returns the verses whose text was kept, the last possibly in part.
+    pub fn verses(&self) -> &[&'a Verse] {
+        &self.verses
+    }
+
+    /// Returns the range of the kept verses, or `None` if nothing was kept.
+    pub fn range(&self) -> Option<ReferenceRange> {
+        Some(ReferenceRange::new(
+            self.verses.first()?.reference(),
+            self.verses.last()?.reference(),
+        ))
+    }
+
+    /// Returns the kept text, ending in [`ELLIPSIS`] if the passage was cut.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns `true` if text was left out.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the last verse kept when the passage was cut, or `None` if the
+    /// whole passage fits or nothing was kept.
+    pub fn cut_reference(&self) -> Option<&Reference> {
+        self.cut.as_ref()
+    }
+
+    /// Returns `true` if the cut falls within a verse rather than between
+    /// verses.
+    pub fn ends_mid_verse(&self) -> bool {
+        self.ends_mid_verse
+    }
+}
+
+impl<'a> Passage<'a> {
+    /// Keeps at most `words` words from the start of the passage. The
+    /// ellipsis is not counted.
+    pub fn truncate_to_words(&self, words: usize) -> TruncatedPassage<'a> {
+        let tokens = self.tokens();
+        self.truncated(&tokens, words.min(tokens.len()), None)
+    }
+
+    /// Keeps as many whole words from the start of the passage as fit in
+    /// `chars` characters, including the ellipsis if the passage is cut.
+    /// Characters are counted as Unicode scalar values. A first word too
+    /// long for the budget is cut within the word, and a budget of 0, too
+    /// small for the ellipsis, keeps no text at all.
+    pub fn truncate_to_chars(&self, chars: usize) -> TruncatedPassage<'a> {
+        let tokens = self.tokens();
+        let full_len = tokens
+            .iter()
+            .map(|(_, word)| word.chars().count() + 1)
+            .sum::<usize>()
+            .saturating_sub(1);
+        if full_len <= chars {
+            return self.truncated(&tokens, tokens.len(), None);
+        }
+
+        let budget = chars.saturating_sub(1);
+        let mut used = 0;
+        let mut keep = 0;
+        for (_, word) in &tokens {
+            let len = word.chars().count() + usize::from(keep > 0);
+            if used + len > budget {
+                break;
+            }
+            used += len;
+            keep += 1;
+        }
+        let partial =
+            (keep == 0 && budget > 0).then(|| tokens[0].1.chars().take(budget).collect::<String>());
+        let mut cut = self.truncated(&tokens, keep, partial);
+        if chars == 0 {
+            cut.text.clear();
+        }
+        cut
+    }
+
+    /// The words of the passage, each with the position of its verse.
+    fn tokens(&self) -> Vec<(usize, &'a str)> {
+        self.verses()
+            .iter()
+            .enumerate()
+            .flat_map(|(i, verse)| verse.text().split_whitespace().map(move |w| (i, w)))
+            .collect()
+    }
+
+    /// Keeps the first `keep` tokens, or only `partial`, a piece of the
+    /// first token, when given.
+    fn truncated(
+        &self,
+        tokens: &[(usize, &'a str)],
+        keep: usize,
+        partial: Option<String>,
+    ) -> TruncatedPassage<'a> {
+        let (mut text, last, next) = match partial {
+            Some(piece) => (piece, Some(0), Some(0)),
+            None => (
+                tokens[..keep]
+                    .iter()
+                    .map(|(_, word)| *word)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                keep.checked_sub(1).map(|i| tokens[i].0),
+                tokens.get(keep).map(|(verse, _)| *verse),
+            ),
+        };
+        let verses = match last {
+            Some(last) => self.verses()[..=last].to_vec(),
+            None => Vec::new(),
+        };
+        let truncated = next.is_some();
+        if truncated {
+            // "love,…" reads badly; drop a dangling separator first.
+            let kept = text.trim_end_matches([',', ';', ':']).len();
+            text.truncate(kept);
+            text.push(ELLIPSIS);
+        }
+        TruncatedPassage {
+            cut: verses
+                .last()
+                .filter(|_| truncated)
+                .map(|verse| verse.reference()),
+            ends_mid_verse: last.is_some() && last == next,
+            truncated,
+            verses,
+            text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bible::Bible,
+        bible_books_enum::BibleBook,
+        book::Book,
+        chapter::Chapter,
+        reference::{Reference, ReferenceRange},
+        test_support::TestBible,
+        verse::Verse,
+    };
+
+    fn create_test_bible() -> Bible {
+        let verses = vec![
+            Verse::new(
+                BibleBook::John,
+                3,
+                16,
+                "For God so loved the world, that he gave".into(),
+            ),
+            Verse::new(BibleBook::John, 3, 17, "For God sent not his Son".into()),
+        ];
+        TestBible::new(vec![Book::new(
+            "jo".into(),
+            "John".into(),
+            vec![Chapter::new(verses, 3)],
+        )])
+        .build()
+    }
+
+    #[test]
+    fn test_truncate_to_words() {
+        let bible = create_test_bible();
+        let passage = bible
+            .passage(&ReferenceRange::new(
+                Reference::new(BibleBook::John, 3, 16),
+                Reference::new(BibleBook::John, 3, 17),
+            ))
+            .unwrap();
+
+        let cut = passage.truncate_to_words(6);
+        assert_eq!(cut.text(), "For God so loved the world…");
+        assert_eq!(
+            cut.cut_reference(),
+            Some(&Reference::new(BibleBook::John, 3, 16))
+        );
+        assert!(cut.ends_mid_verse());
+        assert_eq!(cut.verses().len(), 1);
+
+        let boundary = passage.truncate_to_words(9);
+        assert_eq!(boundary.text(), "For God so loved the world, that he gave…");
+        assert!(boundary.is_truncated() && !boundary.ends_mid_verse());
+
+        let whole = passage.truncate_to_words(100);
+        assert!(!whole.is_truncated());
+        assert_eq!(whole.text(), passage.text());
+        assert_eq!(whole.range(), Some(passage.range().clone()));
+
+        let nothing = passage.truncate_to_words(0);
+        assert_eq!(nothing.text(), "…");
+        assert!(nothing.is_truncated());
+        assert_eq!(nothing.range(), None);
+    }
+
+    #[test]
+    fn test_truncate_to_chars() {
+        let bible = create_test_bible();
+        let passage = bible
+            .passage(&ReferenceRange::single(Reference::new(
+                BibleBook::John,
+                3,
+                16,
+            )))
+            .unwrap();
+
+        let cut = passage.truncate_to_chars(20);
+        assert_eq!(cut.text(), "For God so loved…");
+        assert!(cut.text().chars().count() <= 20);
+        assert_eq!(
+            passage.truncate_to_chars(29).text(),
+            "For God so loved the world…"
+        );
+        assert_eq!(passage.truncate_to_chars(3).text(), "Fo…");
+        assert_eq!(passage.truncate_to_chars(1).text(), "…");
+        assert!(!passage.truncate_to_chars(40).is_truncated());
+
+        let empty = passage.truncate_to_chars(0);
+        assert_eq!(empty.text(), "");
+        assert!(empty.is_truncated());
+        assert_eq!(empty.range(), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! Consistent quotation marks across a translation.
+//!
+//! Source files often mix straight quotes (`"`, `'`) with typographic ones
+//! (`“”`, `‘’`), which looks careless in a published export. [`NormalizeQuotes`]
+//! rewrites verse text to one [`QuoteStyle`], either at load time as a
+//! [`TextProcessor`] or on a loaded Bible with [`Bible::normalize_quotes`].
+
+use crate::{bible::Bible, load_options::TextProcessor};
+
+/// How quotation marks and apostrophes are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum QuoteStyle {
+    /// ASCII `"` and `'`.
+    #[default]
+    Straight,
+    /// Curly `“ ”`, `‘ ’`, with `’` for apostrophes.
+    Typographic,
+}
+
+/// Rewrites quotation marks and apostrophes in one [`QuoteStyle`].
+///
+/// Straight quotes are curled by context: a quote at the start of the text
+/// or after a space, an opening bracket or dash, or another opening quote
+/// opens; any other closes. An apostrophe within or after a word becomes
+/// `’`, as does one before a digit ("’90s"). Elisions at the start of a
+/// word, such as "'tis", are curled as opening quotes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NormalizeQuotes {
+    style: QuoteStyle,
+}
+
+impl NormalizeQuotes {
+    /// Creates a processor writing quotes in `style`.
+    pub fn new(style: QuoteStyle) -> Self {
+        NormalizeQuotes { style }
+    }
+
+    /// Returns the style quotes are written in.
+    pub fn style(&self) -> QuoteStyle {
+        self.style
+    }
+}
+
+impl TextProcessor for NormalizeQuotes {
+    fn process(&self, text: &str) -> String {
+        match self.style {
+            QuoteStyle::Straight => text
+                .chars()
+                .map(|c| match c {
+                    '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{2032}' => '\'',
+                    '\u{201c}' | '\u{201d}' | '\u{201e}' | '\u{2033}' => '"',
+                    c => c,
+                })
+                .collect(),
+            QuoteStyle::Typographic => curl_quotes(text),
+        }
+    }
+}
+
+fn curl_quotes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + text.len() / 8);
+    let mut chars = text.chars().peekable();
+    let mut previous: Option<char> = None;
+    while let Some(c) = chars.next() {
+        let opens = previous.is_none_or(|p| {
+            p.is_whitespace() || "([{<\u{2014}\u{2013}\u{201c}\u{2018}".contains(p)
+        });
+        let curled = match c {
+            '"' if opens => '\u{201c}',
+            '"' => '\u{201d}',
+            '\'' if opens && !chars.peek().is_some_and(char::is_ascii_digit) => '\u{2018}',
+            '\'' => '\u{2019}',
+            c => c,
+        };
+        out.push(curled);
+        previous = Some(curled);
+    }
+    out
+}
+
+impl Bible {
+    /// Rewrites the quotation marks of every verse in `style`, returning the
+    /// number of verses changed.
+    ///
+    /// Each changed verse is updated as by [`Bible::set_verse_text`], so
+    /// supplied words are kept and the search index of its book is rebuilt
+    /// on the next search.
+    pub fn normalize_quotes(&mut self, style: QuoteStyle) -> usize {
+        let processor = NormalizeQuotes::new(style);
+        let changed: Vec<_> = self
+            .all_verses()
+            .filter_map(|verse| {
+                let source = verse.source_text();
+                let text = processor.process(&source);
+                (text != source)
+                    .then(|| (verse.book().clone(), verse.chapter(), verse.number(), text))
+            })
+            .collect();
+        changed
+            .into_iter()
+            .filter(|(book, chapter, verse, text)| {
+                self.set_verse_text(book.clone(), *chapter, *verse, text)
+                    .is_ok()
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bible_books_enum::BibleBook, book::Book, chapter::Chapter, test_support::TestBible,
+        verse::Verse,
+    };
+
+    #[test]
+    fn test_quote_styles() {
+        let straight = NormalizeQuotes::default();
+        let typographic = NormalizeQuotes::new(QuoteStyle::Typographic);
+        assert_eq!(
+            straight.process("\u{201c}the LORD\u{2019}s\u{201d}"),
+            "\"the LORD's\""
+        );
+        assert_eq!(
+            typographic.process(r#"He said, "Don't fear; 'tis the '90s" (and "so")."#),
+            "He said, “Don’t fear; ‘tis the ’90s” (and “so”)."
+        );
+        assert_eq!(
+            typographic.process("'Hear,' said he.\u{2014}\"Amen.\""),
+            "‘Hear,’ said he.—“Amen.”"
+        );
+        let mixed = "“Go,” he said, \"now.\"";
+        assert_eq!(
+            typographic.process(&straight.process(mixed)),
+            typographic.process(mixed)
+        );
+    }
+
+    #[test]
+    fn test_normalize_quotes_on_a_bible() {
+        let verses = vec![
+            Verse::new(BibleBook::John, 1, 1, "\"I am {the} way\"".to_string()),
+            Verse::new(BibleBook::John, 1, 2, "“Peace.”".to_string()),
+        ];
+        let book = Book::new("jo".into(), "John".into(), vec![Chapter::new(verses, 1)]);
+        let mut bible = TestBible::new(vec![book]).build();
+
+        assert_eq!(bible.normalize_quotes(QuoteStyle::Typographic), 1);
+        let verse = bible.get_verse(BibleBook::John, 1, 1).unwrap();
+        assert_eq!(verse.text(), "“I am the way”");
+        assert_eq!(verse.source_text(), "“I am {the} way”");
+        assert_eq!(bible.normalize_quotes(QuoteStyle::Straight), 2);
+        assert_eq!(bible.normalize_quotes(QuoteStyle::Straight), 0);
+    }
+}
@@ -0,0 +1,497 @@
+//! Reading USX, the XML form of USFM used by Paratext and Digital Bible
+//! Library bundles.
+//!
+//! Only the verse text is kept: notes, headings, titles and other material
+//! outside verses are skipped, and paragraph breaks become spaces. Words
+//! marked as added by translators (`<char style="add">`) become supplied
+//! words, as if written in `{}` in the JSON data.
+
+use std::{borrow::Cow, fmt};
+
+use crate::{
+    bible_books_enum::BibleBook,
+    book::Book,
+    book_id::BookId,
+    chapter::Chapter,
+    json_schema::{is_valid_number, MAX_NUMBER},
+    verse::Verse,
+};
+
+/// Paragraph styles whose text is not part of any verse.
+const NON_VERSE_PARAGRAPHS: &[&str] = &[
+    "ide", "h", "h1", "h2", "h3", "toc1", "toc2", "toc3", "toca1", "toca2", "toca3", "imt", "imt1",
+    "imt2", "is", "is1", "is2", "ip", "ipi", "im", "io", "io1", "io2", "iot", "ie", "mt", "mt1",
+    "mt2", "mt3", "mt4", "ms", "ms1", "ms2", "mr", "s", "s1", "s2", "s3", "s4", "sr", "r", "d",
+    "sp", "cl", "cp", "rem", "restore",
+];
+
+/// The error returned when a document is not well-formed USX.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsxError {
+    message: String,
+    offset: usize,
+}
+
+impl UsxError {
+    fn new(message: impl Into<String>, offset: usize) -> Self {
+        UsxError {
+            message: message.into(),
+            offset,
+        }
+    }
+
+    /// Returns the byte offset in the document where the problem was found.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for UsxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid USX at byte {}: {}", self.offset, self.message)
+    }
+}
+
+impl std::error::Error for UsxError {}
+
+impl Book {
+    /// Reads one book from a USX document.
+    ///
+    /// The book is identified by the USFM code of its `<book>` element and
+    /// titled by its `h` (running header) paragraph, falling back to `toc2`,
+    /// `toc1` and the English name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UsxError`] if the document is not well-formed, has no
+    /// `<book>` element, or numbers a chapter or verse outside 1 to 10,000.
+    pub fn from_usx(xml: &str) -> Result<Book, UsxError> {
+        let mut reader = UsxReader::default();
+        let mut events = XmlEvents::new(xml);
+        while let Some(event) = events.next() {
+            reader.handle(event?, events.offset())?;
+        }
+        reader.finish()
+    }
+}
+
+/// Builds a book from the events of a USX document.
+#[derive(Default)]
+struct UsxReader {
+    code: Option<String>,
+    titles: Vec<(String, String)>,
+    chapters: Vec<Chapter>,
+    chapter: usize,
+    verses: Vec<Verse>,
+    /// The first and last number and text so far of the open verse.
+    verse: Option<(usize, usize, String)>,
+    /// Open elements, with whether their text belongs to the verse.
+    open: Vec<(String, bool)>,
+    /// The style of the open non-verse paragraph, whose text is collected.
+    paragraph: Option<(String, String)>,
+}
+
+impl UsxReader {
+    /// Handles one event, which began at byte `offset`.
+    fn handle(&mut self, event: XmlEvent<'_>, offset: usize) -> Result<(), UsxError> {
+        match event {
+            XmlEvent::Start {
+                name,
+                attributes,
+                empty,
+            } => {
+                let attribute = |key: &str| {
+                    attributes
+                        .iter()
+                        .find(|(k, _)| *k == key)
+                        .map(|(_, v)| v.as_ref())
+                };
+                let style = attribute("style").unwrap_or_default();
+                let number = |text: Option<&str>| match text {
+                    Some(text) => checked_number(name, text, offset),
+                    None => Ok(None),
+                };
+                match name {
+                    "book" => self.code = attribute("code").map(str::to_string),
+                    "chapter" => {
+                        if let Some(number) = number(attribute("number"))? {
+                            self.end_chapter();
+                            self.chapter = number;
+                        }
+                    }
+                    "verse" => {
+                        if let Some(first) = number(attribute("number"))? {
+                            self.end_verse();
+                            let end = attribute("number").and_then(|n| Some(n.split_once('-')?.1));
+                            let last = number(end)?.unwrap_or(first);
+                            self.verse = Some((first, last, String::new()));
+                        } else if attribute("eid").is_some() {
+                            self.end_verse();
+                        }
+                    }
+                    "para" if NON_VERSE_PARAGRAPHS.contains(&style) => {
+                        self.paragraph = Some((style.to_string(), String::new()));
+                    }
+                    "char" if style == "add" => self.push_text("{"),
+                    _ => {}
+                }
+                if !empty {
+                    let skipped = matches!(name, "book" | "note" | "figure" | "sidebar")
+                        || (name == "para" && NON_VERSE_PARAGRAPHS.contains(&style));
+                    let in_verse = !skipped;
+                    let close = if name == "char" && style == "add" {
+                        "add"
+                    } else {
+                        name
+                    };
+                    self.open.push((close.to_string(), in_verse));
+                }
+            }
+            XmlEvent::End { .. } => {
+                if let Some((name, _)) = self.open.pop() {
+                    match name.as_str() {
+                        "add" => self.push_text("}"),
+                        "para" => {
+                            if let Some(paragraph) = self.paragraph.take() {
+                                self.titles.push(paragraph);
+                            }
+                            self.push_text(" ");
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            XmlEvent::Text(text) => {
+                if let Some((_, collected)) = &mut self.paragraph {
+                    collected.push_str(&text);
+                } else if self.open.iter().all(|(_, in_verse)| *in_verse) {
+                    self.push_text(&text);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if let Some((_, _, verse_text)) = &mut self.verse {
+            verse_text.push_str(text);
+        }
+    }
+
+    fn end_verse(&mut self) {
+        if let Some((number, last, text)) = self.verse.take() {
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            let text = text.replace("{ ", " {").replace(" }", "} ");
+            let book = self.book_id();
+            self.verses.push(
+                Verse::new(book, self.chapter, number, text.trim().to_string())
+                    .with_bridge_end(last),
+            );
+        }
+    }
+
+    fn end_chapter(&mut self) {
+        self.end_verse();
+        if !self.verses.is_empty() {
+            let verses = std::mem::take(&mut self.verses);
+            self.chapters.push(Chapter::new(verses, self.chapter));
+        }
+    }
+
+    fn book_id(&self) -> BookId {
+        let code = self.code.as_deref().unwrap_or_default();
+        match BibleBook::from_usfm_id(code) {
+            Some(book) => book.into(),
+            None => BookId::from_abbrev(code),
+        }
+    }
+
+    fn finish(mut self) -> Result<Book, UsxError> {
+        self.end_chapter();
+        if self.code.is_none() {
+            return Err(UsxError::new("missing <book> element", 0));
+        }
+        let id = self.book_id();
+        let title = ["h", "h1", "toc2", "toc1"]
+            .iter()
+            .find_map(|style| {
+                self.titles
+                    .iter()
+                    .find(|(s, text)| s == style && !text.trim().is_empty())
+            })
+            .map(|(_, text)| text.split_whitespace().collect::<Vec<_>>().join(" "))
+            .or_else(|| id.known().map(|book| book.full_name().to_string()))
+            .unwrap_or_else(|| id.as_str().to_string());
+        Ok(Book::new(id.as_str().to_string(), title, self.chapters))
+    }
+}
+
+/// Parses the number at the start of a verse or chapter number such as
+/// `"4"`, `"4-5"` (a verse bridge) or `"4a"`, or returns `None` if it does
+/// not start with a digit.
+///
+/// # Errors
+///
+/// Returns [`UsxError`] if the number is outside 1 to [`MAX_NUMBER`], the
+/// same bound as JSON input, so a stray huge number cannot make exports pad
+/// millions of empty verses.
+fn checked_number(element: &str, number: &str, offset: usize) -> Result<Option<usize>, UsxError> {
+    let end = number
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(number.len());
+    if end == 0 {
+        return Ok(None);
+    }
+    match number[..end].parse() {
+        Ok(n) if is_valid_number(n) => Ok(Some(n)),
+        _ => Err(UsxError::new(
+            format!("{element} number '{number}' is out of range; expected 1 to {MAX_NUMBER}"),
+            offset,
+        )),
+    }
+}
+
+/// A piece of an XML document.
+#[derive(Debug, PartialEq)]
+pub(crate) enum XmlEvent<'a> {
+    Start {
+        name: &'a str,
+        attributes: Vec<(&'a str, Cow<'a, str>)>,
+        empty: bool,
+    },
+    End {
+        name: &'a str,
+    },
+    Text(Cow<'a, str>),
+}
+
+/// A minimal XML scanner for the well-formed, namespace-free documents of
+/// USX and DBL metadata. Declarations, comments and processing instructions
+/// are skipped, and CDATA sections are read as text.
+pub(crate) struct XmlEvents<'a> {
+    xml: &'a str,
+    pos: usize,
+    start: usize,
+}
+
+impl<'a> XmlEvents<'a> {
+    pub(crate) fn new(xml: &'a str) -> Self {
+        XmlEvents {
+            xml: xml.strip_prefix('\u{feff}').unwrap_or(xml),
+            pos: 0,
+            start: 0,
+        }
+    }
+
+    /// Returns the byte offset at which the last event began.
+    pub(crate) fn offset(&self) -> usize {
+        self.start
+    }
+
+    fn tag(&mut self, start: usize) -> Result<XmlEvent<'a>, UsxError> {
+        let rest = &self.xml[start + 1..];
+        let end = rest
+            .find('>')
+            .ok_or_else(|| UsxError::new("unterminated tag", start))?;
+        self.pos = start + 1 + end + 1;
+        let inner = &rest[..end];
+        if let Some(name) = inner.strip_prefix('/') {
+            return Ok(XmlEvent::End { name: name.trim() });
+        }
+        let (inner, empty) = match inner.strip_suffix('/') {
+            Some(inner) => (inner, true),
+            None => (inner, false),
+        };
+        let name_end = inner
+            .find(|c: char| c.is_whitespace())
+            .unwrap_or(inner.len());
+        let name = &inner[..name_end];
+        if name.is_empty() {
+            return Err(UsxError::new("missing element name", start));
+        }
+        let mut attributes = Vec::new();
+        let mut rest = inner[name_end..].trim_start();
+        while !rest.is_empty() {
+            let (key, value) = rest
+                .split_once('=')
+                .ok_or_else(|| UsxError::new("malformed attribute", start))?;
+            let value = value.trim_start();
+            let quote = value
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '"' | '\''))
+                .ok_or_else(|| UsxError::new("unquoted attribute value", start))?;
+            let close = value[1..]
+                .find(quote)
+                .ok_or_else(|| UsxError::new("unterminated attribute value", start))?;
+            attributes.push((key.trim(), unescape(&value[1..1 + close])));
+            rest = value[close + 2..].trim_start();
+        }
+        Ok(XmlEvent::Start {
+            name,
+            attributes,
+            empty,
+        })
+    }
+}
+
+impl<'a> Iterator for XmlEvents<'a> {
+    type Item = Result<XmlEvent<'a>, UsxError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rest = &self.xml[self.pos..];
+            if rest.is_empty() {
+                return None;
+            }
+            let start = self.pos;
+            self.start = start;
+            if !rest.starts_with('<') {
+                let end = rest.find('<').unwrap_or(rest.len());
+                self.pos += end;
+                return Some(Ok(XmlEvent::Text(unescape(&rest[..end]))));
+            }
+            if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let Some(end) = cdata.find("]]>") else {
+                    return Some(Err(UsxError::new("unterminated CDATA section", start)));
+                };
+                self.pos += "<![CDATA[".len() + end + "]]>".len();
+                return Some(Ok(XmlEvent::Text(Cow::Borrowed(&cdata[..end]))));
+            }
+            let skipped = [("<?", "?>"), ("<!--", "-->"), ("<!", ">")]
+                .into_iter()
+                .find(|(open, _)| rest.starts_with(open));
+            match skipped {
+                Some((_, close)) => match rest.find(close) {
+                    Some(end) => self.pos += end + close.len(),
+                    None => return Some(Err(UsxError::new("unterminated markup", start))),
+                },
+                None => return Some(self.tag(start)),
+            }
+        }
+    }
+}
+
+/// Replaces the predefined XML entities and character references in `text`.
+fn unescape(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let decoded = entity.and_then(|(name, _)| match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => name.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+        match (decoded, entity) {
+            (Some(c), Some((_, end))) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verse::SpanKind;
+
+    const RUTH: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<usx version="3.0">
+  <book code="RUT" style="id">Ruth - Sample</book>
+  <para style="h">Ruth</para>
+  <para style="mt1">The Book of Ruth</para>
+  <chapter number="1" style="c" sid="RUT 1" />
+  <para style="s1">Elimelech&apos;s family</para>
+  <para style="p">
+    <verse number="1" style="v" sid="RUT 1:1" />Now it came to pass in the days when the judges ruled,<note caller="+" style="f"><char style="fr">1:1 </char><char style="ft">Heb. judged</char></note>
+    that there was a famine in the land.<verse eid="RUT 1:1" />
+    <verse number="2-3" style="v" sid="RUT 1:2-3" />And the name of the man <char style="add">was</char> Elimelech &amp; Naomi.<verse eid="RUT 1:2-3" /></para>
+  <chapter eid="RUT 1" />
+  <chapter number="2" style="c" sid="RUT 2" />
+  <para style="q1"><verse number="1" style="v" />And Naomi had a kinsman</para>
+  <para style="q2">of her husband&#x2019;s.</para>
+</usx>"#;
+
+    #[test]
+    fn test_book_from_usx() {
+        let book = Book::from_usx(RUTH).unwrap();
+        assert_eq!(book.id(), &BookId::Known(BibleBook::Ruth));
+        assert_eq!(book.title(), "Ruth");
+        assert_eq!(book.chapters().len(), 2);
+        let verses = book.chapters()[0].get_verses();
+        assert_eq!(
+            verses[0].text(),
+            "Now it came to pass in the days when the judges ruled, that there was a famine in the land."
+        );
+        assert_eq!(verses[1].number(), 2);
+        assert_eq!(verses[1].last_number(), 3);
+        assert_eq!(book.get_verse(1, 3).unwrap(), &verses[1]);
+        assert_eq!(
+            verses[1].text(),
+            "And the name of the man was Elimelech & Naomi."
+        );
+        let supplied: Vec<&str> = verses[1]
+            .spans()
+            .iter()
+            .filter(|span| span.kind == SpanKind::SuppliedWords)
+            .map(|span| span.text)
+            .collect();
+        assert_eq!(supplied, ["was"]);
+        assert_eq!(
+            book.chapters()[1].get_verses()[0].text(),
+            "And Naomi had a kinsman of her husband\u{2019}s."
+        );
+    }
+
+    #[test]
+    fn test_malformed_usx() {
+        assert!(Book::from_usx("<usx><para style=\"p\">text</para></usx>").is_err());
+        let err = Book::from_usx("<usx><book code=RUT/></usx>").unwrap_err();
+        assert_eq!(err.offset(), 5);
+        assert!(Book::from_usx("<usx><book code=\"RUT\"").is_err());
+    }
+
+    #[test]
+    fn test_out_of_range_numbers() {
+        let usx = |chapter: &str, verse: &str| {
+            format!(
+                "<usx><book code=\"RUT\"/><chapter number=\"{chapter}\"/>\
+                 <para style=\"p\"><verse number=\"{verse}\"/>Text</para></usx>"
+            )
+        };
+        assert!(Book::from_usx(&usx("1", "10000")).is_ok());
+
+        let err = Book::from_usx(&usx("1", "30000000")).unwrap_err();
+        assert_eq!(err.offset(), 60);
+        assert!(err
+            .to_string()
+            .contains("verse number '30000000' is out of range"));
+        assert!(Book::from_usx(&usx("1", "4294967296")).is_err());
+        assert!(Book::from_usx(&usx("1", "99999999999999999999999")).is_err());
+        assert!(Book::from_usx(&usx("1", "0")).is_err());
+        assert!(Book::from_usx(&usx("1", "2-30000000")).is_err());
+        assert!(Book::from_usx(&usx("0", "1")).is_err());
+    }
+}
@@ -1,16 +1,38 @@
-use std::fmt;
+use std::{fmt, ops::Range};
 
-use crate::bible_books_enum::BibleBook;
+use crate::{book_id::BookId, reference::Reference};
+
+/// The kind of text carried by a [`TextSpan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanKind {
+    /// Ordinary verse text.
+    Plain,
+    /// Words supplied by the translators, marked with `{}` in the source data
+    /// and traditionally printed in italics.
+    SuppliedWords,
+}
+
+/// A contiguous run of verse text sharing a single [`SpanKind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextSpan<'a> {
+    pub kind: SpanKind,
+    pub text: &'a str,
+}
 
 /// Represents a single verse from the Bible.
 ///
 /// A verse contains the text content and its reference information within a chapter.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Verse {
-    book: BibleBook,
+    book: BookId,
     chapter_number: usize,
     verse_text: String,
     verse_number: usize,
+    /// The last verse number combined into this one; equal to `verse_number`
+    /// unless the verse is a bridge.
+    last_verse_number: usize,
+    /// Byte ranges of translator-supplied words within `verse_text`.
+    supplied_words: Vec<Range<usize>>,
 }
 
 impl Verse {
@@ -23,22 +45,33 @@ impl Verse {
     /// * `verse_number` - The verse number within its chapter
     /// * `verse_text` - The text content of the verse
     pub fn new(
-        book: BibleBook,
+        book: impl Into<BookId>,
         chapter_number: usize,
         verse_number: usize,
         verse_text: String,
     ) -> Self {
+        let (verse_text, supplied_words) = parse_verse_text(&verse_text);
         Verse {
-            book,
+            book: book.into(),
             chapter_number,
-            verse_text: sanitize_verse_text(verse_text),
+            verse_text,
             verse_number,
+            last_verse_number: verse_number,
+            supplied_words,
         }
     }
 
+    /// Marks this verse as a bridge: one block of text translating verses
+    /// `number()` through `last`, as in "Verses 3–4". A `last` at or before
+    /// the verse number leaves the verse unbridged.
+    pub fn with_bridge_end(mut self, last: usize) -> Self {
+        self.last_verse_number = last.max(self.verse_number);
+        self
+    }
+
     /// Returns the book this verse belongs to.
-    pub fn book(&self) -> BibleBook {
-        self.book
+    pub fn book(&self) -> &BookId {
+        &self.book
     }
 
     /// Returns the chapter number within the book.
@@ -51,37 +84,212 @@ impl Verse {
         &self.verse_text
     }
 
+    /// Replaces the verse text. As in [`Verse::new`], words wrapped in `{}`
+    /// are marked as supplied words.
+    pub fn set_text(&mut self, verse_text: &str) {
+        (self.verse_text, self.supplied_words) = parse_verse_text(verse_text);
+    }
+
     /// Returns the verse number within its chapter.
     pub fn number(&self) -> usize {
         self.verse_number
     }
+
+    /// Returns the last verse number this verse covers, which differs from
+    /// [`Verse::number`] only for a bridge.
+    pub fn last_number(&self) -> usize {
+        self.last_verse_number
+    }
+
+    /// Returns `true` if this verse combines several verse numbers.
+    pub fn is_bridge(&self) -> bool {
+        self.last_verse_number > self.verse_number
+    }
+
+    /// Returns `true` if this verse carries `verse_number`, either as its own
+    /// number or as part of a bridge.
+    pub fn covers(&self, verse_number: usize) -> bool {
+        (self.verse_number..=self.last_verse_number).contains(&verse_number)
+    }
+
+    /// Returns the verse number as printed: `"3"`, or `"3-4"` for a bridge.
+    pub fn number_label(&self) -> String {
+        if self.is_bridge() {
+            format!("{}-{}", self.verse_number, self.last_verse_number)
+        } else {
+            self.verse_number.to_string()
+        }
+    }
+
+    /// Returns the location of this verse.
+    pub fn reference(&self) -> Reference {
+        Reference::new(&self.book, self.chapter_number, self.verse_number)
+    }
+
+    /// Returns the verse text with supplied words wrapped in `{}` again, as in
+    /// the source data.
+    pub(crate) fn source_text(&self) -> String {
+        self.spans()
+            .iter()
+            .map(|span| match span.kind {
+                SpanKind::Plain => span.text.to_string(),
+                SpanKind::SuppliedWords => format!("{{{}}}", span.text),
+            })
+            .collect()
+    }
+
+    /// Iterates over the words of the verse text, without punctuation.
+    ///
+    /// A word is a run of Unicode letters and digits, together with any
+    /// combining accents or points; an apostrophe between two letters or
+    /// digits stays inside the word (`"LORD'S"`, `"don't"`).
+    /// Search, corpus statistics, and similarity detection use the same
+    /// segmentation.
+    pub fn words(&self) -> impl Iterator<Item = &str> + '_ {
+        split_words(&self.verse_text)
+    }
+
+    /// Returns the number of [`words`](Verse::words) in the verse.
+    pub fn word_count(&self) -> usize {
+        self.words().count()
+    }
+
+    /// Splits the verse text into typed spans.
+    ///
+    /// Words enclosed in `{}` in the source data are reported as
+    /// [`SpanKind::SuppliedWords`]; everything else is [`SpanKind::Plain`].
+    /// Concatenating the span texts yields [`Verse::text`].
+    pub fn spans(&self) -> Vec<TextSpan<'_>> {
+        let mut spans = Vec::with_capacity(self.supplied_words.len() * 2 + 1);
+        let mut pos = 0;
+
+        for range in &self.supplied_words {
+            if range.start > pos {
+                spans.push(TextSpan {
+                    kind: SpanKind::Plain,
+                    text: &self.verse_text[pos..range.start],
+                });
+            }
+            spans.push(TextSpan {
+                kind: SpanKind::SuppliedWords,
+                text: &self.verse_text[range.clone()],
+            });
+            pos = range.end;
+        }
+
+        if pos < self.verse_text.len() {
+            spans.push(TextSpan {
+                kind: SpanKind::Plain,
+                text: &self.verse_text[pos..],
+            });
+        }
+
+        spans
+    }
+}
+
+/// Removes `{}` markers from verse text, recording the byte ranges they enclosed.
+///
+/// Unbalanced markers are dropped without producing a span.
+fn parse_verse_text(verse_text: &str) -> (String, Vec<Range<usize>>) {
+    let mut text = String::with_capacity(verse_text.len());
+    let mut supplied = Vec::new();
+    let mut open: Option<usize> = None;
+
+    for c in verse_text.chars() {
+        match c {
+            '{' => {
+                if open.is_none() {
+                    open = Some(text.len());
+                }
+            }
+            '}' => {
+                if let Some(start) = open.take() {
+                    if start < text.len() {
+                        supplied.push(start..text.len());
+                    }
+                }
+            }
+            _ => text.push(c),
+        }
+    }
+
+    (text, supplied)
 }
 
-fn sanitize_verse_text(verse_text: String) -> String {
-    verse_text
-        .chars()
-        .filter(|c| *c != '{' && *c != '}')
-        .collect()
+/// Splits text into words as described on [`Verse::words`].
+pub(crate) fn split_words(text: &str) -> impl Iterator<Item = &str> + '_ {
+    word_ranges(text).map(move |range| &text[range])
+}
+
+/// Returns the byte ranges of the words returned by [`split_words`].
+pub(crate) fn word_ranges(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut chars = text.char_indices().peekable();
+    std::iter::from_fn(move || {
+        let (start, _) = chars.find(|(_, c)| c.is_alphanumeric())?;
+        let mut end = text.len();
+        while let Some(&(i, c)) = chars.peek() {
+            let joins_word = c.is_alphanumeric()
+                || is_combining_mark(c)
+                || (matches!(c, '\'' | '\u{2019}')
+                    && text[i + c.len_utf8()..]
+                        .chars()
+                        .next()
+                        .is_some_and(char::is_alphanumeric));
+            if !joins_word {
+                end = i;
+                break;
+            }
+            chars.next();
+        }
+        Some(start..end)
+    })
+}
+
+/// Returns `true` for combining accents and points, such as U+0301 or Hebrew
+/// niqqud, which belong to the word they follow.
+pub(crate) fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{0483}'..='\u{0489}'
+            | '\u{0591}'..='\u{05BD}'
+            | '\u{05BF}'
+            | '\u{05C1}'..='\u{05C2}'
+            | '\u{05C4}'..='\u{05C5}'
+            | '\u{05C7}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
 }
 
 impl fmt::Display for Verse {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}: {}", self.verse_number, self.verse_text)
+        write!(f, "{}: {}", self.number_label(), self.verse_text)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bible_books_enum::BibleBook;
 
     #[test]
     fn test_new_and_accessors() {
         let verse = Verse::new(BibleBook::Genesis, 1, 1, "Test".to_string());
-        assert_eq!(verse.book(), BibleBook::Genesis);
+        assert_eq!(*verse.book(), BibleBook::Genesis);
         assert_eq!(verse.chapter(), 1);
         assert_eq!(verse.text(), "Test");
         assert_eq!(verse.number(), 1);
         assert_eq!(format!("{}", verse), "1: Test");
+        assert!(!verse.is_bridge());
+
+        let bridge = verse.with_bridge_end(3);
+        assert!(bridge.is_bridge() && bridge.covers(2) && !bridge.covers(4));
+        assert_eq!(format!("{}", bridge), "1-3: Test");
+        assert!(!bridge.with_bridge_end(0).is_bridge());
     }
 
     #[test]
@@ -90,6 +298,52 @@ mod tests {
         assert_eq!(verse.text(), "In the beginning");
     }
 
+    #[test]
+    fn test_spans_mark_supplied_words() {
+        let verse = Verse::new(
+            BibleBook::Genesis,
+            1,
+            2,
+            "darkness {was} upon the face of the deep".to_string(),
+        );
+        let spans = verse.spans();
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[1].kind, SpanKind::SuppliedWords);
+        assert_eq!(spans[1].text, "was");
+        let joined: String = spans.iter().map(|s| s.text).collect();
+        assert_eq!(joined, verse.text());
+
+        let unbalanced = Verse::new(BibleBook::Genesis, 1, 1, "a} b {c".to_string());
+        assert_eq!(unbalanced.text(), "a b c");
+        assert!(unbalanced.spans().iter().all(|s| s.kind == SpanKind::Plain));
+    }
+
+    #[test]
+    fn test_words() {
+        let verse = Verse::new(
+            BibleBook::Psalms,
+            1,
+            1,
+            "The LORD'S portion, O Israel; Moses' ῥῆμα 12".to_string(),
+        );
+        let words: Vec<&str> = verse.words().collect();
+        assert_eq!(
+            words,
+            [
+                "The",
+                "LORD'S",
+                "portion",
+                "O",
+                "Israel",
+                "Moses",
+                "ῥῆμα",
+                "12"
+            ]
+        );
+        assert_eq!(verse.word_count(), 8);
+        assert_eq!(split_words(" -- ").count(), 0);
+    }
+
     #[test]
     fn test_clone_independence() {
         let original = Verse::new(BibleBook::Genesis, 1, 42, "Clone me".to_string());
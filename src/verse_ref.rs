@@ -0,0 +1,161 @@
+use std::fmt;
+
+use crate::{book::Book, book_id::BookId, chapter::Chapter, verse::Verse};
+
+/// A borrowed handle to a verse together with its book and translation.
+///
+/// Returned by [`Bible::verse_ref`](crate::Bible::verse_ref). Displays as
+/// `"Genesis 1:1 (KJV)"`.
+#[derive(Debug, Clone, Copy)]
+pub struct VerseRef<'a> {
+    book: &'a Book,
+    verse: &'a Verse,
+    translation: &'a str,
+}
+
+impl<'a> VerseRef<'a> {
+    pub(crate) fn new(book: &'a Book, verse: &'a Verse, translation: &'a str) -> Self {
+        VerseRef {
+            book,
+            verse,
+            translation,
+        }
+    }
+
+    /// Returns the identifier of the verse's book.
+    pub fn book(&self) -> &'a BookId {
+        self.verse.book()
+    }
+
+    /// Returns the title of the verse's book as given by the translation.
+    pub fn book_title(&self) -> &'a str {
+        self.book.title()
+    }
+
+    /// Returns the chapter number.
+    pub fn chapter(&self) -> usize {
+        self.verse.chapter()
+    }
+
+    /// Returns the verse number within its chapter.
+    pub fn number(&self) -> usize {
+        self.verse.number()
+    }
+
+    /// Returns the verse text.
+    pub fn text(&self) -> &'a str {
+        self.verse.text()
+    }
+
+    /// Returns the id of the translation this verse was taken from.
+    pub fn translation(&self) -> &'a str {
+        self.translation
+    }
+
+    /// Returns the underlying verse.
+    pub fn verse(&self) -> &'a Verse {
+        self.verse
+    }
+}
+
+impl fmt::Display for VerseRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}:{} ({})",
+            self.book.title(),
+            self.chapter(),
+            self.number(),
+            self.translation.to_uppercase()
+        )
+    }
+}
+
+/// A borrowed handle to a chapter together with its book and translation.
+///
+/// Returned by [`Bible::chapter_ref`](crate::Bible::chapter_ref). Displays as
+/// `"Genesis 1 (KJV)"`.
+#[derive(Debug, Clone, Copy)]
+pub struct ChapterRef<'a> {
+    book: &'a Book,
+    chapter: &'a Chapter,
+    translation: &'a str,
+}
+
+impl<'a> ChapterRef<'a> {
+    pub(crate) fn new(book: &'a Book, chapter: &'a Chapter, translation: &'a str) -> Self {
+        ChapterRef {
+            book,
+            chapter,
+            translation,
+        }
+    }
+
+    /// Returns the identifier of the chapter's book.
+    pub fn book(&self) -> &'a BookId {
+        self.book.id()
+    }
+
+    /// Returns the title of the chapter's book as given by the translation.
+    pub fn book_title(&self) -> &'a str {
+        self.book.title()
+    }
+
+    /// Returns the chapter number.
+    pub fn number(&self) -> usize {
+        self.chapter.number()
+    }
+
+    /// Returns the id of the translation this chapter was taken from.
+    pub fn translation(&self) -> &'a str {
+        self.translation
+    }
+
+    /// Returns the underlying chapter.
+    pub fn chapter(&self) -> &'a Chapter {
+        self.chapter
+    }
+
+    /// Returns handles to every verse in the chapter.
+    pub fn verses(&self) -> impl Iterator<Item = VerseRef<'a>> + 'a {
+        let (book, translation) = (self.book, self.translation);
+        self.chapter
+            .get_verses()
+            .iter()
+            .map(move |verse| VerseRef::new(book, verse, translation))
+    }
+}
+
+impl fmt::Display for ChapterRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} ({})",
+            self.book.title(),
+            self.number(),
+            self.translation.to_uppercase()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    #[test]
+    fn test_display() {
+        let verse = Verse::new(BibleBook::Genesis, 1, 1, "In the beginning".into());
+        let chapter = Chapter::new(vec![verse], 1);
+        let book = Book::new("gn".into(), "Genesis".into(), vec![chapter]);
+        let chapter = &book.chapters()[0];
+
+        let chapter_ref = ChapterRef::new(&book, chapter, "kjv");
+        assert_eq!(chapter_ref.to_string(), "Genesis 1 (KJV)");
+
+        let verse_ref = chapter_ref.verses().next().unwrap();
+        assert_eq!(verse_ref.to_string(), "Genesis 1:1 (KJV)");
+        assert_eq!(verse_ref.text(), "In the beginning");
+        assert_eq!(*verse_ref.book(), BibleBook::Genesis);
+    }
+}
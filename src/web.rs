@@ -0,0 +1,186 @@
+//! Helpers for serving scripture from Rust web frameworks.
+//!
+//! [`ReferenceParam`] deserializes from a path segment, so axum's
+//! `Path<ReferenceParam>` and actix-web's `web::Path<ReferenceParam>` accept
+//! `/verses/jn/3/16`, `/verses/John+3:16` or `/verses/John%203:16` on a
+//! catch-all route. [`VerseResponse`] and [`PassageResponse`] serialize to
+//! the JSON returned by the `server` feature's API.
+
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use crate::{
+    bible::{BibleError, ALT_ABBREVS},
+    book_id::BookId,
+    json_schema::resolve_book_key,
+    passage::Passage,
+    reference::Reference,
+    verse::Verse,
+};
+
+/// A verse reference taken from a URL path.
+///
+/// Books may be named by English name or any common abbreviation, with the
+/// chapter and verse given as `3:16` or as further path segments (`jn/3/16`).
+/// `+` stands for a space. Names that match no known book become custom
+/// book ids, to be resolved against a loaded Bible.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReferenceParam(Reference);
+
+impl ReferenceParam {
+    /// Returns the parsed reference.
+    pub fn reference(&self) -> &Reference {
+        &self.0
+    }
+
+    /// Returns the parsed reference, consuming the parameter.
+    pub fn into_inner(self) -> Reference {
+        self.0
+    }
+}
+
+impl From<ReferenceParam> for Reference {
+    fn from(param: ReferenceParam) -> Self {
+        param.0
+    }
+}
+
+impl FromStr for ReferenceParam {
+    type Err = BibleError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let text = path_reference(input);
+        let invalid = || BibleError::InvalidReference {
+            input: input.to_string(),
+        };
+        let (book, location) = text.trim().rsplit_once(' ').ok_or_else(invalid)?;
+        let (chapter, verse) = location.split_once(':').ok_or_else(invalid)?;
+        let (Ok(chapter), Ok(verse)) = (chapter.parse::<usize>(), verse.parse::<usize>()) else {
+            return Err(invalid());
+        };
+        if book.trim().is_empty() || chapter == 0 || verse == 0 {
+            return Err(invalid());
+        }
+        // Common alternatives come first, as in reference parsing, so "jn"
+        // is John rather than the compact abbreviation of Jonah.
+        let book = match ALT_ABBREVS.get(book.trim().to_lowercase().as_str()) {
+            Some(&known) => BookId::Known(known),
+            None => BookId::from_abbrev(&resolve_book_key(book).0),
+        };
+        Ok(ReferenceParam(Reference::new(book, chapter, verse)))
+    }
+}
+
+impl<'de> Deserialize<'de> for ReferenceParam {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(de::Error::custom)
+    }
+}
+
+impl fmt::Display for ReferenceParam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A verse as a JSON response body:
+/// `{"reference":"John 3:16","book":"jo","chapter":3,"verse":16,"text":"..."}`.
+///
+/// A verse bridge also carries `"last_verse"`, the last verse it combines.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VerseResponse<'a> {
+    pub reference: String,
+    pub book: &'a str,
+    pub chapter: usize,
+    pub verse: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_verse: Option<usize>,
+    pub text: &'a str,
+}
+
+impl<'a> From<&'a Verse> for VerseResponse<'a> {
+    fn from(verse: &'a Verse) -> Self {
+        VerseResponse {
+            reference: verse.reference().to_string(),
+            book: verse.book().as_str(),
+            chapter: verse.chapter(),
+            verse: verse.number(),
+            last_verse: verse.is_bridge().then(|| verse.last_number()),
+            text: verse.text().trim(),
+        }
+    }
+}
+
+/// A passage as a JSON response body:
+/// `{"reference":"John 3:16-17","verses":[...]}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PassageResponse<'a> {
+    pub reference: String,
+    pub verses: Vec<VerseResponse<'a>>,
+}
+
+impl<'a> From<&Passage<'a>> for PassageResponse<'a> {
+    fn from(passage: &Passage<'a>) -> Self {
+        PassageResponse {
+            reference: passage.range().to_string(),
+            verses: passage
+                .verses()
+                .iter()
+                .map(|verse| VerseResponse::from(*verse))
+                .collect(),
+        }
+    }
+}
+
+/// Rewrites a reference from a URL path into the usual form: `+` becomes a
+/// space and `jn/3/16` becomes `jn 3:16`.
+pub(crate) fn path_reference(input: &str) -> String {
+    let text = input.replace('+', " ");
+    let segments: Vec<&str> = text.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        [book, chapter, verse] => format!("{book} {chapter}:{verse}"),
+        _ => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_books_enum::BibleBook;
+
+    #[test]
+    fn test_reference_param() {
+        let john = Reference::new(BibleBook::John, 3, 16);
+        let first_john = Reference::new(BibleBook::FirstJohn, 1, 1);
+        for (input, expected) in [
+            ("jn/3/16", &john),
+            ("John+3:16", &john),
+            ("John 3:16", &john),
+            ("/jhn/3/16/", &john),
+            ("1+Jn/1/1", &first_john),
+        ] {
+            let param: ReferenceParam = input.parse().unwrap();
+            assert_eq!(param.reference(), expected, "{input}");
+        }
+        for input in ["", "John", "John 3", "jn/3", "John 0:1", "John 3:x"] {
+            assert!(input.parse::<ReferenceParam>().is_err(), "{input}");
+        }
+
+        let json = String::from("\"jn/3/16\"");
+        let mut bytes = json.into_bytes();
+        let param: ReferenceParam = simd_json::serde::from_slice(&mut bytes).unwrap();
+        assert_eq!(Reference::from(param), john);
+    }
+
+    #[test]
+    fn test_verse_response() {
+        let verse = Verse::new(BibleBook::John, 11, 35, "Jesus wept. ".into());
+        let json = simd_json::serde::to_string(&VerseResponse::from(&verse)).unwrap();
+        assert_eq!(
+            json,
+            "{\"reference\":\"John 11:35\",\"book\":\"jo\",\"chapter\":11,\"verse\":35,\"text\":\"Jesus wept.\"}"
+        );
+    }
+}
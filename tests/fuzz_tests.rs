@@ -0,0 +1,219 @@
+//! Property tests for the parsers: arbitrary and mangled input must produce
+//! a result or an error, never a panic.
+//!
+//! Inputs come from a seeded generator, so failures reproduce exactly. Set
+//! `BIBLE_IO_FUZZ_ITERATIONS` to run longer.
+
+use bible_io::{Bible, Book, JsonSchema, ReferenceParsing, SearchIndex};
+
+/// A xorshift generator; deterministic and dependency-free.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// A string of up to `max_len` characters drawn from `alphabet`.
+    fn string(&mut self, alphabet: &[char], max_len: usize) -> String {
+        let len = self.below(max_len + 1);
+        (0..len).map(|_| *self.pick(alphabet)).collect()
+    }
+
+    /// Applies a few random byte-level edits: flips, deletions, duplicated
+    /// slices and truncation.
+    fn mangle(&mut self, input: &[u8]) -> Vec<u8> {
+        let mut bytes = input.to_vec();
+        for _ in 0..=self.below(4) {
+            if bytes.is_empty() {
+                break;
+            }
+            let at = self.below(bytes.len());
+            match self.below(4) {
+                0 => bytes[at] = self.next() as u8,
+                1 => {
+                    bytes.remove(at);
+                }
+                2 => {
+                    let end = (at + self.below(16)).min(bytes.len());
+                    let copy = bytes[at..end].to_vec();
+                    let to = self.below(bytes.len());
+                    bytes.splice(to..to, copy);
+                }
+                _ => bytes.truncate(at),
+            }
+        }
+        bytes
+    }
+}
+
+fn iterations() -> usize {
+    std::env::var("BIBLE_IO_FUZZ_ITERATIONS")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(2_000)
+}
+
+const NATIVE_JSON: &str = r#"{"id": "t", "name": "Test", "books": {
+    "gn": {"name": "Genesis", "chapters": [["In the {beginning}", "And the earth"], ["Thus"]]},
+    "so": {"name": "Song of Solomon", "chapters": [["The song of songs"]]},
+    "jo": {"name": "John", "chapters": [["In the beginning was the Word"]]},
+    "1jo": {"name": "1 John", "chapters": [["That which was from the beginning"]]}
+}}"#;
+
+const LAYOUT_SAMPLES: [&str; 4] = [
+    NATIVE_JSON,
+    r#"[{"abbrev": "gn", "chapters": [["In the beginning"]]}]"#,
+    r#"{"Genesis": {"1": {"1": "In the beginning", "2": "And the earth"}}}"#,
+    r#"{"verses": [{"book_name": "Genesis", "book": 1, "chapter": 1, "verse": 1, "text": "In"}]}"#,
+];
+
+const USX: &str = r#"<usx version="3.0"><book code="RUT" style="id">Ruth</book>
+<para style="h">Ruth</para><chapter number="1" style="c"/>
+<para style="p"><verse number="1" style="v"/>In the days <char style="add">when</char> the judges
+<note caller="+" style="f">a note</note> ruled<![CDATA[ & more]]>&amp; so on</para></usx>"#;
+
+#[test]
+fn test_reference_parser_never_panics() {
+    let mut bible = Bible::from_json_bytes(NATIVE_JSON.as_bytes(), JsonSchema::Native)
+        .expect("Failed to load test Bible");
+    let alphabet: Vec<char> = "GgenJjohnSsong of1 23:.,-–—;ivxé\u{301}\u{0}\t "
+        .chars()
+        .collect();
+    let mut rng = Rng(0x5eed_0001);
+
+    for parsing in [ReferenceParsing::Strict, ReferenceParsing::Tolerant] {
+        bible.set_reference_parsing(parsing);
+        for _ in 0..iterations() {
+            let input = rng.string(&alphabet, 24);
+            let _ = bible.parse_reference(&input);
+            let _ = bible.parse_range(&input);
+            let _ = bible.get_verse_by_reference(&input);
+        }
+    }
+}
+
+#[test]
+fn test_reference_display_round_trips() {
+    let bible = Bible::from_json_bytes(NATIVE_JSON.as_bytes(), JsonSchema::Native)
+        .expect("Failed to load test Bible");
+    let mut rng = Rng(0x5eed_0002);
+    let books = [
+        "Genesis",
+        "Gen",
+        "Song of Solomon",
+        "John",
+        "Jn",
+        "1 John",
+        "1jn",
+    ];
+
+    for _ in 0..iterations() {
+        let book = rng.pick(&books);
+        let (chapter, verse) = (rng.below(200) + 1, rng.below(200) + 1);
+        let input = format!("{book} {chapter}:{verse}");
+        let reference = bible
+            .parse_reference(&input)
+            .unwrap_or_else(|e| panic!("Failed to parse {input:?}: {e}"));
+        assert_eq!(
+            (reference.chapter(), reference.verse()),
+            (chapter, verse),
+            "{input}"
+        );
+        assert_eq!(
+            bible.parse_reference(&reference.to_string()).unwrap(),
+            reference
+        );
+    }
+}
+
+#[test]
+fn test_json_loader_never_panics() {
+    let mut rng = Rng(0x5eed_0003);
+    let schemas = [
+        JsonSchema::Auto,
+        JsonSchema::Native,
+        JsonSchema::BookArray,
+        JsonSchema::NestedMap,
+        JsonSchema::VerseArray,
+    ];
+
+    for sample in LAYOUT_SAMPLES {
+        assert!(Bible::from_json_bytes(sample.as_bytes(), JsonSchema::Auto).is_ok());
+    }
+    for _ in 0..iterations() {
+        let sample = *rng.pick(&LAYOUT_SAMPLES);
+        let input = rng.mangle(sample.as_bytes());
+        let schema = *rng.pick(&schemas);
+        if let Ok(bible) = Bible::from_json_bytes(&input, schema) {
+            // Whatever loaded must be usable.
+            let _ = bible.to_json();
+            for book in bible.books() {
+                let _ = bible.get_verse(book.id().clone(), 1, 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tokenizer_properties() {
+    let alphabet: Vec<char> = "aZé\u{301}'’-0 9.,;Ααωשָׁ\u{5b8}İßﬁ\t\n{}".chars().collect();
+    let mut rng = Rng(0x5eed_0004);
+
+    for _ in 0..iterations() {
+        let text = rng.string(&alphabet, 40);
+        let tokens = SearchIndex::tokenize(&text);
+        for token in &tokens {
+            assert!(!token.is_empty(), "empty token from {text:?}");
+            assert_eq!(&token.to_lowercase(), token, "from {text:?}");
+        }
+        // Tokens are already normalized, so tokenizing them again is a no-op.
+        assert_eq!(
+            SearchIndex::tokenize(&tokens.join(" ")),
+            tokens,
+            "from {text:?}"
+        );
+    }
+}
+
+#[test]
+fn test_usx_reader_never_panics() {
+    let mut rng = Rng(0x5eed_0005);
+    assert!(Book::from_usx(USX).is_ok());
+    for _ in 0..iterations() {
+        let input = rng.mangle(USX.as_bytes());
+        let _ = Book::from_usx(&String::from_utf8_lossy(&input));
+    }
+}
+
+#[test]
+fn test_out_of_range_numbers_are_errors() {
+    let bible = Bible::from_json_bytes(NATIVE_JSON.as_bytes(), JsonSchema::Native)
+        .expect("Failed to load test Bible");
+    for input in ["Gen 0:0", "Gen 1:0", "Gen 0:1", "Gen 0"] {
+        assert!(bible.parse_reference(input).is_err(), "{input}");
+    }
+
+    // Gaps in numbered rows are padded, so absurd numbers must be rejected
+    // rather than allocated.
+    for (chapter, verse) in [(4_000_000_000_usize, 1), (1, 99_999_999), (0, 1)] {
+        let json = format!(
+            r#"[{{"book": "Genesis", "chapter": {chapter}, "verse": {verse}, "text": "x"}}]"#
+        );
+        assert!(
+            Bible::from_json_bytes(json.as_bytes(), JsonSchema::VerseArray).is_err(),
+            "{chapter}:{verse}"
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use bible_io::{bible_books_enum::ParseBibleBookError, Bible, BibleBook};
+use bible_io::{
+    bible_books_enum::ParseBibleBookError, Bible, BibleBook, Reference, ReferenceRange,
+    TextIssueKind,
+};
 use std::str::FromStr;
 
 mod common;
@@ -60,6 +63,12 @@ fn test_bible_creation_with_real_data() {
         assert_eq!(book.abbrev(), "gn");
         assert_eq!(book.title(), "Genesis");
     }
+
+    // Books keep the order of the data file
+    let books = bible.books();
+    assert_eq!(books.first().map(|b| b.abbrev()), Some("gn"));
+    assert_eq!(books.get(1).map(|b| b.abbrev()), Some("ex"));
+    assert_eq!(books.last().map(|b| b.abbrev()), Some("re"));
 }
 
 #[test]
@@ -100,3 +109,144 @@ fn test_bible_book_clone_copy() {
     assert_eq!(book1, book3);
     assert_eq!(book2, book3);
 }
+
+#[test]
+fn test_json_round_trip_with_real_data() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(path) => path,
+        None => {
+            println!("Skipping test_json_round_trip_with_real_data: en_kjv.json not found");
+            return;
+        }
+    };
+
+    let bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let out_path = std::env::temp_dir().join("bible_io_round_trip_kjv.json");
+    let out_path = out_path.to_str().unwrap();
+    bible
+        .save_json(out_path)
+        .expect("Failed to save Bible JSON");
+
+    let reloaded = Bible::new_from_json(out_path).expect("Failed to reload Bible JSON");
+    std::fs::remove_file(out_path).ok();
+
+    assert_eq!(reloaded.name(), bible.name());
+    assert_eq!(reloaded.metadata(), bible.metadata());
+    assert_eq!(reloaded.books().len(), bible.books().len());
+
+    let original = bible.get_verse(BibleBook::Genesis, 1, 2).unwrap();
+    let copy = reloaded.get_verse(BibleBook::Genesis, 1, 2).unwrap();
+    assert_eq!(copy, original);
+    assert_eq!(copy.spans(), original.spans());
+}
+
+#[test]
+fn test_gospels_subset_with_real_data() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(path) => path,
+        None => {
+            println!("Skipping test_gospels_subset_with_real_data: en_kjv.json not found");
+            return;
+        }
+    };
+
+    let bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let gospels = bible.subset(&[ReferenceRange::new(
+        Reference::new(BibleBook::Matthew, 1, 1),
+        Reference::new(BibleBook::John, 21, 25),
+    )]);
+
+    assert_eq!(gospels.books().len(), 4);
+    assert_eq!(gospels.metadata(), bible.metadata());
+    assert_eq!(
+        gospels.get_verse_by_reference("John 3:16").unwrap(),
+        bible.get_verse_by_reference("John 3:16").unwrap()
+    );
+    assert!(gospels.get_book(BibleBook::Acts).is_err());
+
+    let passage = bible.subset(&[ReferenceRange::new(
+        Reference::new(BibleBook::John, 3, 16),
+        Reference::new(BibleBook::John, 3, 17),
+    )]);
+    let out_path = std::env::temp_dir().join("bible_io_subset_kjv.json");
+    let out_path = out_path.to_str().unwrap();
+    passage.save_json(out_path).expect("Failed to save subset");
+    let reloaded = Bible::new_from_json(out_path).expect("Failed to reload subset");
+    std::fs::remove_file(out_path).ok();
+    assert_eq!(
+        reloaded.get_verse(BibleBook::John, 3, 17).unwrap(),
+        bible.get_verse(BibleBook::John, 3, 17).unwrap()
+    );
+}
+
+#[test]
+fn test_tolerant_load_matches_strict_load_with_real_data() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(path) => path,
+        None => {
+            println!(
+                "Skipping test_tolerant_load_matches_strict_load_with_real_data: en_kjv.json not found"
+            );
+            return;
+        }
+    };
+
+    let strict = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let (tolerant, report) =
+        Bible::new_from_json_with_report(&file_path).expect("Failed to load Bible JSON");
+
+    assert!(report.is_clean(), "unexpected issues:\n{}", report);
+    assert_eq!(tolerant.content_hash(), strict.content_hash());
+    assert_eq!(tolerant.name(), strict.name());
+}
+
+#[test]
+fn test_outputs_are_deterministic_with_real_data() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(path) => path,
+        None => {
+            println!(
+                "Skipping test_outputs_are_deterministic_with_real_data: en_kjv.json not found"
+            );
+            return;
+        }
+    };
+
+    let outputs = || {
+        let mut bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+        assert!(!bible.canonicalize());
+        let hits: Vec<String> = bible
+            .search("love")
+            .iter()
+            .map(|verse| verse.reference().to_string())
+            .collect();
+        let mut postings = Vec::new();
+        bible
+            .build_search_index()
+            .write_postings(&mut postings)
+            .unwrap();
+        (bible.to_json().unwrap(), hits, postings)
+    };
+    assert!(outputs() == outputs());
+}
+
+#[test]
+fn test_check_text_with_real_data() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(path) => path,
+        None => {
+            println!("Skipping test_check_text_with_real_data: en_kjv.json not found");
+            return;
+        }
+    };
+
+    let bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let issues = bible.check_text();
+    // The KJV file puts two spaces before its inline marginal notes, and
+    // one note cites "(1ch:)"; it has no markup, entities or mis-decoding.
+    assert!(issues.len() > 100);
+    assert!(issues.iter().all(|issue| matches!(
+        issue.kind(),
+        TextIssueKind::DoubleSpace | TextIssueKind::StrayDigits(_)
+    )));
+}
@@ -1,4 +1,4 @@
-use bible_io::{Bible, BibleError};
+use bible_io::{Bible, BibleBook, BibleError, ReferenceParsing};
 
 mod common;
 use common::test_utils;
@@ -50,6 +50,35 @@ fn test_get_verse_by_reference_valid() {
         .starts_with("The grace of our Lord Jesus Christ be with you all"));
 }
 
+#[test]
+fn test_get_verse_by_reference_numbered_book_prefixes() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(p) => p,
+        None => {
+            println!(
+                "Skipping test_get_verse_by_reference_numbered_book_prefixes: en_kjv.json not found"
+            );
+            return;
+        }
+    };
+
+    let bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let cases = [
+        ("II Samuel 7:12", BibleBook::SecondSamuel),
+        ("First John 4:8", BibleBook::FirstJohn),
+        ("1st Corinthians 13:4", BibleBook::FirstCorinthians),
+        ("iii john 1:4", BibleBook::ThirdJohn),
+        ("Second Tim 3:16", BibleBook::SecondTimothy),
+    ];
+    for (reference, book) in cases {
+        let verse = bible
+            .get_verse_by_reference(reference)
+            .unwrap_or_else(|e| panic!("{reference}: {e}"));
+        assert_eq!(verse.book(), &book, "{reference}");
+    }
+    assert!(bible.get_verse_by_reference("V Samuel 1:1").is_err());
+}
+
 #[test]
 fn test_get_verse_by_reference_invalid() {
     let file_path = match test_utils::get_kjv_json() {
@@ -63,7 +92,17 @@ fn test_get_verse_by_reference_invalid() {
     let bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
     assert!(matches!(
         bible.get_verse_by_reference("Unknown 1:1"),
-        Err(BibleError::BookNotFound { .. })
+        Err(BibleError::BookNotFound { suggestions, .. }) if suggestions.is_empty()
+    ));
+    let err = bible.get_verse_by_reference("Phillemon 1:1").unwrap_err();
+    assert!(matches!(
+        &err,
+        BibleError::BookNotFound { suggestions, .. } if suggestions.first().is_some_and(|s| s == "Philemon")
+    ));
+    assert!(err.to_string().contains("did you mean Philemon"));
+    assert!(matches!(
+        bible.get_book_by_abbrev("Genisis"),
+        Err(BibleError::BookNotFound { suggestions, .. }) if suggestions == ["Genesis"]
     ));
     assert!(matches!(
         bible.get_verse_by_reference("Genesis 1"),
@@ -74,3 +113,82 @@ fn test_get_verse_by_reference_invalid() {
         Err(BibleError::InvalidReference { input }) if input == "Jn3:16"
     ));
 }
+
+#[test]
+fn test_tolerant_parsing_messy_reference_corpus() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(p) => p,
+        None => {
+            println!(
+                "Skipping test_tolerant_parsing_messy_reference_corpus: en_kjv.json not found"
+            );
+            return;
+        }
+    };
+
+    let mut bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let corpus = std::fs::read_to_string("tests/fixtures/messy_references.txt")
+        .expect("Failed to read messy reference corpus");
+    let cases: Vec<(&str, &str)> = corpus
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.is_empty())
+        .map(|line| {
+            line.split_once('\t')
+                .expect("Corpus lines are tab separated")
+        })
+        .collect();
+
+    assert!(matches!(
+        bible.parse_range("John 3.16"),
+        Err(BibleError::InvalidReference { .. })
+    ));
+    bible.set_reference_parsing(ReferenceParsing::Tolerant);
+    for (input, expected) in &cases {
+        let range = bible
+            .parse_range(input)
+            .unwrap_or_else(|e| panic!("Failed to parse {input:?}: {e}"));
+        assert_eq!(range.to_string(), *expected, "parsing {input:?}");
+    }
+    assert!(bible
+        .get_verse_by_reference("Jn3.16")
+        .unwrap()
+        .text()
+        .starts_with("For God so loved the world"));
+    assert!(matches!(
+        bible.get_verse_by_reference("Jn 3"),
+        Err(BibleError::InvalidReference { input }) if input == "Jn 3"
+    ));
+
+    // Mangled variants of the corpus must fail cleanly rather than panic.
+    for (input, _) in &cases {
+        for cut in input.char_indices().map(|(i, _)| i) {
+            let _ = bible.parse_range(&input[..cut]);
+            let _ = bible.parse_range(&input[cut..]);
+            let _ = bible.parse_range(&format!("{}-{}", &input[..cut], &input[cut..]));
+        }
+    }
+}
+
+#[test]
+fn test_verse_ref_display() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(p) => p,
+        None => {
+            println!("Skipping test_verse_ref_display: en_kjv.json not found");
+            return;
+        }
+    };
+
+    let bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let verse = bible
+        .verse_ref(BibleBook::John, 3, 16)
+        .expect("Verse not found");
+    assert_eq!(verse.to_string(), "John 3:16 (KJV)");
+    assert!(verse.text().starts_with("For God so loved the world"));
+
+    let chapter = bible
+        .chapter_ref(BibleBook::Psalms, 23)
+        .expect("Chapter not found");
+    assert_eq!(chapter.to_string(), "Psalms 23 (KJV)");
+    assert_eq!(chapter.verses().count(), 6);
+}
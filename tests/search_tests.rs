@@ -13,7 +13,7 @@ fn search_finds_multiple_books() {
         }
     };
 
-    let mut bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
     let index = bible.build_search_index();
     let query = "in the beginning";
     let search_results = bible.search(query);
@@ -40,6 +40,13 @@ fn search_finds_multiple_books() {
         })
         .collect();
     assert_eq!(search_results, verses_from_index);
+
+    let from_backend: Vec<_> = bible
+        .search_with(&index, query)
+        .into_iter()
+        .cloned()
+        .collect();
+    assert_eq!(search_results, from_backend);
 }
 
 #[test]
@@ -52,7 +59,7 @@ fn search_is_case_insensitive() {
         }
     };
 
-    let mut bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
     let index = bible.build_search_index();
     let query = "REJOICE EVERMORE";
     let search_results = bible.search(query);
@@ -75,3 +82,34 @@ fn search_is_case_insensitive() {
         .collect();
     assert_eq!(search_results, verses_from_index);
 }
+
+#[test]
+fn search_index_is_compact() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(path) => path,
+        None => {
+            println!("Skipping search_index_is_compact: en_kjv.json not found");
+            return;
+        }
+    };
+
+    let bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let index = bible.build_search_index();
+    let verse_count: usize = bible
+        .books()
+        .iter()
+        .flat_map(|b| b.chapters())
+        .map(|c| c.get_verses().len())
+        .sum();
+
+    // Well under a 32-byte (BookId, usize, usize) tuple per posting.
+    let word_count: usize = bible
+        .books()
+        .iter()
+        .flat_map(|b| b.chapters())
+        .flat_map(|c| c.get_verses())
+        .map(|v| v.word_count())
+        .sum();
+    assert!(index.memory_usage() < word_count * 8);
+    assert!(index.memory_usage() > verse_count);
+}
@@ -1,4 +1,4 @@
-use bible_io::{Bible, BibleBook};
+use bible_io::{Bible, BibleBook, LoadOptions, TextPolicy};
 
 mod common;
 use common::test_utils;
@@ -99,3 +99,68 @@ fn test_first_and_last_verses_of_each_book() {
         assert_eq!(format!("{}: {}", l_vs, l_text), format!("{}", verse));
     }
 }
+
+#[test]
+fn test_psalm_superscriptions_are_split_from_verse_one() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(path) => path,
+        None => {
+            println!("Skipping test_psalm_superscriptions_are_split_from_verse_one: en_kjv.json not found");
+            return;
+        }
+    };
+
+    let plain = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let psalm_3 = plain.get_verse(BibleBook::Psalms, 3, 1).unwrap();
+    assert!(psalm_3.text().starts_with("[A Psalm of David"));
+
+    let options = LoadOptions::new().with_bracketed_superscriptions(true);
+    let bible =
+        Bible::new_from_json_with_options(&file_path, &options).expect("Failed to load Bible JSON");
+    let psalms = bible.get_book(BibleBook::Psalms).unwrap();
+
+    let psalm_3 = psalms.get_chapter(3).unwrap();
+    assert_eq!(
+        psalm_3.superscription(),
+        Some("A Psalm of David, when he fled from Absalom his son.")
+    );
+    assert!(psalm_3
+        .get_verse(1)
+        .unwrap()
+        .text()
+        .starts_with("LORD, how are they increased"));
+
+    assert_eq!(psalms.get_chapter(1).unwrap().superscription(), None);
+    assert_eq!(
+        psalms
+            .chapters()
+            .iter()
+            .filter(|c| c.superscription().is_some())
+            .count(),
+        115
+    );
+}
+
+#[test]
+fn test_text_policy_with_real_data() {
+    let file_path = match test_utils::get_kjv_json() {
+        Some(path) => path,
+        None => {
+            println!("Skipping test_text_policy_with_real_data: en_kjv.json not found");
+            return;
+        }
+    };
+
+    let bible = Bible::new_from_json(&file_path).expect("Failed to load Bible JSON");
+    let verse = bible.get_verse(BibleBook::Psalms, 3, 4).unwrap();
+    assert!(verse.contains_selah());
+    assert!(verse.contains_divine_name());
+
+    let policy = TextPolicy::new()
+        .with_selah(false)
+        .with_divine_name_capitals(false);
+    assert_eq!(
+        verse.text_with_policy(&policy),
+        "I cried unto the Lord with my voice, and he heard me out of his holy hill."
+    );
+}